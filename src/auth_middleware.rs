@@ -17,7 +17,10 @@ use std::{collections::HashMap, env, io::Cursor};
 use std::{collections::HashSet, sync::RwLock};
 use uuid::Uuid;
 
-use crate::{server_error, server_info, service::user_service};
+use crate::{
+    server_error, server_info,
+    service::{app_password_service, user_service},
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -25,12 +28,15 @@ pub struct Claims {
     pub sub: String,     // 사용자 이메일
     pub company: String, // 회사
     pub exp: u64,
+    #[serde(default)]
+    pub jti: String, // 같은 초에 발급된 토큰도 구분되도록
 }
 
 // 인증 정보를 저장할 구조체
 pub struct AuthCache {
     pub auth_failures: RwLock<HashMap<String, String>>,
     pub user_emails: RwLock<HashMap<String, String>>,
+    pub session_ids: RwLock<HashMap<String, i32>>,
 }
 
 impl AuthCache {
@@ -38,6 +44,7 @@ impl AuthCache {
         Self {
             auth_failures: RwLock::new(HashMap::new()),
             user_emails: RwLock::new(HashMap::new()),
+            session_ids: RwLock::new(HashMap::new()),
         }
     }
 }
@@ -184,40 +191,36 @@ impl Fairing for AuthMiddleware {
             Ok(token_data) => {
                 let user_email = token_data.claims.sub;
 
-                // 토큰 DB 검증
-                match user_service::vliadate_access_token(
+                // 토큰 DB 검증 (로그아웃된 세션의 토큰은 거부)
+                match user_service::find_session_id_by_access_token(
                     &self.pool,
                     token.to_string(),
                     user_email.clone(),
                 )
                 .await
                 {
-                    Ok(res) => {
-                        if res {
-                            // 토큰 검증 성공
-                            server_info!("Token validation successful for user: {}", user_email);
-                        } else {
-                            auth_cache.auth_failures.write().unwrap().insert(
-                                request_id.clone(),
-                                "사용자 이메일이 잘못되었습니다.".to_string(),
-                            );
-                        }
+                    Ok(session_id) => {
+                        // 인증 성공
+                        server_info!("Token validation successful for user: {}", user_email);
+                        auth_cache
+                            .session_ids
+                            .write()
+                            .unwrap()
+                            .insert(request_id.clone(), session_id);
+                        auth_cache
+                            .user_emails
+                            .write()
+                            .unwrap()
+                            .insert(request_id, user_email);
                     }
                     Err(_) => {
                         auth_cache
                             .auth_failures
                             .write()
                             .unwrap()
-                            .insert(request_id.clone(), "유효하지 않은 토큰입니다.".to_string());
+                            .insert(request_id, "유효하지 않은 토큰입니다.".to_string());
                     }
                 };
-
-                // 인증 성공
-                auth_cache
-                    .user_emails
-                    .write()
-                    .unwrap()
-                    .insert(request_id, user_email);
             }
             Err(e) => {
                 server_error!("JWT decode error: {}", e);
@@ -270,6 +273,7 @@ impl Fairing for AuthMiddleware {
             // 요청 처리가 끝났으므로 캐시에서 정보 정리
             auth_cache.auth_failures.write().unwrap().remove(request_id);
             auth_cache.user_emails.write().unwrap().remove(request_id);
+            auth_cache.session_ids.write().unwrap().remove(request_id);
         }
     }
 }
@@ -278,6 +282,8 @@ impl Fairing for AuthMiddleware {
 #[derive(JsonSchema)]
 pub struct AuthenticatedUser {
     pub user_email: String,
+    /// 요청에 사용된 토큰의 로그인 세션
+    pub session_id: Option<i32>,
 }
 
 #[rocket::async_trait]
@@ -301,7 +307,16 @@ impl<'r> FromRequest<'r> for AuthenticatedUser {
             // 인증된 사용자 이메일 확인
             let user_emails = auth_cache.user_emails.read().unwrap();
             if let Some(user_email) = user_emails.get(request_id).cloned() {
-                return Outcome::Success(AuthenticatedUser { user_email });
+                let session_id = auth_cache
+                    .session_ids
+                    .read()
+                    .unwrap()
+                    .get(request_id)
+                    .copied();
+                return Outcome::Success(AuthenticatedUser {
+                    user_email,
+                    session_id,
+                });
             }
         }
 
@@ -343,3 +358,68 @@ impl<'a> OpenApiFromRequest<'a> for AuthenticatedUser {
         ))
     }
 }
+
+// Google Reader API 클라이언트용 Request Guard
+// `Authorization: GoogleLogin auth=<앱 비밀번호>` 헤더의 앱 비밀번호 소유자로 인증
+pub struct GoogleReaderUser {
+    pub user_id: i32,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for GoogleReaderUser {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let token = match req
+            .headers()
+            .get_one("Authorization")
+            .and_then(|header| header.strip_prefix("GoogleLogin auth="))
+        {
+            Some(token) => token.trim(),
+            None => {
+                return Outcome::Error((
+                    Status::Unauthorized,
+                    "인증 토큰이 없거나 올바르지 않습니다.",
+                ));
+            }
+        };
+
+        let Some(pool) = req.rocket().state::<MySqlPool>() else {
+            server_error!("Error: MySqlPool not found");
+            return Outcome::Error((Status::InternalServerError, "서버 구성 오류입니다."));
+        };
+
+        match app_password_service::find_user_id_by_app_password(pool, token).await {
+            Ok(user_id) => Outcome::Success(GoogleReaderUser { user_id }),
+            Err(_) => Outcome::Error((Status::Unauthorized, "유효하지 않은 토큰입니다.")),
+        }
+    }
+}
+
+// rapidoc, swagger-ui 전용
+#[allow(clippy::needless_lifetimes)]
+impl<'a> OpenApiFromRequest<'a> for GoogleReaderUser {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        let security_scheme = SecurityScheme {
+            description: Some(
+                "Requires `Authorization: GoogleLogin auth=<app password>` header.".to_owned(),
+            ),
+            data: SecuritySchemeData::ApiKey {
+                name: "Authorization".to_owned(),
+                location: "header".to_owned(),
+            },
+            extensions: Object::default(),
+        };
+        let mut security_req = SecurityRequirement::new();
+        security_req.insert("GoogleLogin".to_owned(), Vec::new());
+        Ok(RequestHeaderInput::Security(
+            "GoogleLogin".to_owned(),
+            security_scheme,
+            security_req,
+        ))
+    }
+}
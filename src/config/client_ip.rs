@@ -0,0 +1,114 @@
+//! ------ Client IP behind a trusted proxy ------
+//!
+//! Rocket의 `client_ip()`는 `X-Real-IP` 헤더를 그대로 믿으므로 클라이언트가 값을 바꿀 수 있고,
+//! 프록시가 헤더를 넘기지 않으면 모든 요청이 프록시 IP 하나로 보입니다.
+//! `TRUSTED_PROXIES`(쉼표로 구분한 IP 목록)에 있는 주소에서 온 요청만 `X-Forwarded-For`를 따릅니다.
+
+use std::{env, net::IpAddr, sync::LazyLock};
+
+use rocket::request::{self, FromRequest, Outcome, Request};
+use rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+};
+
+static TRUSTED_PROXIES: LazyLock<Vec<IpAddr>> = LazyLock::new(|| {
+    env::var("TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect()
+});
+
+/// 요청한 클라이언트 IP, 알 수 없으면 `None`
+pub struct ClientIp(pub Option<IpAddr>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ClientIp {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        Outcome::Success(ClientIp(resolve_client_ip(
+            req.remote().map(|addr| addr.ip()),
+            &req.headers().get("X-Forwarded-For").collect::<Vec<_>>(),
+            &TRUSTED_PROXIES,
+        )))
+    }
+}
+
+impl<'r> OpenApiFromRequest<'r> for ClientIp {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::None)
+    }
+}
+
+/// 신뢰하는 프록시를 거친 경우 `X-Forwarded-For`를 오른쪽부터 보며 처음 나오는
+/// 신뢰하지 않는 주소를 클라이언트로 봄 (왼쪽 값은 클라이언트가 임의로 넣을 수 있음)
+fn resolve_client_ip(
+    remote: Option<IpAddr>,
+    forwarded_for: &[&str],
+    trusted_proxies: &[IpAddr],
+) -> Option<IpAddr> {
+    let remote = remote?;
+    if !trusted_proxies.contains(&remote) {
+        return Some(remote);
+    }
+
+    for value in forwarded_for
+        .iter()
+        .rev()
+        .flat_map(|header| header.rsplit(','))
+    {
+        let ip: IpAddr = value.trim().parse().ok()?;
+        if !trusted_proxies.contains(&ip) {
+            return Some(ip);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn ignores_forwarded_header_from_untrusted_remote() {
+        let trusted = [ip("10.0.0.2")];
+        assert_eq!(
+            resolve_client_ip(Some(ip("203.0.113.7")), &["198.51.100.1"], &trusted),
+            Some(ip("203.0.113.7"))
+        );
+    }
+
+    #[test]
+    fn takes_rightmost_untrusted_forwarded_address() {
+        let trusted = [ip("10.0.0.2"), ip("10.0.0.3")];
+        assert_eq!(
+            resolve_client_ip(
+                Some(ip("10.0.0.2")),
+                &["1.2.3.4, 203.0.113.7", "10.0.0.3"],
+                &trusted
+            ),
+            Some(ip("203.0.113.7"))
+        );
+    }
+
+    #[test]
+    fn unknown_when_trusted_proxy_sends_no_usable_address() {
+        let trusted = [ip("10.0.0.2")];
+        assert_eq!(resolve_client_ip(Some(ip("10.0.0.2")), &[], &trusted), None);
+        assert_eq!(
+            resolve_client_ip(Some(ip("10.0.0.2")), &["unknown"], &trusted),
+            None
+        );
+        assert_eq!(resolve_client_ip(None, &[], &trusted), None);
+    }
+}
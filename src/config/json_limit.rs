@@ -0,0 +1,64 @@
+//! ------ Route-specific JSON body limit ------
+//!
+//! Rocket의 `json` 제한은 모든 JSON 요청에 적용되므로, 큰 본문이 필요한 라우트는
+//! Rocket.toml `[default.limits]`의 별도 이름으로 제한을 읽어 파싱합니다.
+
+use rocket::{
+    data::{self, Data, FromData, ToByteUnit},
+    http::Status,
+    request::Request,
+    serde::json::Json,
+};
+use rocket_okapi::{gen::OpenApiGenerator, okapi::openapi3::RequestBody, request::OpenApiFromData};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+/// 피드백 첨부 파일(base64)을 받는 라우트용 제한 이름
+pub const FEEDBACK_LIMIT: &str = "feedback";
+const DEFAULT_FEEDBACK_LIMIT_MIB: u8 = 16;
+
+/// `feedback` 제한으로 읽는 JSON 본문
+pub struct FeedbackJson<T>(pub T);
+
+impl<T> FeedbackJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for FeedbackJson<T> {
+    type Error = String;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        if !req.content_type().is_some_and(|ct| ct.is_json()) {
+            return data::Outcome::Forward((data, Status::UnsupportedMediaType));
+        }
+
+        let limit = req
+            .limits()
+            .get(FEEDBACK_LIMIT)
+            .unwrap_or_else(|| DEFAULT_FEEDBACK_LIMIT_MIB.mebibytes());
+        let body = match data.open(limit).into_string().await {
+            Ok(body) if body.is_complete() => body.into_inner(),
+            Ok(_) => {
+                return data::Outcome::Error((
+                    Status::PayloadTooLarge,
+                    "Feedback body is too large".to_string(),
+                ))
+            }
+            Err(e) => return data::Outcome::Error((Status::BadRequest, e.to_string())),
+        };
+
+        match serde_json::from_str(&body) {
+            Ok(value) => data::Outcome::Success(FeedbackJson(value)),
+            Err(e) => data::Outcome::Error((Status::UnprocessableEntity, e.to_string())),
+        }
+    }
+}
+
+impl<'r, T: JsonSchema + DeserializeOwned> OpenApiFromData<'r> for FeedbackJson<T> {
+    fn request_body(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<RequestBody> {
+        Json::<T>::request_body(gen)
+    }
+}
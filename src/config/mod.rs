@@ -1,5 +1,7 @@
+pub mod client_ip;
 pub mod env;
 pub mod http_auth;
+pub mod json_limit;
 pub mod logging;
 pub mod openapi;
 pub mod rapidoc;
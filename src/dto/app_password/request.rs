@@ -0,0 +1,12 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AppPasswordRequestDto {
+    #[schemars(example = "example_app_password_name")]
+    pub app_password_name: Option<String>,
+}
+
+fn example_app_password_name() -> &'static str {
+    "Reeder"
+}
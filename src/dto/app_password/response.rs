@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::app_password::AppPassword;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AppPasswordResponseDto {
+    #[schemars(example = "example_app_password_id")]
+    pub app_password_id: Option<i32>,
+    #[schemars(example = "example_app_password_name")]
+    pub app_password_name: Option<String>,
+    #[schemars(example = "example_date")]
+    pub app_password_created_at: Option<NaiveDateTime>,
+    /// 마지막으로 RSS 리더에서 사용한 시각
+    #[schemars(example = "example_date")]
+    pub app_password_last_used_at: Option<NaiveDateTime>,
+}
+
+/// 비밀번호 원문은 발급할 때만 반환
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AppPasswordCreateResponseDto {
+    #[schemars(example = "example_app_password_id")]
+    pub app_password_id: Option<i32>,
+    #[schemars(example = "example_app_password")]
+    pub app_password: Option<String>,
+}
+
+impl AppPasswordResponseDto {
+    pub fn from_model(app_password: AppPassword) -> Self {
+        Self {
+            app_password_id: app_password.app_password_id,
+            app_password_name: app_password.app_password_name,
+            app_password_created_at: app_password.app_password_created_at,
+            app_password_last_used_at: app_password.app_password_last_used_at,
+        }
+    }
+
+    pub fn from_model_list(app_passwords: Vec<AppPassword>) -> Vec<Self> {
+        app_passwords.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl AppPasswordCreateResponseDto {
+    pub fn new(app_password_id: i32, app_password: String) -> Self {
+        Self {
+            app_password_id: Some(app_password_id),
+            app_password: Some(app_password),
+        }
+    }
+}
+
+fn example_app_password_id() -> i32 {
+    4
+}
+
+fn example_app_password_name() -> &'static str {
+    "Reeder"
+}
+
+fn example_date() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_app_password() -> &'static str {
+    "3f9a0c1b2d4e5f6a7b8c9d0e1f2a3b4c"
+}
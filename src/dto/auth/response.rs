@@ -2,7 +2,10 @@ use chrono::NaiveDateTime;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::auth::{AccessToken, JwtToken};
+use crate::model::{
+    auth::{AccessToken, JwtToken},
+    session::UserSession,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct JwtTokenResponseDto {
@@ -24,6 +27,23 @@ pub struct AccessTokenResponseDto {
     pub access_token_expires_at: Option<NaiveDateTime>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionResponseDto {
+    #[schemars(example = "example_session_id")]
+    pub session_id: Option<i32>,
+    #[schemars(example = "example_session_device_name")]
+    pub session_device_name: Option<String>,
+    #[schemars(example = "example_session_platform")]
+    pub session_platform: Option<String>,
+    #[schemars(example = "example_session_created_at")]
+    pub session_created_at: Option<NaiveDateTime>,
+    #[schemars(example = "example_session_last_seen_at")]
+    pub session_last_seen_at: Option<NaiveDateTime>,
+    /// 이 요청을 보낸 세션인지 여부
+    #[schemars(example = "example_is_current")]
+    pub is_current: Option<bool>,
+}
+
 impl JwtTokenResponseDto {
     pub fn from_model(jwt_token: JwtToken) -> Self {
         JwtTokenResponseDto {
@@ -44,6 +64,21 @@ impl AccessTokenResponseDto {
     }
 }
 
+impl SessionResponseDto {
+    pub fn from_model(session: UserSession, current_session_id: Option<i32>) -> Self {
+        SessionResponseDto {
+            is_current: Some(
+                session.session_id.is_some() && session.session_id == current_session_id,
+            ),
+            session_id: session.session_id,
+            session_device_name: session.session_device_name,
+            session_platform: session.session_platform,
+            session_created_at: session.session_created_at,
+            session_last_seen_at: session.session_last_seen_at,
+        }
+    }
+}
+
 fn example_access_token() -> &'static str {
     "eyJ0eXAiOiJKV1QiLCJhbGciOiJIUz..."
 }
@@ -59,3 +94,27 @@ fn example_refresh_token() -> &'static str {
 fn example_refresh_token_expires() -> &'static str {
     "2016-07-09 12:30:00"
 }
+
+fn example_session_id() -> i32 {
+    12
+}
+
+fn example_session_device_name() -> &'static str {
+    "홍길동의 iPhone"
+}
+
+fn example_session_platform() -> &'static str {
+    "ios"
+}
+
+fn example_session_created_at() -> &'static str {
+    "2025-05-01 09:00:00"
+}
+
+fn example_session_last_seen_at() -> &'static str {
+    "2025-06-01 21:14:00"
+}
+
+fn example_is_current() -> bool {
+    true
+}
@@ -0,0 +1,80 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SaveBookmarkRequestDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_collection_id")]
+    pub collection_id: Option<i32>,
+    #[schemars(example = "example_bookmark_note")]
+    pub bookmark_note: Option<String>,
+    #[schemars(example = "example_tags")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateBookmarkRequestDto {
+    #[schemars(example = "example_collection_id")]
+    pub collection_id: Option<i32>,
+    #[schemars(example = "example_bookmark_note")]
+    pub bookmark_note: Option<String>,
+    #[schemars(example = "example_tags")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct BookmarkListRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+    #[schemars(example = "example_q")]
+    pub q: Option<String>,
+    #[schemars(example = "example_collection_id")]
+    pub collection_id: Option<i32>,
+    #[schemars(example = "example_tag")]
+    pub tag: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BookmarkCollectionRequestDto {
+    #[schemars(example = "example_collection_name")]
+    pub collection_name: Option<String>,
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_collection_id() -> i32 {
+    2
+}
+
+fn example_bookmark_note() -> &'static str {
+    "주말에 다시 읽기"
+}
+
+fn example_tags() -> Vec<&'static str> {
+    vec!["반도체", "리포트"]
+}
+
+fn example_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_q() -> &'static str {
+    "반도체"
+}
+
+fn example_tag() -> &'static str {
+    "리포트"
+}
+
+fn example_collection_name() -> &'static str {
+    "나중에 읽기"
+}
@@ -0,0 +1,147 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::bookmark::{Bookmark, BookmarkCollection};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BookmarkResponseDto {
+    #[schemars(example = "example_bookmark_id")]
+    pub bookmark_id: Option<i32>,
+    /// 원본 아이템이 삭제된 경우 null
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_collection_id")]
+    pub collection_id: Option<i32>,
+    #[schemars(example = "example_bookmark_title")]
+    pub bookmark_title: Option<String>,
+    #[schemars(example = "example_bookmark_link")]
+    pub bookmark_link: Option<String>,
+    #[schemars(example = "example_bookmark_image_link")]
+    pub bookmark_image_link: Option<String>,
+    #[schemars(example = "example_bookmark_channel_title")]
+    pub bookmark_channel_title: Option<String>,
+    #[schemars(example = "example_bookmark_note")]
+    pub bookmark_note: Option<String>,
+    #[schemars(example = "example_tags")]
+    pub tags: Option<Vec<String>>,
+    #[schemars(example = "example_bookmark_created_at")]
+    pub bookmark_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BookmarkPageResponseDto {
+    pub bookmarks: Option<Vec<BookmarkResponseDto>>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    #[schemars(example = "example_has_next")]
+    pub has_next: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BookmarkCollectionResponseDto {
+    #[schemars(example = "example_collection_id")]
+    pub collection_id: Option<i32>,
+    #[schemars(example = "example_collection_name")]
+    pub collection_name: Option<String>,
+    #[schemars(example = "example_bookmark_count")]
+    pub bookmark_count: Option<i64>,
+}
+
+impl BookmarkResponseDto {
+    pub fn new(bookmark: Bookmark, tags: Vec<String>) -> Self {
+        Self {
+            bookmark_id: bookmark.bookmark_id,
+            rss_id: bookmark.rss_id,
+            collection_id: bookmark.collection_id,
+            bookmark_title: bookmark.bookmark_title,
+            bookmark_link: bookmark.bookmark_link,
+            bookmark_image_link: bookmark.bookmark_image_link,
+            bookmark_channel_title: bookmark.bookmark_channel_title,
+            bookmark_note: bookmark.bookmark_note,
+            tags: Some(tags),
+            bookmark_created_at: bookmark.bookmark_created_at,
+        }
+    }
+}
+
+impl BookmarkPageResponseDto {
+    pub fn new(bookmarks: Vec<BookmarkResponseDto>, next_cursor: Option<String>) -> Self {
+        Self {
+            bookmarks: Some(bookmarks),
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+        }
+    }
+}
+
+impl BookmarkCollectionResponseDto {
+    pub fn from_model(collection: BookmarkCollection) -> Self {
+        Self {
+            collection_id: collection.collection_id,
+            collection_name: collection.collection_name,
+            bookmark_count: Some(collection.bookmark_count.unwrap_or_default()),
+        }
+    }
+
+    pub fn from_model_list(collections: Vec<BookmarkCollection>) -> Vec<Self> {
+        collections.into_iter().map(Self::from_model).collect()
+    }
+}
+
+fn example_bookmark_id() -> i32 {
+    123
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_collection_id() -> i32 {
+    2
+}
+
+fn example_bookmark_title() -> &'static str {
+    "반도체 수출 3개월 연속 증가"
+}
+
+fn example_bookmark_link() -> &'static str {
+    "https://example.com/item"
+}
+
+fn example_bookmark_image_link() -> &'static str {
+    "https://example.com/item_image.png"
+}
+
+fn example_bookmark_channel_title() -> &'static str {
+    "Example Channel"
+}
+
+fn example_bookmark_note() -> &'static str {
+    "주말에 다시 읽기"
+}
+
+fn example_tags() -> Vec<&'static str> {
+    vec!["반도체", "리포트"]
+}
+
+fn example_bookmark_created_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_next_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_has_next() -> bool {
+    true
+}
+
+fn example_collection_name() -> &'static str {
+    "나중에 읽기"
+}
+
+fn example_bookmark_count() -> i64 {
+    8
+}
@@ -0,0 +1,49 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::digest::DigestFrequency;
+
+/// 생략한 항목은 기존 값(처음이면 기본값) 유지
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DigestSettingsRequestDto {
+    #[schemars(example = "example_digest_enabled")]
+    pub digest_enabled: Option<bool>,
+    #[schemars(example = "example_digest_frequency")]
+    pub digest_frequency: Option<DigestFrequency>,
+    /// 발송 시각 (0~23, `digest_timezone` 기준)
+    #[schemars(example = "example_digest_hour")]
+    pub digest_hour: Option<i32>,
+    /// 주간 발송 요일 (0=월 ~ 6=일)
+    #[schemars(example = "example_digest_weekday")]
+    pub digest_weekday: Option<i32>,
+    /// IANA 시간대 이름
+    #[schemars(example = "example_digest_timezone")]
+    pub digest_timezone: Option<String>,
+    /// 저장된 AI 요약이 있으면 함께 보냄
+    #[schemars(example = "example_digest_include_summary")]
+    pub digest_include_summary: Option<bool>,
+}
+
+fn example_digest_enabled() -> bool {
+    true
+}
+
+fn example_digest_frequency() -> DigestFrequency {
+    DigestFrequency::Daily
+}
+
+fn example_digest_hour() -> i32 {
+    8
+}
+
+fn example_digest_weekday() -> i32 {
+    0
+}
+
+fn example_digest_timezone() -> &'static str {
+    "Asia/Seoul"
+}
+
+fn example_digest_include_summary() -> bool {
+    false
+}
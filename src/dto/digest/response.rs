@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::digest::{DigestFrequency, UserDigest};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DigestSettingsResponseDto {
+    #[schemars(example = "example_digest_enabled")]
+    pub digest_enabled: Option<bool>,
+    #[schemars(example = "example_digest_frequency")]
+    pub digest_frequency: Option<DigestFrequency>,
+    #[schemars(example = "example_digest_hour")]
+    pub digest_hour: Option<i32>,
+    #[schemars(example = "example_digest_weekday")]
+    pub digest_weekday: Option<i32>,
+    #[schemars(example = "example_digest_timezone")]
+    pub digest_timezone: Option<String>,
+    #[schemars(example = "example_digest_include_summary")]
+    pub digest_include_summary: Option<bool>,
+    /// 다음 발송 예정 시각 (KST)
+    #[schemars(example = "example_digest_sent_at")]
+    pub digest_next_send_at: Option<NaiveDateTime>,
+    #[schemars(example = "example_digest_sent_at")]
+    pub digest_last_sent_at: Option<NaiveDateTime>,
+}
+
+impl DigestSettingsResponseDto {
+    pub fn from_model(digest: UserDigest) -> Self {
+        Self {
+            digest_enabled: digest.digest_enabled,
+            digest_frequency: Some(digest.frequency()),
+            digest_hour: digest.digest_hour,
+            digest_weekday: digest.digest_weekday,
+            digest_timezone: digest.digest_timezone,
+            digest_include_summary: digest.digest_include_summary,
+            digest_next_send_at: digest.digest_next_send_at,
+            digest_last_sent_at: digest.digest_last_sent_at,
+        }
+    }
+}
+
+fn example_digest_enabled() -> bool {
+    true
+}
+
+fn example_digest_frequency() -> DigestFrequency {
+    DigestFrequency::Daily
+}
+
+fn example_digest_hour() -> i32 {
+    8
+}
+
+fn example_digest_weekday() -> i32 {
+    0
+}
+
+fn example_digest_timezone() -> &'static str {
+    "Asia/Seoul"
+}
+
+fn example_digest_include_summary() -> bool {
+    false
+}
+
+fn example_digest_sent_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 08:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
@@ -0,0 +1,74 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::feed_rule::{
+    FeedRuleAction, FeedRuleActionType, FeedRuleCondition, FeedRuleConditionType,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleRequestDto {
+    #[schemars(example = "example_feed_rule_name")]
+    pub feed_rule_name: Option<String>,
+    #[schemars(example = "example_conditions")]
+    pub conditions: Option<Vec<FeedRuleCondition>>,
+    #[schemars(example = "example_actions")]
+    pub actions: Option<Vec<FeedRuleAction>>,
+    #[schemars(example = "example_feed_rule_enabled")]
+    pub feed_rule_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeedRuleTaggedItemRequestDto {
+    #[schemars(example = "example_tag")]
+    pub tag: Option<String>,
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+}
+
+fn example_feed_rule_name() -> &'static str {
+    "반도체 리포트"
+}
+
+fn example_conditions() -> Vec<FeedRuleCondition> {
+    vec![
+        FeedRuleCondition {
+            condition_type: FeedRuleConditionType::Keyword,
+            value: "반도체".to_string(),
+        },
+        FeedRuleCondition {
+            condition_type: FeedRuleConditionType::Folder,
+            value: "3".to_string(),
+        },
+    ]
+}
+
+fn example_actions() -> Vec<FeedRuleAction> {
+    vec![
+        FeedRuleAction {
+            action_type: FeedRuleActionType::Tag,
+            value: Some("반도체".to_string()),
+        },
+        FeedRuleAction {
+            action_type: FeedRuleActionType::Notify,
+            value: None,
+        },
+    ]
+}
+
+fn example_feed_rule_enabled() -> bool {
+    true
+}
+
+fn example_tag() -> &'static str {
+    "반도체"
+}
+
+fn example_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_size() -> i32 {
+    20
+}
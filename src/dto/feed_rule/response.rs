@@ -0,0 +1,118 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dto::rss::response::RssItemResponseDto,
+    model::{
+        feed_rule::{FeedRule, FeedRuleAction, FeedRuleCondition},
+        rss::RssTagCount,
+    },
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleResponseDto {
+    #[schemars(example = "example_feed_rule_id")]
+    pub feed_rule_id: Option<i32>,
+    #[schemars(example = "example_feed_rule_name")]
+    pub feed_rule_name: Option<String>,
+    pub conditions: Option<Vec<FeedRuleCondition>>,
+    pub actions: Option<Vec<FeedRuleAction>>,
+    #[schemars(example = "example_feed_rule_enabled")]
+    pub feed_rule_enabled: Option<bool>,
+    #[schemars(example = "example_datetime")]
+    pub feed_rule_created_at: Option<NaiveDateTime>,
+    #[schemars(example = "example_datetime")]
+    pub feed_rule_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleDryRunResponseDto {
+    /// 확인한 최근 구독 아이템 수
+    #[schemars(example = "example_checked_count")]
+    pub checked_count: Option<i32>,
+    /// 규칙 조건을 만족하는 아이템 수
+    #[schemars(example = "example_matched_count")]
+    pub matched_count: Option<i32>,
+    /// 조건을 만족하는 아이템 (최대 20개)
+    pub items: Option<Vec<RssItemResponseDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleTagResponseDto {
+    #[schemars(example = "example_tag_name")]
+    pub tag_name: Option<String>,
+    #[schemars(example = "example_tag_count")]
+    pub tag_count: Option<i64>,
+}
+
+impl FeedRuleResponseDto {
+    pub fn from_model(rule: FeedRule) -> Self {
+        Self {
+            conditions: Some(rule.conditions()),
+            actions: Some(rule.actions()),
+            feed_rule_id: rule.feed_rule_id,
+            feed_rule_name: rule.feed_rule_name,
+            feed_rule_enabled: rule.feed_rule_enabled,
+            feed_rule_created_at: rule.feed_rule_created_at,
+            feed_rule_updated_at: rule.feed_rule_updated_at,
+        }
+    }
+
+    pub fn from_model_list(rules: Vec<FeedRule>) -> Vec<Self> {
+        rules.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl FeedRuleDryRunResponseDto {
+    pub fn new(checked_count: i32, matched_count: i32, items: Vec<RssItemResponseDto>) -> Self {
+        Self {
+            checked_count: Some(checked_count),
+            matched_count: Some(matched_count),
+            items: Some(items),
+        }
+    }
+}
+
+impl FeedRuleTagResponseDto {
+    pub fn from_model_list(tags: Vec<RssTagCount>) -> Vec<Self> {
+        tags.into_iter()
+            .map(|tag| Self {
+                tag_name: tag.tag_name,
+                tag_count: tag.tag_count,
+            })
+            .collect()
+    }
+}
+
+fn example_feed_rule_id() -> i32 {
+    7
+}
+
+fn example_feed_rule_name() -> &'static str {
+    "반도체 리포트"
+}
+
+fn example_feed_rule_enabled() -> bool {
+    true
+}
+
+fn example_datetime() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_checked_count() -> i32 {
+    200
+}
+
+fn example_matched_count() -> i32 {
+    8
+}
+
+fn example_tag_name() -> &'static str {
+    "반도체"
+}
+
+fn example_tag_count() -> i64 {
+    15
+}
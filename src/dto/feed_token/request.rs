@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedTokenRequestDto {
+    #[schemars(example = "example_feed_token_name")]
+    pub feed_token_name: Option<String>,
+}
+
+/// 둘 다 생략하면 구독 타임라인 전체
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeedTokenScopeRequestDto {
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+}
+
+fn example_feed_token_name() -> &'static str {
+    "NetNewsWire"
+}
+
+fn example_folder_id() -> i32 {
+    3
+}
+
+fn example_channel_id() -> i32 {
+    12
+}
@@ -0,0 +1,95 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::feed_token::FeedToken;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedTokenResponseDto {
+    #[schemars(example = "example_feed_token_id")]
+    pub feed_token_id: Option<i32>,
+    #[schemars(example = "example_feed_token_name")]
+    pub feed_token_name: Option<String>,
+    #[schemars(example = "example_date")]
+    pub feed_token_created_at: Option<NaiveDateTime>,
+    /// 마지막으로 피드를 조회한 시각
+    #[schemars(example = "example_date")]
+    pub feed_token_last_used_at: Option<NaiveDateTime>,
+}
+
+/// 토큰 원문은 발급할 때만 반환
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedTokenCreateResponseDto {
+    #[schemars(example = "example_feed_token_id")]
+    pub feed_token_id: Option<i32>,
+    #[schemars(example = "example_feed_token")]
+    pub feed_token: Option<String>,
+    /// 구독 타임라인 RSS 피드 주소 (`folder_id`, `channel_id` 쿼리로 범위 지정)
+    #[schemars(example = "example_rss_url")]
+    pub rss_url: Option<String>,
+    #[schemars(example = "example_atom_url")]
+    pub atom_url: Option<String>,
+    #[schemars(example = "example_json_url")]
+    pub json_url: Option<String>,
+}
+
+impl FeedTokenResponseDto {
+    pub fn from_model(token: FeedToken) -> Self {
+        Self {
+            feed_token_id: token.feed_token_id,
+            feed_token_name: token.feed_token_name,
+            feed_token_created_at: token.feed_token_created_at,
+            feed_token_last_used_at: token.feed_token_last_used_at,
+        }
+    }
+
+    pub fn from_model_list(tokens: Vec<FeedToken>) -> Vec<Self> {
+        tokens.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl FeedTokenCreateResponseDto {
+    pub fn new(
+        feed_token_id: i32,
+        feed_token: String,
+        rss_url: String,
+        atom_url: String,
+        json_url: String,
+    ) -> Self {
+        Self {
+            feed_token_id: Some(feed_token_id),
+            feed_token: Some(feed_token),
+            rss_url: Some(rss_url),
+            atom_url: Some(atom_url),
+            json_url: Some(json_url),
+        }
+    }
+}
+
+fn example_feed_token_id() -> i32 {
+    2
+}
+
+fn example_feed_token_name() -> &'static str {
+    "NetNewsWire"
+}
+
+fn example_date() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_feed_token() -> &'static str {
+    "8c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f"
+}
+
+fn example_rss_url() -> &'static str {
+    "https://example.com/v1/api/public/feed/8c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f/rss"
+}
+
+fn example_atom_url() -> &'static str {
+    "https://example.com/v1/api/public/feed/8c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f/atom"
+}
+
+fn example_json_url() -> &'static str {
+    "https://example.com/v1/api/public/feed/8c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f/json"
+}
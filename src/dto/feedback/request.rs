@@ -0,0 +1,108 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::feedback::{FeedbackCategory, FeedbackPlatform, FeedbackStatus};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackRequestDto {
+    #[schemars(example = "example_feedback_content")]
+    pub feedback_content: Option<String>,
+    #[schemars(example = "example_feedback_category")]
+    pub feedback_category: Option<FeedbackCategory>,
+    /// 답변 받을 이메일 (익명 피드백용, 로그인했으면 생략)
+    #[schemars(example = "example_feedback_email")]
+    pub feedback_email: Option<String>,
+    #[schemars(example = "example_feedback_app_version")]
+    pub feedback_app_version: Option<String>,
+    #[schemars(example = "example_feedback_platform")]
+    pub feedback_platform: Option<FeedbackPlatform>,
+    pub attachments: Option<Vec<FeedbackAttachmentRequestDto>>,
+}
+
+/// 스크린샷 등 첨부 파일, `attachment_data`는 base64 인코딩
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackAttachmentRequestDto {
+    #[schemars(example = "example_attachment_name")]
+    pub attachment_name: Option<String>,
+    #[schemars(example = "example_attachment_content_type")]
+    pub attachment_content_type: Option<String>,
+    #[schemars(example = "example_attachment_data")]
+    pub attachment_data: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeedbackListRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+    #[schemars(example = "example_feedback_status")]
+    pub status: Option<FeedbackStatus>,
+    #[schemars(example = "example_feedback_category")]
+    pub category: Option<FeedbackCategory>,
+    #[schemars(example = "example_feedback_platform")]
+    pub platform: Option<FeedbackPlatform>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackStatusRequestDto {
+    #[schemars(example = "example_feedback_status")]
+    pub feedback_status: Option<FeedbackStatus>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackReplyRequestDto {
+    #[schemars(example = "example_feedback_reply")]
+    pub feedback_reply: Option<String>,
+    /// 답변 후 상태, 생략하면 `Resolved`
+    #[schemars(example = "example_feedback_status")]
+    pub feedback_status: Option<FeedbackStatus>,
+}
+
+fn example_feedback_content() -> &'static str {
+    "폴더를 옮기면 읽음 표시가 사라집니다."
+}
+
+fn example_feedback_category() -> FeedbackCategory {
+    FeedbackCategory::Bug
+}
+
+fn example_feedback_email() -> &'static str {
+    "reader@example.com"
+}
+
+fn example_feedback_app_version() -> &'static str {
+    "2.3.1"
+}
+
+fn example_feedback_platform() -> FeedbackPlatform {
+    FeedbackPlatform::Ios
+}
+
+fn example_attachment_name() -> &'static str {
+    "screenshot.png"
+}
+
+fn example_attachment_content_type() -> &'static str {
+    "image/png"
+}
+
+fn example_attachment_data() -> &'static str {
+    "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg=="
+}
+
+fn example_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_feedback_status() -> FeedbackStatus {
+    FeedbackStatus::Resolved
+}
+
+fn example_feedback_reply() -> &'static str {
+    "제보 감사합니다. 다음 업데이트에서 수정됩니다."
+}
@@ -0,0 +1,170 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::feedback::{
+    Feedback, FeedbackAttachment, FeedbackCategory, FeedbackPlatform, FeedbackStatus,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackResponseDto {
+    #[schemars(example = "example_feedback_id")]
+    pub feedback_id: Option<i32>,
+    /// 익명 피드백이면 null
+    #[schemars(example = "example_user_id")]
+    pub user_id: Option<i32>,
+    #[schemars(example = "example_feedback_email")]
+    pub feedback_email: Option<String>,
+    #[schemars(example = "example_feedback_content")]
+    pub feedback_content: Option<String>,
+    #[schemars(example = "example_feedback_category")]
+    pub feedback_category: Option<FeedbackCategory>,
+    #[schemars(example = "example_feedback_app_version")]
+    pub feedback_app_version: Option<String>,
+    #[schemars(example = "example_feedback_platform")]
+    pub feedback_platform: Option<FeedbackPlatform>,
+    #[schemars(example = "example_feedback_status")]
+    pub feedback_status: Option<FeedbackStatus>,
+    #[schemars(example = "example_feedback_reply")]
+    pub feedback_reply: Option<String>,
+    #[schemars(example = "example_feedback_created_at")]
+    pub feedback_replied_at: Option<NaiveDateTime>,
+    #[schemars(example = "example_feedback_created_at")]
+    pub feedback_created_at: Option<NaiveDateTime>,
+    pub attachments: Option<Vec<FeedbackAttachmentResponseDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackAttachmentResponseDto {
+    #[schemars(example = "example_attachment_id")]
+    pub attachment_id: Option<i32>,
+    #[schemars(example = "example_attachment_name")]
+    pub attachment_name: Option<String>,
+    #[schemars(example = "example_attachment_content_type")]
+    pub attachment_content_type: Option<String>,
+    /// 바이트
+    #[schemars(example = "example_attachment_size")]
+    pub attachment_size: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeedbackPageResponseDto {
+    pub feedbacks: Option<Vec<FeedbackResponseDto>>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    #[schemars(example = "example_has_next")]
+    pub has_next: Option<bool>,
+}
+
+impl FeedbackResponseDto {
+    pub fn new(feedback: Feedback, attachments: Vec<FeedbackAttachment>) -> Self {
+        Self {
+            feedback_id: feedback.feedback_id,
+            user_id: feedback.user_id,
+            feedback_category: Some(feedback.category()),
+            feedback_status: Some(feedback.status()),
+            feedback_platform: feedback
+                .feedback_platform
+                .as_deref()
+                .and_then(FeedbackPlatform::from_value),
+            feedback_email: feedback.feedback_email,
+            feedback_content: feedback.feedback_content,
+            feedback_app_version: feedback.feedback_app_version,
+            feedback_reply: feedback.feedback_reply,
+            feedback_replied_at: feedback.feedback_replied_at,
+            feedback_created_at: feedback.feedback_created_at,
+            attachments: Some(
+                attachments
+                    .into_iter()
+                    .map(FeedbackAttachmentResponseDto::from_model)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl FeedbackAttachmentResponseDto {
+    pub fn from_model(attachment: FeedbackAttachment) -> Self {
+        Self {
+            attachment_id: attachment.attachment_id,
+            attachment_name: attachment.attachment_original_name,
+            attachment_content_type: attachment.attachment_content_type,
+            attachment_size: attachment.attachment_size,
+        }
+    }
+}
+
+impl FeedbackPageResponseDto {
+    pub fn new(feedbacks: Vec<FeedbackResponseDto>, next_cursor: Option<String>) -> Self {
+        Self {
+            feedbacks: Some(feedbacks),
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+        }
+    }
+}
+
+fn example_feedback_id() -> i32 {
+    31
+}
+
+fn example_user_id() -> i32 {
+    7
+}
+
+fn example_feedback_email() -> &'static str {
+    "reader@example.com"
+}
+
+fn example_feedback_content() -> &'static str {
+    "폴더를 옮기면 읽음 표시가 사라집니다."
+}
+
+fn example_feedback_category() -> FeedbackCategory {
+    FeedbackCategory::Bug
+}
+
+fn example_feedback_app_version() -> &'static str {
+    "2.3.1"
+}
+
+fn example_feedback_platform() -> FeedbackPlatform {
+    FeedbackPlatform::Ios
+}
+
+fn example_feedback_status() -> FeedbackStatus {
+    FeedbackStatus::Resolved
+}
+
+fn example_feedback_reply() -> &'static str {
+    "제보 감사합니다. 다음 업데이트에서 수정됩니다."
+}
+
+fn example_feedback_created_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-06-01 09:30:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_attachment_id() -> i32 {
+    4
+}
+
+fn example_attachment_name() -> &'static str {
+    "screenshot.png"
+}
+
+fn example_attachment_content_type() -> &'static str {
+    "image/png"
+}
+
+fn example_attachment_size() -> i32 {
+    183204
+}
+
+fn example_next_cursor() -> &'static str {
+    "aTozMQ"
+}
+
+fn example_has_next() -> bool {
+    true
+}
@@ -0,0 +1,67 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Fever API 클라이언트가 보내는 필드 이름을 그대로 사용
+
+/// 조회할 항목은 값 없이 키만 전달 (예: `?api&groups&items&since_id=10`)
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeverQueryRequestDto {
+    pub groups: Option<String>,
+    pub feeds: Option<String>,
+    pub items: Option<String>,
+    pub unread_item_ids: Option<String>,
+    pub saved_item_ids: Option<String>,
+    /// 이 ID 다음 아이템부터 오름차순 조회
+    #[schemars(example = "example_item_id")]
+    pub since_id: Option<i32>,
+    /// 이 ID 이전 아이템부터 내림차순 조회
+    #[schemars(example = "example_item_id")]
+    pub max_id: Option<i32>,
+    /// 쉼표로 구분한 아이템 ID
+    #[schemars(example = "example_with_ids")]
+    pub with_ids: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FeverRequestDto {
+    /// md5("이메일:앱 비밀번호")
+    #[schemars(example = "example_api_key")]
+    pub api_key: Option<String>,
+    /// item, feed, group
+    #[schemars(example = "example_mark")]
+    pub mark: Option<String>,
+    /// read, unread, saved, unsaved
+    #[field(name = "as")]
+    #[serde(rename = "as")]
+    #[schemars(example = "example_mark_as")]
+    pub mark_as: Option<String>,
+    #[schemars(example = "example_item_id")]
+    pub id: Option<i32>,
+    /// 피드, 그룹 읽음 처리 시 이 시각(초) 이전 발행 아이템만 읽음 처리
+    #[schemars(example = "example_before")]
+    pub before: Option<i64>,
+}
+
+fn example_item_id() -> i32 {
+    42
+}
+
+fn example_with_ids() -> &'static str {
+    "42,43,44"
+}
+
+fn example_api_key() -> &'static str {
+    "5f4dcc3b5aa765d61d8327deb882cf99"
+}
+
+fn example_mark() -> &'static str {
+    "item"
+}
+
+fn example_mark_as() -> &'static str {
+    "read"
+}
+
+fn example_before() -> i64 {
+    1735689600
+}
@@ -0,0 +1,155 @@
+use chrono::{FixedOffset, NaiveDateTime};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::rss::{RssChannel, RssItem};
+
+// Fever API 응답 형식: 불리언은 0/1, ID 목록은 쉼표로 구분한 문자열
+
+const FEVER_API_VERSION: i32 = 3;
+
+/// 요청한 항목만 값이 있음
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeverResponseDto {
+    pub api_version: i32,
+    /// 인증에 성공하면 1
+    pub auth: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_refreshed_on_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<FeverGroupResponseDto>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feeds: Option<Vec<FeverFeedResponseDto>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feeds_groups: Option<Vec<FeverFeedsGroupResponseDto>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<FeverItemResponseDto>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_items: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_item_ids: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saved_item_ids: Option<String>,
+}
+
+/// 폴더
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeverGroupResponseDto {
+    pub id: i32,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeverFeedsGroupResponseDto {
+    pub group_id: i32,
+    /// 쉼표로 구분한 채널 ID
+    pub feed_ids: String,
+}
+
+/// 구독 중인 채널
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeverFeedResponseDto {
+    pub id: i32,
+    pub favicon_id: i32,
+    pub title: String,
+    pub url: String,
+    pub site_url: String,
+    pub is_spark: i32,
+    /// 채널 갱신 시각은 관리하지 않으므로 0
+    pub last_updated_on_time: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FeverItemResponseDto {
+    pub id: i32,
+    pub feed_id: i32,
+    pub title: String,
+    pub author: String,
+    pub html: String,
+    pub url: String,
+    pub is_saved: i32,
+    pub is_read: i32,
+    pub created_on_time: i64,
+}
+
+impl FeverResponseDto {
+    pub fn unauthorized() -> Self {
+        Self::new(0, None)
+    }
+
+    pub fn authorized(last_refreshed_on_time: i64) -> Self {
+        Self::new(1, Some(last_refreshed_on_time))
+    }
+
+    fn new(auth: i32, last_refreshed_on_time: Option<i64>) -> Self {
+        Self {
+            api_version: FEVER_API_VERSION,
+            auth,
+            last_refreshed_on_time,
+            groups: None,
+            feeds: None,
+            feeds_groups: None,
+            items: None,
+            total_items: None,
+            unread_item_ids: None,
+            saved_item_ids: None,
+        }
+    }
+}
+
+impl FeverFeedsGroupResponseDto {
+    pub fn new(group_id: i32, feed_ids: Vec<i32>) -> Self {
+        Self {
+            group_id,
+            feed_ids: join_ids(feed_ids),
+        }
+    }
+}
+
+impl FeverFeedResponseDto {
+    /// 채널 이미지는 favicon으로 제공하지 않음
+    pub fn new(channel: RssChannel) -> Self {
+        Self {
+            id: channel.channel_id.unwrap_or_default(),
+            favicon_id: 0,
+            title: channel.channel_title.unwrap_or_default(),
+            url: channel.channel_rss_link.unwrap_or_default(),
+            site_url: channel.channel_link.unwrap_or_default(),
+            is_spark: 0,
+            last_updated_on_time: 0,
+        }
+    }
+}
+
+impl FeverItemResponseDto {
+    pub fn new(item: RssItem, is_read: bool, is_saved: bool) -> Self {
+        Self {
+            id: item.rss_id.unwrap_or_default(),
+            feed_id: item.channel_id.unwrap_or_default(),
+            title: item.rss_title.unwrap_or_default(),
+            author: item.rss_author.unwrap_or_default(),
+            html: item.rss_description.unwrap_or_default(),
+            url: item.rss_link.unwrap_or_default(),
+            is_saved: is_saved as i32,
+            is_read: is_read as i32,
+            created_on_time: unix_time(item.rss_pub_date),
+        }
+    }
+}
+
+pub fn join_ids(ids: Vec<i32>) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// DB 시각은 KST 기준, 값이 없으면 0
+fn unix_time(date: Option<NaiveDateTime>) -> i64 {
+    date.and_then(|date| {
+        date.and_local_timezone(FixedOffset::east_opt(9 * 3600).unwrap())
+            .single()
+    })
+    .map(|date| date.timestamp())
+    .unwrap_or_default()
+}
@@ -5,6 +5,12 @@ use serde::{Deserialize, Serialize};
 pub struct CreateFolderRequestDto {
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    #[schemars(example = "example_folder_parent_id")]
+    pub folder_parent_id: Option<i32>,
+    #[schemars(example = "example_folder_icon")]
+    pub folder_icon: Option<String>,
+    #[schemars(example = "example_folder_color")]
+    pub folder_color: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -21,6 +27,10 @@ pub struct UpdateFolderRequestDto {
     pub folder_id: Option<i32>,
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    #[schemars(example = "example_folder_icon")]
+    pub folder_icon: Option<String>,
+    #[schemars(example = "example_folder_color")]
+    pub folder_color: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -29,6 +39,50 @@ pub struct DeleteFolderRequestDto {
     pub folder_id: Option<i32>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct FolderItemRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+    #[schemars(example = "example_unread_only")]
+    pub unread_only: Option<bool>,
+}
+
+/// 변경할 폴더, 채널 위치만 전달 (전달하지 않은 항목은 그대로 유지)
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderOrderRequestDto {
+    pub folders: Option<Vec<FolderPositionRequestDto>>,
+    pub channels: Option<Vec<FolderChannelPositionRequestDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderPositionRequestDto {
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    /// 없으면 최상위 폴더
+    #[schemars(example = "example_folder_parent_id")]
+    pub folder_parent_id: Option<i32>,
+    #[schemars(example = "example_position")]
+    pub folder_position: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderChannelPositionRequestDto {
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_position")]
+    pub channel_position: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OpmlImportRequestDto {
+    #[schemars(example = "example_opml")]
+    pub opml: Option<String>,
+}
+
 fn example_folder_name() -> &'static str {
     "Development"
 }
@@ -39,3 +93,35 @@ fn example_channel_id() -> i32 {
 fn example_folder_id() -> i32 {
     2
 }
+
+fn example_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_unread_only() -> bool {
+    false
+}
+
+fn example_folder_parent_id() -> i32 {
+    1
+}
+
+fn example_folder_icon() -> &'static str {
+    "💻"
+}
+
+fn example_folder_color() -> &'static str {
+    "#4A90E2"
+}
+
+fn example_position() -> i32 {
+    0
+}
+
+fn example_opml() -> &'static str {
+    r#"<opml version="2.0"><body><outline text="Tech"><outline text="Rust Blog" type="rss" xmlUrl="https://blog.rust-lang.org/feed.xml"/></outline></body></opml>"#
+}
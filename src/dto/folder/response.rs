@@ -1,7 +1,14 @@
+use chrono::NaiveDateTime;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::{dto::rss::response::RssChannelResponseDto, model::rss::RssChannel};
+use crate::{
+    dto::rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    model::{
+        folder::RssFolder,
+        rss::{RssChannel, RssItem},
+    },
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct RssFolderResponseDto {
@@ -9,20 +16,142 @@ pub struct RssFolderResponseDto {
     pub folder_id: Option<i32>,
     #[schemars(example = "example_folder_name")]
     pub folder_name: Option<String>,
+    /// 없으면 최상위 폴더
+    #[schemars(example = "example_folder_parent_id")]
+    pub folder_parent_id: Option<i32>,
+    #[schemars(example = "example_folder_position")]
+    pub folder_position: Option<i32>,
+    #[schemars(example = "example_folder_icon")]
+    pub folder_icon: Option<String>,
+    #[schemars(example = "example_folder_color")]
+    pub folder_color: Option<String>,
+    /// 공개된 폴더만 값이 있음
+    #[schemars(example = "example_folder_public_slug")]
+    pub folder_public_slug: Option<String>,
+    /// `channel_position` 순으로 정렬
     #[schemars(example = "example_channel_id")]
     pub folder_channels: Option<Vec<RssChannelResponseDto>>,
+    /// 폴더 채널들의 안 읽은 아이템 수
+    #[schemars(example = "example_unread_count")]
+    pub unread_count: Option<i64>,
+    /// 폴더 채널들의 가장 최근 아이템 발행일
+    #[schemars(example = "example_latest_pub_date")]
+    pub latest_pub_date: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OpmlImportResponseDto {
+    /// 새로 만든 폴더 수
+    #[schemars(example = "example_created_folder_count")]
+    pub created_folder_count: Option<i32>,
+    /// 새로 구독한 채널 수
+    #[schemars(example = "example_subscribed_channel_count")]
+    pub subscribed_channel_count: Option<i32>,
+    /// 가져오지 못한 피드 주소
+    #[schemars(example = "example_failed_links")]
+    pub failed_links: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderPublishResponseDto {
+    #[schemars(example = "example_folder_public_slug")]
+    pub folder_public_slug: Option<String>,
+    /// 공개 페이지 주소
+    #[schemars(example = "example_page_url")]
+    pub page_url: Option<String>,
+    /// RSS 2.0 피드 주소
+    #[schemars(example = "example_rss_url")]
+    pub rss_url: Option<String>,
+    /// Atom 피드 주소
+    #[schemars(example = "example_atom_url")]
+    pub atom_url: Option<String>,
+    #[schemars(example = "example_latest_pub_date")]
+    pub folder_published_at: Option<NaiveDateTime>,
+}
+
+/// 공개 폴더 페이지에 표시할 폴더 정보 (소유자 정보는 포함하지 않음)
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PublicFolderResponseDto {
+    #[schemars(example = "example_folder_name")]
+    pub folder_name: Option<String>,
+    #[schemars(example = "example_folder_icon")]
+    pub folder_icon: Option<String>,
+    #[schemars(example = "example_folder_color")]
+    pub folder_color: Option<String>,
+    pub folder_channels: Option<Vec<RssChannelResponseDto>>,
+    /// 최신 아이템
+    pub folder_items: Option<Vec<RssItemResponseDto>>,
+    #[schemars(example = "example_rss_url")]
+    pub rss_url: Option<String>,
+    #[schemars(example = "example_atom_url")]
+    pub atom_url: Option<String>,
 }
 
 impl RssFolderResponseDto {
+    pub fn new(folder: RssFolder, channels: Vec<RssChannel>) -> Self {
+        Self {
+            folder_id: folder.folder_id,
+            folder_name: folder.folder_name,
+            folder_parent_id: folder.folder_parent_id,
+            folder_position: folder.folder_position,
+            folder_icon: folder.folder_icon,
+            folder_color: folder.folder_color,
+            folder_public_slug: folder.folder_public_slug,
+            folder_channels: Some(RssChannelResponseDto::from_model_list(channels)),
+            unread_count: None,
+            latest_pub_date: None,
+        }
+    }
+}
+
+impl FolderPublishResponseDto {
     pub fn new(
-        folder_id: Option<i32>,
-        folder_name: Option<String>,
+        folder_public_slug: String,
+        page_url: String,
+        rss_url: String,
+        atom_url: String,
+        folder_published_at: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            folder_public_slug: Some(folder_public_slug),
+            page_url: Some(page_url),
+            rss_url: Some(rss_url),
+            atom_url: Some(atom_url),
+            folder_published_at,
+        }
+    }
+}
+
+impl PublicFolderResponseDto {
+    pub fn new(
+        folder: RssFolder,
         channels: Vec<RssChannel>,
+        items: Vec<RssItem>,
+        rss_url: String,
+        atom_url: String,
     ) -> Self {
         Self {
-            folder_id,
-            folder_name,
+            folder_name: folder.folder_name,
+            folder_icon: folder.folder_icon,
+            folder_color: folder.folder_color,
             folder_channels: Some(RssChannelResponseDto::from_model_list(channels)),
+            folder_items: Some(RssItemResponseDto::from_model_list(items)),
+            rss_url: Some(rss_url),
+            atom_url: Some(atom_url),
+        }
+    }
+}
+
+impl OpmlImportResponseDto {
+    pub fn new(
+        created_folder_count: i32,
+        subscribed_channel_count: i32,
+        failed_links: Vec<String>,
+    ) -> Self {
+        Self {
+            created_folder_count: Some(created_folder_count),
+            subscribed_channel_count: Some(subscribed_channel_count),
+            failed_links: Some(failed_links),
         }
     }
 }
@@ -36,3 +165,42 @@ fn example_folder_name() -> &'static str {
 fn example_channel_id() -> i32 {
     3
 }
+fn example_unread_count() -> i64 {
+    12
+}
+fn example_latest_pub_date() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+fn example_folder_parent_id() -> i32 {
+    1
+}
+fn example_folder_position() -> i32 {
+    0
+}
+fn example_folder_icon() -> &'static str {
+    "💻"
+}
+fn example_folder_color() -> &'static str {
+    "#4A90E2"
+}
+fn example_created_folder_count() -> i32 {
+    3
+}
+fn example_subscribed_channel_count() -> i32 {
+    12
+}
+fn example_failed_links() -> Vec<&'static str> {
+    vec!["https://example.com/broken.xml"]
+}
+fn example_folder_public_slug() -> &'static str {
+    "3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6"
+}
+fn example_page_url() -> &'static str {
+    "https://example.com/v1/api/public/folder/3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6"
+}
+fn example_rss_url() -> &'static str {
+    "https://example.com/v1/api/public/folder/3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6/rss"
+}
+fn example_atom_url() -> &'static str {
+    "https://example.com/v1/api/public/folder/3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6/atom"
+}
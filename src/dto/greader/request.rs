@@ -0,0 +1,103 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Google Reader API 클라이언트가 보내는 필드 이름을 그대로 사용
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct GReaderLoginRequestDto {
+    #[field(name = "Email")]
+    #[serde(rename = "Email")]
+    #[schemars(example = "example_email")]
+    pub email: Option<String>,
+    /// 앱 비밀번호 발급 API의 `app_password`
+    #[field(name = "Passwd")]
+    #[serde(rename = "Passwd")]
+    #[schemars(example = "example_app_password")]
+    pub passwd: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct GReaderStreamRequestDto {
+    /// 스트림 ID (경로로 전달하지 않은 경우)
+    #[schemars(example = "example_stream_id")]
+    pub s: Option<String>,
+    /// 아이템 수
+    #[schemars(example = "example_count")]
+    pub n: Option<i32>,
+    /// 이전 응답의 `continuation`
+    #[schemars(example = "example_continuation")]
+    pub c: Option<String>,
+    /// 제외할 상태 (예: 읽은 아이템 제외)
+    #[schemars(example = "example_read_state")]
+    pub xt: Option<String>,
+    /// 이 시각(초) 이후 발행된 아이템만 조회
+    #[schemars(example = "example_timestamp")]
+    pub ot: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct GReaderItemIdsRequestDto {
+    #[schemars(example = "example_item_ids")]
+    pub i: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct GReaderEditTagRequestDto {
+    #[schemars(example = "example_item_ids")]
+    pub i: Vec<String>,
+    /// 추가할 상태
+    #[schemars(example = "example_read_states")]
+    pub a: Option<Vec<String>>,
+    /// 제거할 상태
+    #[schemars(example = "example_read_states")]
+    pub r: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct GReaderMarkAllReadRequestDto {
+    #[schemars(example = "example_stream_id")]
+    pub s: Option<String>,
+    /// 이 시각(마이크로초) 이전 발행 아이템만 읽음 처리
+    #[schemars(example = "example_timestamp_usec")]
+    pub ts: Option<i64>,
+}
+
+fn example_email() -> &'static str {
+    "user@example.com"
+}
+
+fn example_app_password() -> &'static str {
+    "3f9a0c1b2d4e5f6a7b8c9d0e1f2a3b4c"
+}
+
+fn example_stream_id() -> &'static str {
+    "user/-/state/com.google/reading-list"
+}
+
+fn example_count() -> i32 {
+    20
+}
+
+fn example_continuation() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_read_state() -> &'static str {
+    "user/-/state/com.google/read"
+}
+
+fn example_read_states() -> Vec<&'static str> {
+    vec!["user/-/state/com.google/read"]
+}
+
+fn example_timestamp() -> i64 {
+    1735689600
+}
+
+fn example_timestamp_usec() -> i64 {
+    1735689600000000
+}
+
+fn example_item_ids() -> Vec<&'static str> {
+    vec!["tag:google.com,2005:reader/item/000000000000002a"]
+}
@@ -0,0 +1,248 @@
+use chrono::{FixedOffset, NaiveDateTime};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::rss::{RssChannel, RssItem};
+
+// Google Reader API 응답 형식을 그대로 따르므로 필드 이름은 camelCase, 값은 null 대신 기본값 사용
+
+pub const ITEM_ID_PREFIX: &str = "tag:google.com,2005:reader/item/";
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderUserInfoResponseDto {
+    pub user_id: String,
+    pub user_name: String,
+    pub user_profile_id: String,
+    pub user_email: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderSubscriptionListResponseDto {
+    pub subscriptions: Vec<GReaderSubscriptionResponseDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderSubscriptionResponseDto {
+    /// `feed/<channel_id>`
+    pub id: String,
+    pub title: String,
+    /// 채널이 속한 폴더
+    pub categories: Vec<GReaderCategoryResponseDto>,
+    pub url: String,
+    pub html_url: String,
+    pub icon_url: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderCategoryResponseDto {
+    /// `user/-/label/<폴더 이름>`
+    pub id: String,
+    pub label: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderTagListResponseDto {
+    pub tags: Vec<GReaderTagResponseDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderTagResponseDto {
+    pub id: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub tag_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderUnreadCountListResponseDto {
+    pub max: i64,
+    pub unreadcounts: Vec<GReaderUnreadCountResponseDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderUnreadCountResponseDto {
+    pub id: String,
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItemIdsResponseDto {
+    pub item_refs: Vec<GReaderItemRefResponseDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItemRefResponseDto {
+    /// 10진수 아이템 ID
+    pub id: String,
+    pub direct_stream_ids: Vec<String>,
+    pub timestamp_usec: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderStreamContentsResponseDto {
+    pub id: String,
+    pub updated: i64,
+    pub items: Vec<GReaderItemResponseDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderItemResponseDto {
+    /// `tag:google.com,2005:reader/item/<16자리 16진수>`
+    pub id: String,
+    pub crawl_time_msec: String,
+    pub timestamp_usec: String,
+    pub published: i64,
+    pub updated: i64,
+    pub title: String,
+    pub canonical: Vec<GReaderLinkResponseDto>,
+    pub alternate: Vec<GReaderLinkResponseDto>,
+    pub summary: GReaderContentResponseDto,
+    pub author: String,
+    /// reading-list, 폴더, 읽음, 별표 상태
+    pub categories: Vec<String>,
+    pub origin: GReaderOriginResponseDto,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderLinkResponseDto {
+    pub href: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub link_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GReaderContentResponseDto {
+    pub direction: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GReaderOriginResponseDto {
+    pub stream_id: String,
+    pub title: String,
+    pub html_url: String,
+}
+
+impl GReaderUserInfoResponseDto {
+    pub fn new(user_id: i32, user_email: String) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            user_name: user_email.clone(),
+            user_profile_id: user_id.to_string(),
+            user_email,
+        }
+    }
+}
+
+impl GReaderSubscriptionResponseDto {
+    pub fn new(channel: RssChannel, labels: Vec<String>) -> Self {
+        Self {
+            id: feed_stream_id(channel.channel_id.unwrap_or_default()),
+            title: channel.channel_title.unwrap_or_default(),
+            categories: labels
+                .into_iter()
+                .map(|label| GReaderCategoryResponseDto {
+                    id: label_stream_id(&label),
+                    label,
+                })
+                .collect(),
+            url: channel.channel_rss_link.unwrap_or_default(),
+            html_url: channel.channel_link.unwrap_or_default(),
+            icon_url: channel.channel_image_url.unwrap_or_default(),
+        }
+    }
+}
+
+impl GReaderTagResponseDto {
+    pub fn state(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            tag_type: None,
+        }
+    }
+
+    pub fn label(label: &str) -> Self {
+        Self {
+            id: label_stream_id(label),
+            tag_type: Some("folder".to_string()),
+        }
+    }
+}
+
+impl GReaderItemRefResponseDto {
+    pub fn new(item: &RssItem) -> Self {
+        Self {
+            id: item.rss_id.unwrap_or_default().to_string(),
+            direct_stream_ids: vec![feed_stream_id(item.channel_id.unwrap_or_default())],
+            timestamp_usec: (timestamp_secs(item.rss_pub_date) * 1_000_000).to_string(),
+        }
+    }
+}
+
+impl GReaderItemResponseDto {
+    pub fn new(item: RssItem, categories: Vec<String>, channel: Option<&RssChannel>) -> Self {
+        let secs = timestamp_secs(item.rss_pub_date);
+        let link = item.rss_link.unwrap_or_default();
+        let channel_id = item.channel_id.unwrap_or_default();
+
+        Self {
+            id: format!("{ITEM_ID_PREFIX}{:016x}", item.rss_id.unwrap_or_default()),
+            crawl_time_msec: (secs * 1_000).to_string(),
+            timestamp_usec: (secs * 1_000_000).to_string(),
+            published: secs,
+            updated: secs,
+            title: item.rss_title.unwrap_or_default(),
+            canonical: vec![GReaderLinkResponseDto {
+                href: link.clone(),
+                link_type: None,
+            }],
+            alternate: vec![GReaderLinkResponseDto {
+                href: link,
+                link_type: Some("text/html".to_string()),
+            }],
+            summary: GReaderContentResponseDto {
+                direction: "ltr".to_string(),
+                content: item.rss_description.unwrap_or_default(),
+            },
+            author: item.rss_author.unwrap_or_default(),
+            categories,
+            origin: GReaderOriginResponseDto {
+                stream_id: feed_stream_id(channel_id),
+                title: channel
+                    .and_then(|channel| channel.channel_title.clone())
+                    .unwrap_or_default(),
+                html_url: channel
+                    .and_then(|channel| channel.channel_link.clone())
+                    .unwrap_or_default(),
+            },
+        }
+    }
+}
+
+pub fn feed_stream_id(channel_id: i32) -> String {
+    format!("feed/{channel_id}")
+}
+
+pub fn label_stream_id(label: &str) -> String {
+    format!("user/-/label/{label}")
+}
+
+// DB에는 KST 기준으로 저장됨 (발행일이 없으면 0)
+fn timestamp_secs(pub_date: Option<NaiveDateTime>) -> i64 {
+    pub_date
+        .and_then(|date| {
+            date.and_local_timezone(FixedOffset::east_opt(9 * 3600).unwrap())
+                .single()
+        })
+        .map(|date| date.timestamp())
+        .unwrap_or_default()
+}
@@ -1,11 +1,24 @@
+pub mod app_password;
 pub mod apple_server_notification;
 pub mod auth;
+pub mod bookmark;
+pub mod digest;
+pub mod feed_rule;
+pub mod feed_token;
+pub mod feedback;
+pub mod fever;
 pub mod folder;
+pub mod greader;
 pub mod health;
+pub mod mute;
 pub mod news;
+pub mod notification;
 pub mod omninews_subscription;
 pub mod premium;
+pub mod read;
+pub mod reading;
 pub mod rss;
 pub mod search;
 pub mod subscribe;
+pub mod sync;
 pub mod user;
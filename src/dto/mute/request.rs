@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::mute::MuteRuleType;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MuteRuleRequestDto {
+    #[schemars(example = "example_mute_rule_type")]
+    pub mute_rule_type: Option<MuteRuleType>,
+    #[schemars(example = "example_mute_rule_value")]
+    pub mute_rule_value: Option<String>,
+}
+
+fn example_mute_rule_type() -> MuteRuleType {
+    MuteRuleType::Keyword
+}
+
+fn example_mute_rule_value() -> &'static str {
+    "광고"
+}
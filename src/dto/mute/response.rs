@@ -0,0 +1,84 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dto::rss::response::RssItemResponseDto,
+    model::mute::{MuteRule, MuteRuleType},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MuteRuleResponseDto {
+    #[schemars(example = "example_mute_rule_id")]
+    pub mute_rule_id: Option<i32>,
+    #[schemars(example = "example_mute_rule_type")]
+    pub mute_rule_type: Option<MuteRuleType>,
+    #[schemars(example = "example_mute_rule_value")]
+    pub mute_rule_value: Option<String>,
+    #[schemars(example = "example_mute_rule_created_at")]
+    pub mute_rule_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MutePreviewResponseDto {
+    /// 확인한 최근 구독 아이템 수
+    #[schemars(example = "example_checked_count")]
+    pub checked_count: Option<i32>,
+    /// 규칙으로 숨겨지는 아이템 수
+    #[schemars(example = "example_hidden_count")]
+    pub hidden_count: Option<i32>,
+    /// 숨겨지는 아이템 (최대 20개)
+    pub items: Option<Vec<RssItemResponseDto>>,
+}
+
+impl MuteRuleResponseDto {
+    pub fn from_model(rule: MuteRule) -> Self {
+        Self {
+            mute_rule_id: rule.mute_rule_id,
+            mute_rule_type: rule
+                .mute_rule_type
+                .as_deref()
+                .and_then(MuteRuleType::from_value),
+            mute_rule_value: rule.mute_rule_value,
+            mute_rule_created_at: rule.mute_rule_created_at,
+        }
+    }
+
+    pub fn from_model_list(rules: Vec<MuteRule>) -> Vec<Self> {
+        rules.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl MutePreviewResponseDto {
+    pub fn new(checked_count: i32, hidden_count: i32, items: Vec<RssItemResponseDto>) -> Self {
+        Self {
+            checked_count: Some(checked_count),
+            hidden_count: Some(hidden_count),
+            items: Some(items),
+        }
+    }
+}
+
+fn example_mute_rule_id() -> i32 {
+    5
+}
+
+fn example_mute_rule_type() -> MuteRuleType {
+    MuteRuleType::Keyword
+}
+
+fn example_mute_rule_value() -> &'static str {
+    "광고"
+}
+
+fn example_mute_rule_created_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_checked_count() -> i32 {
+    200
+}
+
+fn example_hidden_count() -> i32 {
+    12
+}
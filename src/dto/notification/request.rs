@@ -0,0 +1,96 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::notification::NotificationLevel;
+
+/// 방해 금지 시간은 시작, 종료를 함께 보내고 둘 다 생략하면 해제
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PushSettingRequestDto {
+    /// 0~23, `push_timezone` 기준
+    #[schemars(example = "example_push_quiet_start_hour")]
+    pub push_quiet_start_hour: Option<i32>,
+    /// 0~23, 이 시각부터 다시 발송
+    #[schemars(example = "example_push_quiet_end_hour")]
+    pub push_quiet_end_hour: Option<i32>,
+    /// IANA 시간대 이름
+    #[schemars(example = "example_push_timezone")]
+    pub push_timezone: Option<String>,
+}
+
+/// `channel_id`, `folder_id` 중 하나만 지정
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NotificationPreferenceRequestDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_notification_level")]
+    pub notification_level: Option<NotificationLevel>,
+    /// `Keyword` 수준에서 사용
+    #[schemars(example = "example_notification_keywords")]
+    pub notification_keywords: Option<Vec<String>>,
+}
+
+/// `channel_id`, `folder_id` 중 하나만 지정
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct NotificationPreferenceTargetRequestDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct NotificationListRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+}
+
+/// `notification_ids`를 생략하면 모든 알림을 읽음 처리
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NotificationReadRequestDto {
+    #[schemars(example = "example_notification_ids")]
+    pub notification_ids: Option<Vec<i32>>,
+}
+
+fn example_push_quiet_start_hour() -> i32 {
+    23
+}
+
+fn example_push_quiet_end_hour() -> i32 {
+    7
+}
+
+fn example_push_timezone() -> &'static str {
+    "Asia/Seoul"
+}
+
+fn example_channel_id() -> i32 {
+    12
+}
+
+fn example_folder_id() -> i32 {
+    3
+}
+
+fn example_notification_level() -> NotificationLevel {
+    NotificationLevel::Keyword
+}
+
+fn example_notification_keywords() -> Vec<&'static str> {
+    vec!["반도체", "금리"]
+}
+
+fn example_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_notification_ids() -> Vec<i32> {
+    vec![101, 102]
+}
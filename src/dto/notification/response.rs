@@ -0,0 +1,196 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    notification::{NotificationLevel, NotificationPreference, NotificationType, UserNotification},
+    push::UserPushSetting,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PushSettingResponseDto {
+    #[schemars(example = "example_push_quiet_start_hour")]
+    pub push_quiet_start_hour: Option<i32>,
+    #[schemars(example = "example_push_quiet_end_hour")]
+    pub push_quiet_end_hour: Option<i32>,
+    #[schemars(example = "example_push_timezone")]
+    pub push_timezone: Option<String>,
+    /// 마지막 푸시 발송 시각 (KST)
+    #[schemars(example = "example_push_last_sent_at")]
+    pub push_last_sent_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NotificationPreferenceResponseDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_notification_level")]
+    pub notification_level: Option<NotificationLevel>,
+    #[schemars(example = "example_notification_keywords")]
+    pub notification_keywords: Option<Vec<String>>,
+    #[schemars(example = "example_push_last_sent_at")]
+    pub notification_preference_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UserNotificationResponseDto {
+    #[schemars(example = "example_notification_id")]
+    pub notification_id: Option<i32>,
+    #[schemars(example = "example_notification_type")]
+    pub notification_type: Option<NotificationType>,
+    #[schemars(example = "example_notification_title")]
+    pub notification_title: Option<String>,
+    #[schemars(example = "example_notification_body")]
+    pub notification_body: Option<String>,
+    /// 새 글 알림이면 아이템 ID
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    /// 피드백 답변 알림이면 피드백 ID
+    pub feedback_id: Option<i32>,
+    #[schemars(example = "example_notification_is_read")]
+    pub notification_is_read: Option<bool>,
+    #[schemars(example = "example_push_last_sent_at")]
+    pub notification_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NotificationPageResponseDto {
+    pub notifications: Option<Vec<UserNotificationResponseDto>>,
+    /// 읽지 않은 알림 수 (전체)
+    #[schemars(example = "example_unread_count")]
+    pub unread_count: Option<i64>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    #[schemars(example = "example_has_next")]
+    pub has_next: Option<bool>,
+}
+
+impl PushSettingResponseDto {
+    pub fn from_model(setting: UserPushSetting) -> Self {
+        Self {
+            push_quiet_start_hour: setting.push_quiet_start_hour,
+            push_quiet_end_hour: setting.push_quiet_end_hour,
+            push_timezone: setting.push_timezone,
+            push_last_sent_at: setting.push_last_sent_at,
+        }
+    }
+}
+
+impl NotificationPreferenceResponseDto {
+    pub fn from_model(preference: NotificationPreference) -> Self {
+        Self {
+            notification_level: Some(preference.level()),
+            notification_keywords: Some(preference.keywords()),
+            channel_id: preference.channel_id,
+            folder_id: preference.folder_id,
+            notification_preference_updated_at: preference.notification_preference_updated_at,
+        }
+    }
+}
+
+impl UserNotificationResponseDto {
+    pub fn from_model(notification: UserNotification) -> Self {
+        Self {
+            notification_id: notification.notification_id,
+            notification_type: notification
+                .notification_type
+                .as_deref()
+                .and_then(NotificationType::from_value),
+            notification_title: notification.notification_title,
+            notification_body: notification.notification_body,
+            rss_id: notification.rss_id,
+            channel_id: notification.channel_id,
+            feedback_id: notification.feedback_id,
+            notification_is_read: notification.notification_is_read,
+            notification_created_at: notification.notification_created_at,
+        }
+    }
+}
+
+impl NotificationPageResponseDto {
+    pub fn new(
+        notifications: Vec<UserNotificationResponseDto>,
+        unread_count: i64,
+        next_cursor: Option<String>,
+    ) -> Self {
+        Self {
+            notifications: Some(notifications),
+            unread_count: Some(unread_count),
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+        }
+    }
+}
+
+fn example_push_quiet_start_hour() -> i32 {
+    23
+}
+
+fn example_push_quiet_end_hour() -> i32 {
+    7
+}
+
+fn example_push_timezone() -> &'static str {
+    "Asia/Seoul"
+}
+
+fn example_push_last_sent_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_channel_id() -> i32 {
+    12
+}
+
+fn example_folder_id() -> i32 {
+    3
+}
+
+fn example_notification_level() -> NotificationLevel {
+    NotificationLevel::Keyword
+}
+
+fn example_notification_keywords() -> Vec<&'static str> {
+    vec!["반도체", "금리"]
+}
+
+fn example_notification_id() -> i32 {
+    101
+}
+
+fn example_notification_type() -> NotificationType {
+    NotificationType::NewItem
+}
+
+fn example_notification_title() -> &'static str {
+    "테크 뉴스"
+}
+
+fn example_notification_body() -> &'static str {
+    "새 반도체 공정 발표"
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_notification_is_read() -> bool {
+    false
+}
+
+fn example_unread_count() -> i64 {
+    3
+}
+
+fn example_next_cursor() -> &'static str {
+    "aToxMDE"
+}
+
+fn example_has_next() -> bool {
+    true
+}
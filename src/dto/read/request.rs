@@ -0,0 +1,19 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MarkReadBeforeRequestDto {
+    #[schemars(example = "example_before")]
+    pub before: Option<NaiveDateTime>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+}
+
+fn example_before() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_channel_ids() -> Vec<i32> {
+    vec![1, 2, 3]
+}
@@ -0,0 +1,74 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UnreadCountResponseDto {
+    #[schemars(example = "example_total")]
+    pub total: Option<i64>,
+    pub channels: Option<Vec<ChannelUnreadCountResponseDto>>,
+    pub folders: Option<Vec<FolderUnreadCountResponseDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ChannelUnreadCountResponseDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_unread_count")]
+    pub unread_count: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FolderUnreadCountResponseDto {
+    #[schemars(example = "example_folder_id")]
+    pub folder_id: Option<i32>,
+    #[schemars(example = "example_unread_count")]
+    pub unread_count: Option<i64>,
+}
+
+impl UnreadCountResponseDto {
+    pub fn new(
+        total: i64,
+        channels: Vec<ChannelUnreadCountResponseDto>,
+        folders: Vec<FolderUnreadCountResponseDto>,
+    ) -> Self {
+        Self {
+            total: Some(total),
+            channels: Some(channels),
+            folders: Some(folders),
+        }
+    }
+}
+
+impl ChannelUnreadCountResponseDto {
+    pub fn new(channel_id: i32, unread_count: i64) -> Self {
+        Self {
+            channel_id: Some(channel_id),
+            unread_count: Some(unread_count),
+        }
+    }
+}
+
+impl FolderUnreadCountResponseDto {
+    pub fn new(folder_id: i32, unread_count: i64) -> Self {
+        Self {
+            folder_id: Some(folder_id),
+            unread_count: Some(unread_count),
+        }
+    }
+}
+
+fn example_total() -> i64 {
+    128
+}
+
+fn example_channel_id() -> i32 {
+    3
+}
+
+fn example_folder_id() -> i32 {
+    1
+}
+
+fn example_unread_count() -> i64 {
+    42
+}
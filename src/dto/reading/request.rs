@@ -0,0 +1,74 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::reading::ReadingEventType;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingEventsRequestDto {
+    pub events: Option<Vec<ReadingEventRequestDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingEventRequestDto {
+    #[schemars(example = "example_event_type")]
+    pub event_type: Option<ReadingEventType>,
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    /// `Dwell` 이벤트의 체류 시간 (초)
+    #[schemars(example = "example_seconds")]
+    pub seconds: Option<i32>,
+    /// 이벤트 발생 시각, 없으면 서버 수신 시각
+    #[schemars(example = "example_occurred_at")]
+    pub occurred_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct ReadingHistoryRequestDto {
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct ReadingStatsRequestDto {
+    /// 시작 날짜 (YYYY-MM-DD)
+    #[schemars(example = "example_from")]
+    pub from: Option<String>,
+    /// 끝 날짜 (YYYY-MM-DD)
+    #[schemars(example = "example_to")]
+    pub to: Option<String>,
+}
+
+fn example_event_type() -> ReadingEventType {
+    ReadingEventType::Open
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_seconds() -> i32 {
+    95
+}
+
+fn example_occurred_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_from() -> &'static str {
+    "2025-01-01"
+}
+
+fn example_to() -> &'static str {
+    "2025-01-31"
+}
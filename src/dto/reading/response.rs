@@ -0,0 +1,227 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::model::reading::{ChannelReadingStat, ReadingHistoryDetail, TagReadingStat};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingHistoryResponseDto {
+    #[schemars(example = "example_reading_history_id")]
+    pub reading_history_id: Option<i32>,
+    /// 원본 아이템이 삭제된 경우 null
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_rss_title")]
+    pub rss_title: Option<String>,
+    #[schemars(example = "example_rss_link")]
+    pub rss_link: Option<String>,
+    #[schemars(example = "example_channel_title")]
+    pub channel_title: Option<String>,
+    #[schemars(example = "example_reading_opened_at")]
+    pub reading_opened_at: Option<NaiveDateTime>,
+    /// 체류 시간 (초)
+    #[schemars(example = "example_seconds")]
+    pub reading_seconds: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingHistoryPageResponseDto {
+    pub histories: Option<Vec<ReadingHistoryResponseDto>>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    #[schemars(example = "example_has_next")]
+    pub has_next: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingStatsResponseDto {
+    #[schemars(example = "example_from")]
+    pub from: Option<NaiveDate>,
+    #[schemars(example = "example_to")]
+    pub to: Option<NaiveDate>,
+    #[schemars(example = "example_articles_read")]
+    pub total_articles_read: Option<i64>,
+    /// 총 체류 시간 (초)
+    #[schemars(example = "example_total_seconds")]
+    pub total_seconds: Option<i64>,
+    /// 오늘(또는 어제)까지 연속으로 읽은 날 수
+    #[schemars(example = "example_current_streak")]
+    pub current_streak: Option<i32>,
+    /// 기간 중 가장 길게 연속으로 읽은 날 수
+    #[schemars(example = "example_longest_streak")]
+    pub longest_streak: Option<i32>,
+    /// 기간 내 모든 날짜 (읽지 않은 날은 0)
+    pub daily: Option<Vec<ReadingPeriodStatResponseDto>>,
+    /// 월요일 시작 주 단위
+    pub weekly: Option<Vec<ReadingPeriodStatResponseDto>>,
+    pub top_channels: Option<Vec<ChannelReadingStatResponseDto>>,
+    pub top_tags: Option<Vec<TagReadingStatResponseDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReadingPeriodStatResponseDto {
+    #[schemars(example = "example_from")]
+    pub period_start: Option<NaiveDate>,
+    #[schemars(example = "example_articles_read")]
+    pub articles_read: Option<i64>,
+    #[schemars(example = "example_total_seconds")]
+    pub seconds: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ChannelReadingStatResponseDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_channel_title")]
+    pub channel_title: Option<String>,
+    #[schemars(example = "example_articles_read")]
+    pub articles_read: Option<i64>,
+    #[schemars(example = "example_total_seconds")]
+    pub seconds: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TagReadingStatResponseDto {
+    #[schemars(example = "example_tag_name")]
+    pub tag_name: Option<String>,
+    #[schemars(example = "example_articles_read")]
+    pub articles_read: Option<i64>,
+}
+
+impl ReadingHistoryResponseDto {
+    pub fn from_model(history: ReadingHistoryDetail) -> Self {
+        Self {
+            reading_history_id: history.reading_history_id,
+            rss_id: history.rss_id,
+            channel_id: history.channel_id,
+            rss_title: history.rss_title,
+            rss_link: history.rss_link,
+            channel_title: history.channel_title,
+            reading_opened_at: history.reading_opened_at,
+            reading_seconds: history.reading_seconds,
+        }
+    }
+
+    pub fn from_model_list(histories: Vec<ReadingHistoryDetail>) -> Vec<Self> {
+        histories.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl ReadingHistoryPageResponseDto {
+    pub fn new(histories: Vec<ReadingHistoryResponseDto>, next_cursor: Option<String>) -> Self {
+        Self {
+            histories: Some(histories),
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+        }
+    }
+}
+
+impl ReadingPeriodStatResponseDto {
+    pub fn new(period_start: NaiveDate, articles_read: i64, seconds: i64) -> Self {
+        Self {
+            period_start: Some(period_start),
+            articles_read: Some(articles_read),
+            seconds: Some(seconds),
+        }
+    }
+}
+
+impl ChannelReadingStatResponseDto {
+    pub fn from_model(stat: ChannelReadingStat) -> Self {
+        Self {
+            channel_id: stat.channel_id,
+            channel_title: stat.channel_title,
+            articles_read: Some(stat.stat_articles_read.unwrap_or_default()),
+            seconds: Some(stat.stat_seconds.unwrap_or_default()),
+        }
+    }
+
+    pub fn from_model_list(stats: Vec<ChannelReadingStat>) -> Vec<Self> {
+        stats.into_iter().map(Self::from_model).collect()
+    }
+}
+
+impl TagReadingStatResponseDto {
+    pub fn from_model(stat: TagReadingStat) -> Self {
+        Self {
+            tag_name: stat.tag_name,
+            articles_read: Some(stat.stat_articles_read.unwrap_or_default()),
+        }
+    }
+
+    pub fn from_model_list(stats: Vec<TagReadingStat>) -> Vec<Self> {
+        stats.into_iter().map(Self::from_model).collect()
+    }
+}
+
+fn example_reading_history_id() -> i32 {
+    123
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_channel_id() -> i32 {
+    3
+}
+
+fn example_rss_title() -> &'static str {
+    "반도체 수출 3개월 연속 증가"
+}
+
+fn example_rss_link() -> &'static str {
+    "https://example.com/item"
+}
+
+fn example_channel_title() -> &'static str {
+    "Example Channel"
+}
+
+fn example_reading_opened_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_seconds() -> i32 {
+    95
+}
+
+fn example_next_cursor() -> &'static str {
+    "aToxMjM"
+}
+
+fn example_has_next() -> bool {
+    true
+}
+
+fn example_from() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+}
+
+fn example_to() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
+}
+
+fn example_articles_read() -> i64 {
+    42
+}
+
+fn example_total_seconds() -> i64 {
+    3600
+}
+
+fn example_current_streak() -> i32 {
+    3
+}
+
+fn example_longest_streak() -> i32 {
+    12
+}
+
+fn example_tag_name() -> &'static str {
+    "반도체"
+}
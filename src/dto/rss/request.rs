@@ -7,6 +7,10 @@ pub struct RssItemRequestDto {
     pub channel_id: Option<i32>,
     #[schemars(example = "example_page")]
     pub page: Option<i32>,
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -23,6 +27,14 @@ pub struct UpdateRssRankRequestDto {
     pub num: i32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct RelatedRssItemRequestDto {
+    #[schemars(example = "example_exclude_same_channel")]
+    pub exclude_same_channel: Option<bool>,
+    #[schemars(example = "example_limit")]
+    pub limit: Option<i32>,
+}
+
 fn example_rss_link() -> &'static str {
     "https://example.com/rss"
 }
@@ -42,3 +54,19 @@ fn example_channel_id() -> i32 {
 fn example_page() -> i32 {
     1
 }
+
+fn example_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_exclude_same_channel() -> bool {
+    true
+}
+
+fn example_limit() -> i32 {
+    10
+}
@@ -2,7 +2,13 @@ use chrono::NaiveDateTime;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::rss::{RssChannel, RssItem};
+use crate::{
+    dto::search::response::SearchSnippetResponseDto,
+    model::{
+        rss::{RssChannel, RssItem},
+        story::{RssStory, RssStorySource},
+    },
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RssChannelResponseDto {
@@ -46,6 +52,75 @@ pub struct RssItemResponseDto {
     pub rss_rank: Option<i32>,
     #[schemars(example = "example_rss_image_link")]
     pub rss_image_link: Option<String>,
+    #[schemars(example = "example_rss_language")]
+    pub rss_language: Option<String>,
+    /// 스토리 묶음 조회 시 같은 스토리의 다른 출처 (묶이지 않은 경우 null)
+    pub story: Option<RssStoryResponseDto>,
+    /// 검색 결과일 때 매칭된 부분 (검색이 아닌 경우 null)
+    pub snippet: Option<SearchSnippetResponseDto>,
+    /// 사용자의 읽음 여부 (읽음 상태를 조회하지 않는 API는 null)
+    #[schemars(example = "example_is_read")]
+    pub is_read: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RssItemPageResponseDto {
+    pub items: Option<Vec<RssItemResponseDto>>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    #[schemars(example = "example_has_next")]
+    pub has_next: Option<bool>,
+    /// 숨김 규칙으로 이 페이지에서 제외된 아이템 수 (숨김 규칙을 적용하지 않는 목록이면 null)
+    #[schemars(example = "example_hidden_count")]
+    pub hidden_count: Option<i32>,
+}
+
+/// 아이템 목록 응답
+///
+/// `page`로 요청하면 기존과 같이 아이템 배열을, 그 외에는 커서 페이지를 반환합니다.
+/// (`page` 방식은 클라이언트 이전이 끝나면 제거 예정)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum RssItemListResponseDto {
+    Page(RssItemPageResponseDto),
+    Legacy(Vec<RssItemResponseDto>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RssStoryResponseDto {
+    #[schemars(example = "example_story_id")]
+    pub story_id: Option<i32>,
+    #[schemars(example = "example_story_title")]
+    pub story_title: Option<String>,
+    #[schemars(example = "example_story_item_count")]
+    pub story_item_count: Option<i32>,
+    pub sources: Option<Vec<RssStorySourceResponseDto>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RssStorySourceResponseDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_rss_title")]
+    pub rss_title: Option<String>,
+    #[schemars(example = "example_rss_link")]
+    pub rss_link: Option<String>,
+    #[schemars(example = "example_rss_pub_date")]
+    pub rss_pub_date: Option<NaiveDateTime>,
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_channel_title")]
+    pub channel_title: Option<String>,
+    #[schemars(example = "example_channel_image_url")]
+    pub channel_image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RelatedRssItemResponseDto {
+    pub item: RssItemResponseDto,
+    #[schemars(example = "example_similarity")]
+    pub similarity: f32,
 }
 
 impl RssChannelResponseDto {
@@ -80,6 +155,10 @@ impl RssItemResponseDto {
             rss_pub_date: item.rss_pub_date,
             rss_rank: item.rss_rank,
             rss_image_link: item.rss_image_link,
+            rss_language: item.rss_language,
+            story: None,
+            snippet: None,
+            is_read: None,
         }
     }
 
@@ -88,6 +167,56 @@ impl RssItemResponseDto {
     }
 }
 
+impl RssItemPageResponseDto {
+    pub fn new(items: Vec<RssItemResponseDto>, next_cursor: Option<String>) -> Self {
+        Self {
+            items: Some(items),
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+            hidden_count: None,
+        }
+    }
+}
+
+impl RssStoryResponseDto {
+    pub fn new(story: RssStory, sources: Vec<RssStorySource>) -> Self {
+        Self {
+            story_id: story.story_id,
+            story_title: story.story_title,
+            story_item_count: story.story_item_count,
+            sources: Some(
+                sources
+                    .into_iter()
+                    .map(RssStorySourceResponseDto::from_model)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl RssStorySourceResponseDto {
+    pub fn from_model(source: RssStorySource) -> Self {
+        Self {
+            rss_id: source.rss_id,
+            rss_title: source.rss_title,
+            rss_link: source.rss_link,
+            rss_pub_date: source.rss_pub_date,
+            channel_id: source.channel_id,
+            channel_title: source.channel_title,
+            channel_image_url: source.channel_image_url,
+        }
+    }
+}
+
+impl RelatedRssItemResponseDto {
+    pub fn new(item: RssItem, similarity: f32) -> Self {
+        Self {
+            item: RssItemResponseDto::from_model(item),
+            similarity,
+        }
+    }
+}
+
 // channel
 fn example_channel_id() -> i32 {
     12345
@@ -144,3 +273,36 @@ fn example_rss_rank() -> i32 {
 fn example_rss_image_link() -> &'static str {
     "https://example.com/rss/item/image.png"
 }
+fn example_rss_language() -> &'static str {
+    "ko"
+}
+fn example_next_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_has_next() -> bool {
+    true
+}
+
+fn example_hidden_count() -> i32 {
+    2
+}
+
+fn example_is_read() -> bool {
+    false
+}
+
+fn example_similarity() -> f32 {
+    0.82
+}
+
+// story
+fn example_story_id() -> i32 {
+    321
+}
+fn example_story_title() -> &'static str {
+    "Example Story Headline"
+}
+fn example_story_item_count() -> i32 {
+    4
+}
@@ -1,11 +1,69 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::model::search::SearchType;
+use crate::model::search::{SavedSearchMode, SearchType};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromForm, JsonSchema)]
 pub struct SearchRequestDto {
     pub search_value: Option<String>,
     pub search_type: Option<SearchType>,
     pub search_page_size: Option<i32>,
+    pub collapse_story: Option<bool>,
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct SearchSuggestRequestDto {
+    #[schemars(example = "example_q")]
+    pub q: Option<String>,
+    #[schemars(example = "example_limit")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateSavedSearchRequestDto {
+    #[schemars(example = "example_saved_search_name")]
+    pub saved_search_name: Option<String>,
+    #[schemars(example = "example_saved_search_query")]
+    pub saved_search_query: Option<String>,
+    pub saved_search_mode: Option<SavedSearchMode>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+    #[schemars(example = "example_language")]
+    pub language: Option<String>,
+}
+
+fn example_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_q() -> &'static str {
+    "한ㄱ"
+}
+
+fn example_limit() -> i32 {
+    5
+}
+
+fn example_saved_search_name() -> &'static str {
+    "아침 반도체 브리핑"
+}
+
+fn example_saved_search_query() -> &'static str {
+    "반도체 수출"
+}
+
+fn example_channel_ids() -> Vec<i32> {
+    vec![1, 2, 3]
+}
+
+fn example_language() -> &'static str {
+    "ko"
 }
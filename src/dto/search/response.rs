@@ -1,7 +1,10 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
+use crate::{
+    dto::rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    model::search::{SavedSearch, SavedSearchMode, SearchMatchType},
+};
 
 #[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
 pub struct SearchResponseDto {
@@ -15,6 +18,12 @@ pub struct SearchResponseDto {
     pub page: Option<i32>,
     #[schemars(example = "example_has_next")]
     pub has_next: Option<bool>,
+    /// 다음 페이지 조회 시 `cursor`로 전달 (마지막 페이지면 null)
+    #[schemars(example = "example_next_cursor")]
+    pub next_cursor: Option<String>,
+    /// 숨김 규칙으로 제외된 아이템 수
+    #[schemars(example = "example_hidden_count")]
+    pub hidden_count: Option<i32>,
 }
 
 impl SearchResponseDto {
@@ -31,6 +40,130 @@ impl SearchResponseDto {
             total: Some(total),
             page: Some(page),
             has_next: Some(has_next),
+            next_cursor: None,
+            hidden_count: None,
+        }
+    }
+
+    pub fn new_with_cursor(
+        items: Vec<RssItemResponseDto>,
+        total: i32,
+        next_cursor: Option<String>,
+    ) -> Self {
+        Self {
+            channels: Some(vec![]),
+            items: Some(items),
+            total: Some(total),
+            page: None,
+            has_next: Some(next_cursor.is_some()),
+            next_cursor,
+            hidden_count: None,
+        }
+    }
+}
+
+/// 검색 결과가 매칭된 이유
+///
+/// 키워드 매칭은 검색어를 `<em>`, `</em>`로 감싸고, 의미 검색 매칭은 검색어와 가장 가까운 문장을 반환합니다.
+/// `title`, `text`는 HTML 이스케이프되어 있어 `<em>` 외의 태그는 포함되지 않습니다.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SearchSnippetResponseDto {
+    pub match_type: Option<SearchMatchType>,
+    #[schemars(example = "example_snippet_title")]
+    pub title: Option<String>,
+    #[schemars(example = "example_snippet_text")]
+    pub text: Option<String>,
+}
+
+impl SearchSnippetResponseDto {
+    pub fn new(match_type: SearchMatchType, title: Option<String>, text: Option<String>) -> Self {
+        Self {
+            match_type: Some(match_type),
+            title,
+            text,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SearchSuggestResponseDto {
+    pub channels: Option<Vec<RssChannelResponseDto>>,
+    #[schemars(example = "example_queries")]
+    pub queries: Option<Vec<String>>,
+    #[schemars(example = "example_tags")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl SearchSuggestResponseDto {
+    pub fn new(
+        channels: Vec<RssChannelResponseDto>,
+        queries: Vec<String>,
+        tags: Vec<String>,
+    ) -> Self {
+        Self {
+            channels: Some(channels),
+            queries: Some(queries),
+            tags: Some(tags),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SavedSearchResponseDto {
+    #[schemars(example = "example_saved_search_id")]
+    pub saved_search_id: Option<i32>,
+    #[schemars(example = "example_saved_search_name")]
+    pub saved_search_name: Option<String>,
+    #[schemars(example = "example_saved_search_query")]
+    pub saved_search_query: Option<String>,
+    pub saved_search_mode: Option<SavedSearchMode>,
+    #[schemars(example = "example_channel_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+    #[schemars(example = "example_language")]
+    pub language: Option<String>,
+    #[schemars(example = "example_feed_url")]
+    pub feed_url: Option<String>,
+    #[schemars(example = "example_unseen_count")]
+    pub unseen_count: Option<i32>,
+}
+
+impl SavedSearchResponseDto {
+    pub fn new(saved_search: SavedSearch, feed_url: String, unseen_count: i32) -> Self {
+        Self {
+            saved_search_id: saved_search.saved_search_id,
+            saved_search_name: saved_search.saved_search_name,
+            saved_search_query: saved_search.saved_search_query,
+            saved_search_mode: saved_search
+                .saved_search_mode
+                .as_deref()
+                .map(SavedSearchMode::from_value),
+            channel_ids: saved_search.saved_search_channel_ids.map(|ids| {
+                ids.split(',')
+                    .filter_map(|id| id.trim().parse().ok())
+                    .collect()
+            }),
+            language: saved_search.saved_search_language,
+            feed_url: Some(feed_url),
+            unseen_count: Some(unseen_count),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+pub struct SavedSearchHitsResponseDto {
+    #[schemars(example = "example_saved_search_id")]
+    pub saved_search_id: Option<i32>,
+    #[schemars(example = "example_saved_search_name")]
+    pub saved_search_name: Option<String>,
+    pub items: Option<Vec<RssItemResponseDto>>,
+}
+
+impl SavedSearchHitsResponseDto {
+    pub fn new(saved_search: &SavedSearch, items: Vec<RssItemResponseDto>) -> Self {
+        Self {
+            saved_search_id: saved_search.saved_search_id,
+            saved_search_name: saved_search.saved_search_name.clone(),
+            items: Some(items),
         }
     }
 }
@@ -74,6 +207,10 @@ fn example_items() -> Vec<RssItemResponseDto> {
             rss_pub_date: None, // Example without a date
             rss_rank: Some(1),
             rss_image_link: Some("https://example.com/item_image.png".to_string()),
+            rss_language: Some("en".to_string()),
+            story: None,
+            snippet: None,
+            is_read: None,
         },
         RssItemResponseDto {
             rss_id: Some(2),
@@ -85,6 +222,10 @@ fn example_items() -> Vec<RssItemResponseDto> {
             rss_pub_date: None, // Example without a date
             rss_rank: Some(1),
             rss_image_link: Some("https://example.com/item_image.png".to_string()),
+            rss_language: Some("en".to_string()),
+            story: None,
+            snippet: None,
+            is_read: None,
         },
     ]
 }
@@ -100,3 +241,55 @@ fn example_page() -> i32 {
 fn example_has_next() -> bool {
     true
 }
+
+fn example_snippet_title() -> &'static str {
+    "<em>한국은행</em>, 기준<em>금리</em> 동결"
+}
+
+fn example_snippet_text() -> &'static str {
+    "…<em>한국은행</em> 금융통화위원회는 19일 기준<em>금리</em>를 연 3.5%로 동결했다."
+}
+
+fn example_next_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_hidden_count() -> i32 {
+    2
+}
+
+fn example_queries() -> Vec<&'static str> {
+    vec!["한국 경제", "한국은행"]
+}
+
+fn example_tags() -> Vec<&'static str> {
+    vec!["한국", "한류"]
+}
+
+fn example_saved_search_id() -> i32 {
+    7
+}
+
+fn example_saved_search_name() -> &'static str {
+    "아침 반도체 브리핑"
+}
+
+fn example_saved_search_query() -> &'static str {
+    "반도체 수출"
+}
+
+fn example_channel_ids() -> Vec<i32> {
+    vec![1, 2, 3]
+}
+
+fn example_language() -> &'static str {
+    "ko"
+}
+
+fn example_feed_url() -> &'static str {
+    "https://example.com/v1/api/search/saved/feed/3f2b9c0d8e7a4b6c9d1e2f3a4b5c6d7e"
+}
+
+fn example_unseen_count() -> i32 {
+    12
+}
@@ -11,8 +11,27 @@ pub struct SubscribeRequestDto {
 pub struct SubscribeRssItemRequestDto {
     pub channel_ids: Option<String>,
     pub page: Option<i32>,
+    pub collapse_story: Option<bool>,
+    #[schemars(example = "example_cursor")]
+    pub cursor: Option<String>,
+    #[schemars(example = "example_size")]
+    pub size: Option<i32>,
+    #[schemars(example = "example_unread_only")]
+    pub unread_only: Option<bool>,
 }
 
 fn example_channel_id() -> i32 {
     1
 }
+
+fn example_cursor() -> &'static str {
+    "ZDoxNzM1NzIyMDAwMDAwOjQy"
+}
+
+fn example_size() -> i32 {
+    20
+}
+
+fn example_unread_only() -> bool {
+    true
+}
@@ -0,0 +1,60 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, FromForm, JsonSchema)]
+pub struct SyncRequestDto {
+    /// 이전 응답의 `sync_token` (없으면 전체 동기화)
+    #[schemars(example = "example_sync_token")]
+    pub since: Option<String>,
+}
+
+/// 오프라인에서 바꾼 상태를 올림. 대상별로 서버의 마지막 변경보다 늦게 바뀐 값만 반영
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncUploadRequestDto {
+    pub item_read_states: Option<Vec<SyncItemReadRequestDto>>,
+    pub bookmarks: Option<Vec<SyncBookmarkRequestDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncItemReadRequestDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_is_read")]
+    pub is_read: Option<bool>,
+    /// 클라이언트에서 바꾼 시각 (KST)
+    #[schemars(example = "example_changed_at")]
+    pub changed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncBookmarkRequestDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    /// false면 북마크 삭제
+    #[schemars(example = "example_is_saved")]
+    pub is_saved: Option<bool>,
+    /// 클라이언트에서 바꾼 시각 (KST)
+    #[schemars(example = "example_changed_at")]
+    pub changed_at: Option<NaiveDateTime>,
+}
+
+fn example_sync_token() -> &'static str {
+    "czoxMjM6NDU2Nw"
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_is_read() -> bool {
+    true
+}
+
+fn example_is_saved() -> bool {
+    true
+}
+
+fn example_changed_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
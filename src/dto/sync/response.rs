@@ -0,0 +1,204 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dto::{
+        bookmark::response::BookmarkResponseDto,
+        folder::response::RssFolderResponseDto,
+        rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    },
+    model::read::ChannelReadMark,
+};
+
+/// 전체 동기화면 현재 상태 전체, 아니면 `since` 이후 바뀐 대상의 현재 상태만 포함
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncResponseDto {
+    /// 다음 동기화 때 `since`로 전달
+    #[schemars(example = "example_sync_token")]
+    pub sync_token: Option<String>,
+    /// 한 번에 보내지 못한 변경이 남아 있으면 true (`sync_token`으로 바로 다시 요청)
+    #[schemars(example = "example_has_more")]
+    pub has_more: Option<bool>,
+    /// 전체 동기화이면 true (클라이언트는 기존 상태를 응답으로 교체)
+    #[schemars(example = "example_is_full")]
+    pub is_full: Option<bool>,
+    /// 구독 채널의 새 아이템 (숨김 규칙 적용, 읽음 여부 포함)
+    pub items: Option<Vec<RssItemResponseDto>>,
+    pub subscriptions: Option<Vec<RssChannelResponseDto>>,
+    pub folders: Option<Vec<RssFolderResponseDto>>,
+    pub item_read_states: Option<Vec<SyncItemReadResponseDto>>,
+    pub channel_read_marks: Option<Vec<SyncChannelReadResponseDto>>,
+    pub bookmarks: Option<Vec<BookmarkResponseDto>>,
+    pub deleted: Option<SyncDeletedResponseDto>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncItemReadResponseDto {
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    #[schemars(example = "example_is_read")]
+    pub is_read: Option<bool>,
+    #[schemars(example = "example_changed_at")]
+    pub changed_at: Option<NaiveDateTime>,
+}
+
+/// 채널 읽음 기준선. `read_mark_rss_id` 이하이거나 `read_mark_pub_date` 이전에 발행된 아이템은 읽음
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncChannelReadResponseDto {
+    #[schemars(example = "example_channel_id")]
+    pub channel_id: Option<i32>,
+    #[schemars(example = "example_rss_id")]
+    pub read_mark_rss_id: Option<i32>,
+    #[schemars(example = "example_changed_at")]
+    pub read_mark_pub_date: Option<NaiveDateTime>,
+    #[schemars(example = "example_changed_at")]
+    pub changed_at: Option<NaiveDateTime>,
+}
+
+/// 삭제된 대상 (구독 해지한 채널, 삭제한 폴더, 북마크를 해제한 아이템)
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncDeletedResponseDto {
+    #[schemars(example = "example_ids")]
+    pub channel_ids: Option<Vec<i32>>,
+    #[schemars(example = "example_ids")]
+    pub folder_ids: Option<Vec<i32>>,
+    #[schemars(example = "example_ids")]
+    pub bookmark_rss_ids: Option<Vec<i32>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncUploadResponseDto {
+    /// 반영한 변경 수
+    #[schemars(example = "example_applied_count")]
+    pub applied_count: Option<i32>,
+    /// 서버 값이 더 최근이라 반영하지 않은 변경
+    pub conflicts: Option<Vec<SyncConflictResponseDto>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncConflictResponseDto {
+    /// "item_read" 또는 "bookmark"
+    #[schemars(example = "example_entity")]
+    pub entity: Option<String>,
+    #[schemars(example = "example_rss_id")]
+    pub rss_id: Option<i32>,
+    /// 서버의 현재 값 (읽음 여부 또는 북마크 여부)
+    #[schemars(example = "example_is_read")]
+    pub server_value: Option<bool>,
+    #[schemars(example = "example_changed_at")]
+    pub server_changed_at: Option<NaiveDateTime>,
+}
+
+impl SyncResponseDto {
+    pub fn new(sync_token: String, has_more: bool, is_full: bool) -> Self {
+        Self {
+            sync_token: Some(sync_token),
+            has_more: Some(has_more),
+            is_full: Some(is_full),
+            items: Some(vec![]),
+            subscriptions: Some(vec![]),
+            folders: Some(vec![]),
+            item_read_states: Some(vec![]),
+            channel_read_marks: Some(vec![]),
+            bookmarks: Some(vec![]),
+            deleted: Some(SyncDeletedResponseDto::new(vec![], vec![], vec![])),
+        }
+    }
+}
+
+impl SyncItemReadResponseDto {
+    pub fn new(rss_id: i32, is_read: bool, changed_at: Option<NaiveDateTime>) -> Self {
+        Self {
+            rss_id: Some(rss_id),
+            is_read: Some(is_read),
+            changed_at,
+        }
+    }
+}
+
+impl SyncChannelReadResponseDto {
+    pub fn from_model(mark: ChannelReadMark) -> Self {
+        Self {
+            channel_id: mark.channel_id,
+            read_mark_rss_id: mark.read_mark_rss_id,
+            read_mark_pub_date: mark.read_mark_pub_date,
+            changed_at: mark.read_mark_updated_at,
+        }
+    }
+}
+
+impl SyncDeletedResponseDto {
+    pub fn new(channel_ids: Vec<i32>, folder_ids: Vec<i32>, bookmark_rss_ids: Vec<i32>) -> Self {
+        Self {
+            channel_ids: Some(channel_ids),
+            folder_ids: Some(folder_ids),
+            bookmark_rss_ids: Some(bookmark_rss_ids),
+        }
+    }
+}
+
+impl SyncUploadResponseDto {
+    pub fn new(applied_count: i32, conflicts: Vec<SyncConflictResponseDto>) -> Self {
+        Self {
+            applied_count: Some(applied_count),
+            conflicts: Some(conflicts),
+        }
+    }
+}
+
+impl SyncConflictResponseDto {
+    pub fn new(
+        entity: &str,
+        rss_id: i32,
+        server_value: bool,
+        server_changed_at: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            entity: Some(entity.to_string()),
+            rss_id: Some(rss_id),
+            server_value: Some(server_value),
+            server_changed_at,
+        }
+    }
+}
+
+fn example_sync_token() -> &'static str {
+    "czoxMjM6NDU2Nw"
+}
+
+fn example_has_more() -> bool {
+    false
+}
+
+fn example_is_full() -> bool {
+    false
+}
+
+fn example_rss_id() -> i32 {
+    42
+}
+
+fn example_channel_id() -> i32 {
+    1
+}
+
+fn example_is_read() -> bool {
+    true
+}
+
+fn example_changed_at() -> NaiveDateTime {
+    NaiveDateTime::parse_from_str("2025-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+}
+
+fn example_ids() -> Vec<i32> {
+    vec![1, 2, 3]
+}
+
+fn example_applied_count() -> i32 {
+    3
+}
+
+fn example_entity() -> &'static str {
+    "item_read"
+}
@@ -23,12 +23,18 @@ pub struct LoginUserRequestDto {
     pub user_social_provider_id: Option<String>,
     #[schemars(example = "example_user_platform")]
     pub user_platform: Option<String>,
+    /// 세션 목록에 표시할 기기 이름
+    #[schemars(example = "example_user_device_name")]
+    pub user_device_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AppleLoginRequestDto {
     #[schemars(example = "example_user_social_provider_id")]
     pub user_social_provider_id: Option<String>,
+    /// 세션 목록에 표시할 기기 이름
+    #[schemars(example = "example_user_device_name")]
+    pub user_device_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -80,3 +86,7 @@ fn example_theme() -> &'static str {
 fn example_user_platform() -> &'static str {
     "ios"
 }
+
+fn example_user_device_name() -> &'static str {
+    "홍길동의 iPhone"
+}
@@ -0,0 +1,81 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::app_password::{
+        request::AppPasswordRequestDto,
+        response::{AppPasswordCreateResponseDto, AppPasswordResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::app_password_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        create_app_password,
+        get_app_passwords,
+        delete_app_password
+    ]
+}
+
+/// # 앱 비밀번호 발급 API
+///
+/// Google Reader, Fever API를 지원하는 RSS 리더(Reeder, NetNewsWire 등)에 로그인할 비밀번호를 발급합니다. (최대 10개)
+///
+/// 앱 비밀번호로 로그인한 리더는 읽음, 별표 상태를 바꿀 수 있습니다. 피드 토큰은 피드 조회에만 사용할 수 있습니다.
+///
+/// 비밀번호 원문은 발급할 때만 반환됩니다.
+///
+/// ### `app_password_name`: 비밀번호 이름, 최대 100자 (예: "Reeder")
+///
+#[openapi(tag = "App Password")]
+#[post("/user/app-password", data = "<data>")]
+pub async fn create_app_password(
+    pool: &State<MySqlPool>,
+    data: Json<AppPasswordRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<AppPasswordCreateResponseDto>, Status> {
+    match app_password_service::create_app_password(pool, user.user_email, data.into_inner()).await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 앱 비밀번호 목록 조회 API
+///
+#[openapi(tag = "App Password")]
+#[get("/user/app-password")]
+pub async fn get_app_passwords(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<AppPasswordResponseDto>>, Status> {
+    match app_password_service::get_app_passwords(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 앱 비밀번호 폐기 API
+///
+/// 폐기한 비밀번호로 로그인한 RSS 리더는 즉시 접근할 수 없습니다.
+///
+/// ### `app_password_id`: 앱 비밀번호 ID (예: 4)
+///
+#[openapi(tag = "App Password")]
+#[delete("/user/app-password/<app_password_id>")]
+pub async fn delete_app_password(
+    pool: &State<MySqlPool>,
+    app_password_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match app_password_service::delete_app_password(pool, user.user_email, app_password_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,240 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::bookmark::{
+        request::{
+            BookmarkCollectionRequestDto, BookmarkListRequestDto, SaveBookmarkRequestDto,
+            UpdateBookmarkRequestDto,
+        },
+        response::{BookmarkCollectionResponseDto, BookmarkPageResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::bookmark_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        save_bookmark,
+        update_bookmark,
+        delete_bookmark,
+        delete_bookmark_by_rss_id,
+        get_bookmarks,
+        create_bookmark_collection,
+        get_bookmark_collections,
+        rename_bookmark_collection,
+        delete_bookmark_collection
+    ]
+}
+
+/// # 북마크 저장 API
+///
+/// 아이템을 북마크합니다. 제목, 링크, 이미지, 채널명을 함께 저장하므로 원본 아이템이 삭제되어도 북마크는 유지됩니다.
+///
+/// 이미 북마크한 아이템이면 전달된 메모, 컬렉션, 태그만 갱신하고 기존 북마크 ID를 반환합니다.
+///
+/// ### `rss_id`: 아이템 ID (예: 42)
+///
+/// ### `collection_id`: 컬렉션 ID (예: 2)
+///
+/// ### `bookmark_note`: 메모 (예: "주말에 다시 읽기")
+///
+/// ### `tags`: 태그 목록, 최대 10개 (예: ["반도체", "리포트"])
+///
+#[openapi(tag = "Bookmark")]
+#[post("/bookmark", data = "<data>")]
+pub async fn save_bookmark(
+    pool: &State<MySqlPool>,
+    data: Json<SaveBookmarkRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match bookmark_service::save_bookmark(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 수정 API
+///
+/// 북마크의 메모, 컬렉션, 태그를 요청 값으로 교체합니다. 값이 없으면 비워집니다.
+///
+/// ### `bookmark_id`: 북마크 ID (예: 15)
+///
+/// ### `collection_id`: 컬렉션 ID (예: 2)
+///
+/// ### `bookmark_note`: 메모 (예: "주말에 다시 읽기")
+///
+/// ### `tags`: 태그 목록, 최대 10개 (예: ["반도체", "리포트"])
+///
+#[openapi(tag = "Bookmark")]
+#[put("/bookmark/<bookmark_id>", data = "<data>")]
+pub async fn update_bookmark(
+    pool: &State<MySqlPool>,
+    bookmark_id: i32,
+    data: Json<UpdateBookmarkRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match bookmark_service::update_bookmark(pool, user.user_email, bookmark_id, data.into_inner())
+        .await
+    {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 삭제 API
+///
+/// ### `bookmark_id`: 북마크 ID (예: 15)
+///
+#[openapi(tag = "Bookmark")]
+#[delete("/bookmark/<bookmark_id>")]
+pub async fn delete_bookmark(
+    pool: &State<MySqlPool>,
+    bookmark_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match bookmark_service::delete_bookmark(pool, user.user_email, bookmark_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 아이템 북마크 해제 API
+///
+/// 아이템 ID로 북마크를 삭제합니다.
+///
+/// ### `rss_id`: 아이템 ID (예: 42)
+///
+#[openapi(tag = "Bookmark")]
+#[delete("/bookmark/item/<rss_id>")]
+pub async fn delete_bookmark_by_rss_id(
+    pool: &State<MySqlPool>,
+    rss_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match bookmark_service::delete_bookmark_by_rss_id(pool, user.user_email, rss_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 목록 조회 API
+///
+/// 최근 저장한 순서로 북마크를 반환합니다.
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 없으면 첫 페이지 (예: "aToxMjM")
+///
+/// ### `size`: 페이지 크기, 기본 20 최대 100 (예: 20)
+///
+/// ### `q`: 제목, 메모, 태그 검색어 (예: "반도체")
+///
+/// ### `collection_id`: 컬렉션 필터 (예: 2)
+///
+/// ### `tag`: 태그 필터 (예: "리포트")
+///
+#[openapi(tag = "Bookmark")]
+#[get("/bookmark?<data..>")]
+pub async fn get_bookmarks(
+    pool: &State<MySqlPool>,
+    data: BookmarkListRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<BookmarkPageResponseDto>, Status> {
+    match bookmark_service::get_bookmarks(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 컬렉션 생성 API
+///
+/// ### `collection_name`: 컬렉션 이름, 1~50자 (예: "나중에 읽기")
+///
+#[openapi(tag = "Bookmark")]
+#[post("/bookmark/collection", data = "<data>")]
+pub async fn create_bookmark_collection(
+    pool: &State<MySqlPool>,
+    data: Json<BookmarkCollectionRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match bookmark_service::create_collection(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::AlreadyExists(_)) => Err(Status::Conflict),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 컬렉션 목록 조회 API
+///
+/// 컬렉션별 북마크 수와 함께 반환합니다.
+///
+#[openapi(tag = "Bookmark")]
+#[get("/bookmark/collection")]
+pub async fn get_bookmark_collections(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<BookmarkCollectionResponseDto>>, Status> {
+    match bookmark_service::get_collections(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 컬렉션 이름 변경 API
+///
+/// ### `collection_id`: 컬렉션 ID (예: 2)
+///
+/// ### `collection_name`: 새 컬렉션 이름, 1~50자 (예: "나중에 읽기")
+///
+#[openapi(tag = "Bookmark")]
+#[put("/bookmark/collection/<collection_id>", data = "<data>")]
+pub async fn rename_bookmark_collection(
+    pool: &State<MySqlPool>,
+    collection_id: i32,
+    data: Json<BookmarkCollectionRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match bookmark_service::rename_collection(
+        pool,
+        user.user_email,
+        collection_id,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(OmniNewsError::AlreadyExists(_)) => Err(Status::Conflict),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 북마크 컬렉션 삭제 API
+///
+/// 컬렉션을 삭제합니다. 컬렉션에 있던 북마크는 삭제되지 않고 컬렉션 없음으로 바뀝니다.
+///
+/// ### `collection_id`: 컬렉션 ID (예: 2)
+///
+#[openapi(tag = "Bookmark")]
+#[delete("/bookmark/collection/<collection_id>")]
+pub async fn delete_bookmark_collection(
+    pool: &State<MySqlPool>,
+    collection_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match bookmark_service::delete_collection(pool, user.user_email, collection_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
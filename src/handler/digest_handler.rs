@@ -0,0 +1,133 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_dyn_templates::{context, Template};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::digest::{request::DigestSettingsRequestDto, response::DigestSettingsResponseDto},
+    model::error::OmniNewsError,
+    service::digest_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        get_digest_settings,
+        update_digest_settings,
+        send_test_digest,
+        unsubscribe_digest_page,
+        unsubscribe_digest
+    ]
+}
+
+/// # 다이제스트 설정 조회 API
+///
+/// 이메일 다이제스트 설정을 반환합니다. 저장한 적이 없으면 기본값(꺼짐)을 반환합니다.
+///
+#[openapi(tag = "Digest")]
+#[get("/digest")]
+pub async fn get_digest_settings(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<DigestSettingsResponseDto>, Status> {
+    match digest_service::get_digest_settings(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 다이제스트 설정 API
+///
+/// 구독 채널의 읽지 않은 새 글을 폴더별로 모아 이메일로 보내는 다이제스트를 설정합니다.
+///
+/// 생략한 항목은 기존 값을 유지합니다.
+///
+/// ### `digest_enabled`: 발송 여부 (예: true)
+///
+/// ### `digest_frequency`: 발송 주기, "Daily" 또는 "Weekly" (예: "Daily")
+///
+/// ### `digest_hour`: 발송 시각 0~23, `digest_timezone` 기준 (예: 8)
+///
+/// ### `digest_weekday`: 주간 발송 요일 0(월)~6(일) (예: 0)
+///
+/// ### `digest_timezone`: IANA 시간대 (예: "Asia/Seoul")
+///
+/// ### `digest_include_summary`: 저장된 AI 요약 포함 여부 (예: false)
+///
+#[openapi(tag = "Digest")]
+#[put("/digest", data = "<data>")]
+pub async fn update_digest_settings(
+    pool: &State<MySqlPool>,
+    data: Json<DigestSettingsRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<DigestSettingsResponseDto>, Status> {
+    match digest_service::update_digest_settings(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 다이제스트 테스트 발송 API
+///
+/// 현재 설정으로 다이제스트를 바로 보냅니다. 발송 일정은 바뀌지 않습니다.
+///
+/// 보낼 새 글이 없으면 보내지 않고 false를 반환합니다.
+///
+#[openapi(tag = "Digest")]
+#[post("/digest/test")]
+pub async fn send_test_digest(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<bool>, Status> {
+    match digest_service::send_test_digest(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+// 메일의 수신 거부 링크로 여는 HTML 페이지라 문서에서 제외
+// 링크 미리보기, 메일 보안 스캐너의 GET 요청으로 수신 거부되지 않도록 확인 페이지만 보여주고
+// 버튼을 누르면 아래 POST API로 수신 거부
+#[openapi(skip)]
+#[get("/public/digest/unsubscribe/<token>")]
+pub async fn unsubscribe_digest_page(
+    pool: &State<MySqlPool>,
+    token: &str,
+) -> Result<Template, Status> {
+    match digest_service::check_unsubscribe_token(pool, token).await {
+        Ok(_) => Ok(Template::render(
+            "digest_unsubscribe",
+            context! {
+                title: "Omninews - 다이제스트 수신 거부",
+                app_name: "Omninews",
+                unsubscribe_path: format!("/public/digest/unsubscribe/{token}"),
+            },
+        )),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 다이제스트 원클릭 수신 거부 API
+///
+/// 메일 클라이언트가 `List-Unsubscribe-Post` 헤더로 호출하는 원클릭 수신 거부입니다. (RFC 8058)
+///
+/// 수신 거부 링크의 확인 페이지에서도 이 API를 호출합니다.
+///
+/// ### `token`: 다이제스트 메일의 수신 거부 토큰 (예: "3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6")
+///
+#[openapi(tag = "Digest")]
+#[post("/public/digest/unsubscribe/<token>")]
+pub async fn unsubscribe_digest(
+    pool: &State<MySqlPool>,
+    token: &str,
+) -> Result<Json<bool>, Status> {
+    match digest_service::unsubscribe(pool, token).await {
+        Ok(_) => Ok(Json(true)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
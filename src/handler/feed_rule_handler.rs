@@ -0,0 +1,207 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::{
+        feed_rule::{
+            request::{FeedRuleRequestDto, FeedRuleTaggedItemRequestDto},
+            response::{FeedRuleDryRunResponseDto, FeedRuleResponseDto, FeedRuleTagResponseDto},
+        },
+        rss::response::{RssItemPageResponseDto, RssItemResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::feed_rule_service,
+    EmbeddingService,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        create_feed_rule,
+        get_feed_rules,
+        update_feed_rule,
+        delete_feed_rule,
+        dry_run_feed_rule,
+        get_feed_rule_hits,
+        get_tagged_items,
+        get_item_tags
+    ]
+}
+
+/// # 피드 규칙 추가 API
+///
+/// 구독 채널에 새 아이템이 수집될 때 평가할 규칙을 추가합니다. (최대 50개)
+///
+/// 모든 조건을 만족하는 아이템에 동작을 실행하며, 같은 아이템에는 한 번만 실행합니다.
+///
+/// ### `feed_rule_name`: 규칙 이름 (예: "반도체 리포트")
+///
+/// ### `conditions`: 조건 목록, 1-10개 (예: "Keyword" => 제목, 설명에 포함, "Title", "Description" => 해당 필드에 포함, "Author" => 작성자 일치, "Channel" => 채널 ID, "Folder" => 폴더 ID, "Tag" => 아이템 태그, "Semantic" => 문구와 의미 유사, 1개까지)
+///
+/// ### `actions`: 동작 목록, 1-5개 (예: "Tag" => `value` 태그 추가, "Bookmark" => 북마크, `value`는 컬렉션 ID, "MarkRead" => 읽음 처리, "Notify" => 알림함 기록과 푸시, 뮤트된 글은 제외하고 방해 금지 시간에는 미룸)
+///
+/// ### `feed_rule_enabled`: 사용 여부, 기본 true (예: true)
+///
+#[openapi(tag = "Feed Rule")]
+#[post("/feed/rule", data = "<data>")]
+pub async fn create_feed_rule(
+    pool: &State<MySqlPool>,
+    model: &State<EmbeddingService>,
+    data: Json<FeedRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match feed_rule_service::create_feed_rule(pool, model, user.user_email, data.into_inner()).await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 규칙 목록 조회 API
+///
+#[openapi(tag = "Feed Rule")]
+#[get("/feed/rule")]
+pub async fn get_feed_rules(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<FeedRuleResponseDto>>, Status> {
+    match feed_rule_service::get_feed_rules(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 규칙 수정 API
+///
+/// 규칙 전체를 요청 값으로 교체합니다. 이미 규칙이 적용된 아이템에는 다시 적용하지 않습니다.
+///
+/// ### `feed_rule_id`: 피드 규칙 ID (예: 7)
+///
+#[openapi(tag = "Feed Rule")]
+#[put("/feed/rule/<feed_rule_id>", data = "<data>")]
+pub async fn update_feed_rule(
+    pool: &State<MySqlPool>,
+    model: &State<EmbeddingService>,
+    feed_rule_id: i32,
+    data: Json<FeedRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match feed_rule_service::update_feed_rule(
+        pool,
+        model,
+        user.user_email,
+        feed_rule_id,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 규칙 삭제 API
+///
+/// ### `feed_rule_id`: 피드 규칙 ID (예: 7)
+///
+#[openapi(tag = "Feed Rule")]
+#[delete("/feed/rule/<feed_rule_id>")]
+pub async fn delete_feed_rule(
+    pool: &State<MySqlPool>,
+    feed_rule_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match feed_rule_service::delete_feed_rule(pool, user.user_email, feed_rule_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 규칙 미리보기 API
+///
+/// 규칙을 저장하지 않고, 구독 채널의 최근 아이템 200개 중 조건을 만족하는 아이템 수와 예시(최대 20개)를 반환합니다. 동작은 실행하지 않습니다.
+///
+/// ### 요청 형식은 피드 규칙 추가 API와 같습니다.
+///
+#[openapi(tag = "Feed Rule")]
+#[post("/feed/rule/dry-run", data = "<data>")]
+pub async fn dry_run_feed_rule(
+    pool: &State<MySqlPool>,
+    model: &State<EmbeddingService>,
+    data: Json<FeedRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<FeedRuleDryRunResponseDto>, Status> {
+    match feed_rule_service::dry_run_feed_rule(pool, model, user.user_email, data.into_inner())
+        .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 규칙 적용 아이템 조회 API
+///
+/// 규칙이 적용된 최근 아이템을 최대 100개 반환합니다.
+///
+/// ### `feed_rule_id`: 피드 규칙 ID (예: 7)
+///
+#[openapi(tag = "Feed Rule")]
+#[get("/feed/rule/<feed_rule_id>/hits")]
+pub async fn get_feed_rule_hits(
+    pool: &State<MySqlPool>,
+    feed_rule_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
+    match feed_rule_service::get_feed_rule_hits(pool, user.user_email, feed_rule_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 태그된 아이템 조회 API
+///
+/// 피드 규칙으로 태그된 아이템을 최근 수집순으로 반환합니다.
+///
+/// ### `tag`: 태그 (예: "반도체")
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 첫 페이지는 생략 (예: "aToxMjM")
+///
+/// ### `size`: 페이지 크기 (예: 20)
+///
+#[openapi(tag = "Feed Rule")]
+#[get("/feed/rule/tagged?<data..>")]
+pub async fn get_tagged_items(
+    pool: &State<MySqlPool>,
+    data: FeedRuleTaggedItemRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<RssItemPageResponseDto>, Status> {
+    match feed_rule_service::get_tagged_items(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 아이템 태그 목록 조회 API
+///
+/// 피드 규칙으로 붙은 태그와 태그별 아이템 수를 반환합니다.
+///
+#[openapi(tag = "Feed Rule")]
+#[get("/feed/rule/tags")]
+pub async fn get_item_tags(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<FeedRuleTagResponseDto>>, Status> {
+    match feed_rule_service::get_item_tags(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,177 @@
+use okapi::openapi3::OpenApi;
+use rocket::{
+    http::Status,
+    response::content::{RawJson, RawXml},
+    serde::json::Json,
+    State,
+};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::feed_token::{
+        request::{FeedTokenRequestDto, FeedTokenScopeRequestDto},
+        response::{FeedTokenCreateResponseDto, FeedTokenResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::feed_token_service,
+    utils::{feed_util::FeedFormat, rate_limit_util::RateLimiter},
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        create_feed_token,
+        get_feed_tokens,
+        delete_feed_token,
+        get_token_rss_feed,
+        get_token_atom_feed,
+        get_token_json_feed
+    ]
+}
+
+/// # 피드 토큰 발급 API
+///
+/// RSS 리더에서 구독 타임라인, 폴더, 채널을 구독할 수 있는 비밀 토큰을 발급합니다. (최대 10개)
+///
+/// 토큰 원문은 발급할 때만 반환되므로 응답의 피드 주소를 보관해야 합니다.
+///
+/// 피드 조회 전용이며, Google Reader, Fever API 로그인에는 앱 비밀번호를 사용합니다.
+///
+/// ### `feed_token_name`: 토큰 이름, 최대 100자 (예: "NetNewsWire")
+///
+#[openapi(tag = "Feed Token")]
+#[post("/feed/token", data = "<data>")]
+pub async fn create_feed_token(
+    pool: &State<MySqlPool>,
+    data: Json<FeedTokenRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<FeedTokenCreateResponseDto>, Status> {
+    match feed_token_service::create_feed_token(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 토큰 목록 조회 API
+///
+#[openapi(tag = "Feed Token")]
+#[get("/feed/token")]
+pub async fn get_feed_tokens(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<FeedTokenResponseDto>>, Status> {
+    match feed_token_service::get_feed_tokens(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드 토큰 폐기 API
+///
+/// 폐기한 토큰의 피드 주소는 즉시 접근할 수 없습니다.
+///
+/// ### `feed_token_id`: 피드 토큰 ID (예: 2)
+///
+#[openapi(tag = "Feed Token")]
+#[delete("/feed/token/<feed_token_id>")]
+pub async fn delete_feed_token(
+    pool: &State<MySqlPool>,
+    feed_token_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match feed_token_service::delete_feed_token(pool, user.user_email, feed_token_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 개인 RSS 피드 API
+///
+/// 토큰 소유자의 구독 타임라인 최신 아이템 50개를 RSS 2.0 피드로 반환합니다. 숨김 규칙이 적용됩니다.
+///
+/// 토큰당 시간당 60회까지 조회할 수 있으며 초과하면 429를 반환합니다.
+///
+/// ### `token`: 피드 토큰 발급 API의 `feed_token`
+///
+/// ### `folder_id`: 폴더 피드로 조회, 생략 가능 (예: 3)
+///
+/// ### `channel_id`: 채널 피드로 조회, 구독하지 않은 채널도 가능, 생략 가능 (예: 12)
+///
+#[openapi(tag = "Feed Token")]
+#[get("/public/feed/<token>/rss?<data..>")]
+pub async fn get_token_rss_feed(
+    pool: &State<MySqlPool>,
+    rate_limiter: &State<RateLimiter>,
+    token: &str,
+    data: FeedTokenScopeRequestDto,
+) -> Result<RawXml<String>, Status> {
+    match feed_token_service::get_token_feed(pool, rate_limiter, token, data, FeedFormat::Rss).await
+    {
+        Ok(res) => Ok(RawXml(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(OmniNewsError::TooManyRequests(_)) => Err(Status::TooManyRequests),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 개인 Atom 피드 API
+///
+/// 개인 RSS 피드 API와 같은 아이템을 Atom 피드로 반환합니다.
+///
+/// ### `token`: 피드 토큰 발급 API의 `feed_token`
+///
+/// ### `folder_id`: 폴더 피드로 조회, 생략 가능 (예: 3)
+///
+/// ### `channel_id`: 채널 피드로 조회, 구독하지 않은 채널도 가능, 생략 가능 (예: 12)
+///
+#[openapi(tag = "Feed Token")]
+#[get("/public/feed/<token>/atom?<data..>")]
+pub async fn get_token_atom_feed(
+    pool: &State<MySqlPool>,
+    rate_limiter: &State<RateLimiter>,
+    token: &str,
+    data: FeedTokenScopeRequestDto,
+) -> Result<RawXml<String>, Status> {
+    match feed_token_service::get_token_feed(pool, rate_limiter, token, data, FeedFormat::Atom)
+        .await
+    {
+        Ok(res) => Ok(RawXml(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(OmniNewsError::TooManyRequests(_)) => Err(Status::TooManyRequests),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 개인 JSON 피드 API
+///
+/// 개인 RSS 피드 API와 같은 아이템을 JSON Feed 1.1 형식으로 반환합니다.
+///
+/// ### `token`: 피드 토큰 발급 API의 `feed_token`
+///
+/// ### `folder_id`: 폴더 피드로 조회, 생략 가능 (예: 3)
+///
+/// ### `channel_id`: 채널 피드로 조회, 구독하지 않은 채널도 가능, 생략 가능 (예: 12)
+///
+#[openapi(tag = "Feed Token")]
+#[get("/public/feed/<token>/json?<data..>")]
+pub async fn get_token_json_feed(
+    pool: &State<MySqlPool>,
+    rate_limiter: &State<RateLimiter>,
+    token: &str,
+    data: FeedTokenScopeRequestDto,
+) -> Result<RawJson<String>, Status> {
+    match feed_token_service::get_token_feed(pool, rate_limiter, token, data, FeedFormat::Json)
+        .await
+    {
+        Ok(res) => Ok(RawJson(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(OmniNewsError::TooManyRequests(_)) => Err(Status::TooManyRequests),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,235 @@
+use okapi::openapi3::OpenApi;
+use rocket::{fs::NamedFile, http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    config::{client_ip::ClientIp, json_limit::FeedbackJson},
+    dto::feedback::{
+        request::{
+            FeedbackListRequestDto, FeedbackReplyRequestDto, FeedbackRequestDto,
+            FeedbackStatusRequestDto,
+        },
+        response::{FeedbackPageResponseDto, FeedbackResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::feedback_service::{self, FeedbackRateLimiter},
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        submit_feedback,
+        submit_anonymous_feedback,
+        get_feedbacks,
+        get_feedback,
+        update_feedback_status,
+        reply_feedback,
+        get_feedback_attachment
+    ]
+}
+
+/// # 피드백 보내기 API
+///
+/// 로그인한 사용자의 피드백을 저장하고 피드백 ID를 반환합니다. 답변은 알림함으로 전달됩니다.
+///
+/// 사용자당 시간당 5회까지 보낼 수 있으며 초과하면 429를 반환합니다.
+///
+/// ### `feedback_content`: 내용, 1~2000자 (예: "폴더를 옮기면 읽음 표시가 사라집니다.")
+///
+/// ### `feedback_category`: "Bug", "Feature", "Content", "Account", "Other" 중 하나, 생략하면 "Other" (예: "Bug")
+///
+/// ### `feedback_app_version`: 앱 버전 (예: "2.3.1")
+///
+/// ### `feedback_platform`: "Ios", "Android", "Web", "Other" 중 하나 (예: "Ios")
+///
+/// ### `attachments`: 스크린샷 등 첨부 파일, 최대 3개 각 3MB 이하, png/jpeg/webp/gif/txt, 내용이 `attachment_content_type`과 일치해야 함 (예: [{"attachment_name": "screenshot.png", "attachment_content_type": "image/png", "attachment_data": "iVBORw0..."}])
+///
+#[openapi(tag = "Feedback")]
+#[post("/feedback", data = "<data>")]
+pub async fn submit_feedback(
+    pool: &State<MySqlPool>,
+    rate_limiter: &State<FeedbackRateLimiter>,
+    data: FeedbackJson<FeedbackRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match feedback_service::submit_feedback(
+        pool,
+        rate_limiter,
+        Some(user.user_email),
+        None,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) | Err(OmniNewsError::DecodeError) => {
+            Err(Status::BadRequest)
+        }
+        Err(OmniNewsError::TooManyRequests(_)) => Err(Status::TooManyRequests),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 익명 피드백 보내기 API
+///
+/// 로그인 없이 피드백을 보냅니다. 요청 값은 피드백 보내기 API와 같고, IP당 시간당 5회까지 보낼 수 있습니다.
+///
+/// 클라이언트 IP는 `TRUSTED_PROXIES`에 등록된 프록시를 거친 경우 `X-Forwarded-For`에서 읽으며,
+/// 알 수 없으면 400을 반환합니다.
+///
+/// ### `feedback_email`: 답변 받을 이메일, 생략 가능 (예: "reader@example.com")
+///
+#[openapi(tag = "Feedback")]
+#[post("/public/feedback", data = "<data>")]
+pub async fn submit_anonymous_feedback(
+    pool: &State<MySqlPool>,
+    rate_limiter: &State<FeedbackRateLimiter>,
+    data: FeedbackJson<FeedbackRequestDto>,
+    client_ip: ClientIp,
+) -> Result<Json<i32>, Status> {
+    match feedback_service::submit_feedback(
+        pool,
+        rate_limiter,
+        None,
+        client_ip.0,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) | Err(OmniNewsError::DecodeError) => {
+            Err(Status::BadRequest)
+        }
+        Err(OmniNewsError::TooManyRequests(_)) => Err(Status::TooManyRequests),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드백 목록 조회 API (관리자)
+///
+/// 최신순으로 피드백을 반환합니다. `user_role`이 admin인 사용자만 호출할 수 있습니다.
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 없으면 첫 페이지 (예: "aTozMQ")
+///
+/// ### `size`: 페이지 크기, 기본 20 최대 100 (예: 20)
+///
+/// ### `status`: 상태 필터 "Open", "InProgress", "Resolved", "Closed" (예: "Open")
+///
+/// ### `category`: 분류 필터 (예: "Bug")
+///
+/// ### `platform`: 플랫폼 필터 (예: "Android")
+///
+#[openapi(tag = "Feedback")]
+#[get("/admin/feedback?<data..>")]
+pub async fn get_feedbacks(
+    pool: &State<MySqlPool>,
+    data: FeedbackListRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<FeedbackPageResponseDto>, Status> {
+    match feedback_service::get_feedbacks(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::Forbidden(_)) => Err(Status::Forbidden),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드백 조회 API (관리자)
+///
+/// ### `feedback_id`: 피드백 ID (예: 31)
+///
+#[openapi(tag = "Feedback")]
+#[get("/admin/feedback/<feedback_id>")]
+pub async fn get_feedback(
+    pool: &State<MySqlPool>,
+    feedback_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Json<FeedbackResponseDto>, Status> {
+    match feedback_service::get_feedback(pool, user.user_email, feedback_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::Forbidden(_)) => Err(Status::Forbidden),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드백 상태 변경 API (관리자)
+///
+/// ### `feedback_id`: 피드백 ID (예: 31)
+///
+/// ### `feedback_status`: "Open", "InProgress", "Resolved", "Closed" 중 하나 (예: "InProgress")
+///
+#[openapi(tag = "Feedback")]
+#[put("/admin/feedback/<feedback_id>/status", data = "<data>")]
+pub async fn update_feedback_status(
+    pool: &State<MySqlPool>,
+    feedback_id: i32,
+    data: Json<FeedbackStatusRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<bool>, Status> {
+    match feedback_service::update_feedback_status(
+        pool,
+        user.user_email,
+        feedback_id,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::Forbidden(_)) => Err(Status::Forbidden),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드백 답변 API (관리자)
+///
+/// 답변을 저장하고 상태를 바꿉니다. 로그인한 사용자가 보낸 피드백이면 답변이 알림함으로 전달됩니다.
+///
+/// 다시 답변하면 이전 답변을 덮어씁니다.
+///
+/// ### `feedback_id`: 피드백 ID (예: 31)
+///
+/// ### `feedback_reply`: 답변, 1~2000자 (예: "제보 감사합니다. 다음 업데이트에서 수정됩니다.")
+///
+/// ### `feedback_status`: 답변 후 상태, 생략하면 "Resolved" (예: "Resolved")
+///
+#[openapi(tag = "Feedback")]
+#[post("/admin/feedback/<feedback_id>/reply", data = "<data>")]
+pub async fn reply_feedback(
+    pool: &State<MySqlPool>,
+    feedback_id: i32,
+    data: Json<FeedbackReplyRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<FeedbackResponseDto>, Status> {
+    match feedback_service::reply_feedback(pool, user.user_email, feedback_id, data.into_inner())
+        .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::Forbidden(_)) => Err(Status::Forbidden),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 피드백 첨부 파일 다운로드 API (관리자)
+///
+/// ### `attachment_id`: 피드백 조회 응답의 `attachment_id` (예: 4)
+///
+#[openapi(tag = "Feedback")]
+#[get("/admin/feedback/attachment/<attachment_id>")]
+pub async fn get_feedback_attachment(
+    pool: &State<MySqlPool>,
+    attachment_id: i32,
+    user: AuthenticatedUser,
+) -> Result<NamedFile, Status> {
+    match feedback_service::get_feedback_attachment(pool, user.user_email, attachment_id).await {
+        Ok(path) => NamedFile::open(path).await.map_err(|_| Status::NotFound),
+        Err(OmniNewsError::Forbidden(_)) => Err(Status::Forbidden),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use okapi::openapi3::OpenApi;
+use rocket::{form::Form, http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::fever::{
+        request::{FeverQueryRequestDto, FeverRequestDto},
+        response::FeverResponseDto,
+    },
+    model::error::OmniNewsError,
+    service::fever_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: fever]
+}
+
+/// # Fever API
+///
+/// Fever API를 지원하는 RSS 리더에서 사용하는 단일 엔드포인트입니다. 서버 주소는 `/v1/api/fever/`로 설정합니다.
+///
+/// 비밀번호 자리에 앱 비밀번호를 사용하며, `api_key`가 올바르지 않으면 `auth`가 0인 응답을 반환합니다.
+///
+/// ### `api_key`: md5("이메일:앱 비밀번호") (예: "5f4dcc3b5aa765d61d8327deb882cf99")
+///
+/// ### `groups`, `feeds`, `items`, `unread_item_ids`, `saved_item_ids`: 조회할 항목, 값 없이 키만 전달
+///
+/// ### `since_id`: 이 ID 다음 아이템부터 오름차순 50개 조회 (예: 42)
+///
+/// ### `max_id`: 이 ID 이전 아이템부터 내림차순 50개 조회 (예: 42)
+///
+/// ### `with_ids`: 쉼표로 구분한 아이템 ID, 최대 50개 (예: "42,43,44")
+///
+/// ### `mark`, `as`, `id`: 상태 변경 (예: "item", "read", 42 / "feed", "read", 12 / "group", "read", 3)
+///
+/// ### `before`: 피드, 그룹 읽음 처리 시 이 시각(초) 이전 아이템만 읽음 처리 (예: 1735689600)
+///
+#[openapi(tag = "Fever")]
+#[post("/fever/<_path..>?<query..>", data = "<data>")]
+pub async fn fever(
+    pool: &State<MySqlPool>,
+    _path: PathBuf,
+    query: FeverQueryRequestDto,
+    data: Form<FeverRequestDto>,
+) -> Result<Json<FeverResponseDto>, Status> {
+    match fever_service::handle_request(pool, query, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -1,18 +1,24 @@
 use okapi::openapi3::OpenApi;
-use rocket::{http::Status, serde::json::Json, State};
+use rocket::{http::Status, response::content::RawXml, serde::json::Json, State};
 use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
 use sqlx::MySqlPool;
 
 use crate::{
     auth_middleware::AuthenticatedUser,
-    dto::folder::{
-        request::{
-            ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
-            UpdateFolderRequestDto,
+    dto::{
+        folder::{
+            request::{
+                ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
+                FolderItemRequestDto, FolderOrderRequestDto, OpmlImportRequestDto,
+                UpdateFolderRequestDto,
+            },
+            response::{FolderPublishResponseDto, OpmlImportResponseDto, RssFolderResponseDto},
         },
-        response::RssFolderResponseDto,
+        rss::response::RssItemPageResponseDto,
     },
+    model::error::OmniNewsError,
     service::folder_service,
+    EmbeddingService,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -20,9 +26,16 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
         create_folder,
         add_channel_to_folder,
         find_folders,
+        get_folder_items,
         update_folder,
         delete_folder,
-        delete_channel_from_folder
+        delete_channel_from_folder,
+        reorder_folders,
+        export_opml,
+        import_opml,
+        publish_folder,
+        unpublish_folder,
+        subscribe_public_folder
     ]
 }
 
@@ -32,6 +45,12 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
 ///
 /// ### `folder_name`: 폴더 이름 (예: "Development")
 ///
+/// ### `folder_parent_id`: 상위 폴더 ID, 최상위 폴더는 생략 (예: 3)
+///
+/// ### `folder_icon`: 폴더 아이콘, 최대 50자 (예: "💻")
+///
+/// ### `folder_color`: 폴더 색상, `#RRGGBB` 형식 (예: "#4A90E2")
+///
 //TODO: 이거 expected Expr뜨는거, okapi 문제니까 0.9.1 업데이트 후 해결되는지 확인하기
 //https://github.com/GREsau/okapi/issues/166
 #[openapi(tag = "Folder")]
@@ -43,6 +62,8 @@ pub async fn create_folder(
 ) -> Result<Json<i32>, Status> {
     match folder_service::create_folder(pool, user.user_email, folder.into_inner()).await {
         Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
         Err(_) => Err(Status::InternalServerError),
     }
 }
@@ -70,7 +91,7 @@ pub async fn add_channel_to_folder(
 
 /// # 폴더 조회 API
 ///
-/// 사용자의 폴더 목록을 반환합니다.
+/// 사용자의 폴더 목록을 폴더별 안 읽은 아이템 수(`unread_count`), 가장 최근 아이템 발행일(`latest_pub_date`)과 함께 반환합니다.
 ///
 #[openapi(tag = "Folder")]
 #[get("/folder")]
@@ -84,30 +105,66 @@ pub async fn find_folders(
     }
 }
 
+/// # 폴더 타임라인 조회 API
+///
+/// 폴더에 속한 모든 채널의 아이템을 발행일 최신순으로 합쳐 반환합니다.
+///
+/// 숨김 규칙에 해당하는 아이템은 제외되며 제외된 수는 `hidden_count`로 반환합니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 첫 페이지는 생략 (예: "ZDoxNzM1NzIyMDAwMDAwOjQy")
+///
+/// ### `size`: 페이지 크기 (예: 20)
+///
+/// ### `unread_only`: 안 읽은 아이템만 조회 (예: false)
+///
+#[openapi(tag = "Folder")]
+#[get("/folder/<folder_id>/items?<data..>")]
+pub async fn get_folder_items(
+    pool: &State<MySqlPool>,
+    folder_id: i32,
+    data: FolderItemRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<RssItemPageResponseDto>, Status> {
+    match folder_service::get_folder_items(pool, user.user_email, folder_id, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
 /// # 폴더 업데이트 API
 ///
-/// 폴더의 이름을 업데이트합니다.
+/// 폴더의 이름, 아이콘, 색상을 업데이트합니다. 내 폴더가 아니면 404를 반환합니다.
 ///
 /// ### `folder_id`: 수정할 폴더 ID (예: 3)
 ///
 /// ### `folder_name`: 새 폴더 이름 (예: "Development")
 ///
+/// ### `folder_icon`: 새 폴더 아이콘, 생략하면 유지, 빈 문자열이면 삭제 (예: "💻")
+///
+/// ### `folder_color`: 새 폴더 색상, 생략하면 유지, 빈 문자열이면 삭제 (예: "#4A90E2")
+///
 #[openapi(tag = "Folder")]
 #[put("/folder", data = "<folder>")]
 pub async fn update_folder(
     pool: &State<MySqlPool>,
     folder: Json<UpdateFolderRequestDto>,
-    _auth: AuthenticatedUser,
+    user: AuthenticatedUser,
 ) -> Result<Json<i32>, Status> {
-    match folder_service::update_folder(pool, folder.into_inner()).await {
+    match folder_service::update_folder(pool, user.user_email, folder.into_inner()).await {
         Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
         Err(_) => Err(Status::InternalServerError),
     }
 }
 
 /// # 폴더 삭제 API
 ///
-/// 폴더를 삭제합니다.
+/// 폴더를 삭제합니다. 본인 폴더가 아니면 404를 반환합니다.
 ///
 /// ### `folder_id`: 삭제할 폴더 ID (예: 3)
 ///
@@ -116,10 +173,12 @@ pub async fn update_folder(
 pub async fn delete_folder(
     pool: &State<MySqlPool>,
     folder_id: Json<DeleteFolderRequestDto>,
-    _auth: AuthenticatedUser,
+    user: AuthenticatedUser,
 ) -> Result<&str, Status> {
-    match folder_service::delete_folder(pool, folder_id.into_inner()).await {
+    match folder_service::delete_folder(pool, user.user_email, folder_id.into_inner()).await {
         Ok(_) => Ok("Success"),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
         Err(_) => Err(Status::InternalServerError),
     }
 }
@@ -144,3 +203,129 @@ pub async fn delete_channel_from_folder(
         Err(_) => Err(Status::InternalServerError),
     }
 }
+
+/// # 폴더 순서 변경 API
+///
+/// 폴더의 상위 폴더와 위치, 폴더 안 채널의 위치를 한 번에 변경합니다. (최대 500개)
+///
+/// 폴더를 자기 자신이나 하위 폴더 안으로 옮기면 400을 반환합니다.
+///
+/// ### `folders`: 폴더 위치 목록 (예: [{"folder_id": 4, "folder_parent_id": 3, "folder_position": 0}])
+///
+/// ### `channels`: 폴더 안 채널 위치 목록 (예: [{"folder_id": 3, "channel_id": 2, "channel_position": 1}])
+///
+#[openapi(tag = "Folder")]
+#[put("/folder/order", data = "<data>")]
+pub async fn reorder_folders(
+    pool: &State<MySqlPool>,
+    data: Json<FolderOrderRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match folder_service::reorder_folders(pool, user.user_email, data.into_inner()).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # OPML 내보내기 API
+///
+/// 구독 채널을 폴더 계층과 순서를 유지한 OPML 2.0 문서로 반환합니다. 폴더에 없는 구독 채널은 최상위에 포함됩니다.
+///
+#[openapi(tag = "Folder")]
+#[get("/folder/opml")]
+pub async fn export_opml(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<RawXml<String>, Status> {
+    match folder_service::export_opml(pool, user.user_email).await {
+        Ok(res) => Ok(RawXml(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # OPML 가져오기 API
+///
+/// OPML의 폴더 계층과 순서대로 폴더를 만들고 채널을 구독합니다. (최대 300개 피드)
+///
+/// 같은 위치에 같은 이름의 폴더가 있으면 재사용하며, 가져오지 못한 피드 주소는 `failed_links`로 반환합니다.
+///
+/// ### `opml`: OPML 문서 문자열 (예: "<opml version='2.0'>...</opml>")
+///
+#[openapi(tag = "Folder")]
+#[post("/folder/opml", data = "<data>")]
+pub async fn import_opml(
+    pool: &State<MySqlPool>,
+    model: &State<EmbeddingService>,
+    data: Json<OpmlImportRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<OpmlImportResponseDto>, Status> {
+    match folder_service::import_opml(pool, model, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 폴더 공개 API
+///
+/// 폴더를 추측할 수 없는 주소로 공개하고 공개 페이지, RSS, Atom 피드 주소를 반환합니다. 이미 공개된 폴더는 기존 주소를 반환합니다.
+///
+/// 공개 페이지와 피드는 로그인 없이 접근할 수 있으며 폴더에 직접 속한 채널만 포함합니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+#[openapi(tag = "Folder")]
+#[post("/folder/<folder_id>/publish")]
+pub async fn publish_folder(
+    pool: &State<MySqlPool>,
+    folder_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Json<FolderPublishResponseDto>, Status> {
+    match folder_service::publish_folder(pool, user.user_email, folder_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 폴더 공개 중단 API
+///
+/// 공개를 중단하면 기존 공개 주소는 더 이상 접근할 수 없습니다. 다시 공개하면 새 주소가 발급됩니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+#[openapi(tag = "Folder")]
+#[delete("/folder/<folder_id>/publish")]
+pub async fn unpublish_folder(
+    pool: &State<MySqlPool>,
+    folder_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match folder_service::unpublish_folder(pool, user.user_email, folder_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 공개 폴더 전체 구독 API
+///
+/// 공개 폴더의 채널을 모두 구독하고 새로 구독한 채널 수를 반환합니다.
+///
+/// ### `slug`: 폴더 공개 API의 `folder_public_slug` (예: "3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6")
+///
+#[openapi(tag = "Folder")]
+#[post("/folder/shared/<slug>/subscribe")]
+pub async fn subscribe_public_folder(
+    pool: &State<MySqlPool>,
+    slug: &str,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match folder_service::subscribe_public_folder(pool, user.user_email, slug).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,265 @@
+use std::path::PathBuf;
+
+use okapi::openapi3::OpenApi;
+use rocket::{form::Form, http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::GoogleReaderUser,
+    dto::greader::{
+        request::{
+            GReaderEditTagRequestDto, GReaderItemIdsRequestDto, GReaderLoginRequestDto,
+            GReaderMarkAllReadRequestDto, GReaderStreamRequestDto,
+        },
+        response::{
+            GReaderItemIdsResponseDto, GReaderStreamContentsResponseDto,
+            GReaderSubscriptionListResponseDto, GReaderTagListResponseDto,
+            GReaderUnreadCountListResponseDto, GReaderUserInfoResponseDto,
+        },
+    },
+    model::error::OmniNewsError,
+    service::greader_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        client_login,
+        get_token,
+        get_user_info,
+        get_subscription_list,
+        get_tag_list,
+        get_unread_count,
+        get_stream_contents,
+        get_item_ids,
+        get_item_contents,
+        edit_tag,
+        mark_all_as_read
+    ]
+}
+
+/// # Google Reader 로그인 API
+///
+/// Google Reader API를 지원하는 RSS 리더(Reeder, NetNewsWire 등)에서 로그인합니다.
+///
+/// 비밀번호 자리에 앱 비밀번호를 사용하며, 응답의 `Auth` 값을 `Authorization: GoogleLogin auth=<값>` 헤더로 보내야 합니다.
+///
+/// ### `Email`: 사용자 이메일 (예: "user@example.com")
+///
+/// ### `Passwd`: 앱 비밀번호 발급 API의 `app_password`
+///
+#[openapi(tag = "Google Reader")]
+#[post("/greader/accounts/ClientLogin", data = "<data>")]
+pub async fn client_login(
+    pool: &State<MySqlPool>,
+    data: Form<GReaderLoginRequestDto>,
+) -> Result<String, Status> {
+    match greader_service::client_login(pool, data.into_inner()).await {
+        Ok(res) => Ok(res),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::Unauthorized),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 쓰기 토큰 API
+///
+/// 쓰기 요청에 필요한 토큰이지만 별도로 검증하지 않으므로 고정 값을 반환합니다.
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/token")]
+pub async fn get_token(_user: GoogleReaderUser) -> String {
+    "omninews".to_string()
+}
+
+/// # Google Reader 사용자 정보 API
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/user-info")]
+pub async fn get_user_info(
+    pool: &State<MySqlPool>,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderUserInfoResponseDto>, Status> {
+    match greader_service::get_user_info(pool, user.user_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 구독 목록 API
+///
+/// 구독 중인 채널을 `feed/<채널 ID>` 형식으로 반환하며, 채널이 속한 폴더는 라벨로 표시됩니다.
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/subscription/list")]
+pub async fn get_subscription_list(
+    pool: &State<MySqlPool>,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderSubscriptionListResponseDto>, Status> {
+    match greader_service::get_subscription_list(pool, user.user_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 태그 목록 API
+///
+/// 별표(북마크) 상태와 폴더 이름을 라벨로 반환합니다.
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/tag/list")]
+pub async fn get_tag_list(
+    pool: &State<MySqlPool>,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderTagListResponseDto>, Status> {
+    match greader_service::get_tag_list(pool, user.user_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 읽지 않은 아이템 수 API
+///
+/// 채널, 라벨(폴더), 전체 구독 목록별 읽지 않은 아이템 수를 반환합니다.
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/unread-count")]
+pub async fn get_unread_count(
+    pool: &State<MySqlPool>,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderUnreadCountListResponseDto>, Status> {
+    match greader_service::get_unread_count(pool, user.user_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 스트림 내용 조회 API
+///
+/// 스트림의 아이템을 최신순으로 반환합니다. 숨김 규칙이 적용됩니다.
+///
+/// ### `stream`: 스트림 ID, 생략하면 `s` 또는 reading-list 사용 (예: "user/-/state/com.google/reading-list", "user/-/state/com.google/starred", "feed/12", "user/-/label/개발")
+///
+/// ### `n`: 아이템 수, 기본 20 최대 1000 (예: 20)
+///
+/// ### `c`: 이전 응답의 `continuation`, 생략 가능
+///
+/// ### `xt`: "user/-/state/com.google/read"이면 읽지 않은 아이템만 조회
+///
+/// ### `ot`: 이 시각(초) 이후 발행된 아이템만 조회, 생략 가능 (예: 1735689600)
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/stream/contents/<stream..>?<data..>")]
+pub async fn get_stream_contents(
+    pool: &State<MySqlPool>,
+    stream: PathBuf,
+    data: GReaderStreamRequestDto,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderStreamContentsResponseDto>, Status> {
+    let stream_id = match stream.to_string_lossy().to_string() {
+        stream_id if stream_id.is_empty() => data.s.clone().unwrap_or_default(),
+        stream_id => stream_id,
+    };
+
+    match greader_service::get_stream_contents(pool, user.user_id, stream_id, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 아이템 ID 목록 API
+///
+/// 동기화를 위해 스트림의 아이템 ID를 최신순으로 반환합니다.
+///
+/// ### `s`: 스트림 ID (예: "user/-/state/com.google/reading-list")
+///
+/// ### `n`: 아이템 수, 기본 20 최대 10000 (예: 1000)
+///
+/// ### `c`: 이전 응답의 `continuation`, 생략 가능
+///
+/// ### `xt`: "user/-/state/com.google/read"이면 읽지 않은 아이템만 조회
+///
+/// ### `ot`: 이 시각(초) 이후 발행된 아이템만 조회, 생략 가능 (예: 1735689600)
+///
+#[openapi(tag = "Google Reader")]
+#[get("/greader/reader/api/0/stream/items/ids?<data..>")]
+pub async fn get_item_ids(
+    pool: &State<MySqlPool>,
+    data: GReaderStreamRequestDto,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderItemIdsResponseDto>, Status> {
+    match greader_service::get_item_ids(pool, user.user_id, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 아이템 내용 조회 API
+///
+/// ### `i`: 아이템 ID, 여러 번 전달 가능 최대 1000개 (예: "tag:google.com,2005:reader/item/000000000000002a", "42")
+///
+#[openapi(tag = "Google Reader")]
+#[post("/greader/reader/api/0/stream/items/contents", data = "<data>")]
+pub async fn get_item_contents(
+    pool: &State<MySqlPool>,
+    data: Form<GReaderItemIdsRequestDto>,
+    user: GoogleReaderUser,
+) -> Result<Json<GReaderStreamContentsResponseDto>, Status> {
+    match greader_service::get_item_contents(pool, user.user_id, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 아이템 상태 변경 API
+///
+/// 읽음 상태와 별표(북마크)만 반영하며 그 밖의 태그는 무시합니다.
+///
+/// ### `i`: 아이템 ID, 여러 번 전달 가능 최대 1000개 (예: "42")
+///
+/// ### `a`: 추가할 상태 (예: "user/-/state/com.google/read", "user/-/state/com.google/starred")
+///
+/// ### `r`: 제거할 상태 (예: "user/-/state/com.google/read")
+///
+#[openapi(tag = "Google Reader")]
+#[post("/greader/reader/api/0/edit-tag", data = "<data>")]
+pub async fn edit_tag(
+    pool: &State<MySqlPool>,
+    data: Form<GReaderEditTagRequestDto>,
+    user: GoogleReaderUser,
+) -> Result<String, Status> {
+    match greader_service::edit_tag(pool, user.user_id, data.into_inner()).await {
+        Ok(_) => Ok("OK".to_string()),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # Google Reader 모두 읽음 API
+///
+/// 스트림의 아이템을 모두 읽음 처리합니다.
+///
+/// ### `s`: 스트림 ID (예: "feed/12", "user/-/label/개발")
+///
+/// ### `ts`: 이 시각(마이크로초) 이전 발행된 아이템만 읽음 처리, 생략 가능 (예: 1735689600000000)
+///
+#[openapi(tag = "Google Reader")]
+#[post("/greader/reader/api/0/mark-all-as-read", data = "<data>")]
+pub async fn mark_all_as_read(
+    pool: &State<MySqlPool>,
+    data: Form<GReaderMarkAllReadRequestDto>,
+    user: GoogleReaderUser,
+) -> Result<String, Status> {
+    match greader_service::mark_all_as_read(pool, user.user_id, data.into_inner()).await {
+        Ok(_) => Ok("OK".to_string()),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
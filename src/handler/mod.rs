@@ -1,18 +1,33 @@
 use okapi::openapi3::OpenApi;
 use rocket_okapi::{get_nested_endpoints_and_docs, settings::OpenApiSettings};
 
+pub mod app_password_handler;
 pub mod apple_server_notification;
+pub mod bookmark_handler;
 pub mod config_handler;
+pub mod digest_handler;
 pub mod error_handler;
+pub mod feed_rule_handler;
+pub mod feed_token_handler;
+pub mod feedback_handler;
+pub mod fever_handler;
 pub mod folder_handler;
+pub mod greader_handler;
 pub mod health_handler;
 pub mod index;
+pub mod mute_handler;
 pub mod news_handler;
+pub mod notification_handler;
 pub mod omninews_subscription_handler;
 pub mod premium;
+pub mod public_folder_handler;
+pub mod read_handler;
+pub mod reading_handler;
 pub mod rss_handler;
+pub mod saved_search_handler;
 pub mod search_handler;
 pub mod subscription_handler;
+pub mod sync_handler;
 pub mod user_handler;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -21,8 +36,23 @@ pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, O
         "/" => rss_handler::get_routes_and_docs(settings),
         "/" => news_handler::get_routes_and_docs(settings),
         "/" => search_handler::get_routes_and_docs(settings),
+        "/" => saved_search_handler::get_routes_and_docs(settings),
         "/" => subscription_handler::get_routes_and_docs(settings),
         "/" => folder_handler::get_routes_and_docs(settings),
+        "/" => public_folder_handler::get_routes_and_docs(settings),
+        "/" => read_handler::get_routes_and_docs(settings),
+        "/" => reading_handler::get_routes_and_docs(settings),
+        "/" => mute_handler::get_routes_and_docs(settings),
+        "/" => feed_rule_handler::get_routes_and_docs(settings),
+        "/" => feed_token_handler::get_routes_and_docs(settings),
+        "/" => app_password_handler::get_routes_and_docs(settings),
+        "/" => bookmark_handler::get_routes_and_docs(settings),
+        "/" => sync_handler::get_routes_and_docs(settings),
+        "/" => digest_handler::get_routes_and_docs(settings),
+        "/" => notification_handler::get_routes_and_docs(settings),
+        "/" => feedback_handler::get_routes_and_docs(settings),
+        "/" => greader_handler::get_routes_and_docs(settings),
+        "/" => fever_handler::get_routes_and_docs(settings),
         "/" => health_handler::get_routes_and_docs(settings),
         "/" => omninews_subscription_handler::get_routes_and_docs(settings),
 
@@ -0,0 +1,100 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::mute::{
+        request::MuteRuleRequestDto,
+        response::{MutePreviewResponseDto, MuteRuleResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::mute_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        create_mute_rule,
+        get_mute_rules,
+        delete_mute_rule,
+        preview_mute_rule
+    ]
+}
+
+/// # 숨김 규칙 추가 API
+///
+/// 구독 아이템, 추천, 검색 결과에서 숨길 규칙을 추가합니다. (최대 100개)
+///
+/// ### `mute_rule_type`: 규칙 종류 (예: "Keyword" => 제목, 설명에 포함, "Regex" => 제목, 설명에 정규식 매칭, "Author" => 작성자 일치, "Domain" => 링크 도메인, "Channel" => 채널 ID)
+///
+/// ### `mute_rule_value`: 규칙 값, 대소문자 무시 (예: "광고", "^\\[스포츠\\]", "example.com", "3")
+///
+#[openapi(tag = "Mute")]
+#[post("/mute/rule", data = "<data>")]
+pub async fn create_mute_rule(
+    pool: &State<MySqlPool>,
+    data: Json<MuteRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match mute_service::create_mute_rule(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::AlreadyExists(_)) => Err(Status::Conflict),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 숨김 규칙 목록 조회 API
+///
+#[openapi(tag = "Mute")]
+#[get("/mute/rule")]
+pub async fn get_mute_rules(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<MuteRuleResponseDto>>, Status> {
+    match mute_service::get_mute_rules(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 숨김 규칙 삭제 API
+///
+/// ### `mute_rule_id`: 숨김 규칙 ID (예: 5)
+///
+#[openapi(tag = "Mute")]
+#[delete("/mute/rule/<mute_rule_id>")]
+pub async fn delete_mute_rule(
+    pool: &State<MySqlPool>,
+    mute_rule_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match mute_service::delete_mute_rule(pool, user.user_email, mute_rule_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 숨김 규칙 미리보기 API
+///
+/// 규칙을 저장하지 않고, 구독 채널의 최근 아이템 200개 중 숨겨지는 아이템 수와 예시(최대 20개)를 반환합니다.
+///
+/// ### `mute_rule_type`: 규칙 종류 (예: "Keyword")
+///
+/// ### `mute_rule_value`: 규칙 값 (예: "광고")
+///
+#[openapi(tag = "Mute")]
+#[post("/mute/rule/preview", data = "<data>")]
+pub async fn preview_mute_rule(
+    pool: &State<MySqlPool>,
+    data: Json<MuteRuleRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<MutePreviewResponseDto>, Status> {
+    match mute_service::preview_mute_rule(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
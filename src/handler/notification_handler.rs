@@ -0,0 +1,228 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::notification::{
+        request::{
+            NotificationListRequestDto, NotificationPreferenceRequestDto,
+            NotificationPreferenceTargetRequestDto, NotificationReadRequestDto,
+            PushSettingRequestDto,
+        },
+        response::{
+            NotificationPageResponseDto, NotificationPreferenceResponseDto, PushSettingResponseDto,
+        },
+    },
+    model::error::OmniNewsError,
+    service::notification_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        get_push_setting,
+        update_push_setting,
+        get_notification_preferences,
+        update_notification_preference,
+        delete_notification_preference,
+        get_notifications,
+        mark_notifications_read,
+        delete_notification,
+        clear_notifications
+    ]
+}
+
+/// # 푸시 알림 세부 설정 조회 API
+///
+/// 방해 금지 시간과 시간대를 반환합니다. 푸시 켜기/끄기와 FCM 토큰은 사용자 알림 설정 API로 설정합니다.
+///
+#[openapi(tag = "알림 API")]
+#[get("/notification/push")]
+pub async fn get_push_setting(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<PushSettingResponseDto>, Status> {
+    match notification_service::get_push_setting(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 푸시 알림 세부 설정 API
+///
+/// 구독 채널에 새 글이 올라오면 모아서 푸시로 알립니다. 방해 금지 시간에는 보내지 않고 끝난 뒤 한 번에 보냅니다.
+///
+/// 방해 금지 시간은 시작, 종료를 함께 보내고 둘 다 생략하면 해제됩니다.
+///
+/// ### `push_quiet_start_hour`: 방해 금지 시작 시각 0~23 (예: 23)
+///
+/// ### `push_quiet_end_hour`: 방해 금지 종료 시각 0~23 (예: 7)
+///
+/// ### `push_timezone`: IANA 시간대, 생략하면 기존 값 유지 (예: "Asia/Seoul")
+///
+#[openapi(tag = "알림 API")]
+#[put("/notification/push", data = "<data>")]
+pub async fn update_push_setting(
+    pool: &State<MySqlPool>,
+    data: Json<PushSettingRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<PushSettingResponseDto>, Status> {
+    match notification_service::update_push_setting(pool, user.user_email, data.into_inner()).await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림 수준 설정 목록 조회 API
+///
+/// 채널, 폴더별로 저장한 알림 수준을 반환합니다. 설정이 없는 채널은 모든 새 글을 알립니다.
+///
+#[openapi(tag = "알림 API")]
+#[get("/notification/preferences")]
+pub async fn get_notification_preferences(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<NotificationPreferenceResponseDto>>, Status> {
+    match notification_service::get_notification_preferences(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림 수준 설정 API
+///
+/// 구독 중인 채널 또는 내 폴더의 새 글 알림 수준을 설정합니다. `channel_id`, `folder_id` 중 하나만 보냅니다.
+///
+/// 채널 설정이 폴더 설정보다 우선하고, 채널이 여러 폴더에 있으면 가장 넓은 수준을 따릅니다. 알림함과 푸시에 모두 적용됩니다.
+///
+/// ### `channel_id`: 채널 ID (예: 7)
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+/// ### `notification_level`: "All"(모든 새 글), "Keyword"(키워드가 포함된 글), "None"(알리지 않음) (예: "Keyword")
+///
+/// ### `notification_keywords`: `Keyword` 수준의 키워드, 1~20개 각 50자 이하 (예: ["반도체", "금리"])
+///
+#[openapi(tag = "알림 API")]
+#[put("/notification/preference", data = "<data>")]
+pub async fn update_notification_preference(
+    pool: &State<MySqlPool>,
+    data: Json<NotificationPreferenceRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<NotificationPreferenceResponseDto>, Status> {
+    match notification_service::update_notification_preference(
+        pool,
+        user.user_email,
+        data.into_inner(),
+    )
+    .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림 수준 설정 삭제 API
+///
+/// 설정을 지우면 폴더 설정 또는 기본값(모든 새 글)을 따릅니다.
+///
+/// ### `channel_id`: 채널 ID (예: 7)
+///
+/// ### `folder_id`: 폴더 ID (예: 3)
+///
+#[openapi(tag = "알림 API")]
+#[delete("/notification/preference?<data..>")]
+pub async fn delete_notification_preference(
+    pool: &State<MySqlPool>,
+    data: NotificationPreferenceTargetRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<bool>, Status> {
+    match notification_service::delete_notification_preference(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림함 조회 API
+///
+/// 최신순으로 알림을 반환합니다. 푸시를 꺼도 알림함에는 기록되며 90일 뒤 삭제됩니다.
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 없으면 첫 페이지 (예: "aToxMjM")
+///
+/// ### `size`: 페이지 크기, 기본 20 최대 100 (예: 20)
+///
+#[openapi(tag = "알림 API")]
+#[get("/notification?<data..>")]
+pub async fn get_notifications(
+    pool: &State<MySqlPool>,
+    data: NotificationListRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<NotificationPageResponseDto>, Status> {
+    match notification_service::get_notifications(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림 읽음 처리 API
+///
+/// 읽음 처리한 알림 수를 반환합니다.
+///
+/// ### `notification_ids`: 알림 ID 목록, 생략하면 모든 알림 (예: [120, 118])
+///
+#[openapi(tag = "알림 API")]
+#[post("/notification/read", data = "<data>")]
+pub async fn mark_notifications_read(
+    pool: &State<MySqlPool>,
+    data: Json<NotificationReadRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<u64>, Status> {
+    match notification_service::mark_notifications_read(pool, user.user_email, data.into_inner())
+        .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림 삭제 API
+///
+/// ### `notification_id`: 알림 ID (예: 120)
+///
+#[openapi(tag = "알림 API")]
+#[delete("/notification/<notification_id>")]
+pub async fn delete_notification(
+    pool: &State<MySqlPool>,
+    notification_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Json<bool>, Status> {
+    match notification_service::delete_notification(pool, user.user_email, notification_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 알림함 비우기 API
+///
+/// 모든 알림을 삭제하고 삭제한 수를 반환합니다.
+///
+#[openapi(tag = "알림 API")]
+#[delete("/notification")]
+pub async fn clear_notifications(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<u64>, Status> {
+    match notification_service::clear_notifications(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
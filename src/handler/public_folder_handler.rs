@@ -0,0 +1,73 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, response::content::RawXml, State};
+use rocket_dyn_templates::{context, Template};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{model::error::OmniNewsError, service::folder_service};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        public_folder_page,
+        get_public_folder_rss,
+        get_public_folder_atom
+    ]
+}
+
+// HTML 페이지라 문서에서 제외
+#[openapi(skip)]
+#[get("/public/folder/<slug>")]
+pub async fn public_folder_page(pool: &State<MySqlPool>, slug: &str) -> Result<Template, Status> {
+    match folder_service::get_public_folder(pool, slug).await {
+        Ok(folder) => Ok(Template::render(
+            "public_folder",
+            context! {
+                title: format!("Omninews - {}", folder.folder_name.clone().unwrap_or_default()),
+                app_name: "Omninews",
+                folder: folder,
+            },
+        )),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 공개 폴더 RSS 피드 API
+///
+/// 공개 폴더에 속한 채널들의 최신 아이템 50개를 합친 RSS 2.0 피드를 반환합니다.
+///
+/// 로그인 없이 RSS 리더에서 구독할 수 있습니다.
+///
+/// ### `slug`: 폴더 공개 API의 `folder_public_slug` (예: "3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6")
+///
+#[openapi(tag = "Public Folder")]
+#[get("/public/folder/<slug>/rss")]
+pub async fn get_public_folder_rss(
+    pool: &State<MySqlPool>,
+    slug: &str,
+) -> Result<RawXml<String>, Status> {
+    match folder_service::get_public_folder_rss(pool, slug).await {
+        Ok(res) => Ok(RawXml(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 공개 폴더 Atom 피드 API
+///
+/// 공개 폴더에 속한 채널들의 최신 아이템 50개를 합친 Atom 피드를 반환합니다.
+///
+/// ### `slug`: 폴더 공개 API의 `folder_public_slug` (예: "3f2a9c1e7b5d4e8fa0c6b1d2e3f4a5b6")
+///
+#[openapi(tag = "Public Folder")]
+#[get("/public/folder/<slug>/atom")]
+pub async fn get_public_folder_atom(
+    pool: &State<MySqlPool>,
+    slug: &str,
+) -> Result<RawXml<String>, Status> {
+    match folder_service::get_public_folder_atom(pool, slug).await {
+        Ok(res) => Ok(RawXml(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
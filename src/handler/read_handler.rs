@@ -0,0 +1,139 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::read::{request::MarkReadBeforeRequestDto, response::UnreadCountResponseDto},
+    model::error::OmniNewsError,
+    service::read_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        mark_item_read,
+        mark_item_unread,
+        mark_channel_read,
+        mark_folder_read,
+        mark_read_before,
+        get_unread_counts
+    ]
+}
+
+/// # 아이템 읽음 처리 API
+///
+/// 아이템을 읽은 것으로 표시합니다.
+///
+/// ### `rss_id`: 아이템 ID (예: 42)
+///
+#[openapi(tag = "Read")]
+#[put("/read/item/<rss_id>")]
+pub async fn mark_item_read(
+    pool: &State<MySqlPool>,
+    rss_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match read_service::mark_item_read(pool, user.user_email, rss_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 아이템 안 읽음 처리 API
+///
+/// 아이템을 읽지 않은 것으로 표시합니다. 모두 읽음 처리된 채널의 아이템도 다시 안 읽음으로 바꿀 수 있습니다.
+///
+/// ### `rss_id`: 아이템 ID (예: 42)
+///
+#[openapi(tag = "Read")]
+#[delete("/read/item/<rss_id>")]
+pub async fn mark_item_unread(
+    pool: &State<MySqlPool>,
+    rss_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match read_service::mark_item_unread(pool, user.user_email, rss_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 채널 모두 읽음 처리 API
+///
+/// 채널의 현재 아이템을 모두 읽은 것으로 표시합니다. 이후 수집되는 아이템은 안 읽음 상태입니다.
+///
+/// ### `channel_id`: 채널 ID (예: 3)
+///
+#[openapi(tag = "Read")]
+#[put("/read/channel/<channel_id>")]
+pub async fn mark_channel_read(
+    pool: &State<MySqlPool>,
+    channel_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match read_service::mark_channel_read(pool, user.user_email, channel_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 폴더 모두 읽음 처리 API
+///
+/// 폴더에 속한 채널의 현재 아이템을 모두 읽은 것으로 표시합니다.
+///
+/// ### `folder_id`: 폴더 ID (예: 1)
+///
+#[openapi(tag = "Read")]
+#[put("/read/folder/<folder_id>")]
+pub async fn mark_folder_read(
+    pool: &State<MySqlPool>,
+    folder_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match read_service::mark_folder_read(pool, user.user_email, folder_id).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 기준 시각 이전 아이템 읽음 처리 API
+///
+/// 기준 시각 이전에 발행된 아이템을 모두 읽은 것으로 표시합니다.
+///
+/// ### `before`: 기준 시각 (예: "2025-01-01T09:00:00")
+///
+/// ### `channel_ids`: 적용할 채널 ID 목록, 없으면 구독한 채널 전체 (예: [1, 2, 3])
+///
+#[openapi(tag = "Read")]
+#[put("/read/before", data = "<data>")]
+pub async fn mark_read_before(
+    pool: &State<MySqlPool>,
+    data: Json<MarkReadBeforeRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Status, Status> {
+    match read_service::mark_read_before(pool, user.user_email, data.into_inner()).await {
+        Ok(_) => Ok(Status::Ok),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 안 읽은 아이템 수 조회 API
+///
+/// 구독한 채널, 폴더별 안 읽은 아이템 수와 전체 합계를 반환합니다.
+///
+#[openapi(tag = "Read")]
+#[get("/read/unread-count")]
+pub async fn get_unread_counts(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<UnreadCountResponseDto>, Status> {
+    match read_service::get_unread_counts(pool, user.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -0,0 +1,94 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::reading::{
+        request::{ReadingEventsRequestDto, ReadingHistoryRequestDto, ReadingStatsRequestDto},
+        response::{ReadingHistoryPageResponseDto, ReadingStatsResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::reading_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings:
+        record_reading_events,
+        get_reading_histories,
+        get_reading_stats
+    ]
+}
+
+/// # 읽기 이벤트 기록 API
+///
+/// 아이템을 열거나 읽은 시간을 기록합니다. 여러 이벤트를 모아서 한 번에 보낼 수 있습니다. (최대 100개)
+///
+/// 처음 연 아이템은 읽음 처리되고 읽은 아이템 수에 반영됩니다. 처리한 이벤트 수를 반환합니다.
+///
+/// ### `event_type`: 이벤트 종류 (예: "Open" => 아이템 열기, "Dwell" => 체류 시간 추가)
+///
+/// ### `rss_id`: 아이템 ID (예: 42)
+///
+/// ### `seconds`: `Dwell` 이벤트의 체류 시간(초), 이벤트당 최대 1800 (예: 95)
+///
+/// ### `occurred_at`: 이벤트 발생 시각, 없으면 서버 수신 시각 (예: "2025-01-01T09:00:00")
+///
+#[openapi(tag = "Reading")]
+#[post("/user/history/events", data = "<data>")]
+pub async fn record_reading_events(
+    pool: &State<MySqlPool>,
+    data: Json<ReadingEventsRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match reading_service::record_events(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 읽기 기록 조회 API
+///
+/// 최근 읽은 순서로 읽기 기록을 반환합니다.
+///
+/// ### `cursor`: 이전 응답의 `next_cursor`, 없으면 첫 페이지 (예: "aToxMjM")
+///
+/// ### `size`: 페이지 크기, 기본 20 최대 100 (예: 20)
+///
+#[openapi(tag = "Reading")]
+#[get("/user/history?<data..>")]
+pub async fn get_reading_histories(
+    pool: &State<MySqlPool>,
+    data: ReadingHistoryRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<ReadingHistoryPageResponseDto>, Status> {
+    match reading_service::get_histories(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 읽기 통계 조회 API
+///
+/// 기간 내 일별, 주별 읽은 아이템 수와 시간, 많이 읽은 채널과 태그, 연속으로 읽은 날 수를 반환합니다.
+///
+/// ### `from`: 시작 날짜, 없으면 `to` 기준 최근 30일 (예: "2025-01-01")
+///
+/// ### `to`: 끝 날짜, 없으면 오늘. 기간은 최대 366일 (예: "2025-01-31")
+///
+#[openapi(tag = "Reading")]
+#[get("/user/stats?<data..>")]
+pub async fn get_reading_stats(
+    pool: &State<MySqlPool>,
+    data: ReadingStatsRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<ReadingStatsResponseDto>, Status> {
+    match reading_service::get_stats(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
@@ -6,16 +6,21 @@ use rocket_okapi::{openapi, openapi_get_routes_spec};
 use sqlx::MySqlPool;
 
 use crate::auth_middleware::AuthenticatedUser;
-use crate::dto::rss::request::{CreateRssRequestDto, RssItemRequestDto, UpdateRssRankRequestDto};
-use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
-use crate::service::{channel_service, item_service};
+use crate::dto::rss::request::{
+    CreateRssRequestDto, RelatedRssItemRequestDto, RssItemRequestDto, UpdateRssRankRequestDto,
+};
+use crate::dto::rss::response::{
+    RelatedRssItemResponseDto, RssChannelResponseDto, RssItemListResponseDto, RssItemResponseDto,
+};
+use crate::model::error::OmniNewsError;
+use crate::service::{channel_service, item_service, recommendation_service};
 use crate::EmbeddingService;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
     openapi_get_routes_spec![settings: get_channel_id_by_rss_link,
         get_rss_channel_by_id, get_rss_item_by_channel_id, get_recommend_channel,
         get_recommend_item, get_rss_preview, is_rss_exist, create_channel, create_rss_all,
-        update_rss_item_rank]
+        update_rss_item_rank, get_related_items]
 }
 
 /// # RSS 채널 생성 API
@@ -108,8 +113,12 @@ pub async fn get_rss_channel_by_id(
 ///
 /// 특정 채널에 속한 RSS 아이템 목록을 조회합니다.
 ///
+/// 최신순으로 `size`개를 반환하며, 응답의 `next_cursor`를 `cursor`로 전달해 다음 페이지를 조회합니다.
+///
 /// ### `channel_id` : 조회할 채널 ID (예: 3)
-/// ### `page` : 조회할 page 번호 (예: 1)
+/// ### `cursor` : 이전 응답의 `next_cursor`, 첫 페이지는 생략
+/// ### `size` : 페이지 크기, 기본 20개, 최대 100개 (예: 20)
+/// ### `page` : (이전 방식) 조회할 page 번호, 지정하면 기존처럼 20개 배열을 반환 (예: 1)
 ///
 #[openapi(tag = "RSS API")]
 #[get("/rss/items?<data..>")]
@@ -117,48 +126,55 @@ pub async fn get_rss_item_by_channel_id(
     pool: &State<MySqlPool>,
     data: RssItemRequestDto,
     _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
+) -> Result<Json<RssItemListResponseDto>, Status> {
     match item_service::get_rss_item_by_channel_id_pagenation(pool, data).await {
         Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
         Err(_) => Err(Status::InternalServerError),
     }
 }
 
-// TODO 추천 채널 기능 변경 후 여기 내용 추가하기
 /// # 추천 RSS 채널 조회 API
 ///
 /// 사용자에게 추천하는 RSS 채널 목록을 반환합니다.
 ///
 /// ## 기능 설명
-/// 랭크 50순위 채널에서 20개 랜덤 반환
+/// 구독 채널과 최근 읽은 글 임베딩으로 만든 관심사와의 유사도, 채널 랭크를 함께 반영해 20개 반환
+/// (이미 구독한 채널 제외, 비슷한 채널끼리 몰리지 않도록 다양화)
+///
+/// 구독 채널과 최근 읽은 글이 모두 없으면 랭크 50순위 채널에서 20개 랜덤 반환
 ///
 #[openapi(tag = "RSS API")]
 #[get("/rss/recommend/channel")]
 pub async fn get_recommend_channel(
     pool: &State<MySqlPool>,
-    _auth: AuthenticatedUser,
+    auth: AuthenticatedUser,
 ) -> Result<Json<Vec<RssChannelResponseDto>>, Status> {
-    match channel_service::get_recommend_channel(pool).await {
+    match recommendation_service::get_recommend_channel(pool, auth.user_email).await {
         Ok(res) => Ok(Json(res)),
         Err(_) => Err(Status::InternalServerError),
     }
 }
 
-// TODO 추천 rss 기능 변경 후 여기 내용 추가하기
 /// # 추천 RSS 아이템 조회 API
 ///
 /// 사용자에게 추천하는 RSS 아이템 목록을 반환합니다.
 ///
 /// ## 기능 설명
-/// 상위 100개 중 50개 랜덤 반환
+/// 최근 3일간의 아이템 중 관심사 유사도, 랭크, 최신성을 함께 반영해 50개 반환
+/// (비슷한 아이템끼리 몰리지 않도록 다양화)
+///
+/// 구독 채널과 최근 읽은 글이 모두 없으면 상위 100개 중 50개 랜덤 반환
+///
+/// 숨김 규칙에 해당하는 아이템은 제외
 ///
 #[openapi(tag = "RSS API")]
 #[get("/rss/recommend/item")]
 pub async fn get_recommend_item(
     pool: &State<MySqlPool>,
-    _auth: AuthenticatedUser,
+    auth: AuthenticatedUser,
 ) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
-    match item_service::get_recommend_item(pool).await {
+    match recommendation_service::get_recommend_item(pool, auth.user_email).await {
         Ok(res) => Ok(Json(res)),
         Err(_) => Err(Status::InternalServerError),
     }
@@ -221,3 +237,31 @@ pub async fn update_rss_item_rank(
         Err(_) => Err(Status::InternalServerError),
     }
 }
+
+/// # 연관 RSS 아이템 조회 API
+///
+/// 아이템의 임베딩과 유사한 아이템 목록을 유사도와 함께 반환합니다.
+///
+/// 거의 같은 내용의 기사(타 언론사 전재 등)는 하나만 반환합니다.
+/// 기준 아이템과 같은 스토리로 묶였거나 거의 같은 내용인 기사는 제외합니다.
+///
+/// ### `rss_id` : 기준 RSS 아이템 ID (예: 67890)
+///
+/// ### `exclude_same_channel` : 같은 채널의 아이템 제외 여부, 기본 false (예: true)
+///
+/// ### `limit` : 반환 개수, 기본 10개, 최대 50개 (예: 10)
+///
+#[openapi(tag = "RSS API")]
+#[get("/rss/item/<rss_id>/related?<data..>")]
+pub async fn get_related_items(
+    pool: &State<MySqlPool>,
+    rss_id: i32,
+    data: RelatedRssItemRequestDto,
+    _auth: AuthenticatedUser,
+) -> Result<Json<Vec<RelatedRssItemResponseDto>>, Status> {
+    match item_service::get_related_items(pool, rss_id, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
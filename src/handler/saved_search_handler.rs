@@ -0,0 +1,145 @@
+use okapi::openapi3::OpenApi;
+use rocket::http::Status;
+use rocket::response::content::RawXml;
+use rocket::serde::json::Json;
+use rocket::State;
+use rocket_okapi::settings::OpenApiSettings;
+use rocket_okapi::{openapi, openapi_get_routes_spec};
+use sqlx::MySqlPool;
+
+use crate::auth_middleware::AuthenticatedUser;
+use crate::dto::search::request::CreateSavedSearchRequestDto;
+use crate::dto::search::response::{SavedSearchHitsResponseDto, SavedSearchResponseDto};
+use crate::model::error::OmniNewsError;
+use crate::service::saved_search_service;
+use crate::EmbeddingService;
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: create_saved_search, get_saved_searches,
+        delete_saved_search, get_saved_search_hits, mark_saved_search_hits_seen,
+        get_saved_search_feed]
+}
+
+/// # 검색 저장 API
+///
+/// 검색어와 필터를 저장합니다. 이후 수집되는 아이템 중 조건에 맞는 아이템이 알림으로 기록됩니다.
+///
+/// ### `saved_search_name` : 저장할 이름, 없으면 검색어 사용 (예: "아침 반도체 브리핑")
+///
+/// ### `saved_search_query` : 검색어 (예: "반도체 수출")
+///
+/// ### `saved_search_mode` : 검색 방식 (예: "Keyword" => 모든 검색어 포함, "Semantic" => 의미 유사도)
+///
+/// ### `channel_ids` : 채널 필터, 없으면 전체 채널 (예: [1, 2, 3])
+///
+/// ### `language` : 언어 필터 (예: "ko")
+///
+#[openapi(tag = "검색 API")]
+#[post("/search/saved", data = "<data>")]
+pub async fn create_saved_search(
+    pool: &State<MySqlPool>,
+    model: &State<EmbeddingService>,
+    data: Json<CreateSavedSearchRequestDto>,
+    auth: AuthenticatedUser,
+) -> Result<Json<i32>, Status> {
+    match saved_search_service::create_saved_search(pool, model, auth.user_email, data.into_inner())
+        .await
+    {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 저장된 검색 목록 조회 API
+///
+/// 사용자가 저장한 검색 목록을 확인하지 않은 알림 수, 비공개 RSS 피드 URL과 함께 반환합니다.
+///
+#[openapi(tag = "검색 API")]
+#[get("/search/saved")]
+pub async fn get_saved_searches(
+    pool: &State<MySqlPool>,
+    auth: AuthenticatedUser,
+) -> Result<Json<Vec<SavedSearchResponseDto>>, Status> {
+    match saved_search_service::get_saved_searches(pool, auth.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 저장된 검색 삭제 API
+///
+/// 저장된 검색과 알림 기록을 삭제합니다.
+///
+/// ### `saved_search_id` : 삭제할 저장된 검색 ID (예: 7)
+///
+#[openapi(tag = "검색 API")]
+#[delete("/search/saved/<saved_search_id>")]
+pub async fn delete_saved_search(
+    pool: &State<MySqlPool>,
+    saved_search_id: i32,
+    auth: AuthenticatedUser,
+) -> Result<&'static str, Status> {
+    match saved_search_service::delete_saved_search(pool, auth.user_email, saved_search_id).await {
+        Ok(_) => Ok("Success delete saved search"),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 저장된 검색 알림 조회 API
+///
+/// 저장된 검색별로 아직 확인하지 않은 알림 아이템 목록을 반환합니다. (검색별 최대 100개)
+///
+#[openapi(tag = "검색 API")]
+#[get("/search/saved/hits")]
+pub async fn get_saved_search_hits(
+    pool: &State<MySqlPool>,
+    auth: AuthenticatedUser,
+) -> Result<Json<Vec<SavedSearchHitsResponseDto>>, Status> {
+    match saved_search_service::get_unseen_hits(pool, auth.user_email).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 저장된 검색 알림 확인 처리 API
+///
+/// 저장된 검색의 알림을 모두 확인한 것으로 표시합니다. 내 저장된 검색이 아니면 404를 반환합니다.
+///
+/// ### `saved_search_id` : 저장된 검색 ID (예: 7)
+///
+#[openapi(tag = "검색 API")]
+#[put("/search/saved/<saved_search_id>/seen")]
+pub async fn mark_saved_search_hits_seen(
+    pool: &State<MySqlPool>,
+    saved_search_id: i32,
+    auth: AuthenticatedUser,
+) -> Result<&'static str, Status> {
+    match saved_search_service::mark_hits_seen(pool, auth.user_email, saved_search_id).await {
+        Ok(_) => Ok("Success mark saved search hits seen"),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 저장된 검색 RSS 피드 API
+///
+/// 저장된 검색의 최근 알림 아이템을 RSS 피드로 반환합니다.
+///
+/// 토큰으로만 접근하는 비공개 URL이므로 로그인 없이 RSS 리더에서 구독할 수 있습니다.
+///
+/// ### `token` : 저장된 검색 목록 조회 API의 `feed_url`에 포함된 토큰
+///
+#[openapi(tag = "검색 API")]
+#[get("/search/saved/feed/<token>")]
+pub async fn get_saved_search_feed(
+    pool: &State<MySqlPool>,
+    token: &str,
+) -> Result<RawXml<String>, Status> {
+    match saved_search_service::get_saved_search_feed(pool, token).await {
+        Ok(res) => Ok(RawXml(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
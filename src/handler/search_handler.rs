@@ -9,24 +9,36 @@ use sqlx::MySqlPool;
 use crate::auth_middleware::AuthenticatedUser;
 use crate::dto::news::request::ApiNewsRequestDto;
 use crate::dto::news::response::NewsApiResponseDto;
-use crate::dto::search::request::SearchRequestDto;
-use crate::dto::search::response::SearchResponseDto;
-use crate::service::{channel_service, item_service, news_service};
+use crate::dto::search::request::{SearchRequestDto, SearchSuggestRequestDto};
+use crate::dto::search::response::{SearchResponseDto, SearchSuggestResponseDto};
+use crate::model::error::OmniNewsError;
+use crate::service::{channel_service, item_service, news_service, search_service};
 use crate::EmbeddingService;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
-    openapi_get_routes_spec![settings: get_rss_list, get_channel_list, get_news_by_api ]
+    openapi_get_routes_spec![settings: get_rss_list, get_channel_list, get_news_by_api, get_search_suggest ]
 }
 
 /// # 검색 내용으로 RSS 목록 조회 API
 ///
 /// 검색 내용에 해당하는 RSS 아이템 목록을 반환합니다.
 ///
+/// 각 아이템의 `snippet`에 매칭 이유가 포함됩니다. 키워드 매칭(`Lexical`)은 검색어를 `<em>`, `</em>`로 감싸며,
+/// 나머지 텍스트는 HTML 이스케이프됩니다.
+///
+/// 숨김 규칙에 해당하는 아이템은 제외되고, 제외된 수는 `hidden_count`로 반환됩니다.
+///
 /// ### `search_value` : 검색어 (예: "AI", "경제")
 ///
 /// ### `search_type` : 검색 타입 (예: "Accuracy", "Popularity", "Latest")
 ///
-/// ### `page_size` : 프론트에서 요청하는 페이지 번호, 반환 데이터는 기본 20개 (예 : 3, 10)
+/// ### `cursor` : 이전 응답의 `next_cursor`, 첫 페이지는 생략 (검색 타입을 바꾸면 처음부터 조회)
+///
+/// ### `size` : 페이지 크기, 기본 20개, 최대 100개 (예: 20)
+///
+/// ### `search_page_size` : (이전 방식) 프론트에서 요청하는 페이지 번호, 지정하면 커서 대신 페이지 번호로 조회 (예 : 3, 10)
+///
+/// ### `collapse_story` : 같은 스토리의 아이템을 하나로 묶고 출처 목록을 함께 반환 (예: true)
 ///
 #[openapi(tag = "검색 API")]
 #[get("/search/item?<request..>")]
@@ -34,14 +46,21 @@ pub async fn get_rss_list(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
     request: SearchRequestDto,
-    _auth: AuthenticatedUser,
+    auth: AuthenticatedUser,
 ) -> Result<Json<SearchResponseDto>, Status> {
     if request.search_value.is_none() {
         return Err(Status::BadRequest);
     }
 
-    match item_service::get_rss_list(pool, model, request).await {
+    search_service::record_search_query(
+        pool,
+        auth.user_email.clone(),
+        request.search_value.as_deref().unwrap_or_default(),
+    );
+
+    match item_service::get_rss_list(pool, model, auth.user_email, request).await {
         Ok(result) => Ok(Json(result)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
         Err(_) => Err(Status::InternalServerError),
     }
 }
@@ -62,12 +81,18 @@ pub async fn get_channel_list(
     pool: &State<MySqlPool>,
     model: &State<EmbeddingService>,
     request: SearchRequestDto,
-    _auth: AuthenticatedUser,
+    auth: AuthenticatedUser,
 ) -> Result<Json<SearchResponseDto>, Status> {
     if request.search_value.is_none() {
         return Err(Status::BadRequest);
     }
 
+    search_service::record_search_query(
+        pool,
+        auth.user_email,
+        request.search_value.as_deref().unwrap_or_default(),
+    );
+
     match channel_service::get_channel_list(pool, model, request).await {
         Ok(result) => Ok(Json(result)),
         Err(_) => Err(Status::InternalServerError),
@@ -95,3 +120,30 @@ pub async fn get_news_by_api(
         Err(_) => Err(Status::InternalServerError),
     }
 }
+
+/// # 검색어 자동완성 API
+///
+/// 입력 중인 검색어에 대한 채널, 인기 검색어, 태그 추천 목록을 반환합니다.
+///
+/// 한글은 자모 단위로 비교하므로 조합 중인 음절도 매칭됩니다. (예: "하", "한ㄱ" -> "한국", "ㄱ" -> "국제")
+///
+/// ### `q` : 입력 중인 검색어 (예: "한ㄱ", "AI")
+///
+/// ### `limit` : 항목별 최대 개수, 기본 5개, 최대 20개 (예: 5)
+///
+#[openapi(tag = "검색 API")]
+#[get("/search/suggest?<request..>")]
+pub async fn get_search_suggest(
+    pool: &State<MySqlPool>,
+    request: SearchSuggestRequestDto,
+    _auth: AuthenticatedUser,
+) -> Result<Json<SearchSuggestResponseDto>, Status> {
+    if request.q.is_none() {
+        return Err(Status::BadRequest);
+    }
+
+    match search_service::get_search_suggest(pool, request).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
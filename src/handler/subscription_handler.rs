@@ -6,8 +6,9 @@ use rocket_okapi::{openapi, openapi_get_routes_spec};
 use sqlx::MySqlPool;
 
 use crate::auth_middleware::AuthenticatedUser;
-use crate::dto::rss::response::{RssChannelResponseDto, RssItemResponseDto};
+use crate::dto::rss::response::{RssChannelResponseDto, RssItemListResponseDto};
 use crate::dto::subscribe::request::{SubscribeRequestDto, SubscribeRssItemRequestDto};
+use crate::model::error::OmniNewsError;
 use crate::service::subscription_service;
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
@@ -88,18 +89,29 @@ pub async fn get_subscribe_channels(
 ///
 /// 사용자가 구독한 채널의 아이템 목록을 조회합니다.
 ///
+/// 최신순으로 `size`개를 반환하며, 응답의 `next_cursor`를 `cursor`로 전달해 다음 페이지를 조회합니다.
+///
+/// 숨김 규칙에 해당하는 아이템은 제외되고, 제외된 수는 `hidden_count`로 반환됩니다. (그만큼 페이지가 짧아질 수 있음)
+///
 /// ### `channel_ids` : 구독한 채널 ID 목록 (예: "1, 2, 3")
-/// ### `page` : 검색할 페이지 번호 (예: 1)
+/// ### `cursor` : 이전 응답의 `next_cursor`, 첫 페이지는 생략
+/// ### `size` : 페이지 크기, 기본 20개, 최대 100개 (예: 20)
+/// ### `page` : (이전 방식) 검색할 페이지 번호, 지정하면 기존처럼 20개 배열을 반환 (예: 1)
+///
+/// ### `collapse_story` : 같은 스토리의 아이템을 하나로 묶고 출처 목록을 함께 반환 (예: true)
+///
+/// ### `unread_only` : 읽지 않은 아이템만 조회, 커서 방식에서만 적용 (예: true)
 ///
 #[openapi(tag = "Subscription")]
 #[get("/subscription/items?<data..>")]
 pub async fn get_subscribe_items(
     pool: &State<MySqlPool>,
     data: SubscribeRssItemRequestDto,
-    _auth: AuthenticatedUser,
-) -> Result<Json<Vec<RssItemResponseDto>>, Status> {
-    match subscription_service::get_subscription_items(pool, data).await {
+    auth: AuthenticatedUser,
+) -> Result<Json<RssItemListResponseDto>, Status> {
+    match subscription_service::get_subscription_items(pool, auth.user_email, data).await {
         Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
         Err(_) => Err(Status::InternalServerError),
     }
 }
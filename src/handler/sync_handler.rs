@@ -0,0 +1,71 @@
+use okapi::openapi3::OpenApi;
+use rocket::{http::Status, serde::json::Json, State};
+use rocket_okapi::{openapi, openapi_get_routes_spec, settings::OpenApiSettings};
+use sqlx::MySqlPool;
+
+use crate::{
+    auth_middleware::AuthenticatedUser,
+    dto::sync::{
+        request::{SyncRequestDto, SyncUploadRequestDto},
+        response::{SyncResponseDto, SyncUploadResponseDto},
+    },
+    model::error::OmniNewsError,
+    service::sync_service,
+};
+
+pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
+    openapi_get_routes_spec![settings: get_changes, upload_changes]
+}
+
+/// # 동기화 API
+///
+/// 마지막 동기화 이후 바뀐 내용을 반환합니다. 구독 채널의 새 아이템, 구독, 폴더, 읽음 상태, 북마크 변경과 삭제된 대상이 포함됩니다.
+///
+/// `since` 없이 요청하면 현재 상태 전체(`is_full`)를 반환하므로 클라이언트는 기존 상태를 교체해야 합니다.
+///
+/// 응답의 `sync_token`을 다음 요청의 `since`로 전달하고, `has_more`가 true이면 바로 다시 요청합니다.
+///
+/// 이미 받은 아이템의 내용(언어 등)이 바뀌면 `items`에 다시 포함됩니다.
+///
+/// 변경 기록은 30일간 보관하므로, 그보다 오래된 `since`로 요청하면 현재 상태 전체(`is_full`)를 반환합니다.
+///
+/// ### `since`: 이전 응답의 `sync_token` (예: "czoxMjM6NDU2Nw")
+///
+#[openapi(tag = "Sync")]
+#[get("/sync?<data..>")]
+pub async fn get_changes(
+    pool: &State<MySqlPool>,
+    data: SyncRequestDto,
+    user: AuthenticatedUser,
+) -> Result<Json<SyncResponseDto>, Status> {
+    match sync_service::get_changes(pool, user.user_email, data).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 동기화 업로드 API
+///
+/// 오프라인에서 바꾼 읽음 상태와 북마크를 반영합니다. 최대 500개까지 한 번에 올릴 수 있습니다.
+///
+/// 대상별로 서버에서 더 나중에 바뀐 값이 있으면 반영하지 않고 `conflicts`에 서버 값을 반환합니다.
+/// 충돌은 필드가 아닌 대상(아이템 읽음 여부, 북마크 저장 여부) 단위로 판단합니다.
+///
+/// ### `item_read_states`: 읽음 상태 변경 (예: [{"rss_id": 42, "is_read": true, "changed_at": "2025-01-01T09:00:00"}])
+///
+/// ### `bookmarks`: 북마크 변경, `is_saved`가 false면 북마크 해제 (예: [{"rss_id": 42, "is_saved": true, "changed_at": "2025-01-01T09:00:00"}])
+///
+#[openapi(tag = "Sync")]
+#[post("/sync", data = "<data>")]
+pub async fn upload_changes(
+    pool: &State<MySqlPool>,
+    data: Json<SyncUploadRequestDto>,
+    user: AuthenticatedUser,
+) -> Result<Json<SyncUploadResponseDto>, Status> {
+    match sync_service::upload_changes(pool, user.user_email, data.into_inner()).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::InvalidValue(_)) => Err(Status::BadRequest),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
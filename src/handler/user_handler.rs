@@ -8,7 +8,7 @@ use crate::{
     dto::{
         auth::{
             request::VerifyRefreshTokenRequestDto,
-            response::{AccessTokenResponseDto, JwtTokenResponseDto},
+            response::{AccessTokenResponseDto, JwtTokenResponseDto, SessionResponseDto},
         },
         user::{
             request::{
@@ -18,11 +18,12 @@ use crate::{
             response::UserThemeResponseDto,
         },
     },
+    model::error::OmniNewsError,
     service::user_service,
 };
 
 pub fn get_routes_and_docs(settings: &OpenApiSettings) -> (Vec<rocket::Route>, OpenApi) {
-    openapi_get_routes_spec![settings: demo_login, verify_refresh_token, verify_access_token, login, apple_login, logout, notification_setting, get_user_theme, user_theme_setting, remove_user]
+    openapi_get_routes_spec![settings: demo_login, verify_refresh_token, verify_access_token, login, apple_login, logout, get_sessions, revoke_session, revoke_other_sessions, notification_setting, get_user_theme, user_theme_setting, remove_user]
 }
 
 /// # 리프레시 토큰 검증 API
@@ -81,6 +82,10 @@ pub async fn demo_login(
 ///
 /// ### `user_platform` : 사용자의 플랫폼 (예: ios, android 등)
 ///
+/// ### `user_device_name` : 세션 목록에 표시할 기기 이름, 생략 가능 (예: "홍길동의 iPhone")
+///
+/// 로그인할 때마다 새 세션이 만들어지며 다른 기기의 세션은 유지됩니다.
+///
 #[openapi(tag = "인증 API")]
 #[post("/user/login", data = "<user_data>")]
 pub async fn login(
@@ -99,6 +104,8 @@ pub async fn login(
 ///
 /// ### `user_social_provider_id` : 애플에서 발급한 고유 ID (로그인에 사용됩니다.)
 ///
+/// ### `user_device_name` : 세션 목록에 표시할 기기 이름, 생략 가능 (예: "홍길동의 iPhone")
+///
 #[openapi(tag = "인증 API")]
 #[post("/user/apple/login", data = "<user_data>")]
 pub async fn apple_login(
@@ -113,17 +120,69 @@ pub async fn apple_login(
 
 /// # 로그아웃 API
 ///
-/// 현재 로그인된 사용자의 액세스 토큰을 삭제합니다.
+/// 요청에 사용된 세션만 로그아웃합니다. 다른 기기의 세션은 유지됩니다.
 ///
 #[openapi(tag = "인증 API")]
 #[post("/user/logout")]
 pub async fn logout(pool: &State<MySqlPool>, user: AuthenticatedUser) -> Result<Status, Status> {
-    match user_service::delete_user_token(pool, user.user_email).await {
+    match user_service::delete_user_token(pool, user.user_email, user.session_id).await {
         Ok(_) => Ok(Status::Ok),
         Err(_) => Err(Status::InternalServerError),
     }
 }
 
+/// # 로그인 세션 목록 조회 API
+///
+/// 로그인된 기기 목록을 최근 사용순으로 반환합니다. 요청에 사용된 세션은 `is_current`가 true입니다.
+///
+#[openapi(tag = "인증 API")]
+#[get("/user/sessions")]
+pub async fn get_sessions(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<SessionResponseDto>>, Status> {
+    match user_service::get_sessions(pool, user.user_email, user.session_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 로그인 세션 로그아웃 API
+///
+/// 선택한 기기의 세션을 로그아웃합니다. 해당 기기의 토큰은 바로 사용할 수 없게 됩니다.
+///
+/// ### `session_id`: 세션 목록의 `session_id` (예: 12)
+///
+#[openapi(tag = "인증 API")]
+#[delete("/user/session/<session_id>")]
+pub async fn revoke_session(
+    pool: &State<MySqlPool>,
+    session_id: i32,
+    user: AuthenticatedUser,
+) -> Result<Json<bool>, Status> {
+    match user_service::revoke_session(pool, user.user_email, session_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(OmniNewsError::NotFound(_)) => Err(Status::NotFound),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
+/// # 다른 기기 모두 로그아웃 API
+///
+/// 요청에 사용된 세션을 제외한 모든 세션을 로그아웃하고, 로그아웃한 세션 수를 반환합니다.
+///
+#[openapi(tag = "인증 API")]
+#[delete("/user/sessions")]
+pub async fn revoke_other_sessions(
+    pool: &State<MySqlPool>,
+    user: AuthenticatedUser,
+) -> Result<Json<u64>, Status> {
+    match user_service::revoke_other_sessions(pool, user.user_email, user.session_id).await {
+        Ok(res) => Ok(Json(res)),
+        Err(_) => Err(Status::InternalServerError),
+    }
+}
+
 /// # 액세스 토큰 검증 API
 ///
 /// 현재 액세스 토큰의 유효성을 확인합니다.
@@ -23,7 +23,11 @@ use utils::embedding_util::EmbeddingService;
 use crate::{
     config::webdriver::{DriverPool, DriverPoolConfig},
     handler::index::{ads, app_ads, delete_account, index, process_delete_account},
-    utils::db_util,
+    service::{
+        digest_service, feed_token_service, feedback_service::FeedbackRateLimiter,
+        language_service, notification_service, sync_service,
+    },
+    utils::{db_util, rate_limit_util::RateLimiter},
 };
 
 pub const CURRENT_VERSION: &str = "v1";
@@ -40,6 +44,21 @@ async fn rocket() -> _ {
     let pool = db_util::create_pool().await;
     let pool_middleware = pool.clone();
 
+    // 언어가 없는 기존 채널, 아이템 언어 채우기
+    let pool_backfill = pool.clone();
+    tokio::spawn(async move {
+        let _ = language_service::backfill_languages(&pool_backfill).await;
+    });
+
+    // 발송 시각이 된 이메일 다이제스트 보내기
+    tokio::spawn(digest_service::run_scheduler(pool.clone()));
+
+    // 새 글 푸시 알림 모아서 보내기
+    tokio::spawn(notification_service::run_push_dispatcher(pool.clone()));
+
+    // 보관 기간이 지난 동기화 변경 기록 정리
+    tokio::spawn(sync_service::run_change_pruner(pool.clone()));
+
     let embedding_service = EmbeddingService::new();
 
     let exempt_paths = vec![
@@ -49,6 +68,15 @@ async fn rocket() -> _ {
         "/v1/api/user/apple/login".to_string(),
         "/v1/api/user/refresh-token".to_string(),
         "/v1/api/health".to_string(),
+        // 토큰으로 접근하는 비공개 피드
+        "/v1/api/search/saved/feed/".to_string(),
+        // 공개 폴더 페이지, 피드 토큰으로 접근하는 개인 피드
+        "/v1/api/public/".to_string(),
+        // Google Reader API (앱 비밀번호로 인증)
+        "/v1/api/greader/".to_string(),
+        // Fever API (api_key로 인증)
+        "/v1/api/fever".to_string(),
+        "/v1/api/fever/".to_string(),
         // openapi
         "/rapidoc/".to_string(),
         "/swagger-ui/".to_string(),
@@ -64,6 +92,11 @@ async fn rocket() -> _ {
         .manage(pool)
         .manage(embedding_service)
         .manage(AuthCache::new())
+        .manage(RateLimiter::new(
+            feed_token_service::FEED_RATE_LIMIT,
+            feed_token_service::FEED_RATE_WINDOW,
+        ))
+        .manage(FeedbackRateLimiter::new())
         .manage(driver_pool)
         .attach(CORS)
         .attach(AuthMiddleware::new(exempt_paths, pool_middleware))
@@ -0,0 +1,37 @@
+-- 기기별 로그인 세션(user_session)으로 토큰 저장 위치 변경
+-- 새로 만드는 DB는 schema.sql만 실행하면 되고, 기존 DB에만 한 번 실행
+--
+-- 기존 user 토큰은 해시로 옮겨 로그인 상태를 유지한 뒤 컬럼을 삭제함
+
+CREATE TABLE IF NOT EXISTS `user_session` (
+    `session_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `session_access_token_hash` CHAR(64) NOT NULL,
+    `session_refresh_token_hash` CHAR(64) NOT NULL,
+    `session_access_token_expires_at` DATETIME NOT NULL,
+    `session_refresh_token_expires_at` DATETIME NOT NULL,
+    `session_device_name` VARCHAR(100) NULL,
+    `session_platform` VARCHAR(20) NULL,
+    `session_created_at` DATETIME NOT NULL,
+    `session_last_seen_at` DATETIME NOT NULL,
+    PRIMARY KEY (session_id),
+    UNIQUE (session_access_token_hash),
+    UNIQUE (session_refresh_token_hash),
+    INDEX (user_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+INSERT INTO user_session (user_id, session_access_token_hash, session_refresh_token_hash,
+    session_access_token_expires_at, session_refresh_token_expires_at, session_platform,
+    session_created_at, session_last_seen_at)
+SELECT user_id, SHA2(user_access_token, 256), SHA2(user_refresh_token, 256),
+    user_access_token_expires_at, user_refresh_token_expires_at, user_platform, NOW(), NOW()
+FROM user
+WHERE user_access_token IS NOT NULL AND user_refresh_token IS NOT NULL
+    AND user_access_token_expires_at IS NOT NULL AND user_refresh_token_expires_at IS NOT NULL;
+
+ALTER TABLE user
+    DROP COLUMN user_access_token,
+    DROP COLUMN user_refresh_token,
+    DROP COLUMN user_access_token_expires_at,
+    DROP COLUMN user_refresh_token_expires_at;
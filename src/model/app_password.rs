@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone)]
+pub struct NewAppPassword {
+    pub user_id: Option<i32>,
+    pub app_password_name: Option<String>,
+    pub app_password_hash: Option<String>,
+    pub app_password_fever_key: Option<String>,
+    pub app_password_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct AppPassword {
+    pub app_password_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub app_password_name: Option<String>,
+    pub app_password_hash: Option<String>,
+    pub app_password_fever_key: Option<String>,
+    pub app_password_created_at: Option<NaiveDateTime>,
+    pub app_password_last_used_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+/// 아이템이 삭제되어도 북마크가 남도록 제목, 링크, 이미지를 함께 저장
+#[derive(Debug, Clone)]
+pub struct NewBookmark {
+    pub user_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub collection_id: Option<i32>,
+    pub bookmark_title: Option<String>,
+    pub bookmark_link: Option<String>,
+    pub bookmark_image_link: Option<String>,
+    pub bookmark_channel_title: Option<String>,
+    pub bookmark_note: Option<String>,
+    pub bookmark_created_at: Option<NaiveDateTime>,
+    pub bookmark_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct Bookmark {
+    pub bookmark_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub collection_id: Option<i32>,
+    pub bookmark_title: Option<String>,
+    pub bookmark_link: Option<String>,
+    pub bookmark_image_link: Option<String>,
+    pub bookmark_channel_title: Option<String>,
+    pub bookmark_note: Option<String>,
+    pub bookmark_created_at: Option<NaiveDateTime>,
+    pub bookmark_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct BookmarkTag {
+    pub bookmark_tag_id: Option<i32>,
+    pub bookmark_id: Option<i32>,
+    pub tag_name: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct BookmarkCollection {
+    pub collection_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub collection_name: Option<String>,
+    pub collection_created_at: Option<NaiveDateTime>,
+    pub bookmark_count: Option<i64>,
+}
@@ -0,0 +1,53 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+/// 다이제스트 발송 주기
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum DigestFrequency {
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestFrequency::Daily => "daily",
+            DigestFrequency::Weekly => "weekly",
+        }
+    }
+
+    pub fn from_value(frequency: &str) -> Option<Self> {
+        match frequency {
+            "daily" => Some(DigestFrequency::Daily),
+            "weekly" => Some(DigestFrequency::Weekly),
+            _ => None,
+        }
+    }
+}
+
+/// `digest_hour`는 `digest_timezone` 기준, `digest_next_send_at`은 KST 기준
+#[derive(Debug, Clone, FromRow)]
+pub struct UserDigest {
+    pub user_id: Option<i32>,
+    pub digest_enabled: Option<bool>,
+    pub digest_frequency: Option<String>,
+    pub digest_hour: Option<i32>,
+    pub digest_weekday: Option<i32>,
+    pub digest_timezone: Option<String>,
+    pub digest_include_summary: Option<bool>,
+    pub digest_unsubscribe_token: Option<String>,
+    pub digest_next_send_at: Option<NaiveDateTime>,
+    pub digest_last_sent_at: Option<NaiveDateTime>,
+    pub digest_updated_at: Option<NaiveDateTime>,
+}
+
+impl UserDigest {
+    pub fn frequency(&self) -> DigestFrequency {
+        self.digest_frequency
+            .as_deref()
+            .and_then(DigestFrequency::from_value)
+            .unwrap_or(DigestFrequency::Daily)
+    }
+}
@@ -56,6 +56,15 @@ pub enum OmniNewsError {
 
     #[error("Invalid Value: {0}")]
     InvalidValue(String),
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
+    #[error("Failed to send email: {0}")]
+    Email(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
 }
 
 #[derive(Debug, Error)]
@@ -0,0 +1,87 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+/// 규칙 조건 종류 (모든 조건을 만족해야 적용)
+///
+/// `Keyword`: 제목 또는 설명에 포함, `Title`, `Description`: 해당 필드에 포함, `Author`: 작성자 일치,
+/// `Channel`: 채널 ID, `Folder`: 폴더 ID (폴더에 속한 채널), `Tag`: 아이템 태그 일치,
+/// `Semantic`: 문구와 의미가 비슷함
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum FeedRuleConditionType {
+    Keyword,
+    Title,
+    Description,
+    Author,
+    Channel,
+    Folder,
+    Tag,
+    Semantic,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleCondition {
+    pub condition_type: FeedRuleConditionType,
+    pub value: String,
+}
+
+/// 규칙 동작 종류
+///
+/// `Tag`: `value` 태그 추가, `Bookmark`: 북마크 (`value`는 컬렉션 ID, 생략 가능),
+/// `MarkRead`: 읽음 처리, `Notify`: 알림
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum FeedRuleActionType {
+    Tag,
+    Bookmark,
+    MarkRead,
+    Notify,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeedRuleAction {
+    pub action_type: FeedRuleActionType,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewFeedRule {
+    pub user_id: Option<i32>,
+    pub feed_rule_name: Option<String>,
+    pub feed_rule_conditions: Option<String>,
+    pub feed_rule_actions: Option<String>,
+    pub feed_rule_embedding: Option<Vec<u8>>,
+    pub feed_rule_enabled: Option<bool>,
+    pub feed_rule_created_at: Option<NaiveDateTime>,
+    pub feed_rule_updated_at: Option<NaiveDateTime>,
+}
+
+/// 조건, 동작은 JSON 배열로 저장 (`Semantic` 조건의 문구 임베딩은 `feed_rule_embedding`)
+#[derive(Debug, Clone, FromRow)]
+pub struct FeedRule {
+    pub feed_rule_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub feed_rule_name: Option<String>,
+    pub feed_rule_conditions: Option<String>,
+    pub feed_rule_actions: Option<String>,
+    pub feed_rule_embedding: Option<Vec<u8>>,
+    pub feed_rule_enabled: Option<bool>,
+    pub feed_rule_created_at: Option<NaiveDateTime>,
+    pub feed_rule_updated_at: Option<NaiveDateTime>,
+}
+
+impl FeedRule {
+    pub fn conditions(&self) -> Vec<FeedRuleCondition> {
+        self.feed_rule_conditions
+            .as_deref()
+            .and_then(|conditions| serde_json::from_str(conditions).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn actions(&self) -> Vec<FeedRuleAction> {
+        self.feed_rule_actions
+            .as_deref()
+            .and_then(|actions| serde_json::from_str(actions).ok())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone)]
+pub struct NewFeedToken {
+    pub user_id: Option<i32>,
+    pub feed_token_name: Option<String>,
+    pub feed_token_hash: Option<String>,
+    pub feed_token_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct FeedToken {
+    pub feed_token_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub feed_token_name: Option<String>,
+    pub feed_token_hash: Option<String>,
+    pub feed_token_created_at: Option<NaiveDateTime>,
+    pub feed_token_last_used_at: Option<NaiveDateTime>,
+}
@@ -1,15 +1,163 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
+/// 피드백 분류
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, FromFormField, JsonSchema)]
+pub enum FeedbackCategory {
+    Bug,
+    Feature,
+    Content,
+    Account,
+    Other,
+}
+
+impl FeedbackCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedbackCategory::Bug => "bug",
+            FeedbackCategory::Feature => "feature",
+            FeedbackCategory::Content => "content",
+            FeedbackCategory::Account => "account",
+            FeedbackCategory::Other => "other",
+        }
+    }
+
+    pub fn from_value(category: &str) -> Option<Self> {
+        match category {
+            "bug" => Some(FeedbackCategory::Bug),
+            "feature" => Some(FeedbackCategory::Feature),
+            "content" => Some(FeedbackCategory::Content),
+            "account" => Some(FeedbackCategory::Account),
+            "other" => Some(FeedbackCategory::Other),
+            _ => None,
+        }
+    }
+}
+
+/// 피드백을 보낸 플랫폼
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, FromFormField, JsonSchema)]
+pub enum FeedbackPlatform {
+    Ios,
+    Android,
+    Web,
+    Other,
+}
+
+impl FeedbackPlatform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedbackPlatform::Ios => "ios",
+            FeedbackPlatform::Android => "android",
+            FeedbackPlatform::Web => "web",
+            FeedbackPlatform::Other => "other",
+        }
+    }
+
+    pub fn from_value(platform: &str) -> Option<Self> {
+        match platform {
+            "ios" => Some(FeedbackPlatform::Ios),
+            "android" => Some(FeedbackPlatform::Android),
+            "web" => Some(FeedbackPlatform::Web),
+            "other" => Some(FeedbackPlatform::Other),
+            _ => None,
+        }
+    }
+}
+
+/// 피드백 처리 상태
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, FromFormField, JsonSchema)]
+pub enum FeedbackStatus {
+    Open,
+    InProgress,
+    Resolved,
+    Closed,
+}
+
+impl FeedbackStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedbackStatus::Open => "open",
+            FeedbackStatus::InProgress => "in_progress",
+            FeedbackStatus::Resolved => "resolved",
+            FeedbackStatus::Closed => "closed",
+        }
+    }
+
+    pub fn from_value(status: &str) -> Option<Self> {
+        match status {
+            "open" => Some(FeedbackStatus::Open),
+            "in_progress" => Some(FeedbackStatus::InProgress),
+            "resolved" => Some(FeedbackStatus::Resolved),
+            "closed" => Some(FeedbackStatus::Closed),
+            _ => None,
+        }
+    }
+}
+
+/// 익명 피드백이면 `user_id`가 없음
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct NewFeedback {
+    pub user_id: Option<i32>,
     pub feedback_email: Option<String>,
     pub feedback_content: Option<String>,
+    pub feedback_category: Option<String>,
+    pub feedback_app_version: Option<String>,
+    pub feedback_platform: Option<String>,
+    pub feedback_created_at: Option<NaiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Feedback {
     pub feedback_id: Option<i32>,
+    pub user_id: Option<i32>,
     pub feedback_email: Option<String>,
     pub feedback_content: Option<String>,
+    pub feedback_category: Option<String>,
+    pub feedback_app_version: Option<String>,
+    pub feedback_platform: Option<String>,
+    pub feedback_status: Option<String>,
+    pub feedback_reply: Option<String>,
+    pub feedback_replied_at: Option<NaiveDateTime>,
+    pub feedback_created_at: Option<NaiveDateTime>,
+    pub feedback_updated_at: Option<NaiveDateTime>,
+}
+
+impl Feedback {
+    pub fn category(&self) -> FeedbackCategory {
+        self.feedback_category
+            .as_deref()
+            .and_then(FeedbackCategory::from_value)
+            .unwrap_or(FeedbackCategory::Other)
+    }
+
+    pub fn status(&self) -> FeedbackStatus {
+        self.feedback_status
+            .as_deref()
+            .and_then(FeedbackStatus::from_value)
+            .unwrap_or(FeedbackStatus::Open)
+    }
+}
+
+/// 피드백과 함께 저장하는 첨부 파일, `feedback_id`는 저장할 때 채움
+#[derive(Debug, Clone)]
+pub struct NewFeedbackAttachment {
+    pub attachment_file_name: Option<String>,
+    pub attachment_original_name: Option<String>,
+    pub attachment_content_type: Option<String>,
+    pub attachment_size: Option<i32>,
+    pub attachment_created_at: Option<NaiveDateTime>,
+}
+
+/// `attachment_file_name`은 FEEDBACK_ATTACHMENT_DIR 안의 저장 파일명
+#[derive(Debug, Clone, FromRow)]
+pub struct FeedbackAttachment {
+    pub attachment_id: Option<i32>,
+    pub feedback_id: Option<i32>,
+    pub attachment_file_name: Option<String>,
+    pub attachment_original_name: Option<String>,
+    pub attachment_content_type: Option<String>,
+    pub attachment_size: Option<i32>,
+    pub attachment_created_at: Option<NaiveDateTime>,
 }
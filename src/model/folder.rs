@@ -1,8 +1,33 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Debug)]
+pub struct NewRssFolder {
+    pub user_id: Option<i32>,
+    pub folder_name: Option<String>,
+    pub folder_parent_id: Option<i32>,
+    pub folder_icon: Option<String>,
+    pub folder_color: Option<String>,
+}
+
+/// `folder_parent_id`가 없으면 최상위 폴더, 같은 상위 폴더 안에서 `folder_position` 순으로 정렬
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RssFolder {
     pub folder_id: Option<i32>,
     pub folder_name: Option<String>,
     pub user_id: Option<i32>,
+    pub folder_parent_id: Option<i32>,
+    pub folder_position: Option<i32>,
+    pub folder_icon: Option<String>,
+    pub folder_color: Option<String>,
+    /// 공개된 폴더만 값이 있음
+    pub folder_public_slug: Option<String>,
+    pub folder_published_at: Option<NaiveDateTime>,
+}
+
+/// 폴더에 속한 채널들의 가장 최근 아이템 발행일
+#[derive(Clone, Debug)]
+pub struct FolderLatestPubDate {
+    pub folder_id: Option<i32>,
+    pub latest_pub_date: Option<NaiveDateTime>,
 }
@@ -1,13 +1,26 @@
+pub mod app_password;
 pub mod apple_server_notification;
 pub mod appstore_api;
 pub mod auth;
+pub mod bookmark;
+pub mod digest;
 pub mod embedding;
 pub mod error;
+pub mod feed_rule;
+pub mod feed_token;
 pub mod feedback;
 pub mod folder;
+pub mod mute;
 pub mod news;
+pub mod notification;
 pub mod omninews_subscription;
 pub mod premium;
+pub mod push;
+pub mod read;
+pub mod reading;
 pub mod rss;
 pub mod search;
+pub mod session;
+pub mod story;
+pub mod sync;
 pub mod user;
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+/// 숨김 규칙 종류
+///
+/// `Keyword`: 제목, 설명에 포함, `Regex`: 제목, 설명에 정규식 매칭, `Author`: 작성자 일치,
+/// `Domain`: 링크 도메인(하위 도메인 포함), `Channel`: 채널 ID 일치
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum MuteRuleType {
+    Keyword,
+    Regex,
+    Author,
+    Domain,
+    Channel,
+}
+
+impl MuteRuleType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MuteRuleType::Keyword => "Keyword",
+            MuteRuleType::Regex => "Regex",
+            MuteRuleType::Author => "Author",
+            MuteRuleType::Domain => "Domain",
+            MuteRuleType::Channel => "Channel",
+        }
+    }
+
+    pub fn from_value(rule_type: &str) -> Option<Self> {
+        match rule_type {
+            "Keyword" => Some(MuteRuleType::Keyword),
+            "Regex" => Some(MuteRuleType::Regex),
+            "Author" => Some(MuteRuleType::Author),
+            "Domain" => Some(MuteRuleType::Domain),
+            "Channel" => Some(MuteRuleType::Channel),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct MuteRule {
+    pub mute_rule_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub mute_rule_type: Option<String>,
+    pub mute_rule_value: Option<String>,
+    pub mute_rule_created_at: Option<NaiveDateTime>,
+}
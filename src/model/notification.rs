@@ -0,0 +1,116 @@
+use chrono::NaiveDateTime;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+/// 새 글 알림 수준
+///
+/// `All`: 모든 새 글, `Keyword`: 키워드가 제목, 설명에 포함된 글, `None`: 알리지 않음
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum NotificationLevel {
+    All,
+    Keyword,
+    None,
+}
+
+impl NotificationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationLevel::All => "all",
+            NotificationLevel::Keyword => "keyword",
+            NotificationLevel::None => "none",
+        }
+    }
+
+    pub fn from_value(level: &str) -> Option<Self> {
+        match level {
+            "all" => Some(NotificationLevel::All),
+            "keyword" => Some(NotificationLevel::Keyword),
+            "none" => Some(NotificationLevel::None),
+            _ => None,
+        }
+    }
+}
+
+/// 알림 종류
+///
+/// `NewItem`: 구독 채널의 새 글, `FeedRule`: 피드 규칙의 알림 동작, `FeedbackReply`: 보낸 피드백에 대한 답변
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum NotificationType {
+    NewItem,
+    FeedRule,
+    FeedbackReply,
+}
+
+impl NotificationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationType::NewItem => "new_item",
+            NotificationType::FeedRule => "feed_rule",
+            NotificationType::FeedbackReply => "feedback_reply",
+        }
+    }
+
+    pub fn from_value(notification_type: &str) -> Option<Self> {
+        match notification_type {
+            "new_item" => Some(NotificationType::NewItem),
+            "feed_rule" => Some(NotificationType::FeedRule),
+            "feedback_reply" => Some(NotificationType::FeedbackReply),
+            _ => None,
+        }
+    }
+}
+
+/// `channel_id`, `folder_id` 중 하나만 값이 있음. 키워드는 JSON 배열로 저장
+#[derive(Debug, Clone, FromRow)]
+pub struct NotificationPreference {
+    pub notification_preference_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub folder_id: Option<i32>,
+    pub notification_level: Option<String>,
+    pub notification_keywords: Option<String>,
+    pub notification_preference_updated_at: Option<NaiveDateTime>,
+}
+
+impl NotificationPreference {
+    pub fn level(&self) -> NotificationLevel {
+        self.notification_level
+            .as_deref()
+            .and_then(NotificationLevel::from_value)
+            .unwrap_or(NotificationLevel::All)
+    }
+
+    pub fn keywords(&self) -> Vec<String> {
+        self.notification_keywords
+            .as_deref()
+            .and_then(|keywords| serde_json::from_str(keywords).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewUserNotification {
+    pub user_id: Option<i32>,
+    pub notification_type: Option<String>,
+    pub notification_title: Option<String>,
+    pub notification_body: Option<String>,
+    pub rss_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub feedback_id: Option<i32>,
+    pub notification_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct UserNotification {
+    pub notification_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub notification_type: Option<String>,
+    pub notification_title: Option<String>,
+    pub notification_body: Option<String>,
+    pub rss_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub feedback_id: Option<i32>,
+    pub notification_is_read: Option<bool>,
+    pub notification_created_at: Option<NaiveDateTime>,
+}
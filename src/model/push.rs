@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+/// 방해 금지 시간은 `push_timezone` 기준
+#[derive(Debug, Clone, FromRow)]
+pub struct UserPushSetting {
+    pub user_id: Option<i32>,
+    pub push_quiet_start_hour: Option<i32>,
+    pub push_quiet_end_hour: Option<i32>,
+    pub push_timezone: Option<String>,
+    pub push_last_sent_at: Option<NaiveDateTime>,
+    pub push_failure_count: Option<i32>,
+    pub push_retry_at: Option<NaiveDateTime>,
+    pub push_updated_at: Option<NaiveDateTime>,
+}
+
+/// 대기 중인 푸시가 있는 사용자 (푸시를 켜고 FCM 토큰이 있으며 발송 간격, 재시도 시각이 지난 경우만)
+#[derive(Debug, Clone, FromRow)]
+pub struct PushTarget {
+    pub user_id: Option<i32>,
+    pub user_fcm_token: Option<String>,
+    pub push_quiet_start_hour: Option<i32>,
+    pub push_quiet_end_hour: Option<i32>,
+    pub push_timezone: Option<String>,
+    pub push_failure_count: Option<i32>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PendingPushItem {
+    pub push_pending_id: Option<i64>,
+    pub rss_id: Option<i32>,
+    pub rss_title: Option<String>,
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+}
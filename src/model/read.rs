@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+/// 채널별 읽음 기준선
+///
+/// `read_mark_rss_id` 이하이거나 `read_mark_pub_date` 이전에 발행된 아이템은 읽은 것으로 봄
+#[derive(Debug, Clone, FromRow)]
+pub struct ChannelReadMark {
+    pub read_mark_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub read_mark_rss_id: Option<i32>,
+    pub read_mark_pub_date: Option<NaiveDateTime>,
+    pub read_mark_updated_at: Option<NaiveDateTime>,
+}
+
+impl ChannelReadMark {
+    pub fn covers(&self, rss_id: i32, rss_pub_date: Option<NaiveDateTime>) -> bool {
+        rss_id <= self.read_mark_rss_id.unwrap_or_default()
+            || matches!(
+                (rss_pub_date, self.read_mark_pub_date),
+                (Some(pub_date), Some(mark)) if pub_date <= mark
+            )
+    }
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChannelUnreadCount {
+    pub channel_id: Option<i32>,
+    pub unread_count: Option<i64>,
+}
@@ -0,0 +1,57 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+/// 읽기 이벤트 종류
+///
+/// `Open`: 아이템을 엶, `Dwell`: 마지막으로 연 기록에 체류 시간(초) 추가
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum ReadingEventType {
+    Open,
+    Dwell,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ReadingHistory {
+    pub reading_history_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub reading_opened_at: Option<NaiveDateTime>,
+    pub reading_seconds: Option<i32>,
+}
+
+/// 읽기 기록 + 아이템, 채널 제목 (삭제된 아이템이면 제목 없음)
+#[derive(Debug, Clone, FromRow)]
+pub struct ReadingHistoryDetail {
+    pub reading_history_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub channel_id: Option<i32>,
+    pub rss_title: Option<String>,
+    pub rss_link: Option<String>,
+    pub channel_title: Option<String>,
+    pub reading_opened_at: Option<NaiveDateTime>,
+    pub reading_seconds: Option<i32>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct DailyReadingStat {
+    pub stat_date: Option<NaiveDate>,
+    pub stat_articles_read: Option<i64>,
+    pub stat_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ChannelReadingStat {
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+    pub stat_articles_read: Option<i64>,
+    pub stat_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TagReadingStat {
+    pub tag_name: Option<String>,
+    pub stat_articles_read: Option<i64>,
+}
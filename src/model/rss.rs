@@ -14,7 +14,7 @@ pub struct NewRssChannel {
     pub channel_rss_link: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, FromRow)]
 pub struct RssChannel {
     pub channel_id: Option<i32>,
     pub channel_title: Option<String>,
@@ -37,6 +37,7 @@ pub struct NewRssItem {
     pub rss_pub_date: Option<NaiveDateTime>,
     pub rss_rank: Option<i32>,
     pub rss_image_link: Option<String>,
+    pub rss_language: Option<String>,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -50,6 +51,7 @@ pub struct RssItem {
     pub rss_pub_date: Option<NaiveDateTime>,
     pub rss_rank: Option<i32>,
     pub rss_image_link: Option<String>,
+    pub rss_language: Option<String>,
 }
 
 #[derive(Debug, Clone, FromRow)]
@@ -70,7 +72,7 @@ impl NewRssChannel {
         channel_link: String,
         channel_description: String,
         channel_image_url: Option<String>,
-        channel_language: String,
+        channel_language: Option<String>,
         rss_generator: String,
         channel_rank: i32,
         channel_rss_link: String,
@@ -80,7 +82,7 @@ impl NewRssChannel {
             channel_link: Some(channel_link),
             channel_description: Some(channel_description),
             channel_image_url,
-            channel_language: Some(channel_language),
+            channel_language,
             rss_generator: Some(rss_generator),
             channel_rank: Some(channel_rank),
             channel_rss_link: Some(channel_rss_link),
@@ -125,6 +127,19 @@ impl NewRssItem {
             rss_pub_date,
             rss_rank: Some(0),
             rss_image_link: Some(item_image_link),
+            rss_language: None,
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct RssTagCount {
+    pub tag_name: Option<String>,
+    pub tag_count: Option<i64>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RssItemTag {
+    pub rss_id: Option<i32>,
+    pub tag_name: Option<String>,
+}
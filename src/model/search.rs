@@ -1,5 +1,7 @@
+use chrono::NaiveDateTime;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromFormField, JsonSchema)]
 pub enum SearchType {
@@ -7,3 +9,81 @@ pub enum SearchType {
     Popularity,
     Latest,
 }
+
+/// 검색 결과가 매칭된 방식
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum SearchMatchType {
+    Lexical,
+    Semantic,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewSearchQueryLog {
+    pub search_user_hash: Option<String>,
+    pub search_query: Option<String>,
+    pub search_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PopularSearchQuery {
+    pub search_query: Option<String>,
+    pub search_count: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum SavedSearchMode {
+    Keyword,
+    Semantic,
+}
+
+impl SavedSearchMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SavedSearchMode::Keyword => "Keyword",
+            SavedSearchMode::Semantic => "Semantic",
+        }
+    }
+
+    pub fn from_value(mode: &str) -> Self {
+        match mode {
+            "Semantic" => SavedSearchMode::Semantic,
+            _ => SavedSearchMode::Keyword,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewSavedSearch {
+    pub user_id: Option<i32>,
+    pub saved_search_name: Option<String>,
+    pub saved_search_query: Option<String>,
+    pub saved_search_mode: Option<String>,
+    pub saved_search_channel_ids: Option<String>,
+    pub saved_search_language: Option<String>,
+    pub saved_search_embedding: Option<Vec<u8>>,
+    pub saved_search_token: Option<String>,
+    pub saved_search_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SavedSearch {
+    pub saved_search_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub saved_search_name: Option<String>,
+    pub saved_search_query: Option<String>,
+    pub saved_search_mode: Option<String>,
+    pub saved_search_channel_ids: Option<String>,
+    pub saved_search_language: Option<String>,
+    pub saved_search_embedding: Option<Vec<u8>>,
+    pub saved_search_token: Option<String>,
+    pub saved_search_created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SavedSearchHit {
+    pub saved_search_hit_id: Option<i32>,
+    pub saved_search_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub hit_seen: Option<bool>,
+    pub hit_created_at: Option<NaiveDateTime>,
+}
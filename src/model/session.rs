@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone)]
+pub struct NewUserSession {
+    pub user_id: Option<i32>,
+    pub session_access_token_hash: Option<String>,
+    pub session_refresh_token_hash: Option<String>,
+    pub session_access_token_expires_at: Option<NaiveDateTime>,
+    pub session_refresh_token_expires_at: Option<NaiveDateTime>,
+    pub session_device_name: Option<String>,
+    pub session_platform: Option<String>,
+    pub session_created_at: Option<NaiveDateTime>,
+}
+
+/// 토큰은 sha256 해시만 저장
+#[derive(Debug, Clone, FromRow)]
+pub struct UserSession {
+    pub session_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub session_access_token_hash: Option<String>,
+    pub session_refresh_token_hash: Option<String>,
+    pub session_access_token_expires_at: Option<NaiveDateTime>,
+    pub session_refresh_token_expires_at: Option<NaiveDateTime>,
+    pub session_device_name: Option<String>,
+    pub session_platform: Option<String>,
+    pub session_created_at: Option<NaiveDateTime>,
+    pub session_last_seen_at: Option<NaiveDateTime>,
+}
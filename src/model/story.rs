@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone)]
+pub struct NewRssStory {
+    pub story_title: Option<String>,
+    pub story_rss_id: Option<i32>,
+    pub story_centroid: Option<Vec<u8>>,
+    pub story_item_count: Option<i32>,
+    pub story_created_at: Option<NaiveDateTime>,
+    pub story_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RssStory {
+    pub story_id: Option<i32>,
+    pub story_title: Option<String>,
+    pub story_rss_id: Option<i32>,
+    pub story_centroid: Option<Vec<u8>>,
+    pub story_item_count: Option<i32>,
+    pub story_created_at: Option<NaiveDateTime>,
+    pub story_updated_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RssStoryItem {
+    pub story_item_id: Option<i32>,
+    pub story_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub story_similarity: Option<f32>,
+}
+
+/// 스토리에 속한 아이템과 출처 채널 정보
+#[derive(Debug, Clone, FromRow)]
+pub struct RssStorySource {
+    pub story_id: Option<i32>,
+    pub rss_id: Option<i32>,
+    pub rss_title: Option<String>,
+    pub rss_link: Option<String>,
+    pub rss_pub_date: Option<NaiveDateTime>,
+    pub channel_id: Option<i32>,
+    pub channel_title: Option<String>,
+    pub channel_image_url: Option<String>,
+}
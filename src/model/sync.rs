@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use sqlx::prelude::FromRow;
+
+/// 동기화 변경 대상
+///
+/// `Subscription`, `ChannelRead`: 채널 ID, `Folder`: 폴더 ID, `ItemRead`, `Bookmark`: 아이템 ID
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncEntity {
+    Subscription,
+    Folder,
+    ItemRead,
+    ChannelRead,
+    Bookmark,
+}
+
+impl SyncEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncEntity::Subscription => "subscription",
+            SyncEntity::Folder => "folder",
+            SyncEntity::ItemRead => "item_read",
+            SyncEntity::ChannelRead => "channel_read",
+            SyncEntity::Bookmark => "bookmark",
+        }
+    }
+
+    pub fn from_value(entity: &str) -> Option<Self> {
+        match entity {
+            "subscription" => Some(SyncEntity::Subscription),
+            "folder" => Some(SyncEntity::Folder),
+            "item_read" => Some(SyncEntity::ItemRead),
+            "channel_read" => Some(SyncEntity::ChannelRead),
+            "bookmark" => Some(SyncEntity::Bookmark),
+            _ => None,
+        }
+    }
+}
+
+/// 대상별로 묶은 변경 기록 (`sync_change_id`, `sync_changed_at`은 가장 최근 값)
+#[derive(Debug, Clone, FromRow)]
+pub struct SyncChange {
+    pub sync_change_id: Option<i64>,
+    pub sync_entity: Option<String>,
+    pub sync_entity_id: Option<i32>,
+    pub sync_changed_at: Option<NaiveDateTime>,
+}
@@ -4,8 +4,6 @@ use sqlx::prelude::FromRow;
 
 use crate::dto::user::request::LoginUserRequestDto;
 
-use super::auth::JwtToken;
-
 #[derive(Debug, Clone)]
 pub struct NewUser {
     pub user_email: Option<String>,
@@ -13,10 +11,6 @@ pub struct NewUser {
     pub user_photo_url: Option<String>,
     pub user_social_login_provider: Option<String>,
     pub user_social_provider_id: Option<String>,
-    pub user_access_token: Option<String>,
-    pub user_refresh_token: Option<String>,
-    pub user_access_token_expires_at: Option<NaiveDateTime>,
-    pub user_refresh_token_expires_at: Option<NaiveDateTime>,
     pub user_platform: Option<String>,
     pub user_last_active_at: Option<NaiveDateTime>,
     pub user_created_at: Option<NaiveDateTime>,
@@ -31,10 +25,6 @@ pub struct User {
     pub user_photo_url: Option<String>,
     pub user_social_login_provider: Option<String>,
     pub user_social_provider_id: Option<String>,
-    pub user_access_token: Option<String>,
-    pub user_refresh_token: Option<String>,
-    pub user_access_token_expires_at: Option<NaiveDateTime>,
-    pub user_refresh_token_expires_at: Option<NaiveDateTime>,
     pub user_status: Option<String>,
     pub user_role: Option<String>,
     pub user_theme: Option<String>,
@@ -56,17 +46,13 @@ pub struct User {
 }
 
 impl NewUser {
-    pub fn new(user_dto: LoginUserRequestDto, token: JwtToken, now: NaiveDateTime) -> Self {
+    pub fn new(user_dto: LoginUserRequestDto, now: NaiveDateTime) -> Self {
         Self {
             user_email: user_dto.user_email,
             user_display_name: user_dto.user_display_name,
             user_photo_url: user_dto.user_photo_url,
             user_social_login_provider: user_dto.user_social_login_provider,
             user_social_provider_id: user_dto.user_social_provider_id,
-            user_access_token: token.access_token,
-            user_refresh_token: token.refresh_token,
-            user_access_token_expires_at: token.access_token_expires_at,
-            user_refresh_token_expires_at: token.refresh_token_expires_at,
             user_platform: user_dto.user_platform,
             user_last_active_at: Some(now),
             user_created_at: Some(now),
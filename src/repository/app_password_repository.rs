@@ -0,0 +1,154 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::app_password::{AppPassword, NewAppPassword},
+};
+
+pub async fn insert_app_password(
+    pool: &MySqlPool,
+    app_password: NewAppPassword,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_app_password
+            (user_id, app_password_name, app_password_hash, app_password_fever_key,
+            app_password_created_at)
+            VALUES (?, ?, ?, ?, ?);",
+        app_password.user_id,
+        app_password.app_password_name,
+        app_password.app_password_hash,
+        app_password.app_password_fever_key,
+        app_password.app_password_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_app_passwords_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<AppPassword>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        AppPassword,
+        "SELECT * FROM user_app_password WHERE user_id = ? ORDER BY app_password_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_app_password_by_hash(
+    pool: &MySqlPool,
+    app_password_hash: &str,
+) -> Result<AppPassword, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        AppPassword,
+        "SELECT * FROM user_app_password WHERE app_password_hash = ?;",
+        app_password_hash
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_app_password_by_fever_key(
+    pool: &MySqlPool,
+    fever_key: &str,
+) -> Result<AppPassword, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        AppPassword,
+        "SELECT * FROM user_app_password WHERE app_password_fever_key = ?;",
+        fever_key
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_app_passwords_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS app_password_count FROM user_app_password WHERE user_id = ?;",
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.app_password_count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_app_password_last_used_at(
+    pool: &MySqlPool,
+    app_password_id: i32,
+    last_used_at: NaiveDateTime,
+) -> Result<(), sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_app_password SET app_password_last_used_at = ? WHERE app_password_id = ?;",
+        last_used_at,
+        app_password_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_app_password(
+    pool: &MySqlPool,
+    user_id: i32,
+    app_password_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_app_password WHERE app_password_id = ? AND user_id = ?;",
+        app_password_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
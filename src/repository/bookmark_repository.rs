@@ -0,0 +1,481 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, query_scalar, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::{
+        bookmark::{Bookmark, BookmarkCollection, BookmarkTag, NewBookmark},
+        rss::RssItem,
+    },
+};
+
+pub async fn insert_bookmark(pool: &MySqlPool, bookmark: NewBookmark) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO bookmark
+            (user_id, rss_id, collection_id, bookmark_title, bookmark_link, bookmark_image_link,
+            bookmark_channel_title, bookmark_note, bookmark_created_at, bookmark_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?);",
+        bookmark.user_id,
+        bookmark.rss_id,
+        bookmark.collection_id,
+        bookmark.bookmark_title,
+        bookmark.bookmark_link,
+        bookmark.bookmark_image_link,
+        bookmark.bookmark_channel_title,
+        bookmark.bookmark_note,
+        bookmark.bookmark_created_at,
+        bookmark.bookmark_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_bookmark_by_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    bookmark_id: i32,
+) -> Result<Bookmark, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Bookmark,
+        "SELECT * FROM bookmark WHERE bookmark_id = ? AND user_id = ?;",
+        bookmark_id,
+        user_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_bookmark_by_rss_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<Option<Bookmark>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Bookmark,
+        "SELECT * FROM bookmark WHERE user_id = ? AND rss_id = ?;",
+        user_id,
+        rss_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_bookmark(
+    pool: &MySqlPool,
+    bookmark_id: i32,
+    collection_id: Option<i32>,
+    bookmark_note: Option<String>,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE bookmark
+            SET collection_id = ?, bookmark_note = ?, bookmark_updated_at = ?
+        WHERE bookmark_id = ?;",
+        collection_id,
+        bookmark_note,
+        updated_at,
+        bookmark_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_bookmark(
+    pool: &MySqlPool,
+    user_id: i32,
+    bookmark_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM bookmark WHERE bookmark_id = ? AND user_id = ?;",
+        bookmark_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_bookmark_by_rss_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM bookmark WHERE user_id = ? AND rss_id = ?;",
+        user_id,
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 북마크한 아이템을 rss_id 내림차순으로 커서 다음부터 조회
+pub async fn select_bookmarked_items_before_rss_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    cursor_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT ri.* FROM rss_item ri
+        JOIN bookmark b ON b.rss_id = ri.rss_id
+        WHERE b.user_id = ? AND ri.rss_id < ?
+        ORDER BY ri.rss_id DESC
+        LIMIT ?;",
+        user_id,
+        cursor_rss_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 주어진 아이템 중 북마크한 아이템 ID
+pub async fn select_bookmarked_rss_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<i32>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    let query =
+        format!("SELECT rss_id FROM bookmark WHERE user_id = ? AND rss_id IN ({placeholder});");
+
+    let mut query_builder = query_scalar::<_, i32>(&query).bind(user_id);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_bookmarked_rss_ids_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT rss_id FROM bookmark
+        WHERE user_id = ? AND rss_id IS NOT NULL
+        ORDER BY rss_id DESC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().filter_map(|row| row.rss_id).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 북마크 id 내림차순(최근 저장순)으로 커서 다음 북마크 조회
+///
+/// `search_value`는 제목, 메모, 태그에서 검색하고 `collection_id`, `tag_name`으로 거를 수 있음
+pub async fn select_bookmarks_before_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    cursor_bookmark_id: i32,
+    limit: i32,
+    search_value: Option<&str>,
+    collection_id: Option<i32>,
+    tag_name: Option<&str>,
+) -> Result<Vec<Bookmark>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let mut conditions = String::new();
+    if search_value.is_some() {
+        conditions.push_str(
+            " AND (b.bookmark_title LIKE CONCAT('%', ?, '%')
+            OR b.bookmark_note LIKE CONCAT('%', ?, '%')
+            OR EXISTS (SELECT 1 FROM bookmark_tag bt
+                WHERE bt.bookmark_id = b.bookmark_id AND bt.tag_name LIKE CONCAT('%', ?, '%')))",
+        );
+    }
+    if collection_id.is_some() {
+        conditions.push_str(" AND b.collection_id = ?");
+    }
+    if tag_name.is_some() {
+        conditions.push_str(
+            " AND EXISTS (SELECT 1 FROM bookmark_tag bt
+                WHERE bt.bookmark_id = b.bookmark_id AND bt.tag_name = ?)",
+        );
+    }
+
+    let query = format!(
+        "SELECT b.* FROM bookmark b
+        WHERE b.user_id = ? AND b.bookmark_id < ?{conditions}
+        ORDER BY b.bookmark_id DESC
+        LIMIT ?;"
+    );
+
+    let mut query_builder = query_as::<_, Bookmark>(&query)
+        .bind(user_id)
+        .bind(cursor_bookmark_id);
+    if let Some(search_value) = search_value {
+        query_builder = query_builder
+            .bind(search_value)
+            .bind(search_value)
+            .bind(search_value);
+    }
+    if let Some(collection_id) = collection_id {
+        query_builder = query_builder.bind(collection_id);
+    }
+    if let Some(tag_name) = tag_name {
+        query_builder = query_builder.bind(tag_name);
+    }
+    query_builder = query_builder.bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_bookmark_tags(pool: &MySqlPool, bookmark_id: i32) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM bookmark_tag WHERE bookmark_id = ?;",
+        bookmark_id
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_bookmark_tag(
+    pool: &MySqlPool,
+    bookmark_id: i32,
+    tag_name: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT IGNORE INTO bookmark_tag (bookmark_id, tag_name) VALUES (?, ?);",
+        bookmark_id,
+        tag_name,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_bookmark_tags_by_bookmark_ids(
+    pool: &MySqlPool,
+    bookmark_ids: Vec<i32>,
+) -> Result<Vec<BookmarkTag>, sqlx::Error> {
+    if bookmark_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..bookmark_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT * FROM bookmark_tag WHERE bookmark_id IN ({placeholder}) ORDER BY bookmark_tag_id;"
+    );
+
+    let mut query_builder = query_as::<_, BookmarkTag>(&query);
+    for id in bookmark_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_bookmark_collection(
+    pool: &MySqlPool,
+    user_id: i32,
+    collection_name: &str,
+    created_at: NaiveDateTime,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO bookmark_collection (user_id, collection_name, collection_created_at)
+            VALUES (?, ?, ?);",
+        user_id,
+        collection_name,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_bookmark_collections_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<BookmarkCollection>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        BookmarkCollection,
+        "SELECT bc.collection_id, bc.user_id, bc.collection_name, bc.collection_created_at,
+            COUNT(b.bookmark_id) AS bookmark_count
+        FROM bookmark_collection bc
+        LEFT JOIN bookmark b ON b.collection_id = bc.collection_id
+        WHERE bc.user_id = ?
+        GROUP BY bc.collection_id
+        ORDER BY bc.collection_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn is_user_bookmark_collection(
+    pool: &MySqlPool,
+    user_id: i32,
+    collection_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT collection_id FROM bookmark_collection WHERE collection_id = ? AND user_id = ?;",
+        collection_id,
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.is_some()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_bookmark_collection_name(
+    pool: &MySqlPool,
+    user_id: i32,
+    collection_id: i32,
+    collection_name: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE bookmark_collection SET collection_name = ?
+        WHERE collection_id = ? AND user_id = ?;",
+        collection_name,
+        collection_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_bookmark_collection(
+    pool: &MySqlPool,
+    user_id: i32,
+    collection_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM bookmark_collection WHERE collection_id = ? AND user_id = ?;",
+        collection_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
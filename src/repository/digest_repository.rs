@@ -0,0 +1,226 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::{digest::UserDigest, rss::RssItem},
+    repository::read_repository::{IS_READ_CONDITION, READ_STATE_JOIN},
+};
+
+/// 설정을 저장하고 기존 수신 거부 토큰은 유지
+pub async fn upsert_user_digest(pool: &MySqlPool, digest: UserDigest) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_digest
+            (user_id, digest_enabled, digest_frequency, digest_hour, digest_weekday,
+            digest_timezone, digest_include_summary, digest_unsubscribe_token,
+            digest_next_send_at, digest_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            digest_enabled = VALUES(digest_enabled),
+            digest_frequency = VALUES(digest_frequency),
+            digest_hour = VALUES(digest_hour),
+            digest_weekday = VALUES(digest_weekday),
+            digest_timezone = VALUES(digest_timezone),
+            digest_include_summary = VALUES(digest_include_summary),
+            digest_next_send_at = VALUES(digest_next_send_at),
+            digest_updated_at = VALUES(digest_updated_at);",
+        digest.user_id,
+        digest.digest_enabled,
+        digest.digest_frequency,
+        digest.digest_hour,
+        digest.digest_weekday,
+        digest.digest_timezone,
+        digest.digest_include_summary,
+        digest.digest_unsubscribe_token,
+        digest.digest_next_send_at,
+        digest.digest_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_digest(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<UserDigest>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserDigest,
+        "SELECT * FROM user_digest WHERE user_id = ?;",
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_digest_by_unsubscribe_token(
+    pool: &MySqlPool,
+    unsubscribe_token: &str,
+) -> Result<UserDigest, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserDigest,
+        "SELECT * FROM user_digest WHERE digest_unsubscribe_token = ?;",
+        unsubscribe_token,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_digest_disabled(
+    pool: &MySqlPool,
+    user_id: i32,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_digest
+        SET digest_enabled = FALSE, digest_next_send_at = NULL, digest_updated_at = ?
+        WHERE user_id = ?;",
+        updated_at,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 발송 시각이 지난 다이제스트 (오래 기다린 순)
+pub async fn select_due_digests(
+    pool: &MySqlPool,
+    now: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<UserDigest>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserDigest,
+        "SELECT * FROM user_digest
+        WHERE digest_enabled = TRUE AND digest_next_send_at <= ?
+        ORDER BY digest_next_send_at ASC
+        LIMIT ?;",
+        now,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 조회한 발송 시각이 그대로일 때만 다음 발송 시각으로 넘김. 다른 인스턴스가 먼저 선점했으면 false
+pub async fn claim_due_digest(
+    pool: &MySqlPool,
+    user_id: i32,
+    due_at: NaiveDateTime,
+    next_send_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_digest
+        SET digest_next_send_at = ?
+        WHERE user_id = ? AND digest_enabled = TRUE AND digest_next_send_at = ?;",
+        next_send_at,
+        user_id,
+        due_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_digest_last_sent_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    last_sent_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_digest SET digest_last_sent_at = ? WHERE user_id = ?;",
+        last_sent_at,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// `since` 이후 발행된 읽지 않은 아이템 (순위, 최신순)
+pub async fn select_unread_items_since(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_ids: Vec<i32>,
+    since: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channel_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT ri.*
+        FROM rss_item ri
+        {READ_STATE_JOIN}
+        WHERE ri.channel_id IN ({placeholder})
+        AND ri.rss_pub_date > ?
+        AND NOT {IS_READ_CONDITION}
+        ORDER BY ri.rss_rank DESC, ri.rss_pub_date DESC, ri.rss_id DESC
+        LIMIT ?;"
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query).bind(user_id).bind(user_id);
+    for id in channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder.bind(since).bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
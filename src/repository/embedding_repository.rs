@@ -1,6 +1,9 @@
-use sqlx::{query, MySqlPool};
+use sqlx::{query, query_as, MySqlPool};
 
-use crate::{db_util::get_db, model::embedding::NewEmbedding};
+use crate::{
+    db_util::get_db,
+    model::embedding::{Embedding, NewEmbedding},
+};
 
 pub async fn insert_embedding(
     pool: &MySqlPool,
@@ -24,3 +27,157 @@ pub async fn insert_embedding(
         Err(e) => Err(e),
     }
 }
+
+pub async fn select_embedding_by_rss_id(
+    pool: &MySqlPool,
+    rss_id: i32,
+) -> Result<Embedding, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Embedding,
+        "SELECT * FROM embedding WHERE rss_id = ?;",
+        rss_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_embeddings_by_ids(
+    pool: &MySqlPool,
+    embedding_ids: Vec<i32>,
+) -> Result<Vec<Embedding>, sqlx::Error> {
+    if embedding_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..embedding_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM embedding WHERE embedding_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, Embedding>(&query);
+    for id in embedding_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 인덱스에 아직 반영되지 않은 최신 아이템까지 비교하기 위한 최신 rss 임베딩 조회
+pub async fn select_recent_rss_embeddings(
+    pool: &MySqlPool,
+    limit: i32,
+) -> Result<Vec<Embedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Embedding,
+        "SELECT * FROM embedding
+        WHERE rss_id IS NOT NULL
+        ORDER BY rss_id DESC
+        LIMIT ?;",
+        limit
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_embeddings_by_rss_ids(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<Embedding>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM embedding WHERE rss_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, Embedding>(&query);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_embeddings_by_channel_ids(
+    pool: &MySqlPool,
+    channel_ids: Vec<i32>,
+) -> Result<Vec<Embedding>, sqlx::Error> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channel_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM embedding WHERE channel_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, Embedding>(&query);
+    for id in channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_channel_embeddings_order_by_channel_rank(
+    pool: &MySqlPool,
+    limit: i32,
+) -> Result<Vec<Embedding>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Embedding,
+        "SELECT e.* FROM embedding e
+        JOIN rss_channel rc ON e.channel_id = rc.channel_id
+        ORDER BY rc.channel_rank DESC
+        LIMIT ?;",
+        limit
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,314 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::{
+        feed_rule::{FeedRule, NewFeedRule},
+        rss::{RssItem, RssTagCount},
+    },
+};
+
+pub async fn insert_feed_rule(
+    pool: &MySqlPool,
+    feed_rule: NewFeedRule,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO feed_rule
+            (user_id, feed_rule_name, feed_rule_conditions, feed_rule_actions, feed_rule_embedding,
+            feed_rule_enabled, feed_rule_created_at, feed_rule_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?);",
+        feed_rule.user_id,
+        feed_rule.feed_rule_name,
+        feed_rule.feed_rule_conditions,
+        feed_rule.feed_rule_actions,
+        feed_rule.feed_rule_embedding,
+        feed_rule.feed_rule_enabled,
+        feed_rule.feed_rule_created_at,
+        feed_rule.feed_rule_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// `created_at`은 변경하지 않음
+pub async fn update_feed_rule(
+    pool: &MySqlPool,
+    feed_rule_id: i32,
+    feed_rule: NewFeedRule,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE feed_rule
+            SET feed_rule_name = ?, feed_rule_conditions = ?, feed_rule_actions = ?,
+            feed_rule_embedding = ?, feed_rule_enabled = ?, feed_rule_updated_at = ?
+        WHERE feed_rule_id = ? AND user_id = ?;",
+        feed_rule.feed_rule_name,
+        feed_rule.feed_rule_conditions,
+        feed_rule.feed_rule_actions,
+        feed_rule.feed_rule_embedding,
+        feed_rule.feed_rule_enabled,
+        feed_rule.feed_rule_updated_at,
+        feed_rule_id,
+        feed_rule.user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feed_rule_by_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    feed_rule_id: i32,
+) -> Result<FeedRule, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedRule,
+        "SELECT * FROM feed_rule WHERE feed_rule_id = ? AND user_id = ?;",
+        feed_rule_id,
+        user_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feed_rules_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<FeedRule>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedRule,
+        "SELECT * FROM feed_rule WHERE user_id = ? ORDER BY feed_rule_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 채널을 구독한 사용자의 활성화된 규칙
+pub async fn select_enabled_feed_rules_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<Vec<FeedRule>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedRule,
+        "SELECT fr.* FROM feed_rule fr
+        JOIN user_subscription_channel usc ON usc.user_id = fr.user_id
+        WHERE usc.channel_id = ? AND fr.feed_rule_enabled = TRUE;",
+        channel_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_feed_rules_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS rule_count FROM feed_rule WHERE user_id = ?;",
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rule_count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_feed_rule(
+    pool: &MySqlPool,
+    user_id: i32,
+    feed_rule_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM feed_rule WHERE feed_rule_id = ? AND user_id = ?;",
+        feed_rule_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 이미 적용된 아이템이면 false 반환 (같은 아이템에 동작을 두 번 실행하지 않음)
+pub async fn insert_feed_rule_hit(
+    pool: &MySqlPool,
+    feed_rule_id: i32,
+    rss_id: i32,
+    hit_notify: bool,
+    created_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT IGNORE INTO feed_rule_hit (feed_rule_id, rss_id, hit_notify, hit_created_at)
+            VALUES (?, ?, ?, ?);",
+        feed_rule_id,
+        rss_id,
+        hit_notify,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feed_rule_hit_items(
+    pool: &MySqlPool,
+    feed_rule_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT ri.* FROM rss_item ri
+        JOIN feed_rule_hit frh ON frh.rss_id = ri.rss_id
+        WHERE frh.feed_rule_id = ?
+        ORDER BY frh.feed_rule_hit_id DESC
+        LIMIT ?;",
+        feed_rule_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_user_item_tag(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    tag_name: &str,
+    created_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT IGNORE INTO user_item_tag (user_id, rss_id, tag_name, tag_created_at)
+            VALUES (?, ?, ?, ?);",
+        user_id,
+        rss_id,
+        tag_name,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_item_tag_counts(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<RssTagCount>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT tag_name, COUNT(*) AS tag_count
+        FROM user_item_tag
+        WHERE user_id = ?
+        GROUP BY tag_name
+        ORDER BY tag_count DESC, tag_name ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|row| RssTagCount {
+                tag_name: Some(row.tag_name),
+                tag_count: Some(row.tag_count),
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 태그가 붙은 아이템을 rss_id 내림차순으로 커서 다음부터 조회
+pub async fn select_user_tagged_items_before_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    tag_name: &str,
+    cursor_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT ri.* FROM rss_item ri
+        JOIN user_item_tag uit ON uit.rss_id = ri.rss_id
+        WHERE uit.user_id = ? AND uit.tag_name = ? AND ri.rss_id < ?
+        ORDER BY ri.rss_id DESC
+        LIMIT ?;",
+        user_id,
+        tag_name,
+        cursor_rss_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
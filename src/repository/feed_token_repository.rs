@@ -0,0 +1,132 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::feed_token::{FeedToken, NewFeedToken},
+};
+
+pub async fn insert_feed_token(
+    pool: &MySqlPool,
+    feed_token: NewFeedToken,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_feed_token
+            (user_id, feed_token_name, feed_token_hash, feed_token_created_at)
+            VALUES (?, ?, ?, ?);",
+        feed_token.user_id,
+        feed_token.feed_token_name,
+        feed_token.feed_token_hash,
+        feed_token.feed_token_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feed_tokens_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<FeedToken>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedToken,
+        "SELECT * FROM user_feed_token WHERE user_id = ? ORDER BY feed_token_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feed_token_by_hash(
+    pool: &MySqlPool,
+    feed_token_hash: &str,
+) -> Result<FeedToken, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedToken,
+        "SELECT * FROM user_feed_token WHERE feed_token_hash = ?;",
+        feed_token_hash
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_feed_tokens_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS token_count FROM user_feed_token WHERE user_id = ?;",
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.token_count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_feed_token_last_used_at(
+    pool: &MySqlPool,
+    feed_token_id: i32,
+    last_used_at: NaiveDateTime,
+) -> Result<(), sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_feed_token SET feed_token_last_used_at = ? WHERE feed_token_id = ?;",
+        last_used_at,
+        feed_token_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_feed_token(
+    pool: &MySqlPool,
+    user_id: i32,
+    feed_token_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_feed_token WHERE feed_token_id = ? AND user_id = ?;",
+        feed_token_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
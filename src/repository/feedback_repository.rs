@@ -0,0 +1,242 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::feedback::{Feedback, FeedbackAttachment, NewFeedback, NewFeedbackAttachment},
+};
+
+/// 피드백과 첨부 파일 기록을 한 트랜잭션으로 저장하고 피드백 ID 반환
+pub async fn insert_feedback_with_attachments(
+    pool: &MySqlPool,
+    feedback: NewFeedback,
+    attachments: Vec<NewFeedbackAttachment>,
+) -> Result<i32, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let feedback_id = query!(
+        "INSERT INTO feedback
+            (user_id, feedback_email, feedback_content, feedback_category, feedback_app_version,
+            feedback_platform, feedback_created_at, feedback_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?);",
+        feedback.user_id,
+        feedback.feedback_email,
+        feedback.feedback_content,
+        feedback.feedback_category,
+        feedback.feedback_app_version,
+        feedback.feedback_platform,
+        feedback.feedback_created_at,
+        feedback.feedback_created_at,
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_id() as i32;
+
+    if !attachments.is_empty() {
+        let placeholder = (0..attachments.len())
+            .map(|_| "(?, ?, ?, ?, ?, ?)".to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let query = format!(
+            "INSERT INTO feedback_attachment
+                (feedback_id, attachment_file_name, attachment_original_name,
+                attachment_content_type, attachment_size, attachment_created_at)
+                VALUES {placeholder};"
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for attachment in attachments {
+            query_builder = query_builder
+                .bind(feedback_id)
+                .bind(attachment.attachment_file_name)
+                .bind(attachment.attachment_original_name)
+                .bind(attachment.attachment_content_type)
+                .bind(attachment.attachment_size)
+                .bind(attachment.attachment_created_at);
+        }
+        query_builder.execute(&mut *tx).await?;
+    }
+
+    let result = tx.commit().await;
+
+    match result {
+        Ok(_) => Ok(feedback_id),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feedback_by_id(
+    pool: &MySqlPool,
+    feedback_id: i32,
+) -> Result<Feedback, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        Feedback,
+        "SELECT * FROM feedback WHERE feedback_id = ?;",
+        feedback_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `cursor_feedback_id`보다 오래된 피드백 (최신순, 상태, 분류, 플랫폼으로 거를 수 있음)
+pub async fn select_feedbacks_before_id(
+    pool: &MySqlPool,
+    cursor_feedback_id: i32,
+    limit: i32,
+    status: Option<&str>,
+    category: Option<&str>,
+    platform: Option<&str>,
+) -> Result<Vec<Feedback>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let mut conditions = String::new();
+    if status.is_some() {
+        conditions.push_str(" AND feedback_status = ?");
+    }
+    if category.is_some() {
+        conditions.push_str(" AND feedback_category = ?");
+    }
+    if platform.is_some() {
+        conditions.push_str(" AND feedback_platform = ?");
+    }
+
+    let query = format!(
+        "SELECT * FROM feedback
+        WHERE feedback_id < ?{conditions}
+        ORDER BY feedback_id DESC
+        LIMIT ?;"
+    );
+
+    let mut query_builder = query_as::<_, Feedback>(&query).bind(cursor_feedback_id);
+    if let Some(status) = status {
+        query_builder = query_builder.bind(status);
+    }
+    if let Some(category) = category {
+        query_builder = query_builder.bind(category);
+    }
+    if let Some(platform) = platform {
+        query_builder = query_builder.bind(platform);
+    }
+    query_builder = query_builder.bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feedback_attachments_by_feedback_ids(
+    pool: &MySqlPool,
+    feedback_ids: Vec<i32>,
+) -> Result<Vec<FeedbackAttachment>, sqlx::Error> {
+    if feedback_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..feedback_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT * FROM feedback_attachment
+        WHERE feedback_id IN ({placeholder})
+        ORDER BY attachment_id;"
+    );
+
+    let mut query_builder = query_as::<_, FeedbackAttachment>(&query);
+    for id in feedback_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_feedback_attachment_by_id(
+    pool: &MySqlPool,
+    attachment_id: i32,
+) -> Result<FeedbackAttachment, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        FeedbackAttachment,
+        "SELECT * FROM feedback_attachment WHERE attachment_id = ?;",
+        attachment_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_feedback_status(
+    pool: &MySqlPool,
+    feedback_id: i32,
+    status: &str,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE feedback SET feedback_status = ?, feedback_updated_at = ?
+        WHERE feedback_id = ?;",
+        status,
+        updated_at,
+        feedback_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 답변을 저장하고 상태 변경 (답변은 마지막 답변으로 덮어씀)
+pub async fn update_feedback_reply(
+    pool: &MySqlPool,
+    feedback_id: i32,
+    reply: &str,
+    status: &str,
+    replied_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE feedback
+        SET feedback_reply = ?, feedback_status = ?, feedback_replied_at = ?, feedback_updated_at = ?
+        WHERE feedback_id = ?;",
+        reply,
+        status,
+        replied_at,
+        replied_at,
+        feedback_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
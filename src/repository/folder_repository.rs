@@ -1,20 +1,31 @@
+use chrono::NaiveDateTime;
 use sqlx::MySqlPool;
 
 use crate::{
     db_util::get_db,
-    model::{folder::RssFolder, rss::RssChannel},
+    model::{
+        folder::{FolderLatestPubDate, NewRssFolder, RssFolder},
+        rss::RssChannel,
+        sync::SyncEntity,
+    },
 };
 
-pub async fn insert_folder(
-    pool: &MySqlPool,
-    user_id: i32,
-    folder_name: String,
-) -> Result<i32, sqlx::Error> {
+/// 같은 상위 폴더의 마지막 위치에 추가
+pub async fn insert_folder(pool: &MySqlPool, folder: NewRssFolder) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "INSERT INTO rss_folder (user_id, folder_name) VALUES (?, ?)",
-        user_id,
-        folder_name
+        "INSERT INTO rss_folder
+            (user_id, folder_name, folder_parent_id, folder_position, folder_icon, folder_color)
+         SELECT ?, ?, ?, COALESCE(MAX(folder_position) + 1, 0), ?, ?
+         FROM rss_folder
+         WHERE user_id = ? AND folder_parent_id <=> ?",
+        folder.user_id,
+        folder.folder_name,
+        folder.folder_parent_id,
+        folder.folder_icon,
+        folder.folder_color,
+        folder.user_id,
+        folder.folder_parent_id,
     )
     .execute(&mut *conn)
     .await;
@@ -32,9 +43,13 @@ pub async fn insert_channel_to_folder(
 ) -> Result<(), sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "INSERT INTO channels_in_folder (folder_id, channel_id) VALUES (?, ?)",
+        "INSERT INTO channels_in_folder (folder_id, channel_id, channel_position)
+         SELECT ?, ?, COALESCE(MAX(channel_position) + 1, 0)
+         FROM channels_in_folder
+         WHERE folder_id = ?",
         folder_id,
-        channel_id
+        channel_id,
+        folder_id
     )
     .execute(&mut *conn)
     .await;
@@ -49,7 +64,7 @@ pub async fn select_folders(pool: &MySqlPool, user_id: i32) -> Result<Vec<RssFol
     let mut conn = get_db(pool).await?;
     let result = sqlx::query_as!(
         RssFolder,
-        "SELECT * FROM rss_folder WHERE user_id = ?",
+        "SELECT * FROM rss_folder WHERE user_id = ? ORDER BY folder_position, folder_id",
         user_id
     )
     .fetch_all(&mut *conn)
@@ -70,7 +85,8 @@ pub async fn select_channels_in_folder(
         RssChannel,
         "SELECT rc.* FROM rss_channel rc 
          JOIN channels_in_folder cic ON rc.channel_id = cic.channel_id 
-         WHERE cic.folder_id = ?",
+         WHERE cic.folder_id = ?
+         ORDER BY cic.channel_position, cic.channels_in_folder_id",
         folder_id
     )
     .fetch_all(&mut *conn)
@@ -82,16 +98,79 @@ pub async fn select_channels_in_folder(
     }
 }
 
+/// 채널을 담고 있는 모든 사용자의 폴더
+pub async fn select_folders_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<Vec<RssFolder>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = sqlx::query_as!(
+        RssFolder,
+        "SELECT rf.* FROM rss_folder rf
+        JOIN channels_in_folder cic ON rf.folder_id = cic.folder_id
+        WHERE cic.channel_id = ?;",
+        channel_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_folder_latest_pub_dates(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<FolderLatestPubDate>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query!(
+        "SELECT cic.folder_id, MAX(ri.rss_pub_date) AS latest_pub_date
+         FROM rss_folder rf
+         JOIN channels_in_folder cic ON rf.folder_id = cic.folder_id
+         JOIN rss_item ri ON ri.channel_id = cic.channel_id
+         WHERE rf.user_id = ?
+         GROUP BY cic.folder_id",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|row| FolderLatestPubDate {
+                folder_id: Some(row.folder_id),
+                latest_pub_date: row.latest_pub_date,
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 아이콘, 색상은 전달된 값만 변경 (빈 문자열이면 삭제), 내 폴더가 아니면 RowNotFound
 pub async fn update_folder(
     pool: &MySqlPool,
+    user_id: i32,
     folder_id: i32,
     folder_name: String,
+    folder_icon: Option<String>,
+    folder_color: Option<String>,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = sqlx::query!(
-        "UPDATE rss_folder SET folder_name = ? WHERE folder_id = ?",
+        "UPDATE rss_folder
+         SET folder_name = ?,
+             folder_icon = NULLIF(COALESCE(?, folder_icon), ''),
+             folder_color = NULLIF(COALESCE(?, folder_color), '')
+         WHERE folder_id = ? AND user_id = ?",
         folder_name,
-        folder_id
+        folder_icon,
+        folder_color,
+        folder_id,
+        user_id
     )
     .execute(&mut *conn)
     .await;
@@ -108,22 +187,57 @@ pub async fn update_folder(
     }
 }
 
-pub async fn delete_folder(pool: &MySqlPool, folder_id: i32) -> Result<(), sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-    let result = sqlx::query!("DELETE FROM rss_folder WHERE folder_id = ?", folder_id)
-        .execute(&mut *conn)
-        .await;
+/// 사용자의 폴더를 삭제하고, 같은 트랜잭션에서 폴더와 상위 폴더가 비워진 하위 폴더의 변경을 기록
+pub async fn delete_folder(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+    changed_at: NaiveDateTime,
+    recorded_at: NaiveDateTime,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
 
-    match result {
-        Ok(res) => {
-            if res.rows_affected() > 0 {
-                Ok(())
-            } else {
-                Err(sqlx::Error::RowNotFound)
-            }
-        }
-        Err(e) => Err(e),
+    // 삭제되면 하위 폴더의 folder_parent_id가 NULL이 되므로 미리 조회
+    let child_folder_ids: Vec<i32> = sqlx::query!(
+        "SELECT folder_id FROM rss_folder
+        WHERE folder_parent_id = ? AND user_id = ?
+        FOR UPDATE;",
+        folder_id,
+        user_id,
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|row| row.folder_id)
+    .collect();
+
+    let result = sqlx::query!(
+        "DELETE FROM rss_folder WHERE folder_id = ? AND user_id = ?;",
+        folder_id,
+        user_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(sqlx::Error::RowNotFound);
     }
+
+    for changed_folder_id in std::iter::once(folder_id).chain(child_folder_ids) {
+        sqlx::query!(
+            "INSERT INTO user_sync_change
+                (user_id, sync_entity, sync_entity_id, sync_changed_at, sync_recorded_at)
+                VALUES (?, ?, ?, ?, ?);",
+            user_id,
+            SyncEntity::Folder.as_str(),
+            changed_folder_id,
+            changed_at,
+            recorded_at,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
 }
 
 pub async fn delete_channel_from_folder(
@@ -151,3 +265,86 @@ pub async fn delete_channel_from_folder(
         Err(e) => Err(e),
     }
 }
+
+/// 폴더 위치 (folder_id, folder_parent_id, folder_position)와
+/// 폴더 안 채널 위치 (folder_id, channel_id, channel_position)를 한 트랜잭션으로 변경
+pub async fn update_positions(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_positions: Vec<(i32, Option<i32>, i32)>,
+    channel_positions: Vec<(i32, i32, i32)>,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    for (folder_id, folder_parent_id, folder_position) in folder_positions {
+        sqlx::query!(
+            "UPDATE rss_folder SET folder_parent_id = ?, folder_position = ?
+             WHERE folder_id = ? AND user_id = ?",
+            folder_parent_id,
+            folder_position,
+            folder_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for (folder_id, channel_id, channel_position) in channel_positions {
+        sqlx::query!(
+            "UPDATE channels_in_folder SET channel_position = ?
+             WHERE folder_id = ? AND channel_id = ?",
+            channel_position,
+            folder_id,
+            channel_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await
+}
+
+/// 슬러그가 없으면 비공개로 변경
+pub async fn update_folder_public_slug(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+    folder_public_slug: Option<String>,
+    folder_published_at: Option<NaiveDateTime>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query!(
+        "UPDATE rss_folder SET folder_public_slug = ?, folder_published_at = ?
+         WHERE folder_id = ? AND user_id = ?",
+        folder_public_slug,
+        folder_published_at,
+        folder_id,
+        user_id
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_folder_by_public_slug(
+    pool: &MySqlPool,
+    folder_public_slug: &str,
+) -> Result<RssFolder, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = sqlx::query_as!(
+        RssFolder,
+        "SELECT * FROM rss_folder WHERE folder_public_slug = ?",
+        folder_public_slug
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
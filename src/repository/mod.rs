@@ -1,9 +1,25 @@
+pub mod app_password_repository;
+pub mod bookmark_repository;
 pub mod channel_css_repository;
+pub mod digest_repository;
 pub mod embedding_repository;
+pub mod feed_rule_repository;
+pub mod feed_token_repository;
+pub mod feedback_repository;
 pub mod folder_repository;
+pub mod mute_repository;
 pub mod news_repository;
+pub mod notification_repository;
 pub mod omninews_subscription_repository;
+pub mod push_repository;
+pub mod read_repository;
+pub mod reading_repository;
 pub mod rss_channel_repository;
 pub mod rss_item_repository;
+pub mod saved_search_repository;
+pub mod search_repository;
+pub mod session_repository;
+pub mod story_repository;
 pub mod subscribe_repository;
+pub mod sync_repository;
 pub mod user_repository;
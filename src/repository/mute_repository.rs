@@ -0,0 +1,90 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{db_util::get_db, model::mute::MuteRule};
+
+pub async fn insert_mute_rule(
+    pool: &MySqlPool,
+    user_id: i32,
+    mute_rule_type: &str,
+    mute_rule_value: &str,
+    created_at: NaiveDateTime,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO mute_rule (user_id, mute_rule_type, mute_rule_value, mute_rule_created_at)
+            VALUES (?, ?, ?, ?);",
+        user_id,
+        mute_rule_type,
+        mute_rule_value,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_mute_rules_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<MuteRule>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        MuteRule,
+        "SELECT * FROM mute_rule WHERE user_id = ? ORDER BY mute_rule_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_mute_rules_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS rule_count FROM mute_rule WHERE user_id = ?;",
+        user_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rule_count),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_mute_rule(
+    pool: &MySqlPool,
+    user_id: i32,
+    mute_rule_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM mute_rule WHERE mute_rule_id = ? AND user_id = ?;",
+        mute_rule_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,289 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::notification::{NewUserNotification, NotificationPreference, UserNotification},
+};
+
+pub async fn select_notification_preferences(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<NotificationPreference>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        NotificationPreference,
+        "SELECT * FROM notification_preference
+        WHERE user_id = ?
+        ORDER BY notification_preference_id;",
+        user_id,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 채널 설정과 채널이 속한 폴더의 설정 (모든 사용자)
+pub async fn select_notification_preferences_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<Vec<NotificationPreference>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        NotificationPreference,
+        "SELECT * FROM notification_preference
+        WHERE channel_id = ?
+        OR folder_id IN (SELECT folder_id FROM channels_in_folder WHERE channel_id = ?);",
+        channel_id,
+        channel_id,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn upsert_notification_preference(
+    pool: &MySqlPool,
+    preference: NotificationPreference,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO notification_preference
+            (user_id, channel_id, folder_id, notification_level, notification_keywords,
+            notification_preference_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            notification_level = VALUES(notification_level),
+            notification_keywords = VALUES(notification_keywords),
+            notification_preference_updated_at = VALUES(notification_preference_updated_at);",
+        preference.user_id,
+        preference.channel_id,
+        preference.folder_id,
+        preference.notification_level,
+        preference.notification_keywords,
+        preference.notification_preference_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_notification_preference(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: Option<i32>,
+    folder_id: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM notification_preference
+        WHERE user_id = ? AND channel_id <=> ? AND folder_id <=> ?;",
+        user_id,
+        channel_id,
+        folder_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_user_notifications(
+    pool: &MySqlPool,
+    notifications: Vec<NewUserNotification>,
+) -> Result<u64, sqlx::Error> {
+    if notifications.is_empty() {
+        return Ok(0);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..notifications.len())
+        .map(|_| "(?, ?, ?, ?, ?, ?, ?, ?)".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "INSERT INTO user_notification
+            (user_id, notification_type, notification_title, notification_body,
+            rss_id, channel_id, feedback_id, notification_created_at)
+            VALUES {placeholder};"
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for notification in notifications {
+        query_builder = query_builder
+            .bind(notification.user_id)
+            .bind(notification.notification_type)
+            .bind(notification.notification_title)
+            .bind(notification.notification_body)
+            .bind(notification.rss_id)
+            .bind(notification.channel_id)
+            .bind(notification.feedback_id)
+            .bind(notification.notification_created_at);
+    }
+
+    let result = query_builder.execute(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// `before_id`보다 오래된 알림 (최신순)
+pub async fn select_user_notifications(
+    pool: &MySqlPool,
+    user_id: i32,
+    before_id: i32,
+    limit: i32,
+) -> Result<Vec<UserNotification>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserNotification,
+        "SELECT * FROM user_notification
+        WHERE user_id = ? AND notification_id < ?
+        ORDER BY notification_id DESC
+        LIMIT ?;",
+        user_id,
+        before_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_unread_notifications(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS count FROM user_notification
+        WHERE user_id = ? AND notification_is_read = FALSE;",
+        user_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.count),
+        Err(e) => Err(e),
+    }
+}
+
+/// `notification_ids`가 비어 있으면 모든 알림을 읽음 처리
+pub async fn update_notifications_read(
+    pool: &MySqlPool,
+    user_id: i32,
+    notification_ids: Vec<i32>,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let id_condition = if notification_ids.is_empty() {
+        String::new()
+    } else {
+        let placeholder = (0..notification_ids.len())
+            .map(|_| "?".to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        format!("AND notification_id IN ({placeholder})")
+    };
+
+    let query = format!(
+        "UPDATE user_notification
+        SET notification_is_read = TRUE
+        WHERE user_id = ? AND notification_is_read = FALSE {id_condition};"
+    );
+
+    let mut query_builder = sqlx::query(&query).bind(user_id);
+    for id in notification_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.execute(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_user_notification(
+    pool: &MySqlPool,
+    user_id: i32,
+    notification_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_notification WHERE user_id = ? AND notification_id = ?;",
+        user_id,
+        notification_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_user_notifications(pool: &MySqlPool, user_id: i32) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!("DELETE FROM user_notification WHERE user_id = ?;", user_id)
+        .execute(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 보관 기간이 지난 알림 삭제
+pub async fn delete_notifications_before(
+    pool: &MySqlPool,
+    before: NaiveDateTime,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_notification WHERE notification_created_at < ?;",
+        before,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,295 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::push::{PendingPushItem, PushTarget, UserPushSetting},
+};
+
+pub async fn upsert_user_push_setting(
+    pool: &MySqlPool,
+    setting: UserPushSetting,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_push_setting
+            (user_id, push_quiet_start_hour, push_quiet_end_hour, push_timezone, push_updated_at)
+            VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            push_quiet_start_hour = VALUES(push_quiet_start_hour),
+            push_quiet_end_hour = VALUES(push_quiet_end_hour),
+            push_timezone = VALUES(push_timezone),
+            push_updated_at = VALUES(push_updated_at);",
+        setting.user_id,
+        setting.push_quiet_start_hour,
+        setting.push_quiet_end_hour,
+        setting.push_timezone,
+        setting.push_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_user_push_setting(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<UserPushSetting>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserPushSetting,
+        "SELECT * FROM user_push_setting WHERE user_id = ?;",
+        user_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_push_last_sent_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    sent_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_push_setting (user_id, push_last_sent_at)
+            VALUES (?, ?)
+        ON DUPLICATE KEY UPDATE push_last_sent_at = VALUES(push_last_sent_at),
+            push_failure_count = 0, push_retry_at = NULL;",
+        user_id,
+        sent_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// FCM 발송 실패 횟수와 다음 재시도 시각 기록
+pub async fn update_push_failure(
+    pool: &MySqlPool,
+    user_id: i32,
+    failure_count: i32,
+    retry_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_push_setting (user_id, push_failure_count, push_retry_at)
+            VALUES (?, ?, ?)
+        ON DUPLICATE KEY UPDATE push_failure_count = VALUES(push_failure_count),
+            push_retry_at = VALUES(push_retry_at);",
+        user_id,
+        failure_count,
+        retry_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 푸시를 켜고 FCM 토큰이 있는 사용자
+pub async fn select_push_enabled_user_ids(
+    pool: &MySqlPool,
+    user_ids: Vec<i32>,
+) -> Result<Vec<i32>, sqlx::Error> {
+    if user_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..user_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT user_id FROM user
+        WHERE user_id IN ({placeholder})
+        AND user_notification_push = TRUE
+        AND user_fcm_token IS NOT NULL AND user_fcm_token <> '';"
+    );
+
+    let mut query_builder = sqlx::query_scalar::<_, i32>(&query);
+    for id in user_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 푸시를 켜고 FCM 토큰이 있는 사용자면 대기열에 아이템 추가
+pub async fn insert_pending_push_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_ids: Vec<i32>,
+    created_at: NaiveDateTime,
+) -> Result<u64, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(0);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "INSERT IGNORE INTO push_pending_item (user_id, rss_id, push_pending_created_at)
+        SELECT u.user_id, ri.rss_id, ?
+        FROM user u
+        JOIN rss_item ri ON ri.rss_id IN ({placeholder})
+        WHERE u.user_id = ?
+        AND u.user_notification_push = TRUE
+        AND u.user_fcm_token IS NOT NULL AND u.user_fcm_token <> '';"
+    );
+
+    let mut query_builder = sqlx::query(&query).bind(created_at);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder.bind(user_id);
+
+    let result = query_builder.execute(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// `after_user_id`보다 ID가 큰, 지금 보낼 수 있는 대기 푸시가 있는 사용자 (ID 순)
+///
+/// 마지막 발송이 `sent_before` 이전이고 재시도 시각이 지난 사용자만 반환 (방해 금지 시간은 서비스에서 확인)
+pub async fn select_push_targets(
+    pool: &MySqlPool,
+    after_user_id: i32,
+    sent_before: NaiveDateTime,
+    now: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<PushTarget>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        PushTarget,
+        "SELECT u.user_id, u.user_fcm_token,
+            ps.push_quiet_start_hour, ps.push_quiet_end_hour, ps.push_timezone, ps.push_failure_count
+        FROM user u
+        LEFT JOIN user_push_setting ps ON ps.user_id = u.user_id
+        WHERE u.user_id > ?
+        AND EXISTS (SELECT 1 FROM push_pending_item p WHERE p.user_id = u.user_id)
+        AND u.user_notification_push = TRUE
+        AND u.user_fcm_token IS NOT NULL AND u.user_fcm_token <> ''
+        AND (ps.push_last_sent_at IS NULL OR ps.push_last_sent_at <= ?)
+        AND (ps.push_retry_at IS NULL OR ps.push_retry_at <= ?)
+        ORDER BY u.user_id
+        LIMIT ?;",
+        after_user_id,
+        sent_before,
+        now,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_pending_push_items(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<PendingPushItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        PendingPushItem,
+        "SELECT p.push_pending_id, p.rss_id, ri.rss_title, rc.channel_id, rc.channel_title
+        FROM push_pending_item p
+        JOIN rss_item ri ON ri.rss_id = p.rss_id
+        JOIN rss_channel rc ON rc.channel_id = ri.channel_id
+        WHERE p.user_id = ?
+        ORDER BY p.push_pending_id;",
+        user_id,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 발송한 아이템까지 대기열에서 삭제
+pub async fn delete_pending_push_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    max_push_pending_id: i64,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM push_pending_item WHERE user_id = ? AND push_pending_id <= ?;",
+        user_id,
+        max_push_pending_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 오래된 대기 아이템과 푸시를 끄거나 토큰이 없어진 사용자의 대기열 삭제
+pub async fn delete_stale_pending_push_items(
+    pool: &MySqlPool,
+    before: NaiveDateTime,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE p FROM push_pending_item p
+        JOIN user u ON u.user_id = p.user_id
+        WHERE p.push_pending_created_at < ?
+        OR u.user_notification_push = FALSE
+        OR u.user_fcm_token IS NULL OR u.user_fcm_token = '';",
+        before,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,297 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::read::{ChannelReadMark, ChannelUnreadCount},
+};
+
+// rss_item ri 기준 읽음 여부. 아이템별 상태가 있으면 우선하고, 없으면 채널 기준선으로 판단
+// 바인딩 순서: user_id (기준선), user_id (아이템별 상태)
+pub const READ_STATE_JOIN: &str = "LEFT JOIN user_channel_read_mark rm
+        ON rm.user_id = ? AND rm.channel_id = ri.channel_id
+    LEFT JOIN user_item_read_state rs
+        ON rs.user_id = ? AND rs.rss_id = ri.rss_id";
+pub const IS_READ_CONDITION: &str = "COALESCE(rs.item_read,
+        ri.rss_id <= COALESCE(rm.read_mark_rss_id, 0)
+        OR COALESCE(ri.rss_pub_date <= rm.read_mark_pub_date, FALSE))";
+
+pub async fn select_channel_read_mark(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: i32,
+) -> Result<Option<ChannelReadMark>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ChannelReadMark,
+        "SELECT * FROM user_channel_read_mark WHERE user_id = ? AND channel_id = ?;",
+        user_id,
+        channel_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 기준선을 앞으로만 이동 (이미 더 앞선 기준선이면 유지)
+pub async fn upsert_channel_read_mark(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: i32,
+    read_mark_rss_id: i32,
+    read_mark_pub_date: Option<NaiveDateTime>,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_channel_read_mark
+            (user_id, channel_id, read_mark_rss_id, read_mark_pub_date, read_mark_updated_at)
+            VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            read_mark_rss_id = GREATEST(read_mark_rss_id, VALUES(read_mark_rss_id)),
+            read_mark_pub_date = CASE
+                WHEN read_mark_pub_date IS NULL THEN VALUES(read_mark_pub_date)
+                WHEN VALUES(read_mark_pub_date) IS NULL THEN read_mark_pub_date
+                ELSE GREATEST(read_mark_pub_date, VALUES(read_mark_pub_date))
+            END,
+            read_mark_updated_at = VALUES(read_mark_updated_at);",
+        user_id,
+        channel_id,
+        read_mark_rss_id,
+        read_mark_pub_date,
+        updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn upsert_item_read_state(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    channel_id: i32,
+    item_read: bool,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_item_read_state
+            (user_id, rss_id, channel_id, item_read, item_read_updated_at)
+            VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            item_read = VALUES(item_read),
+            item_read_updated_at = VALUES(item_read_updated_at);",
+        user_id,
+        rss_id,
+        channel_id,
+        item_read,
+        updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_item_read_state(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_item_read_state WHERE user_id = ? AND rss_id = ?;",
+        user_id,
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 기준선에 포함된 아이템의 개별 상태 삭제 (기준선 이동 후 정리)
+pub async fn delete_item_read_states_covered_by_mark(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE rs FROM user_item_read_state rs
+        JOIN user_channel_read_mark rm
+            ON rm.user_id = rs.user_id AND rm.channel_id = rs.channel_id
+        JOIN rss_item ri ON ri.rss_id = rs.rss_id
+        WHERE rs.user_id = ? AND rs.channel_id = ?
+        AND (ri.rss_id <= rm.read_mark_rss_id
+            OR COALESCE(ri.rss_pub_date <= rm.read_mark_pub_date, FALSE));",
+        user_id,
+        channel_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_max_rss_id_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<Option<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT MAX(rss_id) AS max_rss_id FROM rss_item WHERE channel_id = ?;",
+        channel_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.max_rss_id),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_unread_counts_by_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_ids: Vec<i32>,
+) -> Result<Vec<ChannelUnreadCount>, sqlx::Error> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channel_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT ri.channel_id, COUNT(*) AS unread_count
+        FROM rss_item ri
+        {READ_STATE_JOIN}
+        WHERE ri.channel_id IN ({placeholder})
+        AND NOT {IS_READ_CONDITION}
+        GROUP BY ri.channel_id;"
+    );
+
+    let mut query_builder = query_as::<_, ChannelUnreadCount>(&query)
+        .bind(user_id)
+        .bind(user_id);
+    for id in channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_read_rss_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<i32>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT ri.rss_id
+        FROM rss_item ri
+        {READ_STATE_JOIN}
+        WHERE ri.rss_id IN ({placeholder})
+        AND {IS_READ_CONDITION};"
+    );
+
+    let mut query_builder = sqlx::query_scalar::<_, i32>(&query)
+        .bind(user_id)
+        .bind(user_id);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 채널들의 읽지 않은 아이템 ID (최신순, 최대 `limit`개)
+pub async fn select_unread_rss_ids_by_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_ids: Vec<i32>,
+    limit: i32,
+) -> Result<Vec<i32>, sqlx::Error> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channel_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT ri.rss_id
+        FROM rss_item ri
+        {READ_STATE_JOIN}
+        WHERE ri.channel_id IN ({placeholder})
+        AND NOT {IS_READ_CONDITION}
+        ORDER BY ri.rss_id DESC
+        LIMIT ?;"
+    );
+
+    let mut query_builder = sqlx::query_scalar::<_, i32>(&query)
+        .bind(user_id)
+        .bind(user_id);
+    for id in channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder.bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
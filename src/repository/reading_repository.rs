@@ -0,0 +1,343 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::reading::{
+        ChannelReadingStat, DailyReadingStat, ReadingHistory, ReadingHistoryDetail, TagReadingStat,
+    },
+};
+
+pub async fn insert_reading_history(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    channel_id: i32,
+    opened_at: NaiveDateTime,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO reading_history (user_id, rss_id, channel_id, reading_opened_at)
+            VALUES (?, ?, ?, ?);",
+        user_id,
+        rss_id,
+        channel_id,
+        opened_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn exists_reading_history(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT reading_history_id FROM reading_history
+        WHERE user_id = ? AND rss_id = ?
+        LIMIT 1;",
+        user_id,
+        rss_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.is_some()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_latest_reading_history(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<Option<ReadingHistory>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ReadingHistory,
+        "SELECT * FROM reading_history
+        WHERE user_id = ? AND rss_id = ?
+        ORDER BY reading_history_id DESC
+        LIMIT 1;",
+        user_id,
+        rss_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn add_reading_history_seconds(
+    pool: &MySqlPool,
+    reading_history_id: i32,
+    seconds: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE reading_history SET reading_seconds = reading_seconds + ?
+        WHERE reading_history_id = ?;",
+        seconds,
+        reading_history_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 기록 id 내림차순(최근 읽은 순)으로 커서 다음 읽기 기록 조회
+pub async fn select_reading_histories_before_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    cursor_reading_history_id: i32,
+    limit: i32,
+) -> Result<Vec<ReadingHistoryDetail>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ReadingHistoryDetail,
+        "SELECT rh.reading_history_id, rh.rss_id, rh.channel_id,
+            ri.rss_title, ri.rss_link, rc.channel_title,
+            rh.reading_opened_at, rh.reading_seconds
+        FROM reading_history rh
+        LEFT JOIN rss_item ri ON ri.rss_id = rh.rss_id
+        LEFT JOIN rss_channel rc ON rc.channel_id = rh.channel_id
+        WHERE rh.user_id = ? AND rh.reading_history_id < ?
+        ORDER BY rh.reading_history_id DESC
+        LIMIT ?;",
+        user_id,
+        cursor_reading_history_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 추천 관심 벡터 계산용, 기준 시각 이후 최근 읽은 글 id 조회 (중복 제거)
+pub async fn select_recent_read_rss_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    opened_after: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT rss_id AS `rss_id!`, MAX(reading_history_id) AS last_history_id
+        FROM reading_history
+        WHERE user_id = ? AND rss_id IS NOT NULL AND reading_opened_at >= ?
+        GROUP BY rss_id
+        ORDER BY last_history_id DESC
+        LIMIT ?;",
+        user_id,
+        opened_after,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().map(|row| row.rss_id).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn upsert_reading_daily_stat(
+    pool: &MySqlPool,
+    user_id: i32,
+    stat_date: NaiveDate,
+    channel_id: i32,
+    articles_read: i32,
+    seconds: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO reading_daily_stat
+            (user_id, stat_date, channel_id, stat_articles_read, stat_seconds)
+            VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            stat_articles_read = stat_articles_read + VALUES(stat_articles_read),
+            stat_seconds = stat_seconds + VALUES(stat_seconds);",
+        user_id,
+        stat_date,
+        channel_id,
+        articles_read,
+        seconds,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 아이템의 태그별로 읽은 아이템 수 1 증가
+pub async fn increment_reading_tag_stats(
+    pool: &MySqlPool,
+    user_id: i32,
+    stat_date: NaiveDate,
+    rss_id: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO reading_tag_stat (user_id, stat_date, tag_name, stat_articles_read)
+            SELECT ?, ?, tag_name, 1 FROM rss_item_tag WHERE rss_id = ?
+        ON DUPLICATE KEY UPDATE stat_articles_read = stat_articles_read + 1;",
+        user_id,
+        stat_date,
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_daily_reading_stats(
+    pool: &MySqlPool,
+    user_id: i32,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<DailyReadingStat>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        DailyReadingStat,
+        "SELECT stat_date,
+            CAST(SUM(stat_articles_read) AS SIGNED) AS stat_articles_read,
+            CAST(SUM(stat_seconds) AS SIGNED) AS stat_seconds
+        FROM reading_daily_stat
+        WHERE user_id = ? AND stat_date BETWEEN ? AND ?
+        GROUP BY stat_date
+        ORDER BY stat_date ASC;",
+        user_id,
+        from,
+        to,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_top_channel_reading_stats(
+    pool: &MySqlPool,
+    user_id: i32,
+    from: NaiveDate,
+    to: NaiveDate,
+    limit: i32,
+) -> Result<Vec<ChannelReadingStat>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        ChannelReadingStat,
+        "SELECT rds.channel_id, rc.channel_title,
+            CAST(SUM(rds.stat_articles_read) AS SIGNED) AS stat_articles_read,
+            CAST(SUM(rds.stat_seconds) AS SIGNED) AS stat_seconds
+        FROM reading_daily_stat rds
+        JOIN rss_channel rc ON rc.channel_id = rds.channel_id
+        WHERE rds.user_id = ? AND rds.stat_date BETWEEN ? AND ?
+        GROUP BY rds.channel_id, rc.channel_title
+        ORDER BY stat_articles_read DESC, stat_seconds DESC
+        LIMIT ?;",
+        user_id,
+        from,
+        to,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_top_tag_reading_stats(
+    pool: &MySqlPool,
+    user_id: i32,
+    from: NaiveDate,
+    to: NaiveDate,
+    limit: i32,
+) -> Result<Vec<TagReadingStat>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        TagReadingStat,
+        "SELECT tag_name,
+            CAST(SUM(stat_articles_read) AS SIGNED) AS stat_articles_read
+        FROM reading_tag_stat
+        WHERE user_id = ? AND stat_date BETWEEN ? AND ?
+        GROUP BY tag_name
+        ORDER BY stat_articles_read DESC
+        LIMIT ?;",
+        user_id,
+        from,
+        to,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 아이템을 하나 이상 처음 읽은 날짜 (오름차순)
+pub async fn select_reading_dates(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<NaiveDate>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT DISTINCT stat_date FROM reading_daily_stat
+        WHERE user_id = ? AND stat_articles_read > 0
+        ORDER BY stat_date ASC;",
+        user_id,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().map(|row| row.stat_date).collect()),
+        Err(e) => Err(e),
+    }
+}
@@ -159,3 +159,111 @@ pub async fn update_rss_channel_rank_by_id(
         Ok(false)
     }
 }
+
+/// 제목이 `stem`(`range`가 있으면 그 범위)으로 시작하거나, 제목 중간 단어가 `stem`으로 시작하는 채널
+pub async fn select_rss_channels_by_title_prefix(
+    pool: &MySqlPool,
+    stem: &str,
+    range: Option<(&str, &str)>,
+    limit: i32,
+) -> Result<Vec<RssChannel>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssChannel,
+        "SELECT * FROM rss_channel
+        WHERE (channel_title LIKE CONCAT(?, '%')
+            AND (? IS NULL OR (channel_title >= ? AND channel_title < ?)))
+        OR (? <> '' AND channel_title LIKE CONCAT('% ', ?, '%'))
+        ORDER BY channel_rank DESC
+        LIMIT ?;",
+        stem,
+        range.map(|(start, _)| start),
+        range.map(|(start, _)| start),
+        range.map(|(_, end)| end),
+        stem,
+        stem,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_channels_by_ids(
+    pool: &MySqlPool,
+    channel_ids: Vec<i32>,
+) -> Result<Vec<RssChannel>, sqlx::Error> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channel_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM rss_channel WHERE channel_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, RssChannel>(&query);
+    for id in channel_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_channels_after_id(
+    pool: &MySqlPool,
+    after_channel_id: i32,
+    limit: i32,
+) -> Result<Vec<RssChannel>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssChannel,
+        "SELECT * FROM rss_channel
+        WHERE channel_id > ?
+        ORDER BY channel_id ASC
+        LIMIT ?;",
+        after_channel_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_rss_channel_language(
+    pool: &MySqlPool,
+    channel_id: i32,
+    channel_language: Option<String>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE rss_channel SET channel_language = ? WHERE channel_id = ?;",
+        channel_language,
+        channel_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
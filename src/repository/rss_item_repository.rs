@@ -1,8 +1,9 @@
+use chrono::NaiveDateTime;
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
     db_util::get_db,
-    model::rss::{NewRssItem, RssItem},
+    model::rss::{NewRssItem, RssItem, RssItemTag, RssTagCount},
 };
 
 pub async fn select_item_by_link(
@@ -24,6 +25,47 @@ pub async fn select_item_by_link(
     }
 }
 
+pub async fn select_rss_item_by_id(pool: &MySqlPool, rss_id: i32) -> Result<RssItem, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(RssItem, "SELECT * FROM rss_item WHERE rss_id = ?;", rss_id)
+        .fetch_one(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_items_by_ids(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM rss_item WHERE rss_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
 pub async fn select_rss_item_by_embedding_id(
     pool: &MySqlPool,
     search_value: &str,
@@ -101,12 +143,169 @@ pub async fn select_rss_items_by_channel_id_pagenation(
     }
 }
 
+/// (rss_pub_date, rss_id) 내림차순으로 커서 다음 아이템 조회 (발행일 없는 아이템은 마지막)
+pub async fn select_rss_items_by_channel_id_before_cursor(
+    pool: &MySqlPool,
+    channel_id: i32,
+    cursor_pub_date: Option<NaiveDateTime>,
+    cursor_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT * FROM rss_item r
+        WHERE r.channel_id = ?
+        AND (r.rss_pub_date < ?
+            OR (r.rss_pub_date <=> ? AND r.rss_id < ?)
+            OR (r.rss_pub_date IS NULL AND ? IS NOT NULL))
+        ORDER BY r.rss_pub_date DESC, r.rss_id DESC
+        LIMIT ?;",
+        channel_id,
+        cursor_pub_date,
+        cursor_pub_date,
+        cursor_rss_id,
+        cursor_pub_date,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `collapse_story`면 같은 스토리에서 검색 결과상 먼저 나오는 아이템이 있는 아이템은 제외
+pub async fn select_rss_items_by_search_value_before_pub_date(
+    pool: &MySqlPool,
+    search_value: &str,
+    cursor_pub_date: Option<NaiveDateTime>,
+    cursor_rss_id: i32,
+    limit: i32,
+    collapse_story: bool,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT ri.* FROM rss_item ri
+        WHERE (ri.rss_title LIKE CONCAT('%', ? ,'%')
+            OR ri.rss_description LIKE CONCAT('%', ? ,'%'))
+        AND (ri.rss_pub_date < ?
+            OR (ri.rss_pub_date <=> ? AND ri.rss_id < ?)
+            OR (ri.rss_pub_date IS NULL AND ? IS NOT NULL))
+        AND (NOT ? OR NOT EXISTS (
+            SELECT 1 FROM rss_story_item si
+            JOIN rss_story_item other_si ON other_si.story_id = si.story_id
+            JOIN rss_item other ON other.rss_id = other_si.rss_id
+            WHERE si.rss_id = ri.rss_id
+            AND (other.rss_title LIKE CONCAT('%', ? ,'%')
+                OR other.rss_description LIKE CONCAT('%', ? ,'%'))
+            AND (other.rss_pub_date > ri.rss_pub_date
+                OR (other.rss_pub_date <=> ri.rss_pub_date AND other.rss_id > ri.rss_id)
+                OR (other.rss_pub_date IS NOT NULL AND ri.rss_pub_date IS NULL))))
+        ORDER BY ri.rss_pub_date DESC, ri.rss_id DESC
+        LIMIT ?;",
+        search_value,
+        search_value,
+        cursor_pub_date,
+        cursor_pub_date,
+        cursor_rss_id,
+        cursor_pub_date,
+        collapse_story,
+        search_value,
+        search_value,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `collapse_story`면 같은 스토리에서 검색 결과상 먼저 나오는 아이템이 있는 아이템은 제외
+pub async fn select_rss_items_by_search_value_before_rank(
+    pool: &MySqlPool,
+    search_value: &str,
+    cursor_rss_rank: i32,
+    cursor_rss_id: i32,
+    limit: i32,
+    collapse_story: bool,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssItem,
+        "SELECT ri.* FROM rss_item ri
+        WHERE (ri.rss_title LIKE CONCAT('%', ? ,'%')
+            OR ri.rss_description LIKE CONCAT('%', ? ,'%'))
+        AND (COALESCE(ri.rss_rank, 0) < ?
+            OR (COALESCE(ri.rss_rank, 0) = ? AND ri.rss_id < ?))
+        AND (NOT ? OR NOT EXISTS (
+            SELECT 1 FROM rss_story_item si
+            JOIN rss_story_item other_si ON other_si.story_id = si.story_id
+            JOIN rss_item other ON other.rss_id = other_si.rss_id
+            WHERE si.rss_id = ri.rss_id
+            AND (other.rss_title LIKE CONCAT('%', ? ,'%')
+                OR other.rss_description LIKE CONCAT('%', ? ,'%'))
+            AND (COALESCE(other.rss_rank, 0) > COALESCE(ri.rss_rank, 0)
+                OR (COALESCE(other.rss_rank, 0) = COALESCE(ri.rss_rank, 0)
+                    AND other.rss_id > ri.rss_id))))
+        ORDER BY COALESCE(ri.rss_rank, 0) DESC, ri.rss_id DESC
+        LIMIT ?;",
+        search_value,
+        search_value,
+        cursor_rss_rank,
+        cursor_rss_rank,
+        cursor_rss_id,
+        collapse_story,
+        search_value,
+        search_value,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_rss_items_by_search_value(
+    pool: &MySqlPool,
+    search_value: &str,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT COUNT(*) AS item_count FROM rss_item
+        WHERE rss_title LIKE CONCAT('%', ? ,'%')
+        OR rss_description LIKE CONCAT('%', ? ,'%');",
+        search_value,
+        search_value,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.item_count),
+        Err(e) => Err(e),
+    }
+}
+
 pub async fn insert_rss_item(pool: &MySqlPool, rss_item: NewRssItem) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
     let result = query!(
         "INSERT INTO rss_item 
-            (channel_id, rss_title, rss_description, rss_link, rss_author, rss_pub_date, rss_rank, rss_image_link)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            (channel_id, rss_title, rss_description, rss_link, rss_author, rss_pub_date, rss_rank, rss_image_link, rss_language)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
         rss_item.channel_id,
         rss_item.rss_title,
         rss_item.rss_description,
@@ -115,6 +314,7 @@ pub async fn insert_rss_item(pool: &MySqlPool, rss_item: NewRssItem) -> Result<i
         rss_item.rss_pub_date,
         rss_item.rss_rank,
         rss_item.rss_image_link,
+        rss_item.rss_language,
     )
     .execute(&mut *conn)
     .await;
@@ -148,3 +348,255 @@ pub async fn update_rss_channel_rank_by_id(
         Ok(false)
     }
 }
+
+pub async fn insert_rss_item_tag(
+    pool: &MySqlPool,
+    rss_id: i32,
+    tag_name: String,
+) -> Result<(), sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "INSERT IGNORE INTO rss_item_tag (rss_id, tag_name) VALUES (?, ?);",
+        rss_id,
+        tag_name,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// `stem`으로 시작하고, `range`가 있으면 그 범위(시작 이상, 끝 미만)에 있는 태그
+pub async fn select_rss_tags_by_prefix(
+    pool: &MySqlPool,
+    stem: &str,
+    range: Option<(&str, &str)>,
+    limit: i32,
+) -> Result<Vec<RssTagCount>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "SELECT tag_name, COUNT(*) AS tag_count
+        FROM rss_item_tag
+        WHERE tag_name LIKE CONCAT(?, '%')
+        AND (? IS NULL OR (tag_name >= ? AND tag_name < ?))
+        GROUP BY tag_name
+        ORDER BY tag_count DESC
+        LIMIT ?;",
+        stem,
+        range.map(|(start, _)| start),
+        range.map(|(start, _)| start),
+        range.map(|(_, end)| end),
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|row| RssTagCount {
+                tag_name: Some(row.tag_name),
+                tag_count: Some(row.tag_count),
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_item_tags_by_rss_ids(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<RssItemTag>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query =
+        format!("SELECT rss_id, tag_name FROM rss_item_tag WHERE rss_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, RssItemTag>(&query);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_items_pub_date_after(
+    pool: &MySqlPool,
+    since: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssItem,
+        "SELECT * FROM rss_item
+        WHERE rss_pub_date >= ?
+        ORDER BY rss_pub_date DESC
+        LIMIT ?;",
+        since,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_items_without_language(
+    pool: &MySqlPool,
+    after_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query_as!(
+        RssItem,
+        "SELECT * FROM rss_item
+        WHERE rss_id > ? AND rss_language IS NULL
+        ORDER BY rss_id ASC
+        LIMIT ?;",
+        after_rss_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_rss_item_language(
+    pool: &MySqlPool,
+    rss_id: i32,
+    rss_language: Option<String>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "UPDATE rss_item SET rss_language = ? WHERE rss_id = ?;",
+        rss_language,
+        rss_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 이미 보낸 아이템의 내용이 바뀐 시각 기록 (동기화에서 다시 보냄)
+pub async fn upsert_rss_item_update(
+    pool: &MySqlPool,
+    rss_id: i32,
+    updated_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "INSERT INTO rss_item_update (rss_id, rss_updated_at) VALUES (?, ?)
+        ON DUPLICATE KEY UPDATE rss_updated_at = VALUES(rss_updated_at);",
+        rss_id,
+        updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_item_summary(
+    pool: &MySqlPool,
+    rss_id: i32,
+) -> Result<Option<String>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "SELECT summary_text FROM rss_item_summary WHERE rss_id = ?;",
+        rss_id,
+    )
+    .fetch_optional(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.map(|row| row.summary_text)),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_item_summaries_by_rss_ids(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<(i32, String)>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT rss_id, summary_text FROM rss_item_summary WHERE rss_id IN ({placeholder});"
+    );
+
+    let mut query_builder = query_as::<_, (i32, String)>(&query);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_rss_item_summary(
+    pool: &MySqlPool,
+    rss_id: i32,
+    summary_text: String,
+    created_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+    let result = query!(
+        "INSERT INTO rss_item_summary (rss_id, summary_text, summary_created_at)
+            VALUES (?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            summary_text = VALUES(summary_text),
+            summary_created_at = VALUES(summary_created_at);",
+        rss_id,
+        summary_text,
+        created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,239 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::search::{NewSavedSearch, SavedSearch, SavedSearchHit},
+};
+
+pub async fn insert_saved_search(
+    pool: &MySqlPool,
+    saved_search: NewSavedSearch,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO saved_search
+            (user_id, saved_search_name, saved_search_query, saved_search_mode, saved_search_channel_ids,
+            saved_search_language, saved_search_embedding, saved_search_token, saved_search_created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);",
+        saved_search.user_id,
+        saved_search.saved_search_name,
+        saved_search.saved_search_query,
+        saved_search.saved_search_mode,
+        saved_search.saved_search_channel_ids,
+        saved_search.saved_search_language,
+        saved_search.saved_search_embedding,
+        saved_search.saved_search_token,
+        saved_search.saved_search_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_saved_searches_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<SavedSearch>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SavedSearch,
+        "SELECT * FROM saved_search WHERE user_id = ? ORDER BY saved_search_id ASC;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_all_saved_searches(pool: &MySqlPool) -> Result<Vec<SavedSearch>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(SavedSearch, "SELECT * FROM saved_search;")
+        .fetch_all(&mut *conn)
+        .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_saved_search_by_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    saved_search_id: i32,
+) -> Result<SavedSearch, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SavedSearch,
+        "SELECT * FROM saved_search WHERE saved_search_id = ? AND user_id = ?;",
+        saved_search_id,
+        user_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_saved_search_by_token(
+    pool: &MySqlPool,
+    token: &str,
+) -> Result<SavedSearch, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SavedSearch,
+        "SELECT * FROM saved_search WHERE saved_search_token = ?;",
+        token
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_saved_search(
+    pool: &MySqlPool,
+    user_id: i32,
+    saved_search_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM saved_search WHERE saved_search_id = ? AND user_id = ?;",
+        saved_search_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_saved_search_hit(
+    pool: &MySqlPool,
+    saved_search_id: i32,
+    rss_id: i32,
+    hit_created_at: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT IGNORE INTO saved_search_hit (saved_search_id, rss_id, hit_created_at)
+            VALUES (?, ?, ?);",
+        saved_search_id,
+        rss_id,
+        hit_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 저장된 검색별 확인하지 않은 최근 알림 (검색별 최대 `limit_per_search`개)
+pub async fn select_unseen_saved_search_hits_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    limit_per_search: i32,
+) -> Result<Vec<SavedSearchHit>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SavedSearchHit,
+        "SELECT saved_search_hit_id, saved_search_id, rss_id, hit_seen, hit_created_at
+        FROM (
+            SELECT ssh.*, ROW_NUMBER() OVER (
+                PARTITION BY ssh.saved_search_id ORDER BY ssh.hit_created_at DESC
+            ) AS hit_rank
+            FROM saved_search_hit ssh
+            JOIN saved_search ss ON ssh.saved_search_id = ss.saved_search_id
+            WHERE ss.user_id = ? AND ssh.hit_seen = FALSE
+        ) ranked
+        WHERE hit_rank <= ?
+        ORDER BY hit_created_at DESC;",
+        user_id,
+        limit_per_search,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_recent_saved_search_hits(
+    pool: &MySqlPool,
+    saved_search_id: i32,
+    limit: i32,
+) -> Result<Vec<SavedSearchHit>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SavedSearchHit,
+        "SELECT * FROM saved_search_hit
+        WHERE saved_search_id = ?
+        ORDER BY hit_created_at DESC
+        LIMIT ?;",
+        saved_search_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn update_saved_search_hits_seen(
+    pool: &MySqlPool,
+    user_id: i32,
+    saved_search_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE saved_search_hit ssh
+        JOIN saved_search ss ON ssh.saved_search_id = ss.saved_search_id
+        SET ssh.hit_seen = TRUE
+        WHERE ss.saved_search_id = ? AND ss.user_id = ?;",
+        saved_search_id,
+        user_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::search::{NewSearchQueryLog, PopularSearchQuery},
+};
+
+pub async fn insert_search_query_log(
+    pool: &MySqlPool,
+    log: NewSearchQueryLog,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO search_query_log (search_user_hash, search_query, search_created_at)
+            VALUES (?, ?, ?);",
+        log.search_user_hash,
+        log.search_query,
+        log.search_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// 최근 검색어를 검색한 사용자 수(익명화 해시 기준) 순으로 조회
+///
+/// `stem`으로 시작하고, `range`가 있으면 그 범위(시작 이상, 끝 미만)에 있는 검색어만
+pub async fn select_popular_search_queries(
+    pool: &MySqlPool,
+    stem: &str,
+    range: Option<(&str, &str)>,
+    since: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<PopularSearchQuery>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT search_query, COUNT(DISTINCT search_user_hash) AS search_count
+        FROM search_query_log
+        WHERE search_created_at >= ?
+        AND search_query LIKE CONCAT(?, '%')
+        AND (? IS NULL OR (search_query >= ? AND search_query < ?))
+        GROUP BY search_query
+        ORDER BY search_count DESC
+        LIMIT ?;",
+        since,
+        stem,
+        range.map(|(start, _)| start),
+        range.map(|(start, _)| start),
+        range.map(|(_, end)| end),
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|row| PopularSearchQuery {
+                search_query: Some(row.search_query),
+                search_count: Some(row.search_count),
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,267 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::session::{NewUserSession, UserSession},
+};
+
+pub async fn insert_user_session(
+    pool: &MySqlPool,
+    session: NewUserSession,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_session
+            (user_id, session_access_token_hash, session_refresh_token_hash,
+            session_access_token_expires_at, session_refresh_token_expires_at,
+            session_device_name, session_platform, session_created_at, session_last_seen_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);",
+        session.user_id,
+        session.session_access_token_hash,
+        session.session_refresh_token_hash,
+        session.session_access_token_expires_at,
+        session.session_refresh_token_expires_at,
+        session.session_device_name,
+        session.session_platform,
+        session.session_created_at,
+        session.session_created_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// 만료되지 않은 액세스 토큰의 세션 ID
+pub async fn select_session_id_by_access_token_hash(
+    pool: &MySqlPool,
+    access_token_hash: &str,
+    user_email: &str,
+    now: NaiveDateTime,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT s.session_id FROM user_session s
+        JOIN user u ON u.user_id = s.user_id
+        WHERE s.session_access_token_hash = ? AND u.user_email = ?
+        AND s.session_access_token_expires_at > ?;",
+        access_token_hash,
+        user_email,
+        now,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.session_id),
+        Err(e) => Err(e),
+    }
+}
+
+/// 만료되지 않은 리프레시 토큰의 세션 ID
+pub async fn select_session_id_by_refresh_token_hash(
+    pool: &MySqlPool,
+    refresh_token_hash: &str,
+    user_email: &str,
+    now: NaiveDateTime,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT s.session_id FROM user_session s
+        JOIN user u ON u.user_id = s.user_id
+        WHERE s.session_refresh_token_hash = ? AND u.user_email = ?
+        AND s.session_refresh_token_expires_at > ?;",
+        refresh_token_hash,
+        user_email,
+        now,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.session_id),
+        Err(e) => Err(e),
+    }
+}
+
+/// 다시 발급한 액세스 토큰으로 교체 (이전 액세스 토큰은 사용 불가)
+pub async fn update_session_access_token(
+    pool: &MySqlPool,
+    session_id: i32,
+    access_token_hash: &str,
+    access_token_expires_at: NaiveDateTime,
+    now: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_session
+        SET session_access_token_hash = ?, session_access_token_expires_at = ?,
+            session_last_seen_at = ?
+        WHERE session_id = ?;",
+        access_token_hash,
+        access_token_expires_at,
+        now,
+        session_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// `seen_before` 이전에 마지막으로 사용한 세션만 갱신 (요청마다 쓰지 않도록)
+pub async fn update_session_last_seen(
+    pool: &MySqlPool,
+    session_id: i32,
+    now: NaiveDateTime,
+    seen_before: NaiveDateTime,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user_session SET session_last_seen_at = ?
+        WHERE session_id = ? AND session_last_seen_at < ?;",
+        now,
+        session_id,
+        seen_before,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// 리프레시 토큰이 만료되지 않은 세션 (최근 사용순)
+pub async fn select_user_sessions(
+    pool: &MySqlPool,
+    user_id: i32,
+    now: NaiveDateTime,
+) -> Result<Vec<UserSession>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        UserSession,
+        "SELECT * FROM user_session
+        WHERE user_id = ? AND session_refresh_token_expires_at > ?
+        ORDER BY session_last_seen_at DESC, session_id DESC;",
+        user_id,
+        now,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_user_session(
+    pool: &MySqlPool,
+    user_id: i32,
+    session_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_session WHERE user_id = ? AND session_id = ?;",
+        user_id,
+        session_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// `keep_session_id`를 제외한 모든 세션 삭제 (없으면 전부)
+pub async fn delete_user_sessions(
+    pool: &MySqlPool,
+    user_id: i32,
+    keep_session_id: Option<i32>,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_session WHERE user_id = ? AND NOT (session_id <=> ?);",
+        user_id,
+        keep_session_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 리프레시 토큰까지 만료된 세션 정리
+pub async fn delete_expired_user_sessions(
+    pool: &MySqlPool,
+    user_id: i32,
+    now: NaiveDateTime,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_session WHERE user_id = ? AND session_refresh_token_expires_at <= ?;",
+        user_id,
+        now,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 최근 사용한 `keep`개 세션만 남기고 삭제
+pub async fn delete_old_user_sessions(
+    pool: &MySqlPool,
+    user_id: i32,
+    keep: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_session
+        WHERE user_id = ? AND session_id NOT IN (
+            SELECT session_id FROM (
+                SELECT session_id FROM user_session
+                WHERE user_id = ?
+                ORDER BY session_last_seen_at DESC, session_id DESC
+                LIMIT ?
+            ) AS recent
+        );",
+        user_id,
+        user_id,
+        keep,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
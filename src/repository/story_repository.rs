@@ -0,0 +1,227 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::story::{NewRssStory, RssStory, RssStoryItem, RssStorySource},
+};
+
+pub async fn insert_rss_story(pool: &MySqlPool, story: NewRssStory) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO rss_story
+            (story_title, story_rss_id, story_centroid, story_item_count, story_created_at, story_updated_at)
+            VALUES (?, ?, ?, ?, ?, ?);",
+        story.story_title,
+        story.story_rss_id,
+        story.story_centroid,
+        story.story_item_count,
+        story.story_created_at,
+        story.story_updated_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+/// 읽은 뒤 다른 아이템이 합류하지 않았을 때만 갱신 (`story_item_count`를 버전으로 사용)
+///
+/// 다른 요청이 먼저 갱신했으면 false를 반환하므로 다시 읽어서 계산해야 함
+pub async fn update_rss_story_if_unchanged(
+    pool: &MySqlPool,
+    story: RssStory,
+    read_item_count: i32,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE rss_story
+        SET story_title = ?, story_rss_id = ?, story_centroid = ?, story_item_count = ?, story_updated_at = ?
+        WHERE story_id = ? AND story_item_count = ?;",
+        story.story_title,
+        story.story_rss_id,
+        story.story_centroid,
+        story.story_item_count,
+        story.story_updated_at,
+        story.story_id,
+        read_item_count,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
+
+/// `from` ~ `until` 사이에 진행 중이던 스토리 (그 전에 만들어지고 그 이후에 갱신됨)
+pub async fn select_rss_stories_active_between(
+    pool: &MySqlPool,
+    from: NaiveDateTime,
+    until: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<RssStory>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssStory,
+        "SELECT * FROM rss_story
+        WHERE story_updated_at >= ? AND story_created_at <= ?
+        ORDER BY story_updated_at DESC
+        LIMIT ?;",
+        from,
+        until,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_story_by_id(
+    pool: &MySqlPool,
+    story_id: i32,
+) -> Result<RssStory, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        RssStory,
+        "SELECT * FROM rss_story WHERE story_id = ?;",
+        story_id
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_stories_by_ids(
+    pool: &MySqlPool,
+    story_ids: Vec<i32>,
+) -> Result<Vec<RssStory>, sqlx::Error> {
+    if story_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..story_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM rss_story WHERE story_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, RssStory>(&query);
+    for id in story_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_rss_story_item(
+    pool: &MySqlPool,
+    story_id: i32,
+    rss_id: i32,
+    similarity: f32,
+) -> Result<i32, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO rss_story_item (story_id, rss_id, story_similarity) VALUES (?, ?, ?);",
+        story_id,
+        rss_id,
+        similarity,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i32),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_story_items_by_rss_ids(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<Vec<RssStoryItem>, sqlx::Error> {
+    if rss_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..rss_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT * FROM rss_story_item WHERE rss_id IN ({placeholder});");
+
+    let mut query_builder = query_as::<_, RssStoryItem>(&query);
+    for id in rss_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_rss_story_sources_by_story_ids(
+    pool: &MySqlPool,
+    story_ids: Vec<i32>,
+) -> Result<Vec<RssStorySource>, sqlx::Error> {
+    if story_ids.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..story_ids.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT rsi.story_id, ri.rss_id, ri.rss_title, ri.rss_link, ri.rss_pub_date,
+            rc.channel_id, rc.channel_title, rc.channel_image_url
+        FROM rss_story_item rsi
+        JOIN rss_item ri ON rsi.rss_id = ri.rss_id
+        JOIN rss_channel rc ON ri.channel_id = rc.channel_id
+        WHERE rsi.story_id IN ({placeholder})
+        ORDER BY ri.rss_pub_date ASC;"
+    );
+
+    let mut query_builder = query_as::<_, RssStorySource>(&query);
+    for id in story_ids {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
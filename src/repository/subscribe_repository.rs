@@ -1,8 +1,10 @@
+use chrono::NaiveDateTime;
 use sqlx::{query, query_as, MySqlPool};
 
 use crate::{
     db_util::get_db,
     model::rss::{RssChannel, RssItem},
+    repository::read_repository::{IS_READ_CONDITION, READ_STATE_JOIN},
 };
 
 pub async fn insert_user_subscribe_channel(
@@ -49,11 +51,13 @@ pub async fn select_subscription_channels(
     }
 }
 
+/// `collapse_story`면 같은 스토리에서 목록상 먼저 나오는 아이템이 있는 아이템은 제외
 pub async fn select_subscription_items(
     pool: &MySqlPool,
     channels: Vec<i32>,
     offset: i32,
     size: i32,
+    collapse_story: bool,
 ) -> Result<Vec<RssItem>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
@@ -61,19 +65,30 @@ pub async fn select_subscription_items(
         .map(|_| "?".to_string())
         .collect::<Vec<String>>()
         .join(",");
+    let story_condition = if collapse_story {
+        story_collapse_condition(&placeholder)
+    } else {
+        String::new()
+    };
 
     let query = format!(
-        "SELECT * FROM rss_item 
-        WHERE channel_id IN ({placeholder})
-        ORDER BY rss_item.rss_pub_date DESC
+        "SELECT ri.* FROM rss_item ri
+        WHERE ri.channel_id IN ({placeholder})
+        {story_condition}
+        ORDER BY ri.rss_pub_date DESC, ri.rss_id DESC
         LIMIT ? OFFSET ?;",
     );
 
     let mut qurey_builder = query_as::<_, RssItem>(&query);
 
-    for id in channels {
+    for id in channels.iter() {
         qurey_builder = qurey_builder.bind(id);
     }
+    if collapse_story {
+        for id in channels.iter() {
+            qurey_builder = qurey_builder.bind(id);
+        }
+    }
     qurey_builder = qurey_builder.bind(size);
     qurey_builder = qurey_builder.bind(offset);
 
@@ -85,6 +100,242 @@ pub async fn select_subscription_items(
     }
 }
 
+/// 같은 스토리에서 (rss_pub_date, rss_id) 내림차순으로 `ri`보다 먼저 나오는
+/// 구독 채널 아이템이 없는 경우만 남기는 조건 (스토리는 페이지와 관계없이 한 번만 나옴)
+fn story_collapse_condition(placeholder: &str) -> String {
+    format!(
+        "AND NOT EXISTS (
+            SELECT 1 FROM rss_story_item si
+            JOIN rss_story_item other_si ON other_si.story_id = si.story_id
+            JOIN rss_item other ON other.rss_id = other_si.rss_id
+            WHERE si.rss_id = ri.rss_id
+            AND other.channel_id IN ({placeholder})
+            AND (other.rss_pub_date > ri.rss_pub_date
+                OR (other.rss_pub_date <=> ri.rss_pub_date AND other.rss_id > ri.rss_id)
+                OR (other.rss_pub_date IS NOT NULL AND ri.rss_pub_date IS NULL)))"
+    )
+}
+
+/// (rss_pub_date, rss_id) 내림차순으로 커서 다음 아이템 조회 (발행일 없는 아이템은 마지막)
+///
+/// `unread_user_id`가 있으면 해당 사용자가 읽지 않은 아이템만 조회
+/// `collapse_story`면 같은 스토리에서 목록상 먼저 나오는 아이템이 있는 아이템은 제외
+pub async fn select_subscription_items_before_cursor(
+    pool: &MySqlPool,
+    channels: Vec<i32>,
+    cursor_pub_date: Option<NaiveDateTime>,
+    cursor_rss_id: i32,
+    limit: i32,
+    unread_user_id: Option<i32>,
+    collapse_story: bool,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if channels.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channels.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    let (unread_join, unread_condition) = match unread_user_id {
+        Some(_) => (READ_STATE_JOIN, format!("AND NOT {IS_READ_CONDITION}")),
+        None => ("", String::new()),
+    };
+    let story_condition = if collapse_story {
+        story_collapse_condition(&placeholder)
+    } else {
+        String::new()
+    };
+
+    let query = format!(
+        "SELECT ri.* FROM rss_item ri
+        {unread_join}
+        WHERE ri.channel_id IN ({placeholder})
+        AND (ri.rss_pub_date < ?
+            OR (ri.rss_pub_date <=> ? AND ri.rss_id < ?)
+            OR (ri.rss_pub_date IS NULL AND ? IS NOT NULL))
+        {unread_condition}
+        {story_condition}
+        ORDER BY ri.rss_pub_date DESC, ri.rss_id DESC
+        LIMIT ?;",
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    if let Some(user_id) = unread_user_id {
+        query_builder = query_builder.bind(user_id).bind(user_id);
+    }
+    for id in channels.iter() {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder
+        .bind(cursor_pub_date)
+        .bind(cursor_pub_date)
+        .bind(cursor_rss_id)
+        .bind(cursor_pub_date);
+    if collapse_story {
+        for id in channels.iter() {
+            query_builder = query_builder.bind(id);
+        }
+    }
+    query_builder = query_builder.bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// rss_id 오름차순으로 `since_rss_id` 다음 아이템 조회 (ID 기준 동기화용)
+pub async fn select_subscription_items_after_rss_id(
+    pool: &MySqlPool,
+    channels: Vec<i32>,
+    since_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if channels.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channels.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT * FROM rss_item
+        WHERE channel_id IN ({placeholder}) AND rss_id > ?
+        ORDER BY rss_id ASC
+        LIMIT ?;",
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    for id in channels {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder.bind(since_rss_id).bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// `max_rss_id` 이하 아이템 중 `updated_since` 이후 내용이 바뀐 아이템 (바뀐 순)
+pub async fn select_subscription_items_updated_since(
+    pool: &MySqlPool,
+    channels: Vec<i32>,
+    max_rss_id: i32,
+    updated_since: NaiveDateTime,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if channels.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channels.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT ri.* FROM rss_item ri
+        JOIN rss_item_update riu ON riu.rss_id = ri.rss_id
+        WHERE ri.channel_id IN ({placeholder}) AND ri.rss_id <= ? AND riu.rss_updated_at >= ?
+        ORDER BY riu.rss_updated_at ASC
+        LIMIT ?;",
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    for id in channels {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder
+        .bind(max_rss_id)
+        .bind(updated_since)
+        .bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// rss_id 내림차순으로 `max_rss_id` 이전 아이템 조회
+pub async fn select_subscription_items_before_rss_id(
+    pool: &MySqlPool,
+    channels: Vec<i32>,
+    max_rss_id: i32,
+    limit: i32,
+) -> Result<Vec<RssItem>, sqlx::Error> {
+    if channels.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channels.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!(
+        "SELECT * FROM rss_item
+        WHERE channel_id IN ({placeholder}) AND rss_id < ?
+        ORDER BY rss_id DESC
+        LIMIT ?;",
+    );
+
+    let mut query_builder = query_as::<_, RssItem>(&query);
+    for id in channels {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder = query_builder.bind(max_rss_id).bind(limit);
+
+    let result = query_builder.fetch_all(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_subscription_items(
+    pool: &MySqlPool,
+    channels: Vec<i32>,
+) -> Result<i64, sqlx::Error> {
+    if channels.is_empty() {
+        return Ok(0);
+    }
+    let mut conn = get_db(pool).await?;
+
+    let placeholder = (0..channels.len())
+        .map(|_| "?".to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let query = format!("SELECT COUNT(*) FROM rss_item WHERE channel_id IN ({placeholder});");
+
+    let mut query_builder = sqlx::query_scalar::<_, i64>(&query);
+    for id in channels {
+        query_builder = query_builder.bind(id);
+    }
+
+    let result = query_builder.fetch_one(&mut *conn).await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
 pub async fn is_already_subscribe_channel(
     pool: &MySqlPool,
     user_id: i32,
@@ -129,3 +380,41 @@ pub async fn delete_subscribe_channel(
         Err(e) => Err(e),
     }
 }
+
+pub async fn select_subscription_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT channel_id FROM user_subscription_channel WHERE user_id = ?;",
+        user_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().filter_map(|row| row.channel_id).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_subscriber_ids_by_channel_id(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<Vec<i32>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT user_id FROM user_subscription_channel WHERE channel_id = ?;",
+        channel_id
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.into_iter().filter_map(|row| row.user_id).collect()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::NaiveDateTime;
+use sqlx::{query, query_as, MySqlPool};
+
+use crate::{
+    db_util::get_db,
+    model::sync::{SyncChange, SyncEntity},
+};
+
+pub async fn insert_sync_change(
+    pool: &MySqlPool,
+    user_id: i32,
+    entity: SyncEntity,
+    entity_id: i32,
+    changed_at: NaiveDateTime,
+    recorded_at: NaiveDateTime,
+) -> Result<i64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_sync_change
+            (user_id, sync_entity, sync_entity_id, sync_changed_at, sync_recorded_at)
+            VALUES (?, ?, ?, ?, ?);",
+        user_id,
+        entity.as_str(),
+        entity_id,
+        changed_at,
+        recorded_at,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_insert_id() as i64),
+        Err(e) => Err(e),
+    }
+}
+
+/// 폴더 소유자 기준으로 폴더와 하위 폴더의 변경을 기록
+pub async fn insert_folder_sync_changes(
+    pool: &MySqlPool,
+    folder_id: i32,
+    changed_at: NaiveDateTime,
+    recorded_at: NaiveDateTime,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "INSERT INTO user_sync_change
+            (user_id, sync_entity, sync_entity_id, sync_changed_at, sync_recorded_at)
+            SELECT user_id, ?, folder_id, ?, ? FROM rss_folder
+            WHERE folder_id = ? OR folder_parent_id = ?;",
+        SyncEntity::Folder.as_str(),
+        changed_at,
+        recorded_at,
+        folder_id,
+        folder_id,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn select_last_sync_change_id(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<i64>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT MAX(sync_change_id) AS last_change_id FROM user_sync_change WHERE user_id = ?;",
+        user_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_change_id),
+        Err(e) => Err(e),
+    }
+}
+
+/// (`since_change_id`, `until_change_id`] 범위의 변경을 대상별로 묶어 오래된 순으로 조회
+pub async fn select_sync_changes(
+    pool: &MySqlPool,
+    user_id: i32,
+    since_change_id: i64,
+    until_change_id: i64,
+    limit: i32,
+) -> Result<Vec<SyncChange>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query_as!(
+        SyncChange,
+        "SELECT MAX(sync_change_id) AS sync_change_id, sync_entity, sync_entity_id,
+            MAX(sync_changed_at) AS sync_changed_at
+        FROM user_sync_change
+        WHERE user_id = ? AND sync_change_id > ? AND sync_change_id <= ?
+        GROUP BY sync_entity, sync_entity_id
+        ORDER BY MAX(sync_change_id) ASC
+        LIMIT ?;",
+        user_id,
+        since_change_id,
+        until_change_id,
+        limit,
+    )
+    .fetch_all(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res),
+        Err(e) => Err(e),
+    }
+}
+
+/// 대상의 마지막 변경 시각
+pub async fn select_last_changed_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    entity: SyncEntity,
+    entity_id: i32,
+) -> Result<Option<NaiveDateTime>, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "SELECT MAX(sync_changed_at) AS last_changed_at FROM user_sync_change
+        WHERE user_id = ? AND sync_entity = ? AND sync_entity_id = ?;",
+        user_id,
+        entity.as_str(),
+        entity_id,
+    )
+    .fetch_one(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.last_changed_at),
+        Err(e) => Err(e),
+    }
+}
+
+/// 보관 기간이 지난 변경 기록 삭제 (한 번에 `limit`개)
+pub async fn delete_sync_changes_recorded_before(
+    pool: &MySqlPool,
+    recorded_before: NaiveDateTime,
+    limit: i32,
+) -> Result<u64, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "DELETE FROM user_sync_change WHERE sync_recorded_at < ? LIMIT ?;",
+        recorded_before,
+        limit,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(e) => Err(e),
+    }
+}
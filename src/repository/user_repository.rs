@@ -1,10 +1,6 @@
-use chrono::NaiveDateTime;
 use sqlx::{query, MySqlPool};
 
-use crate::{
-    db_util::get_db,
-    model::{auth::JwtToken, user::NewUser},
-};
+use crate::{db_util::get_db, model::user::NewUser};
 
 pub async fn insert_user(pool: &MySqlPool, user: NewUser) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
@@ -12,19 +8,14 @@ pub async fn insert_user(pool: &MySqlPool, user: NewUser) -> Result<i32, sqlx::E
     let result = query!(
         "INSERT INTO user 
             (user_email, user_display_name, user_photo_url, user_social_login_provider,
-            user_social_provider_id, user_access_token, user_refresh_token, user_access_token_expires_at,
-            user_refresh_token_expires_at, user_last_active_at,
+            user_social_provider_id, user_last_active_at,
             user_created_at, user_updated_at, user_platform)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ? )",
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ? )",
         user.user_email,
         user.user_display_name,
         user.user_photo_url,
         user.user_social_login_provider,
         user.user_social_provider_id,
-        user.user_access_token,
-        user.user_refresh_token,
-        user.user_access_token_expires_at,
-        user.user_refresh_token_expires_at,
         user.user_last_active_at,
         user.user_created_at,
         user.user_updated_at,
@@ -54,184 +45,74 @@ pub async fn select_user_id_by_email(
     }
 }
 
-pub async fn select_user_email_by_id(
+pub async fn select_user_role_by_email(
     pool: &MySqlPool,
-    user_id: i32,
-) -> Result<String, sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-
-    let result = query!("SELECT user_email FROM user WHERE user_id = ?", user_id)
-        .fetch_one(&mut *conn)
-        .await;
-
-    match result {
-        Ok(res) => Ok(res.user_email),
-        Err(e) => Err(e),
-    }
-}
-
-pub async fn select_user_email_by_social_provider_id(
-    pool: &MySqlPool,
-    user_social_provider_id: String,
-) -> Result<String, sqlx::Error> {
+    user_email: &str,
+) -> Result<Option<String>, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-        "SELECT user_email FROM user WHERE user_social_provider_id = ?",
-        user_social_provider_id
+        "SELECT user_role FROM user WHERE user_email = ?",
+        user_email
     )
     .fetch_one(&mut *conn)
     .await;
 
     match result {
-        Ok(res) => Ok(res.user_email),
+        Ok(res) => Ok(res.user_role),
         Err(e) => Err(e),
     }
 }
 
-pub async fn delete_user_token_by_email(
+pub async fn select_user_email_by_id(
     pool: &MySqlPool,
-    user_email: String,
-) -> Result<i32, sqlx::Error> {
+    user_id: i32,
+) -> Result<String, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
-    let result = query!(
-        "UPDATE user
-            SET user_access_token = NULL, user_refresh_token = NULL,
-                user_access_token_expires_at = NULL, user_refresh_token_expires_at = NULL
-        WHERE user_email = ?",
-        user_email
-    )
-    .execute(&mut *conn)
-    .await;
+    let result = query!("SELECT user_email FROM user WHERE user_id = ?", user_id)
+        .fetch_one(&mut *conn)
+        .await;
 
     match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
+        Ok(res) => Ok(res.user_email),
         Err(e) => Err(e),
     }
 }
 
-pub async fn validate_users_all_tokens(
-    pool: &MySqlPool,
-    user_email: String,
-) -> Result<(bool, bool), sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-
-    let access_result = query!(
-        "SELECT user_email FROM user
-        WHERE user_email = ? AND user_access_token IS NOT NULL AND user_access_token_expires_at > NOW()",
-        user_email
-    )
-    .fetch_one(&mut *conn)
-    .await;
-
-    let refresh_result = query!(
-        "SELECT user_email FROM user
-        WHERE user_email = ? AND user_refresh_token IS NOT NULL AND user_refresh_token_expires_at > NOW()",
-        user_email
-    )
-    .fetch_one(&mut *conn)
-    .await;
-
-    match (access_result, refresh_result) {
-        (Ok(_), Ok(_)) => Ok((true, true)),
-        (Ok(_), Err(_)) => Ok((true, false)),
-        (Err(_), Ok(_)) => Ok((false, true)),
-        (Err(_), Err(_)) => Ok((false, false)),
-    }
-}
-
-pub async fn validate_access_token_by_user_email(
+pub async fn select_user_email_by_social_provider_id(
     pool: &MySqlPool,
-    token: String,
-    email: String,
+    user_social_provider_id: String,
 ) -> Result<String, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
-        "SELECT user_email FROM user
-        WHERE user_access_token = ? AND user_email = ?",
-        token,
-        email
+        "SELECT user_email FROM user WHERE user_social_provider_id = ?",
+        user_social_provider_id
     )
     .fetch_one(&mut *conn)
     .await;
 
     match result {
         Ok(res) => Ok(res.user_email),
-        Err(_) => Err(sqlx::Error::RowNotFound),
-    }
-}
-
-pub async fn validate_refresh_token_by_user_email(
-    pool: &MySqlPool,
-    token: String,
-    email: String,
-) -> Result<JwtToken, sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-    info!("Validating refresh token: {}, for email: {}", token, email);
-
-    let result = query!(
-        "SELECT * FROM user
-        WHERE user_refresh_token = ? AND user_email = ? AND user_refresh_token_expires_at > NOW();",
-        token,
-        email
-    )
-    .fetch_one(&mut *conn)
-    .await;
-
-    match result {
-        Ok(res) => Ok(JwtToken {
-            access_token: res.user_access_token,
-            access_token_expires_at: res.user_access_token_expires_at,
-            refresh_token: res.user_refresh_token,
-            refresh_token_expires_at: res.user_refresh_token_expires_at,
-        }),
-        Err(_) => Err(sqlx::Error::RowNotFound),
-    }
-}
-
-pub async fn update_user_access_token(
-    pool: &MySqlPool,
-    user_email: String,
-    access_token: String,
-    access_token_expires_at: NaiveDateTime,
-) -> Result<i32, sqlx::Error> {
-    let mut conn = get_db(pool).await?;
-
-    let result = query!(
-        "UPDATE user
-            SET user_access_token = ?, user_access_token_expires_at = ?
-        WHERE user_email = ?",
-        access_token,
-        access_token_expires_at,
-        user_email
-    )
-    .execute(&mut *conn)
-    .await;
-
-    match result {
-        Ok(res) => Ok(res.rows_affected() as i32),
         Err(e) => Err(e),
     }
 }
 
-pub async fn update_uesr_tokens(
+pub async fn update_user_notification_setting(
     pool: &MySqlPool,
     user_email: String,
-    tokens: JwtToken,
+    notification_push: bool,
+    user_fcm_token: String,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         "UPDATE user
-            SET user_access_token = ?, user_refresh_token = ?,
-                user_access_token_expires_at = ?, user_refresh_token_expires_at = ?
+            SET user_notification_push = ?, user_fcm_token = ?
         WHERE user_email = ?",
-        tokens.access_token.unwrap(),
-        tokens.refresh_token.unwrap(),
-        tokens.access_token_expires_at.unwrap(),
-        tokens.refresh_token_expires_at.unwrap(),
+        notification_push,
+        user_fcm_token,
         user_email
     )
     .execute(&mut *conn)
@@ -243,21 +124,17 @@ pub async fn update_uesr_tokens(
     }
 }
 
-pub async fn update_user_notification_setting(
+pub async fn increment_user_articles_read(
     pool: &MySqlPool,
-    user_email: String,
-    notification_push: bool,
-    user_fcm_token: String,
+    user_id: i32,
 ) -> Result<i32, sqlx::Error> {
     let mut conn = get_db(pool).await?;
 
     let result = query!(
         "UPDATE user
-            SET user_notification_push = ?, user_fcm_token = ?
-        WHERE user_email = ?",
-        notification_push,
-        user_fcm_token,
-        user_email
+            SET user_articles_read = COALESCE(user_articles_read, 0) + 1
+        WHERE user_id = ?",
+        user_id
     )
     .execute(&mut *conn)
     .await;
@@ -355,3 +232,25 @@ pub async fn delete_user_by_email(
         Err(e) => Err(e),
     }
 }
+
+/// FCM에서 더 이상 유효하지 않다고 응답한 토큰 삭제 (그 사이 바뀐 토큰은 유지)
+pub async fn delete_user_fcm_token(
+    pool: &MySqlPool,
+    user_id: i32,
+    user_fcm_token: String,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = get_db(pool).await?;
+
+    let result = query!(
+        "UPDATE user SET user_fcm_token = NULL WHERE user_id = ? AND user_fcm_token = ?;",
+        user_id,
+        user_fcm_token,
+    )
+    .execute(&mut *conn)
+    .await;
+
+    match result {
+        Ok(res) => Ok(res.rows_affected() > 0),
+        Err(e) => Err(e),
+    }
+}
@@ -7,10 +7,83 @@
 --    user_subscription_channel.user_id → user.user_id
 --    user_subscription_channel.channel_id → rss_channel.channel_id
 --    rss_folder.user_id → user.user_id
+--    rss_folder.folder_parent_id → rss_folder.folder_id (SET NULL)
 --    channels_in_folder.folder_id → rss_folder.folder_id
 --    channels_in_folder.channel_id → rss_channel.channel_id
 --    rss_css_channel.channel_id → rss_channel.channel_id
+--    rss_item_tag.rss_id → rss_item.rss_id
+--    rss_story_item.story_id → rss_story.story_id
+--    rss_story_item.rss_id → rss_item.rss_id
+--    saved_search.user_id → user.user_id
+--    saved_search_hit.saved_search_id → saved_search.saved_search_id
+--    saved_search_hit.rss_id → rss_item.rss_id
+--    user_channel_read_mark.user_id → user.user_id
+--    user_channel_read_mark.channel_id → rss_channel.channel_id
+--    user_item_read_state.user_id → user.user_id
+--    user_item_read_state.rss_id → rss_item.rss_id
+--    user_item_read_state.channel_id → rss_channel.channel_id
+--    bookmark_collection.user_id → user.user_id
+--    bookmark.user_id → user.user_id
+--    bookmark_tag.bookmark_id → bookmark.bookmark_id
+--    reading_history.user_id → user.user_id
+--    reading_daily_stat.user_id → user.user_id
+--    reading_daily_stat.channel_id → rss_channel.channel_id
+--    reading_tag_stat.user_id → user.user_id
+--    mute_rule.user_id → user.user_id
+--    feed_rule.user_id → user.user_id
+--    feed_rule_hit.feed_rule_id → feed_rule.feed_rule_id
+--    feed_rule_hit.rss_id → rss_item.rss_id
+--    user_item_tag.user_id → user.user_id
+--    user_item_tag.rss_id → rss_item.rss_id
+--    user_feed_token.user_id → user.user_id
+--    user_app_password.user_id → user.user_id
+--    user_sync_change.user_id → user.user_id
+--    rss_item_update.rss_id → rss_item.rss_id
+--    rss_item_summary.rss_id → rss_item.rss_id
+--    user_digest.user_id → user.user_id
+--    user_push_setting.user_id → user.user_id
+--    push_pending_item.user_id → user.user_id
+--    push_pending_item.rss_id → rss_item.rss_id
+--    notification_preference.user_id → user.user_id
+--    notification_preference.channel_id → rss_channel.channel_id
+--    notification_preference.folder_id → rss_folder.folder_id
+--    user_notification.user_id → user.user_id
+--    user_notification.rss_id → rss_item.rss_id (SET NULL)
+--    user_notification.feedback_id → feedback.feedback_id (SET NULL)
+--    feedback.user_id → user.user_id (SET NULL)
+--    feedback_attachment.feedback_id → feedback.feedback_id
+--    user_session.user_id → user.user_id
 
+DROP TABLE IF EXISTS user_session;
+DROP TABLE IF EXISTS feedback_attachment;
+DROP TABLE IF EXISTS user_notification;
+DROP TABLE IF EXISTS notification_preference;
+DROP TABLE IF EXISTS push_pending_item;
+DROP TABLE IF EXISTS user_push_setting;
+DROP TABLE IF EXISTS user_digest;
+DROP TABLE IF EXISTS rss_item_summary;
+DROP TABLE IF EXISTS rss_item_update;
+DROP TABLE IF EXISTS user_sync_change;
+DROP TABLE IF EXISTS user_app_password;
+DROP TABLE IF EXISTS user_feed_token;
+DROP TABLE IF EXISTS user_item_tag;
+DROP TABLE IF EXISTS feed_rule_hit;
+DROP TABLE IF EXISTS feed_rule;
+DROP TABLE IF EXISTS mute_rule;
+DROP TABLE IF EXISTS reading_tag_stat;
+DROP TABLE IF EXISTS reading_daily_stat;
+DROP TABLE IF EXISTS reading_history;
+DROP TABLE IF EXISTS bookmark_tag;
+DROP TABLE IF EXISTS bookmark;
+DROP TABLE IF EXISTS bookmark_collection;
+DROP TABLE IF EXISTS user_item_read_state;
+DROP TABLE IF EXISTS user_channel_read_mark;
+DROP TABLE IF EXISTS saved_search_hit;
+DROP TABLE IF EXISTS saved_search;
+DROP TABLE IF EXISTS rss_story_item;
+DROP TABLE IF EXISTS rss_story;
+DROP TABLE IF EXISTS search_query_log;
+DROP TABLE IF EXISTS rss_item_tag;
 DROP TABLE IF EXISTS embedding;
 DROP TABLE IF EXISTS feedback;
 DROP TABLE IF EXISTS channels_in_folder;
@@ -32,10 +105,6 @@ CREATE TABLE `user` (
 	`user_photo_url` TEXT,
 	`user_social_login_provider` ENUM('google', 'kakao', 'apple') NOT NULL,
 	`user_social_provider_id` VARCHAR(255) NOT NULL,
-	`user_access_token` TEXT,
-	`user_refresh_token` TEXT,
-	`user_access_token_expires_at` DATETIME,
-    `user_refresh_token_expires_at` DATETIME,
 	`user_status` ENUM('active', 'inactive', 'suspended', 'deleted') DEFAULT 'active',
 	`user_role` ENUM('user', 'admin', 'editor') DEFAULT 'user',
 	`user_theme` ENUM('light', 'dark', 'blue', 'paper') DEFAULT 'paper',
@@ -78,18 +147,28 @@ CREATE TABLE `user_subscription_channel` (
     FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
 );
 
+-- 폴더 계층: 상위 폴더가 삭제되면 하위 폴더는 최상위로 이동
+-- folder_public_slug: 공개 폴더 페이지, RSS/Atom 피드 URL용 슬러그 (NULL이면 비공개)
 CREATE TABLE `rss_folder` (
 	`folder_id` INT NOT NULL AUTO_INCREMENT,
 	`folder_name` VARCHAR(50) NULL,
     `user_id` INT NULL,
+    `folder_parent_id` INT NULL,
+    `folder_position` INT NOT NULL DEFAULT 0,
+    `folder_icon` VARCHAR(50) NULL,
+    `folder_color` VARCHAR(7) NULL,
+    `folder_public_slug` VARCHAR(32) NULL UNIQUE,
+    `folder_published_at` DATETIME NULL,
     PRIMARY KEY (folder_id),
-    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`folder_parent_id`) REFERENCES `rss_folder`(`folder_id`) ON DELETE SET NULL
 );
 
 CREATE TABLE `channels_in_folder` (
     `channels_in_folder_id` INT NOT NULL AUTO_INCREMENT,
     `folder_id` INT NULL,
     `channel_id` INT NULL,
+    `channel_position` INT NOT NULL DEFAULT 0,
     PRIMARY KEY (channels_in_folder_id),
     FOREIGN KEY (`folder_id`) REFERENCES `rss_folder`(`folder_id`) ON DELETE CASCADE,
     FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
@@ -131,7 +210,10 @@ CREATE TABLE `rss_item` (
 	`rss_pub_date` DATETIME NULL,
 	`rss_rank` INT NULL,
 	`rss_image_link` VARCHAR(1500) NULL,
+	`rss_language` VARCHAR(10) NULL,
 	PRIMARY KEY (`rss_id`),
+    INDEX (`channel_id`, `rss_pub_date`, `rss_id`),
+    INDEX (`rss_pub_date`, `rss_id`),
     FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
 );
 
@@ -150,9 +232,20 @@ CREATE TABLE `embedding` (
 
 CREATE TABLE `feedback` (
     `feedback_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NULL,
     `feedback_email` VARCHAR(100) NULL,
     `feedback_content` VARCHAR(2000) NOT NULL,
-    PRIMARY KEY (`feedback_id`)
+    `feedback_category` VARCHAR(20) NOT NULL DEFAULT 'other',
+    `feedback_app_version` VARCHAR(30) NULL,
+    `feedback_platform` VARCHAR(20) NULL,
+    `feedback_status` VARCHAR(20) NOT NULL DEFAULT 'open',
+    `feedback_reply` VARCHAR(2000) NULL,
+    `feedback_replied_at` DATETIME NULL,
+    `feedback_created_at` DATETIME NULL,
+    `feedback_updated_at` DATETIME NULL,
+    PRIMARY KEY (`feedback_id`),
+    INDEX (feedback_status, feedback_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE SET NULL
 );
 
 
@@ -167,3 +260,435 @@ CREATE TABLE `rss_css_channel` (
     PRIMARY KEY (channel_id),
     FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
 );
+
+CREATE TABLE `rss_item_tag` (
+    `rss_item_tag_id` INT NOT NULL AUTO_INCREMENT,
+    `rss_id` INT NOT NULL,
+    `tag_name` VARCHAR(100) NOT NULL,
+    UNIQUE (rss_id, tag_name),
+    INDEX (tag_name),
+    PRIMARY KEY (rss_item_tag_id),
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- search_user_hash: sha256(SEARCH_LOG_SALT:user_id), 사용자 식별 불가하도록 익명화
+CREATE TABLE `search_query_log` (
+    `search_log_id` INT NOT NULL AUTO_INCREMENT,
+    `search_user_hash` CHAR(64) NOT NULL,
+    `search_query` VARCHAR(200) NOT NULL,
+    `search_created_at` DATETIME NOT NULL,
+    INDEX (search_created_at),
+    INDEX (search_query),
+    PRIMARY KEY (search_log_id)
+);
+
+-- 여러 채널에서 같은 사건을 다룬 아이템 묶음
+-- story_rss_id: 대표 헤드라인 아이템, story_centroid: 소속 아이템 임베딩 평균
+CREATE TABLE `rss_story` (
+    `story_id` INT NOT NULL AUTO_INCREMENT,
+    `story_title` VARCHAR(200) NULL,
+    `story_rss_id` INT NULL,
+    `story_centroid` BLOB NOT NULL,
+    `story_item_count` INT NOT NULL,
+    `story_created_at` DATETIME NOT NULL,
+    `story_updated_at` DATETIME NOT NULL,
+    INDEX (story_updated_at),
+    PRIMARY KEY (story_id),
+    FOREIGN KEY (`story_rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE SET NULL
+);
+
+CREATE TABLE `rss_story_item` (
+    `story_item_id` INT NOT NULL AUTO_INCREMENT,
+    `story_id` INT NOT NULL,
+    `rss_id` INT NOT NULL UNIQUE,
+    `story_similarity` FLOAT NOT NULL,
+    PRIMARY KEY (story_item_id),
+    FOREIGN KEY (`story_id`) REFERENCES `rss_story`(`story_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- saved_search_mode: Keyword(모든 검색어 포함), Semantic(임베딩 유사도)
+-- saved_search_channel_ids: 채널 필터 (콤마 구분, NULL이면 전체 채널)
+-- saved_search_token: 비공개 RSS 피드 URL용 토큰
+CREATE TABLE `saved_search` (
+    `saved_search_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `saved_search_name` VARCHAR(100) NOT NULL,
+    `saved_search_query` VARCHAR(200) NOT NULL,
+    `saved_search_mode` VARCHAR(20) NOT NULL,
+    `saved_search_channel_ids` VARCHAR(1000) NULL,
+    `saved_search_language` VARCHAR(10) NULL,
+    `saved_search_embedding` BLOB NULL,
+    `saved_search_token` CHAR(32) NOT NULL UNIQUE,
+    `saved_search_created_at` DATETIME NOT NULL,
+    PRIMARY KEY (saved_search_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+CREATE TABLE `saved_search_hit` (
+    `saved_search_hit_id` INT NOT NULL AUTO_INCREMENT,
+    `saved_search_id` INT NOT NULL,
+    `rss_id` INT NOT NULL,
+    `hit_seen` BOOLEAN NOT NULL DEFAULT FALSE,
+    `hit_created_at` DATETIME NOT NULL,
+    UNIQUE (saved_search_id, rss_id),
+    INDEX (saved_search_id, hit_seen, hit_created_at),
+    PRIMARY KEY (saved_search_hit_id),
+    FOREIGN KEY (`saved_search_id`) REFERENCES `saved_search`(`saved_search_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 읽음 상태: 채널별 기준선 + 기준선과 다른 아이템별 상태
+-- read_mark_rss_id 이하이거나 read_mark_pub_date 이전에 발행된 아이템은 읽음으로 간주
+CREATE TABLE `user_channel_read_mark` (
+    `read_mark_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `channel_id` INT NOT NULL,
+    `read_mark_rss_id` INT NOT NULL DEFAULT 0,
+    `read_mark_pub_date` DATETIME NULL,
+    `read_mark_updated_at` DATETIME NOT NULL,
+    UNIQUE (user_id, channel_id),
+    PRIMARY KEY (read_mark_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
+);
+
+-- item_read: TRUE(기준선 이후 읽음), FALSE(기준선 이전이지만 안 읽음 처리)
+CREATE TABLE `user_item_read_state` (
+    `item_read_state_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `rss_id` INT NOT NULL,
+    `channel_id` INT NOT NULL,
+    `item_read` BOOLEAN NOT NULL,
+    `item_read_updated_at` DATETIME NOT NULL,
+    UNIQUE (user_id, rss_id),
+    INDEX (user_id, channel_id),
+    PRIMARY KEY (item_read_state_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
+);
+
+CREATE TABLE `bookmark_collection` (
+    `collection_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `collection_name` VARCHAR(50) NOT NULL,
+    `collection_created_at` DATETIME NOT NULL,
+    UNIQUE (user_id, collection_name),
+    PRIMARY KEY (collection_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 아이템, 채널이 삭제되어도 남도록 제목, 링크, 이미지, 채널 이름을 복사해 둠
+CREATE TABLE `bookmark` (
+    `bookmark_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `rss_id` INT NULL,
+    `collection_id` INT NULL,
+    `bookmark_title` VARCHAR(200) NULL,
+    `bookmark_link` VARCHAR(1000) NULL,
+    `bookmark_image_link` VARCHAR(1500) NULL,
+    `bookmark_channel_title` VARCHAR(200) NULL,
+    `bookmark_note` VARCHAR(1000) NULL,
+    `bookmark_created_at` DATETIME NOT NULL,
+    `bookmark_updated_at` DATETIME NOT NULL,
+    UNIQUE (user_id, rss_id),
+    PRIMARY KEY (bookmark_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE SET NULL,
+    FOREIGN KEY (`collection_id`) REFERENCES `bookmark_collection`(`collection_id`) ON DELETE SET NULL
+);
+
+CREATE TABLE `bookmark_tag` (
+    `bookmark_tag_id` INT NOT NULL AUTO_INCREMENT,
+    `bookmark_id` INT NOT NULL,
+    `tag_name` VARCHAR(50) NOT NULL,
+    UNIQUE (bookmark_id, tag_name),
+    INDEX (tag_name),
+    PRIMARY KEY (bookmark_tag_id),
+    FOREIGN KEY (`bookmark_id`) REFERENCES `bookmark`(`bookmark_id`) ON DELETE CASCADE
+);
+
+-- 읽기 기록: 아이템을 열 때마다 한 행, 체류 시간은 이후 이벤트로 누적 (초)
+CREATE TABLE `reading_history` (
+    `reading_history_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `rss_id` INT NULL,
+    `channel_id` INT NULL,
+    `reading_opened_at` DATETIME NOT NULL,
+    `reading_seconds` INT NOT NULL DEFAULT 0,
+    INDEX (user_id, rss_id),
+    PRIMARY KEY (reading_history_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE SET NULL,
+    FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE SET NULL
+);
+
+-- 읽기 통계 집계 (통계 조회 시 reading_history를 직접 훑지 않음)
+-- stat_articles_read는 처음 연 아이템만 집계
+CREATE TABLE `reading_daily_stat` (
+    `reading_daily_stat_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `stat_date` DATE NOT NULL,
+    `channel_id` INT NOT NULL,
+    `stat_articles_read` INT NOT NULL DEFAULT 0,
+    `stat_seconds` INT NOT NULL DEFAULT 0,
+    UNIQUE (user_id, stat_date, channel_id),
+    PRIMARY KEY (reading_daily_stat_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE
+);
+
+CREATE TABLE `reading_tag_stat` (
+    `reading_tag_stat_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `stat_date` DATE NOT NULL,
+    `tag_name` VARCHAR(100) NOT NULL,
+    `stat_articles_read` INT NOT NULL DEFAULT 0,
+    UNIQUE (user_id, stat_date, tag_name),
+    PRIMARY KEY (reading_tag_stat_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 숨김 규칙: mute_rule_type은 Keyword, Regex, Author, Domain, Channel
+CREATE TABLE `mute_rule` (
+    `mute_rule_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `mute_rule_type` VARCHAR(20) NOT NULL,
+    `mute_rule_value` VARCHAR(200) NOT NULL,
+    `mute_rule_created_at` DATETIME NOT NULL,
+    UNIQUE (user_id, mute_rule_type, mute_rule_value),
+    PRIMARY KEY (mute_rule_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 피드 규칙: 조건(feed_rule_conditions)과 동작(feed_rule_actions)은 JSON 배열
+-- 수집 시 채널 구독자의 규칙을 평가
+CREATE TABLE `feed_rule` (
+    `feed_rule_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `feed_rule_name` VARCHAR(100) NOT NULL,
+    `feed_rule_conditions` TEXT NOT NULL,
+    `feed_rule_actions` TEXT NOT NULL,
+    `feed_rule_embedding` BLOB NULL,
+    `feed_rule_enabled` BOOLEAN NOT NULL DEFAULT TRUE,
+    `feed_rule_created_at` DATETIME NOT NULL,
+    `feed_rule_updated_at` DATETIME NOT NULL,
+    INDEX (user_id),
+    PRIMARY KEY (feed_rule_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 규칙이 적용된 아이템 (hit_notify: Notify 동작 포함 여부)
+CREATE TABLE `feed_rule_hit` (
+    `feed_rule_hit_id` INT NOT NULL AUTO_INCREMENT,
+    `feed_rule_id` INT NOT NULL,
+    `rss_id` INT NOT NULL,
+    `hit_notify` BOOLEAN NOT NULL DEFAULT FALSE,
+    `hit_created_at` DATETIME NOT NULL,
+    UNIQUE (feed_rule_id, rss_id),
+    PRIMARY KEY (feed_rule_hit_id),
+    FOREIGN KEY (`feed_rule_id`) REFERENCES `feed_rule`(`feed_rule_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 사용자별 아이템 태그 (피드 규칙의 Tag 동작)
+CREATE TABLE `user_item_tag` (
+    `user_item_tag_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `rss_id` INT NOT NULL,
+    `tag_name` VARCHAR(50) NOT NULL,
+    `tag_created_at` DATETIME NOT NULL,
+    UNIQUE (user_id, rss_id, tag_name),
+    INDEX (user_id, tag_name),
+    PRIMARY KEY (user_item_tag_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 개인 피드 토큰: 토큰 원문은 발급 시에만 반환하고 sha256 해시만 저장
+CREATE TABLE `user_feed_token` (
+    `feed_token_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `feed_token_name` VARCHAR(100) NOT NULL,
+    `feed_token_hash` CHAR(64) NOT NULL UNIQUE,
+    `feed_token_created_at` DATETIME NOT NULL,
+    `feed_token_last_used_at` DATETIME NULL,
+    PRIMARY KEY (feed_token_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 앱 비밀번호: Google Reader, Fever API 로그인용, 피드 토큰과 달리 읽음/별표 상태를 바꿀 수 있음
+-- 원문은 발급 시에만 반환하고 sha256 해시만 저장
+-- app_password_fever_key: Fever API 인증용 md5("이메일:앱 비밀번호")
+CREATE TABLE `user_app_password` (
+    `app_password_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `app_password_name` VARCHAR(100) NOT NULL,
+    `app_password_hash` CHAR(64) NOT NULL UNIQUE,
+    `app_password_fever_key` CHAR(32) NOT NULL UNIQUE,
+    `app_password_created_at` DATETIME NOT NULL,
+    `app_password_last_used_at` DATETIME NULL,
+    PRIMARY KEY (app_password_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 동기화 변경 기록: 변경된 대상만 기록하고 값은 동기화할 때 현재 상태로 조회 (없으면 삭제로 봄)
+-- sync_entity: subscription(channel_id), folder(folder_id), item_read(rss_id), channel_read(channel_id), bookmark(rss_id)
+-- sync_changed_at은 클라이언트가 올린 변경과 비교하는 대상별 마지막 변경 시각
+-- sync_recorded_at은 기록한 서버 시각, 보관 기간이 지난 기록 정리에 사용 (오프라인 변경은 sync_changed_at이 더 이를 수 있음)
+CREATE TABLE `user_sync_change` (
+    `sync_change_id` BIGINT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `sync_entity` VARCHAR(20) NOT NULL,
+    `sync_entity_id` INT NOT NULL,
+    `sync_changed_at` DATETIME NOT NULL,
+    `sync_recorded_at` DATETIME NOT NULL,
+    PRIMARY KEY (sync_change_id),
+    INDEX (user_id, sync_change_id),
+    INDEX (user_id, sync_entity, sync_entity_id),
+    INDEX (sync_recorded_at),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 이미 보낸 아이템의 내용이 바뀐 시각 (동기화에서 다시 보냄, 순위 변경은 기록하지 않음)
+CREATE TABLE `rss_item_update` (
+    `rss_id` INT NOT NULL,
+    `rss_updated_at` DATETIME NOT NULL,
+    PRIMARY KEY (rss_id),
+    INDEX (rss_updated_at),
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 아이템 요약 캐시: 요약 API와 이메일 다이제스트가 함께 사용
+CREATE TABLE `rss_item_summary` (
+    `rss_id` INT NOT NULL,
+    `summary_text` VARCHAR(1000) NOT NULL,
+    `summary_created_at` DATETIME NOT NULL,
+    PRIMARY KEY (rss_id),
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 이메일 다이제스트 설정 (사용자당 하나)
+-- digest_frequency: daily, weekly / digest_weekday: 0(월)~6(일), 주간 발송에만 사용
+-- digest_hour는 digest_timezone 기준 발송 시각, digest_next_send_at은 KST 기준 다음 발송 시각
+-- digest_unsubscribe_token: 로그인 없이 수신 거부하는 링크용
+CREATE TABLE `user_digest` (
+    `user_id` INT NOT NULL,
+    `digest_enabled` BOOLEAN NOT NULL DEFAULT FALSE,
+    `digest_frequency` VARCHAR(10) NOT NULL DEFAULT 'daily',
+    `digest_hour` INT NOT NULL DEFAULT 8,
+    `digest_weekday` INT NOT NULL DEFAULT 0,
+    `digest_timezone` VARCHAR(64) NOT NULL DEFAULT 'Asia/Seoul',
+    `digest_include_summary` BOOLEAN NOT NULL DEFAULT FALSE,
+    `digest_unsubscribe_token` CHAR(32) NOT NULL UNIQUE,
+    `digest_next_send_at` DATETIME NULL,
+    `digest_last_sent_at` DATETIME NULL,
+    `digest_updated_at` DATETIME NOT NULL,
+    PRIMARY KEY (user_id),
+    INDEX (digest_enabled, digest_next_send_at),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 푸시 알림 설정 (사용자당 하나, 켜기/끄기와 FCM 토큰은 user 테이블)
+-- 방해 금지 시간: push_timezone 기준 push_quiet_start_hour부터 push_quiet_end_hour 전까지 (둘 다 있을 때만 적용)
+-- push_failure_count, push_retry_at: FCM 발송 실패 시 재시도를 점점 늦춤 (성공하면 초기화)
+CREATE TABLE `user_push_setting` (
+    `user_id` INT NOT NULL,
+    `push_quiet_start_hour` INT NULL,
+    `push_quiet_end_hour` INT NULL,
+    `push_timezone` VARCHAR(64) NOT NULL DEFAULT 'Asia/Seoul',
+    `push_last_sent_at` DATETIME NULL,
+    `push_failure_count` INT NOT NULL DEFAULT 0,
+    `push_retry_at` DATETIME NULL,
+    `push_updated_at` DATETIME NULL,
+    PRIMARY KEY (user_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
+
+-- 보낼 푸시 대기열: 새 아이템을 모아 사용자별로 한 번에 발송하고 삭제
+CREATE TABLE `push_pending_item` (
+    `push_pending_id` BIGINT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `rss_id` INT NOT NULL,
+    `push_pending_created_at` DATETIME NOT NULL,
+    PRIMARY KEY (push_pending_id),
+    UNIQUE KEY (user_id, rss_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE CASCADE
+);
+
+-- 채널, 폴더별 새 글 알림 수준 (channel_id, folder_id 중 하나만 값이 있음)
+-- notification_level: all(모든 새 글), keyword(notification_keywords가 제목, 설명에 포함된 글), none(알리지 않음)
+-- notification_keywords: 키워드 JSON 배열
+-- 채널 설정 > 채널이 속한 폴더 설정 > 기본값(all) 순으로 적용
+CREATE TABLE `notification_preference` (
+    `notification_preference_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `channel_id` INT NULL,
+    `folder_id` INT NULL,
+    `notification_level` VARCHAR(10) NOT NULL,
+    `notification_keywords` TEXT NULL,
+    `notification_preference_updated_at` DATETIME NOT NULL,
+    PRIMARY KEY (notification_preference_id),
+    UNIQUE (user_id, channel_id),
+    UNIQUE (user_id, folder_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`channel_id`) REFERENCES `rss_channel`(`channel_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`folder_id`) REFERENCES `rss_folder`(`folder_id`) ON DELETE CASCADE
+);
+
+-- 앱 내 알림함: 푸시 설정과 관계없이 기록
+-- notification_type: new_item(구독 채널의 새 글)
+CREATE TABLE `user_notification` (
+    `notification_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `notification_type` VARCHAR(20) NOT NULL,
+    `notification_title` VARCHAR(200) NOT NULL,
+    `notification_body` VARCHAR(500) NOT NULL,
+    `rss_id` INT NULL,
+    `channel_id` INT NULL,
+    `feedback_id` INT NULL,
+    `notification_is_read` BOOLEAN NOT NULL DEFAULT FALSE,
+    `notification_created_at` DATETIME NOT NULL,
+    PRIMARY KEY (notification_id),
+    INDEX (user_id, notification_id),
+    INDEX (notification_created_at),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE,
+    FOREIGN KEY (`rss_id`) REFERENCES `rss_item`(`rss_id`) ON DELETE SET NULL,
+    FOREIGN KEY (`feedback_id`) REFERENCES `feedback`(`feedback_id`) ON DELETE SET NULL
+);
+
+-- 피드백 첨부 파일 (FEEDBACK_ATTACHMENT_DIR에 저장한 파일명)
+CREATE TABLE `feedback_attachment` (
+    `attachment_id` INT NOT NULL AUTO_INCREMENT,
+    `feedback_id` INT NOT NULL,
+    `attachment_file_name` VARCHAR(100) NOT NULL,
+    `attachment_original_name` VARCHAR(200) NULL,
+    `attachment_content_type` VARCHAR(50) NOT NULL,
+    `attachment_size` INT NOT NULL,
+    `attachment_created_at` DATETIME NULL,
+    PRIMARY KEY (attachment_id),
+    INDEX (feedback_id),
+    FOREIGN KEY (`feedback_id`) REFERENCES `feedback`(`feedback_id`) ON DELETE CASCADE
+);
+
+-- 기기별 로그인 세션. 토큰은 sha256 해시로 저장
+-- 기존 DB는 src/migrations/20261019_user_session.sql로 토큰 컬럼을 옮긴 뒤 삭제
+CREATE TABLE `user_session` (
+    `session_id` INT NOT NULL AUTO_INCREMENT,
+    `user_id` INT NOT NULL,
+    `session_access_token_hash` CHAR(64) NOT NULL,
+    `session_refresh_token_hash` CHAR(64) NOT NULL,
+    `session_access_token_expires_at` DATETIME NOT NULL,
+    `session_refresh_token_expires_at` DATETIME NOT NULL,
+    `session_device_name` VARCHAR(100) NULL,
+    `session_platform` VARCHAR(20) NULL,
+    `session_created_at` DATETIME NOT NULL,
+    `session_last_seen_at` DATETIME NOT NULL,
+    PRIMARY KEY (session_id),
+    UNIQUE (session_access_token_hash),
+    UNIQUE (session_refresh_token_hash),
+    INDEX (user_id),
+    FOREIGN KEY (`user_id`) REFERENCES `user`(`user_id`) ON DELETE CASCADE
+);
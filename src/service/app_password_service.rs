@@ -0,0 +1,219 @@
+use md5::Md5;
+use sha2::{Digest, Sha256};
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::{
+    dto::app_password::{
+        request::AppPasswordRequestDto,
+        response::{AppPasswordCreateResponseDto, AppPasswordResponseDto},
+    },
+    model::{
+        app_password::{AppPassword, NewAppPassword},
+        error::OmniNewsError,
+    },
+    repository::app_password_repository,
+    user_error, user_warn,
+    utils::time_util::kst_now,
+};
+
+use super::user_service;
+
+// 앱 비밀번호: Google Reader, Fever API를 지원하는 RSS 리더의 로그인용 비밀번호
+// 읽기 전용인 피드 토큰과 달리 읽음, 별표 등 상태를 바꿀 수 있으므로 따로 발급하고 폐기함
+
+const MAX_APP_PASSWORDS: i64 = 10;
+const MAX_APP_PASSWORD_NAME_LENGTH: usize = 100;
+
+/// 앱 비밀번호 발급 (원문은 응답으로만 전달하고 해시만 저장)
+pub async fn create_app_password(
+    pool: &MySqlPool,
+    user_email: String,
+    data: AppPasswordRequestDto,
+) -> Result<AppPasswordCreateResponseDto, OmniNewsError> {
+    let name = data
+        .app_password_name
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    if name.is_empty() || name.chars().count() > MAX_APP_PASSWORD_NAME_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "app_password_name must be 1-{MAX_APP_PASSWORD_NAME_LENGTH} characters"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email.clone()).await?;
+
+    let app_password_count = app_password_repository::count_app_passwords_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to count app passwords: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if app_password_count >= MAX_APP_PASSWORDS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_APP_PASSWORDS} app passwords are allowed"
+        )));
+    }
+
+    let app_password = Uuid::new_v4().simple().to_string();
+    let new_app_password = NewAppPassword {
+        user_id: Some(user_id),
+        app_password_name: Some(name),
+        app_password_hash: Some(hash_app_password(&app_password)),
+        app_password_fever_key: Some(fever_key(&user_email, &app_password)),
+        app_password_created_at: Some(kst_now()),
+    };
+    let app_password_id = app_password_repository::insert_app_password(pool, new_app_password)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to insert app password: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(AppPasswordCreateResponseDto::new(
+        app_password_id,
+        app_password,
+    ))
+}
+
+pub async fn get_app_passwords(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<AppPasswordResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match app_password_repository::select_app_passwords_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(AppPasswordResponseDto::from_model_list(res)),
+        Err(e) => {
+            user_error!("[Service] Failed to select app passwords: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 폐기하면 이 비밀번호로 로그인한 RSS 리더는 즉시 접근할 수 없음
+pub async fn delete_app_password(
+    pool: &MySqlPool,
+    user_email: String,
+    app_password_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match app_password_repository::delete_app_password(pool, user_id, app_password_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound(
+            "App password not found".to_string(),
+        )),
+        Err(e) => {
+            user_error!("[Service] Failed to delete app password: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 앱 비밀번호 소유자 ID 조회 (조회할 때마다 마지막 사용 시각 갱신)
+pub async fn find_user_id_by_app_password(
+    pool: &MySqlPool,
+    app_password: &str,
+) -> Result<i32, OmniNewsError> {
+    let app_password = match app_password_repository::select_app_password_by_hash(
+        pool,
+        &hash_app_password(app_password),
+    )
+    .await
+    {
+        Ok(res) => res,
+        Err(sqlx::Error::RowNotFound) => {
+            return Err(OmniNewsError::NotFound(
+                "App password not found".to_string(),
+            ));
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to select app password: {}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    };
+
+    Ok(touch_app_password(pool, app_password).await)
+}
+
+/// Fever API의 `api_key`(md5("이메일:앱 비밀번호"))로 앱 비밀번호 소유자 ID 조회
+pub async fn find_user_id_by_fever_key(
+    pool: &MySqlPool,
+    api_key: &str,
+) -> Result<i32, OmniNewsError> {
+    let app_password = match app_password_repository::select_app_password_by_fever_key(
+        pool,
+        &api_key.trim().to_lowercase(),
+    )
+    .await
+    {
+        Ok(res) => res,
+        Err(sqlx::Error::RowNotFound) => {
+            return Err(OmniNewsError::NotFound(
+                "App password not found".to_string(),
+            ));
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to select app password: {}", e);
+            return Err(OmniNewsError::Database(e));
+        }
+    };
+
+    Ok(touch_app_password(pool, app_password).await)
+}
+
+async fn touch_app_password(pool: &MySqlPool, app_password: AppPassword) -> i32 {
+    if let Err(e) = app_password_repository::update_app_password_last_used_at(
+        pool,
+        app_password.app_password_id.unwrap_or_default(),
+        kst_now(),
+    )
+    .await
+    {
+        user_warn!(
+            "[Service] Failed to update app password last used at: {}",
+            e
+        );
+    }
+    app_password.user_id.unwrap_or_default()
+}
+
+fn hash_app_password(app_password: &str) -> String {
+    format!("{:x}", Sha256::digest(app_password.as_bytes()))
+}
+
+/// Fever 클라이언트는 md5("이메일:비밀번호")를 보내므로 원문이 있는 발급 시점에 계산
+fn fever_key(user_email: &str, app_password: &str) -> String {
+    format!(
+        "{:x}",
+        Md5::digest(format!("{user_email}:{app_password}").as_bytes())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const APP_PASSWORD: &str = "3f9a0c1b2d4e5f6a7b8c9d0e1f2a3b4c";
+
+    #[test]
+    fn fever_key_is_md5_of_email_and_app_password() {
+        assert_eq!(
+            fever_key("reader@example.com", APP_PASSWORD),
+            "9dbf8302b23844d318ab5fea491a26a9"
+        );
+        assert_ne!(
+            fever_key("other@example.com", APP_PASSWORD),
+            fever_key("reader@example.com", APP_PASSWORD)
+        );
+    }
+
+    #[test]
+    fn app_password_is_stored_as_sha256_hex() {
+        assert_eq!(
+            hash_app_password(APP_PASSWORD),
+            "63b256a64d6644ff6daca85bb49a783add70ccc51d0d1756ebb369f3a82957b5"
+        );
+    }
+}
@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::bookmark::{
+        request::{
+            BookmarkCollectionRequestDto, BookmarkListRequestDto, SaveBookmarkRequestDto,
+            UpdateBookmarkRequestDto,
+        },
+        response::{BookmarkCollectionResponseDto, BookmarkPageResponseDto, BookmarkResponseDto},
+    },
+    model::{
+        bookmark::{Bookmark, NewBookmark},
+        error::OmniNewsError,
+        sync::SyncEntity,
+    },
+    repository::{bookmark_repository, rss_channel_repository, rss_item_repository},
+    user_error,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        time_util::kst_now,
+    },
+};
+
+use super::{sync_service, user_service};
+
+const MAX_TAGS: usize = 10;
+const MAX_TAG_LENGTH: usize = 50;
+const MAX_NOTE_LENGTH: usize = 1000;
+const MAX_COLLECTION_NAME_LENGTH: usize = 50;
+
+/// 아이템 북마크. 이미 북마크한 아이템이면 전달된 값만 갱신하고 기존 북마크 ID 반환
+pub async fn save_bookmark(
+    pool: &MySqlPool,
+    user_email: String,
+    data: SaveBookmarkRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    save_bookmark_by_user_id(pool, user_id, data).await
+}
+
+pub async fn save_bookmark_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: SaveBookmarkRequestDto,
+) -> Result<i32, OmniNewsError> {
+    save_bookmark_at(pool, user_id, data, kst_now()).await
+}
+
+/// 아이템 북마크 해제. 이미 북마크가 없으면 false
+pub async fn unsave_bookmark_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<bool, OmniNewsError> {
+    unsave_bookmark_at(pool, user_id, rss_id, kst_now()).await
+}
+
+/// 동기화로 올라온 북마크 여부를 클라이언트에서 바꾼 시각으로 반영
+pub async fn sync_bookmark(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    is_saved: bool,
+    changed_at: NaiveDateTime,
+) -> Result<(), OmniNewsError> {
+    if !is_saved {
+        return unsave_bookmark_at(pool, user_id, rss_id, changed_at)
+            .await
+            .map(|_| ());
+    }
+
+    let data = SaveBookmarkRequestDto {
+        rss_id: Some(rss_id),
+        collection_id: None,
+        bookmark_note: None,
+        tags: None,
+    };
+    save_bookmark_at(pool, user_id, data, changed_at)
+        .await
+        .map(|_| ())
+}
+
+async fn save_bookmark_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: SaveBookmarkRequestDto,
+    now: NaiveDateTime,
+) -> Result<i32, OmniNewsError> {
+    let Some(rss_id) = data.rss_id else {
+        return Err(OmniNewsError::InvalidValue(
+            "rss_id is required".to_string(),
+        ));
+    };
+    validate_collection(pool, user_id, data.collection_id).await?;
+    let note = normalize_note(data.bookmark_note);
+
+    let existing = bookmark_repository::select_bookmark_by_rss_id(pool, user_id, rss_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select bookmark: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let bookmark_id = match existing {
+        Some(bookmark) => {
+            let bookmark_id = bookmark.bookmark_id.unwrap_or_default();
+            bookmark_repository::update_bookmark(
+                pool,
+                bookmark_id,
+                data.collection_id.or(bookmark.collection_id),
+                note.or(bookmark.bookmark_note),
+                now,
+            )
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to update bookmark: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            bookmark_id
+        }
+        None => {
+            let item = rss_item_repository::select_rss_item_by_id(pool, rss_id)
+                .await
+                .map_err(|e| match e {
+                    sqlx::Error::RowNotFound => OmniNewsError::NotFound("Item not found".into()),
+                    e => {
+                        user_error!("[Service] Failed to select item: {}", e);
+                        OmniNewsError::Database(e)
+                    }
+                })?;
+            let channel_title = rss_channel_repository::select_rss_channel_by_id(
+                pool,
+                item.channel_id.unwrap_or_default(),
+            )
+            .await
+            .ok()
+            .and_then(|channel| channel.channel_title);
+
+            let bookmark = NewBookmark {
+                user_id: Some(user_id),
+                rss_id: Some(rss_id),
+                collection_id: data.collection_id,
+                bookmark_title: item.rss_title,
+                bookmark_link: item.rss_link,
+                bookmark_image_link: item.rss_image_link,
+                bookmark_channel_title: channel_title,
+                bookmark_note: note,
+                bookmark_created_at: Some(now),
+                bookmark_updated_at: Some(now),
+            };
+            bookmark_repository::insert_bookmark(pool, bookmark)
+                .await
+                .map_err(|e| {
+                    user_error!("[Service] Failed to insert bookmark: {}", e);
+                    OmniNewsError::Database(e)
+                })?
+        }
+    };
+
+    if let Some(tags) = data.tags {
+        replace_tags(pool, bookmark_id, tags).await?;
+    }
+    sync_service::record_change(pool, user_id, SyncEntity::Bookmark, rss_id, now).await;
+    Ok(bookmark_id)
+}
+
+async fn unsave_bookmark_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    changed_at: NaiveDateTime,
+) -> Result<bool, OmniNewsError> {
+    match bookmark_repository::delete_bookmark_by_rss_id(pool, user_id, rss_id).await {
+        Ok(deleted) => {
+            if deleted {
+                sync_service::record_change(
+                    pool,
+                    user_id,
+                    SyncEntity::Bookmark,
+                    rss_id,
+                    changed_at,
+                )
+                .await;
+            }
+            Ok(deleted)
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to delete bookmark: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 북마크 메모, 태그, 컬렉션을 요청 값으로 교체 (null이면 비움)
+pub async fn update_bookmark(
+    pool: &MySqlPool,
+    user_email: String,
+    bookmark_id: i32,
+    data: UpdateBookmarkRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let bookmark = find_bookmark(pool, user_id, bookmark_id).await?;
+    validate_collection(pool, user_id, data.collection_id).await?;
+    let now = kst_now();
+
+    bookmark_repository::update_bookmark(
+        pool,
+        bookmark_id,
+        data.collection_id,
+        normalize_note(data.bookmark_note),
+        now,
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to update bookmark: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    replace_tags(pool, bookmark_id, data.tags.unwrap_or_default()).await?;
+    if let Some(rss_id) = bookmark.rss_id {
+        sync_service::record_change(pool, user_id, SyncEntity::Bookmark, rss_id, now).await;
+    }
+    Ok(())
+}
+
+pub async fn delete_bookmark(
+    pool: &MySqlPool,
+    user_email: String,
+    bookmark_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let bookmark = find_bookmark(pool, user_id, bookmark_id).await?;
+
+    match bookmark_repository::delete_bookmark(pool, user_id, bookmark_id).await {
+        Ok(true) => {
+            if let Some(rss_id) = bookmark.rss_id {
+                sync_service::record_change(pool, user_id, SyncEntity::Bookmark, rss_id, kst_now())
+                    .await;
+            }
+            Ok(())
+        }
+        Ok(false) => Err(OmniNewsError::NotFound("Bookmark not found".to_string())),
+        Err(e) => {
+            user_error!("[Service] Failed to delete bookmark: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_bookmark_by_rss_id(
+    pool: &MySqlPool,
+    user_email: String,
+    rss_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match unsave_bookmark_by_user_id(pool, user_id, rss_id).await? {
+        true => Ok(()),
+        false => Err(OmniNewsError::NotFound("Bookmark not found".to_string())),
+    }
+}
+
+/// 최근 저장순 북마크 목록 (검색어, 컬렉션, 태그로 거를 수 있음)
+pub async fn get_bookmarks(
+    pool: &MySqlPool,
+    user_email: String,
+    data: BookmarkListRequestDto,
+) -> Result<BookmarkPageResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let limit = page_size(data.size);
+    let ItemCursor::Id { id: cursor_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_id)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match bookmark order".to_string(),
+        ));
+    };
+    let search_value = data.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let tag_name = data
+        .tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty());
+
+    let bookmarks = bookmark_repository::select_bookmarks_before_id(
+        pool,
+        user_id,
+        cursor_id,
+        limit + 1,
+        search_value,
+        data.collection_id,
+        tag_name,
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to select bookmarks: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let (bookmarks, next_cursor) = split_page(bookmarks, limit, |bookmark| ItemCursor::Id {
+        id: bookmark.bookmark_id.unwrap_or_default(),
+    });
+
+    let mut tags_by_bookmark: HashMap<i32, Vec<String>> = HashMap::new();
+    for tag in bookmark_repository::select_bookmark_tags_by_bookmark_ids(
+        pool,
+        bookmarks.iter().filter_map(|b| b.bookmark_id).collect(),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to select bookmark tags: {}", e);
+        OmniNewsError::Database(e)
+    })? {
+        if let (Some(bookmark_id), Some(tag_name)) = (tag.bookmark_id, tag.tag_name) {
+            tags_by_bookmark
+                .entry(bookmark_id)
+                .or_default()
+                .push(tag_name);
+        }
+    }
+
+    let bookmarks = bookmarks
+        .into_iter()
+        .map(|bookmark| {
+            let tags = bookmark
+                .bookmark_id
+                .and_then(|id| tags_by_bookmark.remove(&id))
+                .unwrap_or_default();
+            BookmarkResponseDto::new(bookmark, tags)
+        })
+        .collect();
+
+    Ok(BookmarkPageResponseDto::new(bookmarks, next_cursor))
+}
+
+pub async fn create_collection(
+    pool: &MySqlPool,
+    user_email: String,
+    data: BookmarkCollectionRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let name = normalize_collection_name(data.collection_name)?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match bookmark_repository::insert_bookmark_collection(pool, user_id, &name, kst_now()).await {
+        Ok(res) => Ok(res),
+        Err(e) if is_unique_violation(&e) => Err(OmniNewsError::AlreadyExists(name)),
+        Err(e) => {
+            user_error!("[Service] Failed to insert bookmark collection: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_collections(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<BookmarkCollectionResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match bookmark_repository::select_bookmark_collections_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(BookmarkCollectionResponseDto::from_model_list(res)),
+        Err(e) => {
+            user_error!("[Service] Failed to select bookmark collections: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn rename_collection(
+    pool: &MySqlPool,
+    user_email: String,
+    collection_id: i32,
+    data: BookmarkCollectionRequestDto,
+) -> Result<(), OmniNewsError> {
+    let name = normalize_collection_name(data.collection_name)?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    validate_collection(pool, user_id, Some(collection_id)).await?;
+
+    match bookmark_repository::update_bookmark_collection_name(pool, user_id, collection_id, &name)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) if is_unique_violation(&e) => Err(OmniNewsError::AlreadyExists(name)),
+        Err(e) => {
+            user_error!("[Service] Failed to update bookmark collection: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 컬렉션 삭제. 컬렉션에 있던 북마크는 삭제되지 않고 컬렉션 없음으로 바뀜
+pub async fn delete_collection(
+    pool: &MySqlPool,
+    user_email: String,
+    collection_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match bookmark_repository::delete_bookmark_collection(pool, user_id, collection_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Collection not found".to_string())),
+        Err(e) => {
+            user_error!("[Service] Failed to delete bookmark collection: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_bookmark(
+    pool: &MySqlPool,
+    user_id: i32,
+    bookmark_id: i32,
+) -> Result<Bookmark, OmniNewsError> {
+    match bookmark_repository::select_bookmark_by_id(pool, user_id, bookmark_id).await {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound("Bookmark not found".to_string()))
+        }
+        Err(e) => {
+            user_error!("[Service] Failed to select bookmark: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn validate_collection(
+    pool: &MySqlPool,
+    user_id: i32,
+    collection_id: Option<i32>,
+) -> Result<(), OmniNewsError> {
+    let Some(collection_id) = collection_id else {
+        return Ok(());
+    };
+
+    match bookmark_repository::is_user_bookmark_collection(pool, user_id, collection_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Collection not found".to_string())),
+        Err(e) => {
+            user_error!("[Service] Failed to select bookmark collection: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn replace_tags(
+    pool: &MySqlPool,
+    bookmark_id: i32,
+    tags: Vec<String>,
+) -> Result<(), OmniNewsError> {
+    let mut tag_names: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag: String = tag.trim().chars().take(MAX_TAG_LENGTH).collect();
+        if !tag.is_empty() && !tag_names.contains(&tag) {
+            tag_names.push(tag);
+        }
+    }
+    tag_names.truncate(MAX_TAGS);
+
+    bookmark_repository::delete_bookmark_tags(pool, bookmark_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to delete bookmark tags: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    for tag_name in tag_names {
+        bookmark_repository::insert_bookmark_tag(pool, bookmark_id, &tag_name)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to insert bookmark tag: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    }
+    Ok(())
+}
+
+fn normalize_note(note: Option<String>) -> Option<String> {
+    note.map(|note| {
+        note.trim()
+            .chars()
+            .take(MAX_NOTE_LENGTH)
+            .collect::<String>()
+    })
+    .filter(|note| !note.is_empty())
+}
+
+fn normalize_collection_name(name: Option<String>) -> Result<String, OmniNewsError> {
+    let name = name.unwrap_or_default().trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_COLLECTION_NAME_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Collection name must be 1-{MAX_COLLECTION_NAME_LENGTH} characters"
+        )));
+    }
+    Ok(name)
+}
+
+fn is_unique_violation(e: &sqlx::Error) -> bool {
+    e.as_database_error()
+        .is_some_and(|e| e.is_unique_violation())
+}
@@ -1,7 +1,8 @@
 use chrono::{FixedOffset, Utc};
 use feed_rs::parser::parse;
+use rand::{rng, seq::SliceRandom};
 use reqwest::Url;
-use rss::{Channel, ChannelBuilder, Image, Item, ItemBuilder};
+use rss::{Category, CategoryBuilder, Channel, ChannelBuilder, Image, Item, ItemBuilder};
 use serde_json::Value;
 use sqlx::MySqlPool;
 use thirtyfour::WebDriver;
@@ -20,7 +21,11 @@ use crate::{
     repository::rss_channel_repository,
     rss_error, rss_info, rss_warn,
     service::embedding_service,
-    utils::{annoy_util::load_channel_annoy, embedding_util::EmbeddingService},
+    utils::{
+        annoy_util::load_channel_annoy,
+        embedding_util::EmbeddingService,
+        language_util::{detect_language, normalize_language_tag},
+    },
 };
 
 use super::item_service;
@@ -173,12 +178,23 @@ fn parse_with_feed_rs(body: String) -> Result<Channel, OmniNewsError> {
                         .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
                         .to_rfc2822();
 
+                    let en_categories = entry
+                        .categories
+                        .iter()
+                        .map(|category| {
+                            CategoryBuilder::default()
+                                .name(category.term.clone())
+                                .build()
+                        })
+                        .collect::<Vec<Category>>();
+
                     ItemBuilder::default()
                         .title(en_title)
                         .description(en_description)
                         .link(en_link)
                         .author(en_author)
                         .pub_date(en_pub_date)
+                        .categories(en_categories)
                         .build()
                 })
                 .collect::<Vec<Item>>();
@@ -286,7 +302,10 @@ pub fn make_rss_channel(
         channel.link().to_string(),
         channel.description().to_string(),
         channel.image().map(|e| e.url().to_string()),
-        channel.language().unwrap_or("None").to_string(),
+        channel
+            .language()
+            .and_then(normalize_language_tag)
+            .or_else(|| detect_channel_language(channel)),
         channel
             .generator()
             .unwrap_or(if is_generated_channel {
@@ -300,6 +319,24 @@ pub fn make_rss_channel(
     )
 }
 
+// <language>가 없으면 채널 제목, 설명, 아이템 제목으로 감지
+fn detect_channel_language(channel: &Channel) -> Option<String> {
+    let item_titles = channel
+        .items()
+        .iter()
+        .filter_map(|item| item.title())
+        .take(10)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    detect_language(&format!(
+        "{}\n{}\n{}",
+        channel.title(),
+        channel.description(),
+        item_titles
+    ))
+}
+
 pub async fn store_channel_and_embedding(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
@@ -548,16 +585,17 @@ fn remove_html_tags(text: &str) -> String {
     re.replace_all(text, "").to_string()
 }
 
-// TODO 랭크 50순위 채널에서 20개 랜덤 반환
+/// 랭크 50순위 채널에서 20개 랜덤 반환 (개인화 추천이 불가능할 때 사용)
 pub async fn get_recommend_channel(
     pool: &MySqlPool,
 ) -> Result<Vec<RssChannelResponseDto>, OmniNewsError> {
     match rss_channel_repository::select_rss_channels_order_by_channel_rank(pool).await {
         Ok(res) => {
-            //            let mut rng = rng();
-            //            res.shuffle(&mut rng);
-            //            Ok(res.into_iter().take(20).collect())
-            Ok(RssChannelResponseDto::from_model_list(res))
+            let mut res = res.into_iter().take(50).collect::<Vec<RssChannel>>();
+            res.shuffle(&mut rng());
+            Ok(RssChannelResponseDto::from_model_list(
+                res.into_iter().take(20).collect(),
+            ))
         }
         Err(e) => {
             rss_error!(
@@ -0,0 +1,500 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration as StdDuration,
+};
+
+use chrono::{Datelike, Duration, FixedOffset, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use rocket_dyn_templates::handlebars::{no_escape, Handlebars};
+use serde_json::{json, Value};
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::{
+    dto::digest::{request::DigestSettingsRequestDto, response::DigestSettingsResponseDto},
+    model::{
+        digest::{DigestFrequency, UserDigest},
+        error::OmniNewsError,
+        rss::RssItem,
+    },
+    repository::{digest_repository, folder_repository, rss_item_repository, subscribe_repository},
+    user_error, user_info, user_warn,
+    utils::{
+        email_util::{self, EmailMessage},
+        feed_util::public_feed_url,
+        time_util::kst_now,
+    },
+};
+
+use super::{mute_service, user_service};
+
+const DEFAULT_HOUR: i32 = 8;
+const DEFAULT_WEEKDAY: i32 = 0;
+const DEFAULT_TIMEZONE: &str = "Asia/Seoul";
+/// 폴더별로 보낼 아이템 수
+const ITEMS_PER_FOLDER: usize = 5;
+/// 숨김 규칙으로 빠질 아이템을 감안해 더 많이 조회
+const ITEM_FETCH_LIMIT: i32 = 30;
+const OTHER_FOLDER_NAME: &str = "기타 구독";
+const SCHEDULER_INTERVAL: StdDuration = StdDuration::from_secs(5 * 60);
+const SCHEDULER_BATCH_SIZE: i32 = 100;
+
+const DIGEST_HTML_TEMPLATE: &str = include_str!("../../templates/email/digest.html.hbs");
+const DIGEST_TEXT_TEMPLATE: &str = include_str!("../../templates/email/digest.txt.hbs");
+
+pub async fn get_digest_settings(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<DigestSettingsResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let digest = find_user_digest(pool, user_id).await?;
+    Ok(DigestSettingsResponseDto::from_model(
+        digest.unwrap_or_else(|| default_digest(user_id)),
+    ))
+}
+
+/// 설정을 저장하고 다음 발송 시각을 다시 계산
+pub async fn update_digest_settings(
+    pool: &MySqlPool,
+    user_email: String,
+    data: DigestSettingsRequestDto,
+) -> Result<DigestSettingsResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let mut digest = find_user_digest(pool, user_id)
+        .await?
+        .unwrap_or_else(|| default_digest(user_id));
+
+    if let Some(hour) = data.digest_hour {
+        if !(0..=23).contains(&hour) {
+            return Err(OmniNewsError::InvalidValue(
+                "digest_hour must be 0-23".to_string(),
+            ));
+        }
+        digest.digest_hour = Some(hour);
+    }
+    if let Some(weekday) = data.digest_weekday {
+        if !(0..=6).contains(&weekday) {
+            return Err(OmniNewsError::InvalidValue(
+                "digest_weekday must be 0-6".to_string(),
+            ));
+        }
+        digest.digest_weekday = Some(weekday);
+    }
+    if let Some(timezone) = data.digest_timezone {
+        let timezone = timezone.trim().to_string();
+        if timezone.parse::<Tz>().is_err() {
+            return Err(OmniNewsError::InvalidValue(format!(
+                "Unknown timezone: {timezone}"
+            )));
+        }
+        digest.digest_timezone = Some(timezone);
+    }
+    if let Some(frequency) = data.digest_frequency {
+        digest.digest_frequency = Some(frequency.as_str().to_string());
+    }
+    if let Some(enabled) = data.digest_enabled {
+        digest.digest_enabled = Some(enabled);
+    }
+    if let Some(include_summary) = data.digest_include_summary {
+        digest.digest_include_summary = Some(include_summary);
+    }
+
+    let now = kst_now();
+    digest.digest_next_send_at = if digest.digest_enabled.unwrap_or(false) {
+        Some(next_send_at(&digest, now))
+    } else {
+        None
+    };
+    digest.digest_updated_at = Some(now);
+
+    digest_repository::upsert_user_digest(pool, digest)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to upsert user digest: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    get_user_digest(pool, user_id)
+        .await
+        .map(DigestSettingsResponseDto::from_model)
+}
+
+/// 현재 설정으로 다이제스트를 바로 보내 확인 (발송 일정은 바꾸지 않음)
+pub async fn send_test_digest(pool: &MySqlPool, user_email: String) -> Result<bool, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email.clone()).await?;
+    let digest = get_user_digest(pool, user_id).await?;
+
+    let since = digest_period_start(&digest, kst_now());
+    send_digest(pool, &digest, user_email, since).await
+}
+
+/// 수신 거부 확인 페이지를 열기 전에 토큰만 확인 (수신 거부는 POST로 처리)
+pub async fn check_unsubscribe_token(
+    pool: &MySqlPool,
+    unsubscribe_token: &str,
+) -> Result<(), OmniNewsError> {
+    find_digest_by_unsubscribe_token(pool, unsubscribe_token)
+        .await
+        .map(|_| ())
+}
+
+/// 확인 페이지의 수신 거부 버튼, `List-Unsubscribe-Post` 헤더로 호출 (로그인 없이 토큰으로 처리)
+pub async fn unsubscribe(pool: &MySqlPool, unsubscribe_token: &str) -> Result<(), OmniNewsError> {
+    let digest = find_digest_by_unsubscribe_token(pool, unsubscribe_token).await?;
+
+    digest_repository::update_digest_disabled(pool, digest.user_id.unwrap_or_default(), kst_now())
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to disable digest: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    user_info!(
+        "[Service] Digest unsubscribed: user_id {}",
+        digest.user_id.unwrap_or_default()
+    );
+    Ok(())
+}
+
+/// 발송 시각이 된 다이제스트를 주기적으로 보냄
+pub async fn run_scheduler(pool: MySqlPool) {
+    let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(e) = send_due_digests(&pool).await {
+            user_error!("[Service] Digest scheduler failed: {}", e);
+        }
+    }
+}
+
+async fn send_due_digests(pool: &MySqlPool) -> Result<(), OmniNewsError> {
+    let now = kst_now();
+    let digests = digest_repository::select_due_digests(pool, now, SCHEDULER_BATCH_SIZE)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select due digests: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    for digest in digests {
+        let user_id = digest.user_id.unwrap_or_default();
+        let since = digest_period_start(&digest, now);
+        let next = next_send_at(&digest, now);
+
+        // 보내기 전에 다음 발송 시각으로 넘겨 선점. 여러 인스턴스가 같은 다이제스트를 조회해도 한 곳만 발송하고,
+        // 실패해도 같은 다이제스트를 반복해서 보내지 않음
+        let Some(due_at) = digest.digest_next_send_at else {
+            continue;
+        };
+        match digest_repository::claim_due_digest(pool, user_id, due_at, next).await {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => {
+                user_error!("[Service] Failed to claim digest: {}", e);
+                continue;
+            }
+        }
+
+        let sent = match user_service::find_user_email_by_id(pool, user_id).await {
+            Ok(email) => match send_digest(pool, &digest, email, since).await {
+                Ok(sent) => sent,
+                Err(e) => {
+                    user_warn!("[Service] Failed to send digest to {}: {}", user_id, e);
+                    false
+                }
+            },
+            Err(_) => false,
+        };
+
+        if sent {
+            if let Err(e) = digest_repository::update_digest_last_sent_at(pool, user_id, now).await
+            {
+                user_error!("[Service] Failed to update digest last sent at: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 보낼 아이템이 없으면 보내지 않고 false 반환
+async fn send_digest(
+    pool: &MySqlPool,
+    digest: &UserDigest,
+    user_email: String,
+    since: NaiveDateTime,
+) -> Result<bool, OmniNewsError> {
+    let user_id = digest.user_id.unwrap_or_default();
+    let folders = build_digest_folders(pool, digest, since).await?;
+    if folders.is_empty() {
+        return Ok(false);
+    }
+
+    let unsubscribe_url = public_feed_url(&format!(
+        "/public/digest/unsubscribe/{}",
+        digest.digest_unsubscribe_token.clone().unwrap_or_default()
+    ));
+    let title = match digest.frequency() {
+        DigestFrequency::Daily => "Omninews 데일리 다이제스트",
+        DigestFrequency::Weekly => "Omninews 위클리 다이제스트",
+    };
+    let context = json!({
+        "title": title,
+        "app_name": "Omninews",
+        "period": match digest.frequency() {
+            DigestFrequency::Daily => "지난 하루",
+            DigestFrequency::Weekly => "지난 한 주",
+        },
+        "folders": folders,
+        "unsubscribe_url": unsubscribe_url,
+    });
+    let (html_body, text_body) = render_digest(&context)?;
+
+    email_util::send_email(EmailMessage {
+        to: user_email,
+        subject: title.to_string(),
+        text_body,
+        html_body,
+        unsubscribe_url: Some(unsubscribe_url),
+    })
+    .await?;
+
+    user_info!("[Service] Digest sent: user_id {}", user_id);
+    Ok(true)
+}
+
+/// 폴더별 읽지 않은 상위 아이템. 폴더에 없는 구독 채널은 "기타 구독"으로 묶음
+async fn build_digest_folders(
+    pool: &MySqlPool,
+    digest: &UserDigest,
+    since: NaiveDateTime,
+) -> Result<Vec<Value>, OmniNewsError> {
+    let user_id = digest.user_id.unwrap_or_default();
+    let channels = subscribe_repository::select_subscription_channels(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select subscription channels: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let channel_titles = channels
+        .iter()
+        .filter_map(|c| Some((c.channel_id?, c.channel_title.clone().unwrap_or_default())))
+        .collect::<HashMap<i32, String>>();
+
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    // 여러 폴더에 있는 채널은 처음 폴더에만 포함
+    let mut assigned = HashSet::new();
+    let mut groups = vec![];
+    for folder in folders {
+        let folder_channels = folder_repository::select_channels_in_folder(
+            pool,
+            folder.folder_id.unwrap_or_default(),
+        )
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select channels in folder: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        let channel_ids = folder_channels
+            .iter()
+            .filter_map(|c| c.channel_id)
+            .filter(|id| channel_titles.contains_key(id) && assigned.insert(*id))
+            .collect::<Vec<i32>>();
+        groups.push((folder.folder_name.unwrap_or_default(), channel_ids));
+    }
+    let other_channel_ids = channel_titles
+        .keys()
+        .filter(|id| !assigned.contains(*id))
+        .copied()
+        .collect::<Vec<i32>>();
+    groups.push((OTHER_FOLDER_NAME.to_string(), other_channel_ids));
+
+    let mute_filter = mute_service::find_mute_filter(pool, user_id).await?;
+
+    let mut grouped_items = vec![];
+    for (folder_name, channel_ids) in groups {
+        let mut items = digest_repository::select_unread_items_since(
+            pool,
+            user_id,
+            channel_ids,
+            since,
+            ITEM_FETCH_LIMIT,
+        )
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select digest items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        mute_filter.retain_visible(&mut items);
+        items.truncate(ITEMS_PER_FOLDER);
+        if !items.is_empty() {
+            grouped_items.push((folder_name, items));
+        }
+    }
+
+    let summaries = if digest.digest_include_summary.unwrap_or(false) {
+        let rss_ids = grouped_items
+            .iter()
+            .flat_map(|(_, items)| items.iter().filter_map(|item| item.rss_id))
+            .collect::<Vec<i32>>();
+        rss_item_repository::select_rss_item_summaries_by_rss_ids(pool, rss_ids)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select item summaries: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect::<HashMap<i32, String>>()
+    } else {
+        HashMap::new()
+    };
+
+    Ok(grouped_items
+        .into_iter()
+        .map(|(folder_name, items)| {
+            json!({
+                "folder_name": folder_name,
+                "items": items
+                    .into_iter()
+                    .map(|item| digest_item(item, &channel_titles, &summaries))
+                    .collect::<Vec<Value>>(),
+            })
+        })
+        .collect())
+}
+
+fn digest_item(
+    item: RssItem,
+    channel_titles: &HashMap<i32, String>,
+    summaries: &HashMap<i32, String>,
+) -> Value {
+    json!({
+        "title": item.rss_title.unwrap_or_default(),
+        "link": item.rss_link.unwrap_or_default(),
+        "channel": item
+            .channel_id
+            .and_then(|id| channel_titles.get(&id).cloned())
+            .unwrap_or_default(),
+        "pub_date": item.rss_pub_date.map(|d| d.format("%Y-%m-%d %H:%M").to_string()),
+        "summary": item.rss_id.and_then(|id| summaries.get(&id).cloned()),
+    })
+}
+
+/// HTML, 텍스트 본문을 렌더링 (텍스트는 이스케이프하지 않음)
+fn render_digest(context: &Value) -> Result<(String, String), OmniNewsError> {
+    let html = Handlebars::new()
+        .render_template(DIGEST_HTML_TEMPLATE, context)
+        .map_err(|e| OmniNewsError::Email(e.to_string()))?;
+
+    let mut text_registry = Handlebars::new();
+    text_registry.register_escape_fn(no_escape);
+    let text = text_registry
+        .render_template(DIGEST_TEXT_TEMPLATE, context)
+        .map_err(|e| OmniNewsError::Email(e.to_string()))?;
+
+    Ok((html, text))
+}
+
+/// 마지막 발송 이후, 최대 한 주기 전부터의 아이템
+fn digest_period_start(digest: &UserDigest, now: NaiveDateTime) -> NaiveDateTime {
+    let period_start = match digest.frequency() {
+        DigestFrequency::Daily => now - Duration::days(1),
+        DigestFrequency::Weekly => now - Duration::days(7),
+    };
+    digest
+        .digest_last_sent_at
+        .map_or(period_start, |last| last.max(period_start))
+}
+
+/// 사용자 시간대 기준으로 `after` 이후 첫 발송 시각을 KST로 반환
+fn next_send_at(digest: &UserDigest, after: NaiveDateTime) -> NaiveDateTime {
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let tz = digest
+        .digest_timezone
+        .as_deref()
+        .and_then(|tz| tz.parse::<Tz>().ok())
+        .unwrap_or(chrono_tz::Asia::Seoul);
+    let hour = digest.digest_hour.unwrap_or(DEFAULT_HOUR) as u32;
+    let weekday = digest.digest_weekday.unwrap_or(DEFAULT_WEEKDAY) as u32;
+
+    let after_local = after.and_local_timezone(kst).unwrap().with_timezone(&tz);
+    let mut date = after_local.date_naive();
+    for _ in 0..=8 {
+        let is_send_day = match digest.frequency() {
+            DigestFrequency::Daily => true,
+            DigestFrequency::Weekly => date.weekday().num_days_from_monday() == weekday,
+        };
+        if is_send_day {
+            // 서머타임으로 없는 시각이면 그날은 건너뜀
+            let candidate = date
+                .and_hms_opt(hour, 0, 0)
+                .and_then(|local| tz.from_local_datetime(&local).earliest());
+            if let Some(candidate) = candidate {
+                if candidate > after_local {
+                    return candidate.with_timezone(&kst).naive_local();
+                }
+            }
+        }
+        date = date.succ_opt().unwrap_or(date);
+    }
+    after + Duration::days(1)
+}
+
+async fn find_digest_by_unsubscribe_token(
+    pool: &MySqlPool,
+    unsubscribe_token: &str,
+) -> Result<UserDigest, OmniNewsError> {
+    digest_repository::select_user_digest_by_unsubscribe_token(pool, unsubscribe_token)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Digest not found".to_string()),
+            e => {
+                user_error!(
+                    "[Service] Failed to select digest by unsubscribe token: {}",
+                    e
+                );
+                OmniNewsError::Database(e)
+            }
+        })
+}
+
+async fn find_user_digest(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<UserDigest>, OmniNewsError> {
+    digest_repository::select_user_digest(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select user digest: {}", e);
+            OmniNewsError::Database(e)
+        })
+}
+
+async fn get_user_digest(pool: &MySqlPool, user_id: i32) -> Result<UserDigest, OmniNewsError> {
+    find_user_digest(pool, user_id)
+        .await?
+        .ok_or_else(|| OmniNewsError::NotFound("Digest settings not found".to_string()))
+}
+
+/// 처음 저장할 때 쓰는 기본 설정 (수신 거부 토큰 발급). 다이제스트는 직접 켜야 발송
+fn default_digest(user_id: i32) -> UserDigest {
+    UserDigest {
+        user_id: Some(user_id),
+        digest_enabled: Some(false),
+        digest_frequency: Some(DigestFrequency::Daily.as_str().to_string()),
+        digest_hour: Some(DEFAULT_HOUR),
+        digest_weekday: Some(DEFAULT_WEEKDAY),
+        digest_timezone: Some(DEFAULT_TIMEZONE.to_string()),
+        digest_include_summary: Some(false),
+        digest_unsubscribe_token: Some(Uuid::new_v4().simple().to_string()),
+        digest_next_send_at: None,
+        digest_last_sent_at: None,
+        digest_updated_at: None,
+    }
+}
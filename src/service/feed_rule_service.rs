@@ -0,0 +1,720 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        bookmark::request::SaveBookmarkRequestDto,
+        feed_rule::{
+            request::{FeedRuleRequestDto, FeedRuleTaggedItemRequestDto},
+            response::{FeedRuleDryRunResponseDto, FeedRuleResponseDto, FeedRuleTagResponseDto},
+        },
+        rss::response::{RssItemPageResponseDto, RssItemResponseDto},
+    },
+    model::{
+        error::OmniNewsError,
+        feed_rule::{
+            FeedRule, FeedRuleAction, FeedRuleActionType, FeedRuleCondition, FeedRuleConditionType,
+            NewFeedRule,
+        },
+        rss::RssItem,
+    },
+    repository::{
+        bookmark_repository, embedding_repository, feed_rule_repository, rss_item_repository,
+        subscribe_repository,
+    },
+    rss_error, rss_info, rss_warn,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        embedding_util::{
+            cosine_similarity, decode_embedding, embedding_sentence, encode_embedding,
+            EmbeddingService,
+        },
+        time_util::kst_now,
+    },
+};
+
+use super::{bookmark_service, notification_service, read_service, user_service};
+
+const MAX_RULES: i64 = 50;
+const MAX_CONDITIONS: usize = 10;
+const MAX_ACTIONS: usize = 5;
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_CONDITION_VALUE_LENGTH: usize = 200;
+const MAX_TAG_LENGTH: usize = 50;
+// 이 이상 유사하면 의미 조건을 만족한 것으로 봄
+const SEMANTIC_SIMILARITY: f32 = 0.5;
+const DRY_RUN_ITEM_LIMIT: i32 = 200;
+const DRY_RUN_SAMPLE_COUNT: usize = 20;
+const HIT_ITEM_LIMIT: i32 = 100;
+
+/// 평가할 수 있게 준비한 규칙 (폴더 채널, 의미 조건 벡터를 미리 조회)
+struct CompiledFeedRule {
+    conditions: Vec<FeedRuleCondition>,
+    semantic_vector: Option<Vec<f32>>,
+    folder_channel_ids: HashMap<i32, HashSet<i32>>,
+}
+
+/// 규칙 평가에 필요한 아이템 정보
+struct RuleItem<'a> {
+    item: &'a RssItem,
+    tags: &'a [String],
+    vector: Option<&'a [f32]>,
+}
+
+impl CompiledFeedRule {
+    async fn new(
+        pool: &MySqlPool,
+        user_id: i32,
+        conditions: Vec<FeedRuleCondition>,
+        semantic_vector: Option<Vec<f32>>,
+    ) -> Self {
+        let mut folder_channel_ids = HashMap::new();
+        for condition in &conditions {
+            if condition.condition_type != FeedRuleConditionType::Folder {
+                continue;
+            }
+            let Ok(folder_id) = condition.value.parse::<i32>() else {
+                continue;
+            };
+            // 삭제된 폴더는 빈 채널 목록으로 두어 규칙이 적용되지 않게 함
+            let channel_ids = read_service::find_user_folder_channel_ids(pool, user_id, folder_id)
+                .await
+                .unwrap_or_default();
+            folder_channel_ids.insert(folder_id, channel_ids.into_iter().collect());
+        }
+
+        Self {
+            conditions,
+            semantic_vector,
+            folder_channel_ids,
+        }
+    }
+
+    fn matches(&self, target: &RuleItem) -> bool {
+        !self.conditions.is_empty()
+            && self
+                .conditions
+                .iter()
+                .all(|condition| self.matches_condition(condition, target))
+    }
+
+    fn matches_condition(&self, condition: &FeedRuleCondition, target: &RuleItem) -> bool {
+        let item = target.item;
+        let value = condition.value.to_lowercase();
+        let contains =
+            |text: Option<&str>| text.unwrap_or_default().to_lowercase().contains(&value);
+
+        match condition.condition_type {
+            FeedRuleConditionType::Keyword => {
+                contains(item.rss_title.as_deref()) || contains(item.rss_description.as_deref())
+            }
+            FeedRuleConditionType::Title => contains(item.rss_title.as_deref()),
+            FeedRuleConditionType::Description => contains(item.rss_description.as_deref()),
+            FeedRuleConditionType::Author => item
+                .rss_author
+                .as_deref()
+                .is_some_and(|author| author.trim().to_lowercase() == value),
+            FeedRuleConditionType::Channel => {
+                item.channel_id.map(|id| id.to_string()).as_deref() == Some(value.as_str())
+            }
+            FeedRuleConditionType::Folder => {
+                let (Ok(folder_id), Some(channel_id)) = (value.parse::<i32>(), item.channel_id)
+                else {
+                    return false;
+                };
+                self.folder_channel_ids
+                    .get(&folder_id)
+                    .is_some_and(|channel_ids| channel_ids.contains(&channel_id))
+            }
+            FeedRuleConditionType::Tag => target.tags.iter().any(|tag| tag.to_lowercase() == value),
+            FeedRuleConditionType::Semantic => match (&self.semantic_vector, target.vector) {
+                (Some(rule_vector), Some(item_vector)) => {
+                    cosine_similarity(rule_vector, item_vector) >= SEMANTIC_SIMILARITY
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+pub async fn create_feed_rule(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    data: FeedRuleRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let rule_count = feed_rule_repository::count_feed_rules_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to count feed rules: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if rule_count >= MAX_RULES {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_RULES} feed rules are allowed"
+        )));
+    }
+
+    let feed_rule = validate_rule(pool, embedding_service, user_id, data).await?;
+    match feed_rule_repository::insert_feed_rule(pool, feed_rule).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            rss_error!("[Service] Failed to insert feed rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_feed_rules(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<FeedRuleResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match feed_rule_repository::select_feed_rules_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(FeedRuleResponseDto::from_model_list(res)),
+        Err(e) => {
+            rss_error!("[Service] Failed to select feed rules: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 규칙 전체를 요청 값으로 교체 (이미 적용된 아이템에는 다시 적용하지 않음)
+pub async fn update_feed_rule(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    feed_rule_id: i32,
+    data: FeedRuleRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let feed_rule = validate_rule(pool, embedding_service, user_id, data).await?;
+
+    match feed_rule_repository::update_feed_rule(pool, feed_rule_id, feed_rule).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Feed rule not found".to_string())),
+        Err(e) => {
+            rss_error!("[Service] Failed to update feed rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_feed_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    feed_rule_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match feed_rule_repository::delete_feed_rule(pool, user_id, feed_rule_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Feed rule not found".to_string())),
+        Err(e) => {
+            rss_error!("[Service] Failed to delete feed rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 저장하지 않고 최근 구독 아이템 중 규칙 조건을 만족하는 아이템 확인 (동작은 실행하지 않음)
+pub async fn dry_run_feed_rule(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    data: FeedRuleRequestDto,
+) -> Result<FeedRuleDryRunResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let feed_rule = validate_rule(pool, embedding_service, user_id, data).await?;
+
+    let channel_ids = subscribe_repository::select_subscription_channel_ids(pool, user_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select subscription channel ids: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if channel_ids.is_empty() {
+        return Ok(FeedRuleDryRunResponseDto::new(0, 0, vec![]));
+    }
+    let items = subscribe_repository::select_subscription_items(
+        pool,
+        channel_ids,
+        0,
+        DRY_RUN_ITEM_LIMIT,
+        false,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    let rss_ids: Vec<i32> = items.iter().filter_map(|item| item.rss_id).collect();
+    let tags = find_item_tags(pool, rss_ids.clone()).await?;
+    let vectors = find_item_vectors(pool, rss_ids).await?;
+    let compiled = CompiledFeedRule::new(
+        pool,
+        user_id,
+        serde_json::from_str(
+            feed_rule
+                .feed_rule_conditions
+                .as_deref()
+                .unwrap_or_default(),
+        )
+        .unwrap_or_default(),
+        feed_rule
+            .feed_rule_embedding
+            .as_deref()
+            .map(decode_embedding),
+    )
+    .await;
+
+    let checked_count = items.len() as i32;
+    let matched: Vec<RssItem> = items
+        .into_iter()
+        .filter(|item| compiled.matches(&rule_item(item, &tags, &vectors)))
+        .collect();
+    let matched_count = matched.len() as i32;
+
+    Ok(FeedRuleDryRunResponseDto::new(
+        checked_count,
+        matched_count,
+        RssItemResponseDto::from_model_list(
+            matched.into_iter().take(DRY_RUN_SAMPLE_COUNT).collect(),
+        ),
+    ))
+}
+
+/// 규칙이 적용된 최근 아이템 (최대 100개)
+pub async fn get_feed_rule_hits(
+    pool: &MySqlPool,
+    user_email: String,
+    feed_rule_id: i32,
+) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    feed_rule_repository::select_feed_rule_by_id(pool, user_id, feed_rule_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Feed rule not found".into()),
+            e => {
+                rss_error!("[Service] Failed to select feed rule: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+
+    match feed_rule_repository::select_feed_rule_hit_items(pool, feed_rule_id, HIT_ITEM_LIMIT).await
+    {
+        Ok(res) => Ok(RssItemResponseDto::from_model_list(res)),
+        Err(e) => {
+            rss_error!("[Service] Failed to select feed rule hit items: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_tagged_items(
+    pool: &MySqlPool,
+    user_email: String,
+    data: FeedRuleTaggedItemRequestDto,
+) -> Result<RssItemPageResponseDto, OmniNewsError> {
+    let Some(tag_name) = data
+        .tag
+        .as_deref()
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+    else {
+        return Err(OmniNewsError::InvalidValue("tag is required".to_string()));
+    };
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let limit = page_size(data.size);
+    let ItemCursor::Id { id: cursor_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_id)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match tagged item order".to_string(),
+        ));
+    };
+
+    let items = feed_rule_repository::select_user_tagged_items_before_id(
+        pool,
+        user_id,
+        tag_name,
+        cursor_id,
+        limit + 1,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select tagged items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let (items, next_cursor) = split_page(items, limit, |item| ItemCursor::Id {
+        id: item.rss_id.unwrap_or_default(),
+    });
+
+    Ok(RssItemPageResponseDto::new(
+        RssItemResponseDto::from_model_list(items),
+        next_cursor,
+    ))
+}
+
+pub async fn get_item_tags(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<FeedRuleTagResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match feed_rule_repository::select_user_item_tag_counts(pool, user_id).await {
+        Ok(res) => Ok(FeedRuleTagResponseDto::from_model_list(res)),
+        Err(e) => {
+            rss_error!("[Service] Failed to select item tags: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 새로 수집된 아이템에 채널 구독자들의 규칙을 평가하고 동작 실행
+pub async fn apply_feed_rules(pool: &MySqlPool, rss_ids: Vec<i32>) -> Result<(), OmniNewsError> {
+    if rss_ids.is_empty() {
+        return Ok(());
+    }
+
+    let items = rss_item_repository::select_rss_items_by_ids(pool, rss_ids.clone())
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select new items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let mut items_by_channel: HashMap<i32, Vec<RssItem>> = HashMap::new();
+    for item in items {
+        items_by_channel
+            .entry(item.channel_id.unwrap_or_default())
+            .or_default()
+            .push(item);
+    }
+
+    let mut rules_by_channel: HashMap<i32, Vec<FeedRule>> = HashMap::new();
+    for channel_id in items_by_channel.keys() {
+        let rules =
+            feed_rule_repository::select_enabled_feed_rules_by_channel_id(pool, *channel_id)
+                .await
+                .map_err(|e| {
+                    rss_error!("[Service] Failed to select feed rules: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+        if !rules.is_empty() {
+            rules_by_channel.insert(*channel_id, rules);
+        }
+    }
+    if rules_by_channel.is_empty() {
+        return Ok(());
+    }
+
+    let tags = find_item_tags(pool, rss_ids.clone()).await?;
+    let vectors = find_item_vectors(pool, rss_ids).await?;
+
+    let now = kst_now();
+    let mut hit_count = 0;
+    for (channel_id, rules) in rules_by_channel {
+        let items = &items_by_channel[&channel_id];
+        for rule in rules {
+            let (Some(feed_rule_id), Some(user_id)) = (rule.feed_rule_id, rule.user_id) else {
+                continue;
+            };
+            let compiled = CompiledFeedRule::new(
+                pool,
+                user_id,
+                rule.conditions(),
+                rule.feed_rule_embedding.as_deref().map(decode_embedding),
+            )
+            .await;
+            let actions = rule.actions();
+            let notify = actions
+                .iter()
+                .any(|action| action.action_type == FeedRuleActionType::Notify);
+            let rule_name = rule.feed_rule_name.as_deref().unwrap_or_default();
+
+            for item in items {
+                if !compiled.matches(&rule_item(item, &tags, &vectors)) {
+                    continue;
+                }
+                let rss_id = item.rss_id.unwrap_or_default();
+
+                // 같은 아이템에 같은 규칙의 동작을 두 번 실행하지 않음
+                match feed_rule_repository::insert_feed_rule_hit(
+                    pool,
+                    feed_rule_id,
+                    rss_id,
+                    notify,
+                    now,
+                )
+                .await
+                {
+                    Ok(true) => hit_count += 1,
+                    Ok(false) => continue,
+                    Err(e) => {
+                        rss_error!("[Service] Failed to insert feed rule hit: {}", e);
+                        continue;
+                    }
+                }
+
+                for action in &actions {
+                    if let Err(e) = run_action(pool, user_id, rule_name, item, action, now).await {
+                        rss_warn!(
+                            "[Service] Failed to run feed rule {} action {:?}: {:?}",
+                            feed_rule_id,
+                            action.action_type,
+                            e
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    rss_info!("[Service] Applied {} feed rule hits", hit_count);
+    Ok(())
+}
+
+async fn run_action(
+    pool: &MySqlPool,
+    user_id: i32,
+    rule_name: &str,
+    item: &RssItem,
+    action: &FeedRuleAction,
+    now: NaiveDateTime,
+) -> Result<(), OmniNewsError> {
+    let rss_id = item.rss_id.unwrap_or_default();
+    match action.action_type {
+        FeedRuleActionType::Tag => {
+            let tag_name = action.value.as_deref().unwrap_or_default();
+            feed_rule_repository::insert_user_item_tag(pool, user_id, rss_id, tag_name, now)
+                .await
+                .map_err(OmniNewsError::Database)?;
+            Ok(())
+        }
+        FeedRuleActionType::Bookmark => {
+            let data = SaveBookmarkRequestDto {
+                rss_id: Some(rss_id),
+                collection_id: action.value.as_deref().and_then(|id| id.parse().ok()),
+                bookmark_note: None,
+                tags: None,
+            };
+            bookmark_service::save_bookmark_by_user_id(pool, user_id, data).await?;
+            Ok(())
+        }
+        FeedRuleActionType::MarkRead => {
+            read_service::mark_item_read_by_user_id(pool, user_id, rss_id).await
+        }
+        FeedRuleActionType::Notify => {
+            notification_service::notify_feed_rule_match(pool, user_id, rule_name, item).await?;
+            Ok(())
+        }
+    }
+}
+
+/// 조건, 동작을 검증하고 저장할 형태로 정리
+async fn validate_rule(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_id: i32,
+    data: FeedRuleRequestDto,
+) -> Result<NewFeedRule, OmniNewsError> {
+    let name = data.feed_rule_name.unwrap_or_default().trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "feed_rule_name must be 1-{MAX_NAME_LENGTH} characters"
+        )));
+    }
+
+    let conditions = data.conditions.unwrap_or_default();
+    if conditions.is_empty() || conditions.len() > MAX_CONDITIONS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "conditions must have 1-{MAX_CONDITIONS} items"
+        )));
+    }
+    let actions = data.actions.unwrap_or_default();
+    if actions.is_empty() || actions.len() > MAX_ACTIONS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "actions must have 1-{MAX_ACTIONS} items"
+        )));
+    }
+
+    let mut semantic_phrase = None;
+    let mut normalized_conditions = Vec::with_capacity(conditions.len());
+    for condition in conditions {
+        let value = condition.value.trim().to_string();
+        if value.is_empty() || value.chars().count() > MAX_CONDITION_VALUE_LENGTH {
+            return Err(OmniNewsError::InvalidValue(format!(
+                "Condition value must be 1-{MAX_CONDITION_VALUE_LENGTH} characters"
+            )));
+        }
+
+        let value = match condition.condition_type {
+            FeedRuleConditionType::Channel | FeedRuleConditionType::Folder => {
+                let id = value
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|id| *id > 0)
+                    .ok_or_else(|| OmniNewsError::InvalidValue(format!("Invalid id: {value}")))?;
+                if condition.condition_type == FeedRuleConditionType::Folder {
+                    read_service::find_user_folder_channel_ids(pool, user_id, id)
+                        .await
+                        .map_err(|e| match e {
+                            OmniNewsError::NotFound(msg) => OmniNewsError::InvalidValue(msg),
+                            e => e,
+                        })?;
+                }
+                id.to_string()
+            }
+            FeedRuleConditionType::Semantic => {
+                if semantic_phrase.is_some() {
+                    return Err(OmniNewsError::InvalidValue(
+                        "Only one Semantic condition is allowed".to_string(),
+                    ));
+                }
+                semantic_phrase = Some(value.clone());
+                value
+            }
+            _ => value,
+        };
+        normalized_conditions.push(FeedRuleCondition {
+            condition_type: condition.condition_type,
+            value,
+        });
+    }
+
+    let mut normalized_actions: Vec<FeedRuleAction> = Vec::with_capacity(actions.len());
+    for action in actions {
+        if normalized_actions.iter().any(|a| {
+            a.action_type == action.action_type && action.action_type != FeedRuleActionType::Tag
+        }) {
+            continue;
+        }
+        let value = action
+            .value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+
+        let value = match action.action_type {
+            FeedRuleActionType::Tag => {
+                let Some(tag_name) = value.filter(|tag| tag.chars().count() <= MAX_TAG_LENGTH)
+                else {
+                    return Err(OmniNewsError::InvalidValue(format!(
+                        "Tag action value must be 1-{MAX_TAG_LENGTH} characters"
+                    )));
+                };
+                Some(tag_name)
+            }
+            FeedRuleActionType::Bookmark => match value {
+                Some(value) => {
+                    let collection_id = value.parse::<i32>().map_err(|_| {
+                        OmniNewsError::InvalidValue(format!("Invalid collection id: {value}"))
+                    })?;
+                    let is_owned = bookmark_repository::is_user_bookmark_collection(
+                        pool,
+                        user_id,
+                        collection_id,
+                    )
+                    .await
+                    .map_err(|e| {
+                        rss_error!("[Service] Failed to select bookmark collection: {}", e);
+                        OmniNewsError::Database(e)
+                    })?;
+                    if !is_owned {
+                        return Err(OmniNewsError::InvalidValue(
+                            "Collection not found".to_string(),
+                        ));
+                    }
+                    Some(collection_id.to_string())
+                }
+                None => None,
+            },
+            FeedRuleActionType::MarkRead | FeedRuleActionType::Notify => None,
+        };
+        normalized_actions.push(FeedRuleAction {
+            action_type: action.action_type,
+            value,
+        });
+    }
+
+    let embedding = match semantic_phrase {
+        Some(phrase) => Some(encode_embedding(
+            &embedding_sentence(embedding_service, phrase).await?,
+        )),
+        None => None,
+    };
+
+    let now = kst_now();
+    Ok(NewFeedRule {
+        user_id: Some(user_id),
+        feed_rule_name: Some(name),
+        feed_rule_conditions: serde_json::to_string(&normalized_conditions).ok(),
+        feed_rule_actions: serde_json::to_string(&normalized_actions).ok(),
+        feed_rule_embedding: embedding,
+        feed_rule_enabled: Some(data.feed_rule_enabled.unwrap_or(true)),
+        feed_rule_created_at: Some(now),
+        feed_rule_updated_at: Some(now),
+    })
+}
+
+async fn find_item_tags(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<HashMap<i32, Vec<String>>, OmniNewsError> {
+    let mut tags: HashMap<i32, Vec<String>> = HashMap::new();
+    for tag in rss_item_repository::select_rss_item_tags_by_rss_ids(pool, rss_ids)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select item tags: {}", e);
+            OmniNewsError::Database(e)
+        })?
+    {
+        if let (Some(rss_id), Some(tag_name)) = (tag.rss_id, tag.tag_name) {
+            tags.entry(rss_id).or_default().push(tag_name);
+        }
+    }
+    Ok(tags)
+}
+
+async fn find_item_vectors(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<HashMap<i32, Vec<f32>>, OmniNewsError> {
+    match embedding_repository::select_embeddings_by_rss_ids(pool, rss_ids).await {
+        Ok(res) => Ok(res
+            .into_iter()
+            .filter_map(|embedding| {
+                Some((
+                    embedding.rss_id?,
+                    decode_embedding(&embedding.embedding_value?),
+                ))
+            })
+            .collect()),
+        Err(e) => {
+            rss_error!("[Service] Failed to select item embeddings: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+fn rule_item<'a>(
+    item: &'a RssItem,
+    tags: &'a HashMap<i32, Vec<String>>,
+    vectors: &'a HashMap<i32, Vec<f32>>,
+) -> RuleItem<'a> {
+    let rss_id = item.rss_id.unwrap_or_default();
+    RuleItem {
+        item,
+        tags: tags.get(&rss_id).map(Vec::as_slice).unwrap_or_default(),
+        vector: vectors.get(&rss_id).map(Vec::as_slice),
+    }
+}
@@ -0,0 +1,307 @@
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::{
+    dto::feed_token::{
+        request::{FeedTokenRequestDto, FeedTokenScopeRequestDto},
+        response::{FeedTokenCreateResponseDto, FeedTokenResponseDto},
+    },
+    model::{
+        error::OmniNewsError,
+        feed_token::{FeedToken, NewFeedToken},
+        rss::RssItem,
+    },
+    repository::{
+        feed_token_repository, folder_repository, rss_channel_repository, subscribe_repository,
+    },
+    subscription_error, subscription_warn,
+    utils::{
+        feed_util::{
+            build_atom_feed, build_json_feed, build_rss_feed, public_feed_url, FeedFormat,
+        },
+        rate_limit_util::RateLimiter,
+        time_util::kst_now,
+    },
+};
+
+use super::{mute_service, user_service};
+
+const MAX_TOKENS: i64 = 10;
+const MAX_TOKEN_NAME_LENGTH: usize = 100;
+const FEED_ITEM_LIMIT: i32 = 50;
+/// 토큰별 피드 조회 제한 (RSS 리더의 주기적 조회 기준)
+pub const FEED_RATE_LIMIT: u32 = 60;
+pub const FEED_RATE_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// 피드 토큰 발급 (토큰 원문은 응답으로만 전달하고 해시만 저장)
+pub async fn create_feed_token(
+    pool: &MySqlPool,
+    user_email: String,
+    data: FeedTokenRequestDto,
+) -> Result<FeedTokenCreateResponseDto, OmniNewsError> {
+    let name = data.feed_token_name.unwrap_or_default().trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_TOKEN_NAME_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "feed_token_name must be 1-{MAX_TOKEN_NAME_LENGTH} characters"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let token_count = feed_token_repository::count_feed_tokens_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to count feed tokens: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if token_count >= MAX_TOKENS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_TOKENS} feed tokens are allowed"
+        )));
+    }
+
+    let token = Uuid::new_v4().simple().to_string();
+    let new_token = NewFeedToken {
+        user_id: Some(user_id),
+        feed_token_name: Some(name),
+        feed_token_hash: Some(hash_token(&token)),
+        feed_token_created_at: Some(kst_now()),
+    };
+    let feed_token_id = feed_token_repository::insert_feed_token(pool, new_token)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to insert feed token: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(FeedTokenCreateResponseDto::new(
+        feed_token_id,
+        token.clone(),
+        token_feed_url(&token, FeedFormat::Rss),
+        token_feed_url(&token, FeedFormat::Atom),
+        token_feed_url(&token, FeedFormat::Json),
+    ))
+}
+
+pub async fn get_feed_tokens(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<FeedTokenResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match feed_token_repository::select_feed_tokens_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(FeedTokenResponseDto::from_model_list(res)),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select feed tokens: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 토큰을 폐기하면 해당 토큰의 피드 주소는 즉시 접근할 수 없음
+pub async fn delete_feed_token(
+    pool: &MySqlPool,
+    user_email: String,
+    feed_token_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match feed_token_repository::delete_feed_token(pool, user_id, feed_token_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Feed token not found".to_string())),
+        Err(e) => {
+            subscription_error!("[Service] Failed to delete feed token: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 토큰 소유자의 구독 타임라인, 폴더 또는 채널 피드
+///
+/// 숨김 규칙이 적용되며 채널은 구독 중인 채널만 조회할 수 있음
+pub async fn get_token_feed(
+    pool: &MySqlPool,
+    rate_limiter: &RateLimiter,
+    token: &str,
+    scope: FeedTokenScopeRequestDto,
+    format: FeedFormat,
+) -> Result<String, OmniNewsError> {
+    // 유효한 토큰만 세어 없는 토큰을 무작위로 보내도 제한 기록이 늘어나지 않음
+    let token_hash = hash_token(token);
+    let user_id = find_user_id_by_feed_token_hash(pool, &token_hash).await?;
+    if !rate_limiter.check(&token_hash) {
+        return Err(OmniNewsError::TooManyRequests(
+            "Feed token rate limit exceeded".to_string(),
+        ));
+    }
+
+    let (title, link, channel_ids) = match (scope.folder_id, scope.channel_id) {
+        (Some(_), Some(_)) => {
+            return Err(OmniNewsError::InvalidValue(
+                "Only one of folder_id and channel_id can be set".to_string(),
+            ));
+        }
+        (Some(folder_id), None) => {
+            let (name, channel_ids) = find_folder_scope(pool, user_id, folder_id).await?;
+            (format!("Omninews - {name}"), None, channel_ids)
+        }
+        (None, Some(channel_id)) => {
+            let (name, link) = find_channel_scope(pool, channel_id).await?;
+            (format!("Omninews - {name}"), link, vec![channel_id])
+        }
+        (None, None) => {
+            let channel_ids = subscribe_repository::select_subscription_channel_ids(pool, user_id)
+                .await
+                .map_err(|e| {
+                    subscription_error!(
+                        "[Service] Failed to select subscription channel ids: {}",
+                        e
+                    );
+                    OmniNewsError::Database(e)
+                })?;
+            ("Omninews - Subscriptions".to_string(), None, channel_ids)
+        }
+    };
+
+    let mut items = find_feed_items(pool, channel_ids).await?;
+    mute_service::find_mute_filter(pool, user_id)
+        .await?
+        .retain_visible(&mut items);
+
+    let feed_url = token_feed_url(token, format);
+    let link = link.unwrap_or_else(|| feed_url.clone());
+    match format {
+        FeedFormat::Rss => Ok(build_rss_feed(&title, &link, &title, items)),
+        FeedFormat::Atom => build_atom_feed(&title, &link, &feed_url, items),
+        FeedFormat::Json => build_json_feed(&title, &link, &feed_url, items),
+    }
+}
+
+async fn find_user_id_by_feed_token_hash(
+    pool: &MySqlPool,
+    token_hash: &str,
+) -> Result<i32, OmniNewsError> {
+    let feed_token = find_feed_token(pool, token_hash).await?;
+
+    Ok(touch_feed_token(pool, feed_token).await)
+}
+
+/// 조회할 때마다 마지막 사용 시각을 갱신하고 소유자 ID 반환
+async fn touch_feed_token(pool: &MySqlPool, feed_token: FeedToken) -> i32 {
+    if let Err(e) = feed_token_repository::update_feed_token_last_used_at(
+        pool,
+        feed_token.feed_token_id.unwrap_or_default(),
+        kst_now(),
+    )
+    .await
+    {
+        subscription_warn!("[Service] Failed to update feed token last used at: {}", e);
+    }
+    feed_token.user_id.unwrap_or_default()
+}
+
+async fn find_feed_token(pool: &MySqlPool, token_hash: &str) -> Result<FeedToken, OmniNewsError> {
+    match feed_token_repository::select_feed_token_by_hash(pool, token_hash).await {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound("Feed token not found".to_string()))
+        }
+        Err(e) => {
+            subscription_error!("[Service] Failed to select feed token: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 사용자 폴더 이름과 폴더에 직접 속한 채널 ID
+async fn find_folder_scope(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+) -> Result<(String, Vec<i32>), OmniNewsError> {
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let Some(folder) = folders
+        .into_iter()
+        .find(|folder| folder.folder_id == Some(folder_id))
+    else {
+        return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+    };
+
+    match folder_repository::select_channels_in_folder(pool, folder_id).await {
+        Ok(res) => Ok((
+            folder.folder_name.unwrap_or_default(),
+            res.into_iter()
+                .filter_map(|channel| channel.channel_id)
+                .collect(),
+        )),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select channels in folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 채널의 이름과 링크. 구독하지 않은 채널도 피드로 받을 수 있음 (생성 채널을 다른 리더에서 구독하는 용도)
+async fn find_channel_scope(
+    pool: &MySqlPool,
+    channel_id: i32,
+) -> Result<(String, Option<String>), OmniNewsError> {
+    match rss_channel_repository::select_rss_channel_by_id(pool, channel_id).await {
+        Ok(channel) => Ok((
+            channel.channel_title.unwrap_or_default(),
+            channel.channel_link,
+        )),
+        Err(sqlx::Error::RowNotFound) => {
+            Err(OmniNewsError::NotFound("Channel not found".to_string()))
+        }
+        Err(e) => {
+            subscription_error!("[Service] Failed to select rss channel: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_feed_items(
+    pool: &MySqlPool,
+    channel_ids: Vec<i32>,
+) -> Result<Vec<RssItem>, OmniNewsError> {
+    if channel_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    match subscribe_repository::select_subscription_items(
+        pool,
+        channel_ids,
+        0,
+        FEED_ITEM_LIMIT,
+        false,
+    )
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select feed items: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+fn token_feed_url(token: &str, format: FeedFormat) -> String {
+    let format = match format {
+        FeedFormat::Rss => "rss",
+        FeedFormat::Atom => "atom",
+        FeedFormat::Json => "json",
+    };
+    public_feed_url(&format!("/public/feed/{token}/{format}"))
+}
+
+fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,591 @@
+use std::{collections::HashMap, env, net::IpAddr, path::PathBuf, time::Duration as StdDuration};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::NaiveDateTime;
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::{
+    dto::feedback::{
+        request::{
+            FeedbackAttachmentRequestDto, FeedbackListRequestDto, FeedbackReplyRequestDto,
+            FeedbackRequestDto, FeedbackStatusRequestDto,
+        },
+        response::{FeedbackPageResponseDto, FeedbackResponseDto},
+    },
+    model::{
+        error::OmniNewsError,
+        feedback::{
+            FeedbackAttachment, FeedbackCategory, FeedbackStatus, NewFeedback,
+            NewFeedbackAttachment,
+        },
+        notification::{NewUserNotification, NotificationType},
+    },
+    repository::{feedback_repository, notification_repository},
+    user_error, user_info,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        rate_limit_util::RateLimiter,
+        time_util::kst_now,
+    },
+};
+
+use super::user_service;
+
+/// 사용자(익명이면 IP)별 시간당 피드백 수
+const FEEDBACK_RATE_LIMIT: u32 = 5;
+const FEEDBACK_RATE_WINDOW: StdDuration = StdDuration::from_secs(60 * 60);
+const MAX_CONTENT_LENGTH: usize = 2000;
+const MAX_EMAIL_LENGTH: usize = 100;
+const MAX_APP_VERSION_LENGTH: usize = 30;
+const MAX_ATTACHMENTS: usize = 3;
+const MAX_ATTACHMENT_BYTES: usize = 3 * 1024 * 1024;
+const DEFAULT_ATTACHMENT_DIR: &str = "uploads/feedback";
+/// 첨부 가능한 형식과 저장 확장자
+const ATTACHMENT_TYPES: [(&str, &str); 5] = [
+    ("image/png", "png"),
+    ("image/jpeg", "jpg"),
+    ("image/webp", "webp"),
+    ("image/gif", "gif"),
+    ("text/plain", "txt"),
+];
+
+/// 피드백 요청 제한 (피드 토큰 요청 제한과 구분되는 Rocket 관리 상태)
+pub struct FeedbackRateLimiter(RateLimiter);
+
+impl FeedbackRateLimiter {
+    pub fn new() -> Self {
+        Self(RateLimiter::new(FEEDBACK_RATE_LIMIT, FEEDBACK_RATE_WINDOW))
+    }
+}
+
+struct DecodedAttachment {
+    original_name: Option<String>,
+    content_type: &'static str,
+    extension: &'static str,
+    bytes: Vec<u8>,
+}
+
+/// 피드백을 저장하고 ID 반환. `user_email`이 없으면 익명 피드백
+///
+/// 요청 제한은 검증을 통과한 요청만 셈
+pub async fn submit_feedback(
+    pool: &MySqlPool,
+    rate_limiter: &FeedbackRateLimiter,
+    user_email: Option<String>,
+    client_ip: Option<IpAddr>,
+    data: FeedbackRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = match user_email {
+        Some(user_email) => Some(user_service::find_user_id_by_email(pool, user_email).await?),
+        None => None,
+    };
+    // IP를 알 수 없는 익명 요청을 한 키로 묶으면 서로의 제한을 소모하므로 받지 않음
+    let rate_key = match (user_id, client_ip) {
+        (Some(user_id), _) => format!("user:{user_id}"),
+        (None, Some(ip)) => format!("ip:{ip}"),
+        (None, None) => {
+            return Err(OmniNewsError::InvalidValue(
+                "Client IP is required for anonymous feedback".to_string(),
+            ));
+        }
+    };
+
+    let content = data.feedback_content.unwrap_or_default().trim().to_string();
+    if content.is_empty() || content.chars().count() > MAX_CONTENT_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "feedback_content must be 1-{MAX_CONTENT_LENGTH} characters"
+        )));
+    }
+    let email = data
+        .feedback_email
+        .map(|email| email.trim().to_string())
+        .filter(|email| !email.is_empty());
+    if email
+        .as_deref()
+        .is_some_and(|email| !email.contains('@') || email.len() > MAX_EMAIL_LENGTH)
+    {
+        return Err(OmniNewsError::InvalidValue(
+            "Invalid feedback_email".to_string(),
+        ));
+    }
+    let app_version = data
+        .feedback_app_version
+        .map(|version| version.trim().to_string())
+        .filter(|version| !version.is_empty());
+    if app_version
+        .as_deref()
+        .is_some_and(|version| version.len() > MAX_APP_VERSION_LENGTH)
+    {
+        return Err(OmniNewsError::InvalidValue(
+            "Invalid feedback_app_version".to_string(),
+        ));
+    }
+    let attachments = decode_attachments(data.attachments.unwrap_or_default())?;
+
+    if !rate_limiter.0.check(&rate_key) {
+        return Err(OmniNewsError::TooManyRequests(
+            "Feedback rate limit exceeded".to_string(),
+        ));
+    }
+
+    let now = kst_now();
+    let saved = save_attachments(attachments, now).await?;
+    let saved_file_names = saved
+        .iter()
+        .filter_map(|attachment| attachment.attachment_file_name.clone())
+        .collect::<Vec<String>>();
+
+    let feedback_id = match feedback_repository::insert_feedback_with_attachments(
+        pool,
+        NewFeedback {
+            user_id,
+            feedback_email: email,
+            feedback_content: Some(content),
+            feedback_category: Some(
+                data.feedback_category
+                    .unwrap_or(FeedbackCategory::Other)
+                    .as_str()
+                    .to_string(),
+            ),
+            feedback_app_version: app_version,
+            feedback_platform: data
+                .feedback_platform
+                .map(|platform| platform.as_str().to_string()),
+            feedback_created_at: Some(now),
+        },
+        saved,
+    )
+    .await
+    {
+        Ok(feedback_id) => feedback_id,
+        Err(e) => {
+            user_error!("[Service] Failed to insert feedback: {}", e);
+            remove_attachment_files(&saved_file_names).await;
+            return Err(OmniNewsError::Database(e));
+        }
+    };
+
+    user_info!("[Service] Feedback {} submitted", feedback_id);
+    Ok(feedback_id)
+}
+
+/// 최신순 피드백 목록 (관리자)
+pub async fn get_feedbacks(
+    pool: &MySqlPool,
+    admin_email: String,
+    data: FeedbackListRequestDto,
+) -> Result<FeedbackPageResponseDto, OmniNewsError> {
+    validate_admin(pool, &admin_email).await?;
+    let limit = page_size(data.size);
+    let ItemCursor::Id { id: cursor_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_id)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match feedback order".to_string(),
+        ));
+    };
+
+    let feedbacks = feedback_repository::select_feedbacks_before_id(
+        pool,
+        cursor_id,
+        limit + 1,
+        data.status.map(|status| status.as_str()),
+        data.category.map(|category| category.as_str()),
+        data.platform.map(|platform| platform.as_str()),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to select feedbacks: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let (feedbacks, next_cursor) = split_page(feedbacks, limit, |feedback| ItemCursor::Id {
+        id: feedback.feedback_id.unwrap_or_default(),
+    });
+
+    let mut attachments_by_feedback: HashMap<i32, Vec<FeedbackAttachment>> = HashMap::new();
+    for attachment in feedback_repository::select_feedback_attachments_by_feedback_ids(
+        pool,
+        feedbacks.iter().filter_map(|f| f.feedback_id).collect(),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to select feedback attachments: {}", e);
+        OmniNewsError::Database(e)
+    })? {
+        if let Some(feedback_id) = attachment.feedback_id {
+            attachments_by_feedback
+                .entry(feedback_id)
+                .or_default()
+                .push(attachment);
+        }
+    }
+
+    let feedbacks = feedbacks
+        .into_iter()
+        .map(|feedback| {
+            let attachments = feedback
+                .feedback_id
+                .and_then(|id| attachments_by_feedback.remove(&id))
+                .unwrap_or_default();
+            FeedbackResponseDto::new(feedback, attachments)
+        })
+        .collect();
+
+    Ok(FeedbackPageResponseDto::new(feedbacks, next_cursor))
+}
+
+pub async fn get_feedback(
+    pool: &MySqlPool,
+    admin_email: String,
+    feedback_id: i32,
+) -> Result<FeedbackResponseDto, OmniNewsError> {
+    validate_admin(pool, &admin_email).await?;
+    find_feedback(pool, feedback_id).await
+}
+
+pub async fn update_feedback_status(
+    pool: &MySqlPool,
+    admin_email: String,
+    feedback_id: i32,
+    data: FeedbackStatusRequestDto,
+) -> Result<bool, OmniNewsError> {
+    validate_admin(pool, &admin_email).await?;
+    let Some(status) = data.feedback_status else {
+        return Err(OmniNewsError::InvalidValue(
+            "feedback_status is required".to_string(),
+        ));
+    };
+
+    match feedback_repository::update_feedback_status(pool, feedback_id, status.as_str(), kst_now())
+        .await
+    {
+        Ok(true) => Ok(true),
+        Ok(false) => Err(OmniNewsError::NotFound("Feedback not found".to_string())),
+        Err(e) => {
+            user_error!("[Service] Failed to update feedback status: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 답변을 저장하고 로그인 사용자가 보낸 피드백이면 알림함으로 전달
+pub async fn reply_feedback(
+    pool: &MySqlPool,
+    admin_email: String,
+    feedback_id: i32,
+    data: FeedbackReplyRequestDto,
+) -> Result<FeedbackResponseDto, OmniNewsError> {
+    validate_admin(pool, &admin_email).await?;
+    let reply = data.feedback_reply.unwrap_or_default().trim().to_string();
+    if reply.is_empty() || reply.chars().count() > MAX_CONTENT_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "feedback_reply must be 1-{MAX_CONTENT_LENGTH} characters"
+        )));
+    }
+    let status = data.feedback_status.unwrap_or(FeedbackStatus::Resolved);
+
+    let now = kst_now();
+    let updated =
+        feedback_repository::update_feedback_reply(pool, feedback_id, &reply, status.as_str(), now)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to update feedback reply: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    if !updated {
+        return Err(OmniNewsError::NotFound("Feedback not found".to_string()));
+    }
+
+    let feedback = find_feedback(pool, feedback_id).await?;
+    if let Some(user_id) = feedback.user_id {
+        notification_repository::insert_user_notifications(
+            pool,
+            vec![NewUserNotification {
+                user_id: Some(user_id),
+                notification_type: Some(NotificationType::FeedbackReply.as_str().to_string()),
+                notification_title: Some("보내주신 피드백에 답변이 도착했습니다".to_string()),
+                notification_body: Some(reply.chars().take(500).collect()),
+                rss_id: None,
+                channel_id: None,
+                feedback_id: Some(feedback_id),
+                notification_created_at: Some(now),
+            }],
+        )
+        .await
+        .map_err(|e| {
+            user_error!(
+                "[Service] Failed to insert feedback reply notification: {}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?;
+    }
+
+    user_info!("[Service] Feedback {} replied", feedback_id);
+    Ok(feedback)
+}
+
+/// 저장된 첨부 파일 경로 (관리자)
+pub async fn get_feedback_attachment(
+    pool: &MySqlPool,
+    admin_email: String,
+    attachment_id: i32,
+) -> Result<PathBuf, OmniNewsError> {
+    validate_admin(pool, &admin_email).await?;
+
+    let attachment = feedback_repository::select_feedback_attachment_by_id(pool, attachment_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                OmniNewsError::NotFound("Feedback attachment not found".to_string())
+            }
+            _ => {
+                user_error!("[Service] Failed to select feedback attachment: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+
+    Ok(attachment_dir().join(attachment.attachment_file_name.unwrap_or_default()))
+}
+
+async fn validate_admin(pool: &MySqlPool, user_email: &str) -> Result<(), OmniNewsError> {
+    if !user_service::validate_admin_user(pool, user_email).await? {
+        return Err(OmniNewsError::Forbidden("Admin only".to_string()));
+    }
+    Ok(())
+}
+
+async fn find_feedback(
+    pool: &MySqlPool,
+    feedback_id: i32,
+) -> Result<FeedbackResponseDto, OmniNewsError> {
+    let feedback = feedback_repository::select_feedback_by_id(pool, feedback_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Feedback not found".to_string()),
+            _ => {
+                user_error!("[Service] Failed to select feedback: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+    let attachments =
+        feedback_repository::select_feedback_attachments_by_feedback_ids(pool, vec![feedback_id])
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select feedback attachments: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+
+    Ok(FeedbackResponseDto::new(feedback, attachments))
+}
+
+/// 저장 전에 모든 첨부 파일의 개수, 형식, 크기를 검사
+fn decode_attachments(
+    attachments: Vec<FeedbackAttachmentRequestDto>,
+) -> Result<Vec<DecodedAttachment>, OmniNewsError> {
+    if attachments.len() > MAX_ATTACHMENTS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_ATTACHMENTS} attachments are allowed"
+        )));
+    }
+
+    attachments
+        .into_iter()
+        .map(|attachment| {
+            let content_type = attachment
+                .attachment_content_type
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase();
+            let Some((content_type, extension)) = ATTACHMENT_TYPES
+                .iter()
+                .find(|(allowed, _)| *allowed == content_type)
+                .copied()
+            else {
+                return Err(OmniNewsError::InvalidValue(format!(
+                    "Unsupported attachment type: {content_type}"
+                )));
+            };
+            let bytes = STANDARD
+                .decode(attachment.attachment_data.unwrap_or_default().trim())
+                .map_err(|_| OmniNewsError::DecodeError)?;
+            if bytes.is_empty() || bytes.len() > MAX_ATTACHMENT_BYTES {
+                return Err(OmniNewsError::InvalidValue(format!(
+                    "Attachments must be 1-{MAX_ATTACHMENT_BYTES} bytes"
+                )));
+            }
+            if !matches_content_type(content_type, &bytes) {
+                return Err(OmniNewsError::InvalidValue(format!(
+                    "Attachment content does not match {content_type}"
+                )));
+            }
+
+            Ok(DecodedAttachment {
+                original_name: attachment
+                    .attachment_name
+                    .map(|name| name.trim().chars().take(200).collect::<String>())
+                    .filter(|name| !name.is_empty()),
+                content_type,
+                extension,
+                bytes,
+            })
+        })
+        .collect()
+}
+
+/// 첨부 파일은 임의 파일명으로 저장 (원래 이름은 DB에만 보관). 중간에 실패하면 저장한 파일을 지움
+async fn save_attachments(
+    attachments: Vec<DecodedAttachment>,
+    now: NaiveDateTime,
+) -> Result<Vec<NewFeedbackAttachment>, OmniNewsError> {
+    if attachments.is_empty() {
+        return Ok(vec![]);
+    }
+    let dir = attachment_dir();
+    tokio::fs::create_dir_all(&dir).await.map_err(|e| {
+        user_error!("[Service] Failed to create attachment dir: {}", e);
+        OmniNewsError::Config(format!("Failed to create {}: {e}", dir.display()))
+    })?;
+
+    let mut saved: Vec<NewFeedbackAttachment> = vec![];
+    for attachment in attachments {
+        let file_name = format!("{}.{}", Uuid::new_v4(), attachment.extension);
+        let path = dir.join(&file_name);
+        if let Err(e) = tokio::fs::write(&path, &attachment.bytes).await {
+            user_error!("[Service] Failed to write attachment: {}", e);
+            let written = saved
+                .iter()
+                .filter_map(|attachment| attachment.attachment_file_name.clone())
+                .chain(std::iter::once(file_name))
+                .collect::<Vec<String>>();
+            remove_attachment_files(&written).await;
+            return Err(OmniNewsError::Config(format!(
+                "Failed to write {}: {e}",
+                path.display()
+            )));
+        }
+        saved.push(NewFeedbackAttachment {
+            attachment_file_name: Some(file_name),
+            attachment_original_name: attachment.original_name,
+            attachment_content_type: Some(attachment.content_type.to_string()),
+            attachment_size: Some(attachment.bytes.len() as i32),
+            attachment_created_at: Some(now),
+        });
+    }
+    Ok(saved)
+}
+
+async fn remove_attachment_files(file_names: &[String]) {
+    let dir = attachment_dir();
+    for file_name in file_names {
+        let path = dir.join(file_name);
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                user_error!(
+                    "[Service] Failed to remove attachment {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// 선언한 형식과 실제 내용이 맞는지 파일 시그니처로 확인
+fn matches_content_type(content_type: &str, bytes: &[u8]) -> bool {
+    match content_type {
+        "image/png" => bytes.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/jpeg" => bytes.starts_with(&[0xFF, 0xD8, 0xFF]),
+        "image/webp" => bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP",
+        "image/gif" => bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a"),
+        "text/plain" => !bytes.contains(&0) && std::str::from_utf8(bytes).is_ok(),
+        _ => false,
+    }
+}
+
+fn attachment_dir() -> PathBuf {
+    PathBuf::from(
+        env::var("FEEDBACK_ATTACHMENT_DIR").unwrap_or_else(|_| DEFAULT_ATTACHMENT_DIR.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    fn attachment(content_type: &str, bytes: &[u8]) -> FeedbackAttachmentRequestDto {
+        FeedbackAttachmentRequestDto {
+            attachment_name: Some(" screenshot.png ".to_string()),
+            attachment_content_type: Some(content_type.to_string()),
+            attachment_data: Some(STANDARD.encode(bytes)),
+        }
+    }
+
+    #[test]
+    fn matches_content_type_by_signature() {
+        assert!(matches_content_type("image/png", PNG));
+        assert!(matches_content_type(
+            "image/jpeg",
+            &[0xFF, 0xD8, 0xFF, 0xE0]
+        ));
+        assert!(matches_content_type(
+            "image/webp",
+            b"RIFF\x24\0\0\0WEBPVP8 "
+        ));
+        assert!(matches_content_type("image/gif", b"GIF89a\x01\0"));
+        assert!(matches_content_type("text/plain", "로그 내용\n".as_bytes()));
+    }
+
+    #[test]
+    fn rejects_content_that_does_not_match_declared_type() {
+        assert!(!matches_content_type("image/jpeg", PNG));
+        assert!(!matches_content_type("image/webp", b"RIFF\x24\0\0\0WAVE"));
+        assert!(!matches_content_type("image/gif", b"GIF"));
+        assert!(!matches_content_type("text/plain", b"MZ\x90\0\x03"));
+        assert!(!matches_content_type("text/plain", &[0xC3, 0x28]));
+        assert!(!matches_content_type("application/pdf", b"%PDF-1.7"));
+    }
+
+    #[test]
+    fn decode_attachments_checks_type_and_signature() {
+        let decoded = decode_attachments(vec![attachment(" Image/PNG ", PNG)]).unwrap();
+        assert_eq!(decoded[0].content_type, "image/png");
+        assert_eq!(decoded[0].extension, "png");
+        assert_eq!(decoded[0].original_name.as_deref(), Some("screenshot.png"));
+
+        assert!(matches!(
+            decode_attachments(vec![attachment("image/jpeg", PNG)]),
+            Err(OmniNewsError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            decode_attachments(vec![attachment("image/svg+xml", b"<svg/>")]),
+            Err(OmniNewsError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            decode_attachments(vec![attachment("image/png", b"")]),
+            Err(OmniNewsError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn decode_attachments_limits_count_and_encoding() {
+        assert!(matches!(
+            decode_attachments(vec![attachment("image/png", PNG); MAX_ATTACHMENTS + 1]),
+            Err(OmniNewsError::InvalidValue(_))
+        ));
+
+        let mut invalid = attachment("image/png", PNG);
+        invalid.attachment_data = Some("not base64!".to_string());
+        assert!(matches!(
+            decode_attachments(vec![invalid]),
+            Err(OmniNewsError::DecodeError)
+        ));
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        bookmark::request::SaveBookmarkRequestDto,
+        fever::{
+            request::{FeverQueryRequestDto, FeverRequestDto},
+            response::{
+                join_ids, FeverFeedResponseDto, FeverFeedsGroupResponseDto, FeverGroupResponseDto,
+                FeverItemResponseDto, FeverResponseDto,
+            },
+        },
+    },
+    model::{error::OmniNewsError, rss::RssItem},
+    repository::{
+        bookmark_repository, folder_repository, read_repository, rss_item_repository,
+        subscribe_repository,
+    },
+    subscription_error,
+    utils::time_util::kst_from_timestamp,
+};
+
+use super::{app_password_service, bookmark_service, mute_service, read_service};
+
+// Fever API의 그룹은 폴더, 피드는 구독 채널, 아이템 ID는 rss_id에 대응
+
+const ITEM_LIMIT: i32 = 50;
+/// 읽지 않은 아이템 ID 목록 최대 개수
+const MAX_UNREAD_ITEM_IDS: i32 = 10000;
+
+/// 인증에 실패하면 `auth: 0` 응답을 반환 (Fever 클라이언트는 상태 코드 대신 이 값을 확인)
+///
+/// 상태 변경(`mark`)을 먼저 처리한 뒤 요청한 항목을 조회
+pub async fn handle_request(
+    pool: &MySqlPool,
+    query: FeverQueryRequestDto,
+    data: FeverRequestDto,
+) -> Result<FeverResponseDto, OmniNewsError> {
+    let Some(api_key) = data.api_key.as_deref() else {
+        return Ok(FeverResponseDto::unauthorized());
+    };
+    let user_id = match app_password_service::find_user_id_by_fever_key(pool, api_key).await {
+        Ok(user_id) => user_id,
+        Err(OmniNewsError::NotFound(_)) => return Ok(FeverResponseDto::unauthorized()),
+        Err(e) => return Err(e),
+    };
+
+    if data.mark.is_some() {
+        mark(pool, user_id, &data).await?;
+    }
+
+    let mut res = FeverResponseDto::authorized(Utc::now().timestamp());
+    if query.groups.is_some() || query.feeds.is_some() {
+        let (groups, feeds_groups) = find_groups(pool, user_id).await?;
+        if query.groups.is_some() {
+            res.groups = Some(groups);
+        }
+        res.feeds_groups = Some(feeds_groups);
+    }
+    if query.feeds.is_some() {
+        res.feeds = Some(find_feeds(pool, user_id).await?);
+    }
+    if query.items.is_some() {
+        let channel_ids = find_subscription_channel_ids(pool, user_id).await?;
+        let total_items = subscribe_repository::count_subscription_items(pool, channel_ids.clone())
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to count subscription items: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+        res.items = Some(find_items(pool, user_id, channel_ids, &query).await?);
+        res.total_items = Some(total_items);
+    }
+    if query.unread_item_ids.is_some() {
+        let channel_ids = find_subscription_channel_ids(pool, user_id).await?;
+        let unread_item_ids = read_repository::select_unread_rss_ids_by_channel_ids(
+            pool,
+            user_id,
+            channel_ids,
+            MAX_UNREAD_ITEM_IDS,
+        )
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select unread rss ids: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        res.unread_item_ids = Some(join_ids(unread_item_ids));
+    }
+    if query.saved_item_ids.is_some() {
+        let saved_item_ids =
+            bookmark_repository::select_bookmarked_rss_ids_by_user_id(pool, user_id)
+                .await
+                .map_err(|e| {
+                    subscription_error!("[Service] Failed to select bookmarked rss ids: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+        res.saved_item_ids = Some(join_ids(saved_item_ids));
+    }
+
+    Ok(res)
+}
+
+/// 아이템은 읽음/안 읽음/저장/저장 취소, 피드와 그룹은 읽음 처리만 지원
+///
+/// 그룹 ID 0은 전체 구독 채널, 음수(Sparks)는 대응하는 채널이 없으므로 무시
+async fn mark(pool: &MySqlPool, user_id: i32, data: &FeverRequestDto) -> Result<(), OmniNewsError> {
+    let (Some(mark), Some(mark_as), Some(id)) = (&data.mark, &data.mark_as, data.id) else {
+        return Err(OmniNewsError::InvalidValue(
+            "mark, as and id are required".to_string(),
+        ));
+    };
+    let before = match data.before {
+        Some(before) => Some(kst_from_timestamp(before)?),
+        None => None,
+    };
+
+    match (mark.as_str(), mark_as.as_str()) {
+        ("item", "read") => read_service::mark_item_read_by_user_id(pool, user_id, id).await,
+        ("item", "unread") => read_service::mark_item_unread_by_user_id(pool, user_id, id).await,
+        ("item", "saved") => bookmark_service::save_bookmark_by_user_id(
+            pool,
+            user_id,
+            SaveBookmarkRequestDto {
+                rss_id: Some(id),
+                collection_id: None,
+                bookmark_note: None,
+                tags: None,
+            },
+        )
+        .await
+        .map(|_| ()),
+        // 이미 저장하지 않은 아이템이면 그대로 성공 처리
+        ("item", "unsaved") => bookmark_service::unsave_bookmark_by_user_id(pool, user_id, id)
+            .await
+            .map(|_| ()),
+        ("feed", "read") => {
+            let subscribed = subscribe_repository::is_already_subscribe_channel(pool, user_id, id)
+                .await
+                .map_err(|e| {
+                    subscription_error!("[Service] Failed to check subscription: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+            if !subscribed {
+                return Err(OmniNewsError::NotFound(
+                    "Subscribed feed not found".to_string(),
+                ));
+            }
+            read_service::mark_channels_read(pool, user_id, vec![id], before).await
+        }
+        ("group", "read") => {
+            let channel_ids = match id {
+                0 => find_subscription_channel_ids(pool, user_id).await?,
+                id if id < 0 => return Ok(()),
+                id => read_service::find_user_folder_channel_ids(pool, user_id, id).await?,
+            };
+            read_service::mark_channels_read(pool, user_id, channel_ids, before).await
+        }
+        _ => Err(OmniNewsError::InvalidValue(format!(
+            "Unsupported mark: {mark} as {mark_as}"
+        ))),
+    }
+}
+
+/// 폴더 목록과 폴더별로 직접 속한 채널 ID
+async fn find_groups(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<(Vec<FeverGroupResponseDto>, Vec<FeverFeedsGroupResponseDto>), OmniNewsError> {
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut groups = vec![];
+    let mut feeds_groups = vec![];
+    for folder in folders {
+        let Some(folder_id) = folder.folder_id else {
+            continue;
+        };
+        let channels = folder_repository::select_channels_in_folder(pool, folder_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select channels in folder: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+
+        groups.push(FeverGroupResponseDto {
+            id: folder_id,
+            title: folder.folder_name.unwrap_or_default(),
+        });
+        feeds_groups.push(FeverFeedsGroupResponseDto::new(
+            folder_id,
+            channels
+                .into_iter()
+                .filter_map(|channel| channel.channel_id)
+                .collect(),
+        ));
+    }
+
+    Ok((groups, feeds_groups))
+}
+
+async fn find_feeds(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<FeverFeedResponseDto>, OmniNewsError> {
+    match subscribe_repository::select_subscription_channels(pool, user_id).await {
+        Ok(res) => Ok(res.into_iter().map(FeverFeedResponseDto::new).collect()),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select subscription channels: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 최대 50개 아이템 조회
+///
+/// `with_ids`가 있으면 해당 아이템, `max_id`가 있으면 그 이전 아이템을 내림차순,
+/// 그 밖에는 `since_id`(기본 0) 다음 아이템을 오름차순으로 조회. 숨김 규칙이 적용됨
+async fn find_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_ids: Vec<i32>,
+    query: &FeverQueryRequestDto,
+) -> Result<Vec<FeverItemResponseDto>, OmniNewsError> {
+    let result = if let Some(with_ids) = query.with_ids.as_deref() {
+        let rss_ids = parse_ids(with_ids)?;
+        if rss_ids.len() > ITEM_LIMIT as usize {
+            return Err(OmniNewsError::InvalidValue(format!(
+                "Up to {ITEM_LIMIT} items can be requested"
+            )));
+        }
+        let channel_ids: HashSet<i32> = channel_ids.into_iter().collect();
+        rss_item_repository::select_rss_items_by_ids(pool, rss_ids)
+            .await
+            .map(|items| {
+                items
+                    .into_iter()
+                    .filter(|item| {
+                        item.channel_id
+                            .is_some_and(|channel_id| channel_ids.contains(&channel_id))
+                    })
+                    .collect()
+            })
+    } else if let Some(max_id) = query.max_id.filter(|max_id| *max_id > 0) {
+        subscribe_repository::select_subscription_items_before_rss_id(
+            pool,
+            channel_ids,
+            max_id,
+            ITEM_LIMIT,
+        )
+        .await
+    } else {
+        subscribe_repository::select_subscription_items_after_rss_id(
+            pool,
+            channel_ids,
+            query.since_id.unwrap_or_default(),
+            ITEM_LIMIT,
+        )
+        .await
+    };
+    let mut items: Vec<RssItem> = result.map_err(|e| {
+        subscription_error!("[Service] Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    mute_service::find_mute_filter(pool, user_id)
+        .await?
+        .retain_visible(&mut items);
+
+    let rss_ids: Vec<i32> = items.iter().filter_map(|item| item.rss_id).collect();
+    let read_rss_ids = read_service::find_read_rss_ids(pool, user_id, rss_ids.clone()).await?;
+    let saved_rss_ids: HashSet<i32> =
+        bookmark_repository::select_bookmarked_rss_ids(pool, user_id, rss_ids)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select bookmarked rss ids: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect();
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let rss_id = item.rss_id.unwrap_or_default();
+            FeverItemResponseDto::new(
+                item,
+                read_rss_ids.contains(&rss_id),
+                saved_rss_ids.contains(&rss_id),
+            )
+        })
+        .collect())
+}
+
+async fn find_subscription_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<i32>, OmniNewsError> {
+    match subscribe_repository::select_subscription_channel_ids(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select subscription channel ids: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+fn parse_ids(ids: &str) -> Result<Vec<i32>, OmniNewsError> {
+    ids.split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            id.parse::<i32>()
+                .map_err(|_| OmniNewsError::InvalidValue(format!("Invalid item id: {id}")))
+        })
+        .collect()
+}
@@ -1,19 +1,52 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
 use sqlx::MySqlPool;
+use uuid::Uuid;
 
 use crate::{
-    dto::folder::{
-        request::{
-            ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
-            UpdateFolderRequestDto,
+    dto::{
+        folder::{
+            request::{
+                ChannelFolderRequestDto, CreateFolderRequestDto, DeleteFolderRequestDto,
+                FolderItemRequestDto, FolderOrderRequestDto, OpmlImportRequestDto,
+                UpdateFolderRequestDto,
+            },
+            response::{
+                FolderPublishResponseDto, OpmlImportResponseDto, PublicFolderResponseDto,
+                RssFolderResponseDto,
+            },
         },
-        response::RssFolderResponseDto,
+        rss::response::{RssItemPageResponseDto, RssItemResponseDto},
+    },
+    folder_error, folder_warn,
+    model::{
+        error::OmniNewsError,
+        folder::{NewRssFolder, RssFolder},
+        rss::{RssChannel, RssItem},
+        sync::SyncEntity,
+    },
+    repository::{
+        folder_repository, read_repository, rss_channel_repository, subscribe_repository,
+    },
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        embedding_util::EmbeddingService,
+        feed_util::{build_atom_feed, build_rss_feed, public_feed_url, safe_link},
+        opml_util::{build_opml, parse_opml, OpmlOutline},
+        time_util::kst_now,
     },
-    folder_error,
-    model::error::OmniNewsError,
-    repository::folder_repository,
 };
 
-use super::user_service;
+use super::{channel_service, mute_service, read_service, sync_service, user_service};
+
+const MAX_FOLDER_NAME_LENGTH: usize = 50;
+const MAX_FOLDER_ICON_LENGTH: usize = 50;
+const MAX_REORDER_ITEMS: usize = 500;
+const MAX_IMPORT_FEEDS: usize = 300;
+const PUBLIC_FEED_ITEM_LIMIT: i32 = 50;
+// 내보내기 시 이보다 깊은 폴더는 생략
+const MAX_FOLDER_DEPTH: usize = 16;
 
 pub async fn create_folder(
     pool: &MySqlPool,
@@ -21,9 +54,30 @@ pub async fn create_folder(
     folder: CreateFolderRequestDto,
 ) -> Result<i32, OmniNewsError> {
     let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_icon = normalize_folder_icon(folder.folder_icon)?.filter(|icon| !icon.is_empty());
+    let folder_color =
+        normalize_folder_color(folder.folder_color)?.filter(|color| !color.is_empty());
+    if let Some(parent_id) = folder.folder_parent_id {
+        let folders = find_user_folders(pool, user_id).await?;
+        if !folders.iter().any(|f| f.folder_id == Some(parent_id)) {
+            return Err(OmniNewsError::NotFound(
+                "Parent folder not found".to_string(),
+            ));
+        }
+    }
 
-    match folder_repository::insert_folder(pool, user_id, folder.folder_name.unwrap()).await {
-        Ok(res) => Ok(res),
+    let new_folder = NewRssFolder {
+        user_id: Some(user_id),
+        folder_name: folder.folder_name,
+        folder_parent_id: folder.folder_parent_id,
+        folder_icon,
+        folder_color,
+    };
+    match folder_repository::insert_folder(pool, new_folder).await {
+        Ok(res) => {
+            sync_service::record_change(pool, user_id, SyncEntity::Folder, res, kst_now()).await;
+            Ok(res)
+        }
         Err(e) => {
             folder_error!("[Service] Failed to create folder: {}", e);
             Err(OmniNewsError::Database(e))
@@ -35,14 +89,18 @@ pub async fn add_channel_to_folder(
     pool: &MySqlPool,
     channel_folder_id: ChannelFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
+    let folder_id = channel_folder_id.folder_id.unwrap();
     match folder_repository::insert_channel_to_folder(
         pool,
-        channel_folder_id.folder_id.unwrap(),
+        folder_id,
         channel_folder_id.channel_id.unwrap(),
     )
     .await
     {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            sync_service::record_folder_change(pool, folder_id, kst_now()).await;
+            Ok(())
+        }
         Err(e) => {
             folder_error!("[Service] Failed to add channel in folder: {}", e);
             Err(OmniNewsError::Database(e))
@@ -50,51 +108,165 @@ pub async fn add_channel_to_folder(
     }
 }
 
+/// 폴더별 채널 목록과 안 읽은 아이템 수, 가장 최근 아이템 발행일
 pub async fn fetch_folders(
     pool: &MySqlPool,
     user_email: String,
 ) -> Result<Vec<RssFolderResponseDto>, OmniNewsError> {
     let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
-    let mut result: Vec<RssFolderResponseDto> = Vec::new();
 
-    match folder_repository::select_folders(pool, user_id).await {
-        Ok(res) => {
-            for folder in res {
-                match folder_repository::select_channels_in_folder(pool, folder.folder_id.unwrap())
-                    .await
-                {
-                    Ok(channels) => result.push(RssFolderResponseDto::new(
-                        folder.folder_id,
-                        folder.folder_name,
-                        channels,
-                    )),
-                    Err(e) => {
-                        folder_error!("[Service] Failed to fetch channels in folder: {}", e);
-                        return Err(OmniNewsError::Database(e));
-                    }
-                }
-            }
-            Ok(result)
-        }
-        Err(e) => {
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
             folder_error!("[Service] Failed to fetch folders: {}", e);
-            Err(OmniNewsError::Database(e))
+            OmniNewsError::Database(e)
+        })?;
+    let mut result: Vec<RssFolderResponseDto> = Vec::with_capacity(folders.len());
+    for folder in folders {
+        match folder_repository::select_channels_in_folder(pool, folder.folder_id.unwrap()).await {
+            Ok(channels) => result.push(RssFolderResponseDto::new(folder, channels)),
+            Err(e) => {
+                folder_error!("[Service] Failed to fetch channels in folder: {}", e);
+                return Err(OmniNewsError::Database(e));
+            }
         }
     }
+
+    let channel_ids: HashSet<i32> = result
+        .iter()
+        .flat_map(|folder| folder.folder_channels.iter().flatten())
+        .filter_map(|channel| channel.channel_id)
+        .collect();
+    let unread_counts: HashMap<i32, i64> = read_repository::select_unread_counts_by_channel_ids(
+        pool,
+        user_id,
+        channel_ids.into_iter().collect(),
+    )
+    .await
+    .map_err(|e| {
+        folder_error!("[Service] Failed to select unread counts: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter_map(|count| Some((count.channel_id?, count.unread_count.unwrap_or_default())))
+    .collect();
+    let latest_pub_dates: HashMap<i32, NaiveDateTime> =
+        folder_repository::select_folder_latest_pub_dates(pool, user_id)
+            .await
+            .map_err(|e| {
+                folder_error!("[Service] Failed to select folder latest pub dates: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|folder| Some((folder.folder_id?, folder.latest_pub_date?)))
+            .collect();
+
+    for folder in result.iter_mut() {
+        folder.unread_count = Some(
+            folder
+                .folder_channels
+                .iter()
+                .flatten()
+                .filter_map(|channel| channel.channel_id)
+                .map(|channel_id| unread_counts.get(&channel_id).copied().unwrap_or_default())
+                .sum(),
+        );
+        folder.latest_pub_date = folder
+            .folder_id
+            .and_then(|folder_id| latest_pub_dates.get(&folder_id).copied());
+    }
+    Ok(result)
+}
+
+/// 폴더에 속한 모든 채널의 아이템을 발행일 순으로 합친 타임라인
+pub async fn get_folder_items(
+    pool: &MySqlPool,
+    user_email: String,
+    folder_id: i32,
+    data: FolderItemRequestDto,
+) -> Result<RssItemPageResponseDto, OmniNewsError> {
+    let limit = page_size(data.size);
+    let ItemCursor::PubDate { pub_date, rss_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_pub_date)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match item order".to_string(),
+        ));
+    };
+
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let channel_ids = read_service::find_user_folder_channel_ids(pool, user_id, folder_id).await?;
+    let unread_only = data.unread_only.unwrap_or(false);
+    let mute_filter = mute_service::find_mute_filter(pool, user_id).await?;
+
+    let items = subscribe_repository::select_subscription_items_before_cursor(
+        pool,
+        channel_ids,
+        pub_date,
+        rss_id,
+        limit + 1,
+        unread_only.then_some(user_id),
+        false,
+    )
+    .await
+    .map_err(|e| {
+        folder_error!("[Service] Failed to select folder items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let (mut items, next_cursor) = split_page(items, limit, ItemCursor::by_pub_date);
+    let hidden_count = mute_filter.retain_visible(&mut items);
+
+    let read_rss_ids = if unread_only {
+        HashSet::new()
+    } else {
+        read_service::find_read_rss_ids(
+            pool,
+            user_id,
+            items.iter().filter_map(|item| item.rss_id).collect(),
+        )
+        .await?
+    };
+    let mut items = RssItemResponseDto::from_model_list(items);
+    for item in items.iter_mut() {
+        item.is_read = item.rss_id.map(|id| read_rss_ids.contains(&id));
+    }
+
+    let mut page = RssItemPageResponseDto::new(items, next_cursor);
+    page.hidden_count = Some(hidden_count);
+    Ok(page)
 }
 
 pub async fn update_folder(
     pool: &MySqlPool,
+    user_email: String,
     folder: UpdateFolderRequestDto,
 ) -> Result<i32, OmniNewsError> {
+    let folder_icon = normalize_folder_icon(folder.folder_icon)?;
+    let folder_color = normalize_folder_color(folder.folder_color)?;
+    let folder_id = folder.folder_id.unwrap();
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
     match folder_repository::update_folder(
         pool,
-        folder.folder_id.unwrap(),
+        user_id,
+        folder_id,
         folder.folder_name.unwrap(),
+        folder_icon,
+        folder_color,
     )
     .await
     {
-        Ok(res) => Ok(res),
+        Ok(res) => {
+            sync_service::record_folder_change(pool, folder_id, kst_now()).await;
+            Ok(res)
+        }
+        Err(sqlx::Error::RowNotFound) => Err(OmniNewsError::NotFound(format!(
+            "Folder not found: {folder_id}"
+        ))),
         Err(e) => {
             folder_error!("[Service] Failed to update folder: {}", e);
             Err(OmniNewsError::Database(e))
@@ -104,11 +276,19 @@ pub async fn update_folder(
 
 pub async fn delete_folder(
     pool: &MySqlPool,
+    user_email: String,
     folder_id: DeleteFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
-    let folder_id = folder_id.folder_id.unwrap();
-    match folder_repository::delete_folder(pool, folder_id).await {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_id = folder_id.folder_id.ok_or(OmniNewsError::InvalidValue(
+        "folder_id is required".to_string(),
+    ))?;
+    let now = kst_now();
+    match folder_repository::delete_folder(pool, user_id, folder_id, now, now).await {
         Ok(_) => Ok(()),
+        Err(sqlx::Error::RowNotFound) => Err(OmniNewsError::NotFound(format!(
+            "Folder not found: {folder_id}"
+        ))),
         Err(e) => {
             folder_error!("[Service] Failed to delete folder: {}", e);
             Err(OmniNewsError::Database(e))
@@ -120,17 +300,656 @@ pub async fn delete_channel_from_folder(
     pool: &MySqlPool,
     channel_folder_id: ChannelFolderRequestDto,
 ) -> Result<(), OmniNewsError> {
+    let folder_id = channel_folder_id.folder_id.unwrap();
     match folder_repository::delete_channel_from_folder(
         pool,
-        channel_folder_id.folder_id.unwrap(),
+        folder_id,
         channel_folder_id.channel_id.unwrap(),
     )
     .await
     {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            sync_service::record_folder_change(pool, folder_id, kst_now()).await;
+            Ok(())
+        }
         Err(e) => {
             folder_error!("[Service] Failed to delete channel from folder: {}", e);
             Err(OmniNewsError::Database(e))
         }
     }
 }
+
+/// 폴더 위치(상위 폴더 포함)와 폴더 안 채널 위치를 한 번에 변경
+pub async fn reorder_folders(
+    pool: &MySqlPool,
+    user_email: String,
+    data: FolderOrderRequestDto,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let folder_positions = data.folders.unwrap_or_default();
+    let channel_positions = data.channels.unwrap_or_default();
+    if folder_positions.len() + channel_positions.len() > MAX_REORDER_ITEMS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_REORDER_ITEMS} positions can be changed at once"
+        )));
+    }
+
+    let mut parents: HashMap<i32, Option<i32>> = find_user_folders(pool, user_id)
+        .await?
+        .into_iter()
+        .filter_map(|folder| Some((folder.folder_id?, folder.folder_parent_id)))
+        .collect();
+
+    let mut folder_updates = Vec::with_capacity(folder_positions.len());
+    for position in folder_positions {
+        let (Some(folder_id), Some(folder_position)) =
+            (position.folder_id, position.folder_position)
+        else {
+            return Err(OmniNewsError::InvalidValue(
+                "folder_id and folder_position are required".to_string(),
+            ));
+        };
+        if !parents.contains_key(&folder_id)
+            || position
+                .folder_parent_id
+                .is_some_and(|parent_id| !parents.contains_key(&parent_id))
+        {
+            return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+        }
+        parents.insert(folder_id, position.folder_parent_id);
+        folder_updates.push((folder_id, position.folder_parent_id, folder_position));
+    }
+    if has_cycle(&parents) {
+        return Err(OmniNewsError::InvalidValue(
+            "A folder cannot be moved into itself or its subfolder".to_string(),
+        ));
+    }
+
+    let mut folder_channel_ids: HashMap<i32, HashSet<i32>> = HashMap::new();
+    let mut channel_updates = Vec::with_capacity(channel_positions.len());
+    for position in channel_positions {
+        let (Some(folder_id), Some(channel_id), Some(channel_position)) = (
+            position.folder_id,
+            position.channel_id,
+            position.channel_position,
+        ) else {
+            return Err(OmniNewsError::InvalidValue(
+                "folder_id, channel_id and channel_position are required".to_string(),
+            ));
+        };
+        if !parents.contains_key(&folder_id) {
+            return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+        }
+        if !folder_channel_ids.contains_key(&folder_id) {
+            let channel_ids = find_folder_channels(pool, folder_id)
+                .await?
+                .into_iter()
+                .filter_map(|channel| channel.channel_id)
+                .collect();
+            folder_channel_ids.insert(folder_id, channel_ids);
+        }
+        if !folder_channel_ids[&folder_id].contains(&channel_id) {
+            return Err(OmniNewsError::NotFound(
+                "Channel not found in folder".to_string(),
+            ));
+        }
+        channel_updates.push((folder_id, channel_id, channel_position));
+    }
+
+    let changed_folder_ids: HashSet<i32> = folder_updates
+        .iter()
+        .map(|(folder_id, _, _)| *folder_id)
+        .chain(channel_updates.iter().map(|(folder_id, _, _)| *folder_id))
+        .collect();
+    folder_repository::update_positions(pool, user_id, folder_updates, channel_updates)
+        .await
+        .map_err(|e| {
+            folder_error!("[Service] Failed to update positions: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let now = kst_now();
+    for folder_id in changed_folder_ids {
+        sync_service::record_change(pool, user_id, SyncEntity::Folder, folder_id, now).await;
+    }
+    Ok(())
+}
+
+/// 폴더 계층과 순서를 유지한 OPML (폴더에 없는 구독 채널은 최상위)
+pub async fn export_opml(pool: &MySqlPool, user_email: String) -> Result<String, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let folders = find_user_folders(pool, user_id).await?;
+    let mut channels_by_folder: HashMap<i32, Vec<RssChannel>> = HashMap::new();
+    for folder in &folders {
+        let folder_id = folder.folder_id.unwrap_or_default();
+        channels_by_folder.insert(folder_id, find_folder_channels(pool, folder_id).await?);
+    }
+    let mut folders_by_parent: HashMap<Option<i32>, Vec<RssFolder>> = HashMap::new();
+    for folder in folders {
+        folders_by_parent
+            .entry(folder.folder_parent_id)
+            .or_default()
+            .push(folder);
+    }
+
+    let foldered_channel_ids: HashSet<i32> = channels_by_folder
+        .values()
+        .flatten()
+        .filter_map(|channel| channel.channel_id)
+        .collect();
+    let root_channels = subscribe_repository::select_subscription_channels(pool, user_id)
+        .await
+        .map_err(|e| {
+            folder_error!("[Service] Failed to select subscription channels: {}", e);
+            OmniNewsError::Database(e)
+        })?
+        .into_iter()
+        .filter(|channel| {
+            channel
+                .channel_id
+                .is_some_and(|id| !foldered_channel_ids.contains(&id))
+        });
+
+    let mut outlines = folder_outlines(None, &folders_by_parent, &channels_by_folder, 0);
+    outlines.extend(root_channels.filter_map(channel_outline));
+    build_opml("Omninews subscriptions", outlines)
+}
+
+/// OPML의 폴더 계층과 순서대로 폴더를 만들고 채널을 구독
+///
+/// 같은 상위 폴더에 같은 이름의 폴더가 있으면 재사용하고, 처음 보는 피드는 새 채널로 등록
+pub async fn import_opml(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    data: OpmlImportRequestDto,
+) -> Result<OpmlImportResponseDto, OmniNewsError> {
+    let outlines = parse_opml(data.opml.as_deref().unwrap_or_default())?;
+    if count_feeds(&outlines) > MAX_IMPORT_FEEDS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_IMPORT_FEEDS} feeds can be imported at once"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let mut folders = find_user_folders(pool, user_id).await?;
+
+    let mut created_folder_count = 0;
+    let mut subscribed_channel_count = 0;
+    let mut failed_links = Vec::new();
+    let mut folder_channel_ids: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+    // 문서 순서대로 처리해야 위치가 유지되므로 역순으로 쌓음
+    let mut stack: Vec<(Option<i32>, OpmlOutline)> =
+        outlines.into_iter().rev().map(|o| (None, o)).collect();
+    while let Some((parent_id, outline)) = stack.pop() {
+        if !outline.is_feed() {
+            let name = outline
+                .name()
+                .chars()
+                .take(MAX_FOLDER_NAME_LENGTH)
+                .collect::<String>();
+            let name = if name.is_empty() {
+                "Imported".to_string()
+            } else {
+                name
+            };
+
+            let existing = folders.iter().find(|folder| {
+                folder.folder_parent_id == parent_id
+                    && folder.folder_name.as_deref() == Some(name.as_str())
+            });
+            let folder_id = match existing.and_then(|folder| folder.folder_id) {
+                Some(folder_id) => folder_id,
+                None => {
+                    let new_folder = NewRssFolder {
+                        user_id: Some(user_id),
+                        folder_name: Some(name.clone()),
+                        folder_parent_id: parent_id,
+                        folder_icon: None,
+                        folder_color: None,
+                    };
+                    let folder_id = folder_repository::insert_folder(pool, new_folder)
+                        .await
+                        .map_err(|e| {
+                            folder_error!("[Service] Failed to create folder: {}", e);
+                            OmniNewsError::Database(e)
+                        })?;
+                    folders.push(RssFolder {
+                        folder_id: Some(folder_id),
+                        folder_name: Some(name),
+                        user_id: Some(user_id),
+                        folder_parent_id: parent_id,
+                        folder_position: None,
+                        folder_icon: None,
+                        folder_color: None,
+                        folder_public_slug: None,
+                        folder_published_at: None,
+                    });
+                    sync_service::record_change(
+                        pool,
+                        user_id,
+                        SyncEntity::Folder,
+                        folder_id,
+                        kst_now(),
+                    )
+                    .await;
+                    created_folder_count += 1;
+                    folder_id
+                }
+            };
+            stack.extend(
+                outline
+                    .children
+                    .into_iter()
+                    .rev()
+                    .map(|child| (Some(folder_id), child)),
+            );
+            continue;
+        }
+
+        let link = outline.xml_url.unwrap_or_default().trim().to_string();
+        let channel_id = match find_or_create_channel(pool, embedding_service, &link).await {
+            Ok(channel_id) => channel_id,
+            Err(e) => {
+                folder_warn!("[Service] Failed to import feed {}: {:?}", link, e);
+                failed_links.push(link);
+                continue;
+            }
+        };
+        if subscribe_if_needed(pool, user_id, channel_id).await? {
+            subscribed_channel_count += 1;
+        }
+
+        let Some(folder_id) = parent_id else {
+            continue;
+        };
+        if !folder_channel_ids.contains_key(&folder_id) {
+            let channel_ids = find_folder_channels(pool, folder_id)
+                .await?
+                .into_iter()
+                .filter_map(|channel| channel.channel_id)
+                .collect();
+            folder_channel_ids.insert(folder_id, channel_ids);
+        }
+        if folder_channel_ids
+            .get_mut(&folder_id)
+            .is_some_and(|channel_ids| channel_ids.insert(channel_id))
+        {
+            folder_repository::insert_channel_to_folder(pool, folder_id, channel_id)
+                .await
+                .map_err(|e| {
+                    folder_error!("[Service] Failed to add channel in folder: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+            sync_service::record_change(pool, user_id, SyncEntity::Folder, folder_id, kst_now())
+                .await;
+        }
+    }
+
+    Ok(OpmlImportResponseDto::new(
+        created_folder_count,
+        subscribed_channel_count,
+        failed_links,
+    ))
+}
+
+/// 폴더를 공개하고 공개 주소를 반환 (이미 공개된 폴더는 기존 주소 유지)
+pub async fn publish_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    folder_id: i32,
+) -> Result<FolderPublishResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let Some(folder) = find_user_folders(pool, user_id)
+        .await?
+        .into_iter()
+        .find(|folder| folder.folder_id == Some(folder_id))
+    else {
+        return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+    };
+
+    if let Some(slug) = folder.folder_public_slug {
+        return Ok(folder_publish_response(slug, folder.folder_published_at));
+    }
+
+    let slug = Uuid::new_v4().simple().to_string();
+    let published_at = kst_now();
+    folder_repository::update_folder_public_slug(
+        pool,
+        user_id,
+        folder_id,
+        Some(slug.clone()),
+        Some(published_at),
+    )
+    .await
+    .map_err(|e| {
+        folder_error!("[Service] Failed to publish folder: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    sync_service::record_change(pool, user_id, SyncEntity::Folder, folder_id, published_at).await;
+    Ok(folder_publish_response(slug, Some(published_at)))
+}
+
+/// 공개를 중단하면 기존 공개 주소는 더 이상 접근할 수 없음
+pub async fn unpublish_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    folder_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match folder_repository::update_folder_public_slug(pool, user_id, folder_id, None, None).await {
+        Ok(true) => {
+            sync_service::record_change(pool, user_id, SyncEntity::Folder, folder_id, kst_now())
+                .await;
+            Ok(())
+        }
+        Ok(false) => Err(OmniNewsError::NotFound("Folder not found".to_string())),
+        Err(e) => {
+            folder_error!("[Service] Failed to unpublish folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 공개 폴더 페이지 (로그인 없이 슬러그로 접근)
+pub async fn get_public_folder(
+    pool: &MySqlPool,
+    slug: &str,
+) -> Result<PublicFolderResponseDto, OmniNewsError> {
+    let (folder, channels, items) = find_public_folder(pool, slug).await?;
+
+    Ok(PublicFolderResponseDto::new(
+        folder,
+        channels,
+        items,
+        public_feed_url(&format!("/public/folder/{slug}/rss")),
+        public_feed_url(&format!("/public/folder/{slug}/atom")),
+    ))
+}
+
+/// 공개 폴더 채널들의 최신 아이템을 합친 RSS 2.0 피드
+pub async fn get_public_folder_rss(pool: &MySqlPool, slug: &str) -> Result<String, OmniNewsError> {
+    let (folder, _, items) = find_public_folder(pool, slug).await?;
+    let name = folder.folder_name.unwrap_or_default();
+
+    Ok(build_rss_feed(
+        &format!("Omninews - {name}"),
+        &public_feed_url(&format!("/public/folder/{slug}")),
+        &format!("Shared folder: {name}"),
+        items,
+    ))
+}
+
+/// 공개 폴더 채널들의 최신 아이템을 합친 Atom 피드
+pub async fn get_public_folder_atom(pool: &MySqlPool, slug: &str) -> Result<String, OmniNewsError> {
+    let (folder, _, items) = find_public_folder(pool, slug).await?;
+
+    build_atom_feed(
+        &format!("Omninews - {}", folder.folder_name.unwrap_or_default()),
+        &public_feed_url(&format!("/public/folder/{slug}")),
+        &public_feed_url(&format!("/public/folder/{slug}/atom")),
+        items,
+    )
+}
+
+/// 공개 폴더의 채널을 모두 구독하고 새로 구독한 채널 수를 반환
+pub async fn subscribe_public_folder(
+    pool: &MySqlPool,
+    user_email: String,
+    slug: &str,
+) -> Result<i32, OmniNewsError> {
+    let folder = find_folder_by_public_slug(pool, slug).await?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let mut subscribed_channel_count = 0;
+    for channel in find_folder_channels(pool, folder.folder_id.unwrap_or_default()).await? {
+        let Some(channel_id) = channel.channel_id else {
+            continue;
+        };
+        if subscribe_if_needed(pool, user_id, channel_id).await? {
+            subscribed_channel_count += 1;
+        }
+    }
+    Ok(subscribed_channel_count)
+}
+
+async fn find_folder_by_public_slug(
+    pool: &MySqlPool,
+    slug: &str,
+) -> Result<RssFolder, OmniNewsError> {
+    match folder_repository::select_folder_by_public_slug(pool, slug).await {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(OmniNewsError::NotFound(
+            "Public folder not found".to_string(),
+        )),
+        Err(e) => {
+            folder_error!("[Service] Failed to select folder by public slug: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 공개 폴더와 채널 목록, 채널들의 최신 아이템
+/// 로그인 없이 보이는 페이지, 피드에 쓰이므로 http, https가 아닌 링크는 제거
+async fn find_public_folder(
+    pool: &MySqlPool,
+    slug: &str,
+) -> Result<(RssFolder, Vec<RssChannel>, Vec<RssItem>), OmniNewsError> {
+    let folder = find_folder_by_public_slug(pool, slug).await?;
+    let mut channels = find_folder_channels(pool, folder.folder_id.unwrap_or_default()).await?;
+    for channel in channels.iter_mut() {
+        channel.channel_link = safe_link(channel.channel_link.take());
+        channel.channel_image_url = safe_link(channel.channel_image_url.take());
+    }
+    let channel_ids: Vec<i32> = channels.iter().filter_map(|c| c.channel_id).collect();
+    if channel_ids.is_empty() {
+        return Ok((folder, channels, vec![]));
+    }
+
+    let mut items = subscribe_repository::select_subscription_items(
+        pool,
+        channel_ids,
+        0,
+        PUBLIC_FEED_ITEM_LIMIT,
+        false,
+    )
+    .await
+    .map_err(|e| {
+        folder_error!("[Service] Failed to select public folder items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    for item in items.iter_mut() {
+        item.rss_link = safe_link(item.rss_link.take());
+        item.rss_image_link = safe_link(item.rss_image_link.take());
+    }
+    Ok((folder, channels, items))
+}
+
+fn folder_publish_response(
+    slug: String,
+    published_at: Option<NaiveDateTime>,
+) -> FolderPublishResponseDto {
+    let page_url = public_feed_url(&format!("/public/folder/{slug}"));
+    let rss_url = public_feed_url(&format!("/public/folder/{slug}/rss"));
+    let atom_url = public_feed_url(&format!("/public/folder/{slug}/atom"));
+    FolderPublishResponseDto::new(slug, page_url, rss_url, atom_url, published_at)
+}
+
+async fn find_user_folders(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<RssFolder>, OmniNewsError> {
+    match folder_repository::select_folders(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            folder_error!("[Service] Failed to fetch folders: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_folder_channels(
+    pool: &MySqlPool,
+    folder_id: i32,
+) -> Result<Vec<RssChannel>, OmniNewsError> {
+    match folder_repository::select_channels_in_folder(pool, folder_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            folder_error!("[Service] Failed to fetch channels in folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_or_create_channel(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    link: &str,
+) -> Result<i32, OmniNewsError> {
+    match rss_channel_repository::select_rss_channel_by_rss_link(pool, link.to_string()).await {
+        Ok(channel) => Ok(channel.channel_id.unwrap_or_default()),
+        Err(sqlx::Error::RowNotFound) => {
+            channel_service::create_rss_and_embedding(pool, embedding_service, link.to_string())
+                .await
+        }
+        Err(e) => Err(OmniNewsError::Database(e)),
+    }
+}
+
+/// 새로 구독했으면 true
+async fn subscribe_if_needed(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: i32,
+) -> Result<bool, OmniNewsError> {
+    let subscribed = subscribe_repository::is_already_subscribe_channel(pool, user_id, channel_id)
+        .await
+        .map_err(|e| {
+            folder_error!("[Service] Failed to check subscription: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if subscribed {
+        return Ok(false);
+    }
+
+    subscribe_repository::insert_user_subscribe_channel(pool, user_id, channel_id)
+        .await
+        .map_err(|e| {
+            folder_error!("[Service] Failed to subscribe channel: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    channel_service::update_rss_channel_rank(pool, channel_id, 1).await?;
+    sync_service::record_change(
+        pool,
+        user_id,
+        SyncEntity::Subscription,
+        channel_id,
+        kst_now(),
+    )
+    .await;
+    Ok(true)
+}
+
+fn folder_outlines(
+    parent_id: Option<i32>,
+    folders_by_parent: &HashMap<Option<i32>, Vec<RssFolder>>,
+    channels_by_folder: &HashMap<i32, Vec<RssChannel>>,
+    depth: usize,
+) -> Vec<OpmlOutline> {
+    if depth >= MAX_FOLDER_DEPTH {
+        return vec![];
+    }
+
+    folders_by_parent
+        .get(&parent_id)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|folder| {
+            let folder_id = folder.folder_id.unwrap_or_default();
+            let mut children = folder_outlines(
+                Some(folder_id),
+                folders_by_parent,
+                channels_by_folder,
+                depth + 1,
+            );
+            children.extend(
+                channels_by_folder
+                    .get(&folder_id)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(channel_outline),
+            );
+            OpmlOutline::folder(folder.folder_name.clone().unwrap_or_default(), children)
+        })
+        .collect()
+}
+
+fn channel_outline(channel: RssChannel) -> Option<OpmlOutline> {
+    Some(OpmlOutline::feed(
+        channel.channel_title.unwrap_or_default(),
+        channel.channel_rss_link?,
+        channel.channel_link,
+    ))
+}
+
+fn count_feeds(outlines: &[OpmlOutline]) -> usize {
+    outlines
+        .iter()
+        .map(|outline| {
+            if outline.is_feed() {
+                1
+            } else {
+                count_feeds(&outline.children)
+            }
+        })
+        .sum()
+}
+
+/// 상위 폴더를 따라가다 자기 자신으로 돌아오면 순환
+fn has_cycle(parents: &HashMap<i32, Option<i32>>) -> bool {
+    parents.keys().any(|&start| {
+        let mut current = parents.get(&start).copied().flatten();
+        let mut steps = 0;
+        while let Some(folder_id) = current {
+            if folder_id == start || steps > parents.len() {
+                return true;
+            }
+            current = parents.get(&folder_id).copied().flatten();
+            steps += 1;
+        }
+        false
+    })
+}
+
+/// 빈 문자열은 삭제 요청으로 유지
+fn normalize_folder_icon(icon: Option<String>) -> Result<Option<String>, OmniNewsError> {
+    let Some(icon) = icon.map(|icon| icon.trim().to_string()) else {
+        return Ok(None);
+    };
+    if icon.chars().count() > MAX_FOLDER_ICON_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "folder_icon must be at most {MAX_FOLDER_ICON_LENGTH} characters"
+        )));
+    }
+    Ok(Some(icon))
+}
+
+/// `#RRGGBB` 형식만 허용 (빈 문자열은 삭제 요청으로 유지)
+fn normalize_folder_color(color: Option<String>) -> Result<Option<String>, OmniNewsError> {
+    let Some(color) = color.map(|color| color.trim().to_uppercase()) else {
+        return Ok(None);
+    };
+    let is_hex_color = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !color.is_empty() && !is_hex_color {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Invalid folder_color: {color}"
+        )));
+    }
+    Ok(Some(color))
+}
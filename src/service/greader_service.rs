@@ -0,0 +1,708 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        bookmark::request::SaveBookmarkRequestDto,
+        greader::{
+            request::{
+                GReaderEditTagRequestDto, GReaderItemIdsRequestDto, GReaderLoginRequestDto,
+                GReaderMarkAllReadRequestDto, GReaderStreamRequestDto,
+            },
+            response::{
+                feed_stream_id, label_stream_id, GReaderItemIdsResponseDto,
+                GReaderItemRefResponseDto, GReaderItemResponseDto,
+                GReaderStreamContentsResponseDto, GReaderSubscriptionListResponseDto,
+                GReaderSubscriptionResponseDto, GReaderTagListResponseDto, GReaderTagResponseDto,
+                GReaderUnreadCountListResponseDto, GReaderUnreadCountResponseDto,
+                GReaderUserInfoResponseDto, ITEM_ID_PREFIX,
+            },
+        },
+    },
+    model::{error::OmniNewsError, rss::RssItem},
+    repository::{
+        bookmark_repository, folder_repository, read_repository, rss_channel_repository,
+        rss_item_repository, subscribe_repository,
+    },
+    subscription_error,
+    utils::{
+        cursor_util::{split_page, ItemCursor},
+        time_util::kst_from_timestamp,
+    },
+};
+
+use super::{app_password_service, bookmark_service, mute_service, read_service, user_service};
+
+// Google Reader API의 스트림을 구독 채널, 폴더(라벨), 읽음/북마크 상태에 대응
+// reading-list: 구독 채널 전체, starred: 북마크, feed/<채널>: 구독 채널 하나, label/<폴더 이름>: 같은 이름의 폴더
+
+const READING_LIST: &str = "user/-/state/com.google/reading-list";
+const READ: &str = "user/-/state/com.google/read";
+const KEPT_UNREAD: &str = "user/-/state/com.google/kept-unread";
+const STARRED: &str = "user/-/state/com.google/starred";
+const LABEL_PREFIX: &str = "user/-/label/";
+const FEED_PREFIX: &str = "feed/";
+
+const DEFAULT_COUNT: i32 = 20;
+const MAX_CONTENTS_COUNT: i32 = 1000;
+const MAX_IDS_COUNT: i32 = 10000;
+
+enum Stream {
+    Channels(Vec<i32>),
+    Starred,
+}
+
+/// 비밀번호 자리에 앱 비밀번호를 받아 소유자의 이메일인지 확인
+///
+/// 클라이언트는 `Auth` 값을 `Authorization: GoogleLogin auth=<값>`으로 보내므로 앱 비밀번호를 그대로 돌려줌
+/// (앱 비밀번호를 폐기하면 이후 요청은 인증되지 않음)
+pub async fn client_login(
+    pool: &MySqlPool,
+    data: GReaderLoginRequestDto,
+) -> Result<String, OmniNewsError> {
+    let (Some(email), Some(app_password)) = (data.email, data.passwd) else {
+        return Err(OmniNewsError::InvalidValue(
+            "Email and Passwd are required".to_string(),
+        ));
+    };
+    let app_password = app_password.trim();
+    let user_id = app_password_service::find_user_id_by_app_password(pool, app_password).await?;
+    let user_email = user_service::find_user_email_by_id(pool, user_id).await?;
+    if !user_email.eq_ignore_ascii_case(email.trim()) {
+        return Err(OmniNewsError::NotFound(
+            "App password not found".to_string(),
+        ));
+    }
+
+    Ok(format!(
+        "SID={app_password}\nLSID={app_password}\nAuth={app_password}\n"
+    ))
+}
+
+pub async fn get_user_info(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<GReaderUserInfoResponseDto, OmniNewsError> {
+    let user_email = user_service::find_user_email_by_id(pool, user_id).await?;
+
+    Ok(GReaderUserInfoResponseDto::new(user_id, user_email))
+}
+
+pub async fn get_subscription_list(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<GReaderSubscriptionListResponseDto, OmniNewsError> {
+    let channels = subscribe_repository::select_subscription_channels(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select subscription channels: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let mut channel_labels = find_channel_labels(pool, user_id).await?;
+
+    let subscriptions = channels
+        .into_iter()
+        .map(|channel| {
+            let labels = channel
+                .channel_id
+                .and_then(|channel_id| channel_labels.remove(&channel_id))
+                .unwrap_or_default();
+            GReaderSubscriptionResponseDto::new(channel, labels)
+        })
+        .collect();
+
+    Ok(GReaderSubscriptionListResponseDto { subscriptions })
+}
+
+/// 별표 상태와 폴더 이름(라벨) 목록
+pub async fn get_tag_list(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<GReaderTagListResponseDto, OmniNewsError> {
+    let labels = find_folder_names(pool, user_id).await?;
+
+    let mut tags = vec![GReaderTagResponseDto::state(STARRED)];
+    tags.extend(
+        labels
+            .iter()
+            .map(|label| GReaderTagResponseDto::label(label)),
+    );
+
+    Ok(GReaderTagListResponseDto { tags })
+}
+
+/// 채널, 라벨, reading-list별 읽지 않은 아이템 수
+pub async fn get_unread_count(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<GReaderUnreadCountListResponseDto, OmniNewsError> {
+    let channel_ids = find_subscription_channel_ids(pool, user_id).await?;
+    let counts = read_repository::select_unread_counts_by_channel_ids(pool, user_id, channel_ids)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select unread counts: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let channel_labels = find_channel_labels(pool, user_id).await?;
+
+    let mut total = 0;
+    let mut label_counts: HashMap<String, i64> = HashMap::new();
+    let mut unreadcounts = vec![];
+    for count in counts {
+        let (Some(channel_id), Some(unread_count)) = (count.channel_id, count.unread_count) else {
+            continue;
+        };
+        total += unread_count;
+        for label in channel_labels.get(&channel_id).into_iter().flatten() {
+            *label_counts.entry(label.clone()).or_default() += unread_count;
+        }
+        unreadcounts.push(GReaderUnreadCountResponseDto {
+            id: feed_stream_id(channel_id),
+            count: unread_count,
+        });
+    }
+    unreadcounts.extend(label_counts.into_iter().map(|(label, count)| {
+        GReaderUnreadCountResponseDto {
+            id: label_stream_id(&label),
+            count,
+        }
+    }));
+    unreadcounts.push(GReaderUnreadCountResponseDto {
+        id: READING_LIST.to_string(),
+        count: total,
+    });
+
+    Ok(GReaderUnreadCountListResponseDto {
+        max: MAX_CONTENTS_COUNT as i64,
+        unreadcounts,
+    })
+}
+
+pub async fn get_stream_contents(
+    pool: &MySqlPool,
+    user_id: i32,
+    stream_id: String,
+    data: GReaderStreamRequestDto,
+) -> Result<GReaderStreamContentsResponseDto, OmniNewsError> {
+    let stream_id = match stream_id.trim() {
+        "" => READING_LIST.to_string(),
+        stream_id => normalize_stream_id(stream_id),
+    };
+    let stream = find_stream(pool, user_id, &stream_id).await?;
+    let (items, continuation) =
+        find_stream_items(pool, user_id, stream, data, MAX_CONTENTS_COUNT).await?;
+    let items = build_items(pool, user_id, items).await?;
+
+    Ok(GReaderStreamContentsResponseDto {
+        id: stream_id,
+        updated: Utc::now().timestamp(),
+        items,
+        continuation,
+    })
+}
+
+pub async fn get_item_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: GReaderStreamRequestDto,
+) -> Result<GReaderItemIdsResponseDto, OmniNewsError> {
+    let stream_id = normalize_stream_id(data.s.as_deref().unwrap_or(READING_LIST));
+    let stream = find_stream(pool, user_id, &stream_id).await?;
+    let (items, continuation) =
+        find_stream_items(pool, user_id, stream, data, MAX_IDS_COUNT).await?;
+
+    Ok(GReaderItemIdsResponseDto {
+        item_refs: items.iter().map(GReaderItemRefResponseDto::new).collect(),
+        continuation,
+    })
+}
+
+/// 요청한 아이템 ID 순서대로 내용 조회 (없는 아이템은 제외)
+pub async fn get_item_contents(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: GReaderItemIdsRequestDto,
+) -> Result<GReaderStreamContentsResponseDto, OmniNewsError> {
+    let rss_ids = parse_item_ids(&data.i)?;
+    if rss_ids.len() > MAX_CONTENTS_COUNT as usize {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_CONTENTS_COUNT} items can be requested"
+        )));
+    }
+
+    let mut items: HashMap<i32, RssItem> =
+        rss_item_repository::select_rss_items_by_ids(pool, rss_ids.clone())
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select rss items: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|item| item.rss_id.map(|rss_id| (rss_id, item)))
+            .collect();
+    let items = rss_ids
+        .iter()
+        .filter_map(|rss_id| items.remove(rss_id))
+        .collect();
+    let items = build_items(pool, user_id, items).await?;
+
+    Ok(GReaderStreamContentsResponseDto {
+        id: READING_LIST.to_string(),
+        updated: Utc::now().timestamp(),
+        items,
+        continuation: None,
+    })
+}
+
+/// 읽음(kept-unread 포함)과 별표 상태만 반영하고 그 밖의 태그는 무시
+pub async fn edit_tag(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: GReaderEditTagRequestDto,
+) -> Result<(), OmniNewsError> {
+    let rss_ids = parse_item_ids(&data.i)?;
+    if rss_ids.len() > MAX_CONTENTS_COUNT as usize {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_CONTENTS_COUNT} items can be edited"
+        )));
+    }
+    let adds: Vec<String> = data
+        .a
+        .unwrap_or_default()
+        .iter()
+        .map(|tag| normalize_stream_id(tag))
+        .collect();
+    let removes: Vec<String> = data
+        .r
+        .unwrap_or_default()
+        .iter()
+        .map(|tag| normalize_stream_id(tag))
+        .collect();
+
+    for rss_id in rss_ids {
+        for tag in &adds {
+            match tag.as_str() {
+                READ => read_service::mark_item_read_by_user_id(pool, user_id, rss_id).await?,
+                KEPT_UNREAD => {
+                    read_service::mark_item_unread_by_user_id(pool, user_id, rss_id).await?
+                }
+                STARRED => {
+                    bookmark_service::save_bookmark_by_user_id(
+                        pool,
+                        user_id,
+                        SaveBookmarkRequestDto {
+                            rss_id: Some(rss_id),
+                            collection_id: None,
+                            bookmark_note: None,
+                            tags: None,
+                        },
+                    )
+                    .await?;
+                }
+                _ => {}
+            }
+        }
+        for tag in &removes {
+            match tag.as_str() {
+                READ => read_service::mark_item_unread_by_user_id(pool, user_id, rss_id).await?,
+                STARRED => {
+                    // 이미 북마크가 없으면 그대로 성공 처리
+                    bookmark_service::unsave_bookmark_by_user_id(pool, user_id, rss_id).await?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `ts`(마이크로초)가 있으면 그 이전에 발행된 아이템만 읽음 처리
+pub async fn mark_all_as_read(
+    pool: &MySqlPool,
+    user_id: i32,
+    data: GReaderMarkAllReadRequestDto,
+) -> Result<(), OmniNewsError> {
+    let Some(stream_id) = data.s else {
+        return Err(OmniNewsError::InvalidValue("s is required".to_string()));
+    };
+    let before = match data.ts {
+        Some(ts) => Some(kst_from_timestamp(ts / 1_000_000)?),
+        None => None,
+    };
+
+    match find_stream(pool, user_id, &normalize_stream_id(&stream_id)).await? {
+        Stream::Channels(channel_ids) => {
+            read_service::mark_channels_read(pool, user_id, channel_ids, before).await
+        }
+        Stream::Starred => Err(OmniNewsError::InvalidValue(
+            "Starred stream cannot be marked as read".to_string(),
+        )),
+    }
+}
+
+/// 스트림의 아이템과 다음 `continuation`
+///
+/// `xt`가 읽음 상태면 읽지 않은 아이템만, `ot`가 있으면 그 이후 발행된 아이템만 조회
+async fn find_stream_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    stream: Stream,
+    data: GReaderStreamRequestDto,
+    max_count: i32,
+) -> Result<(Vec<RssItem>, Option<String>), OmniNewsError> {
+    let limit = data.n.unwrap_or(DEFAULT_COUNT).clamp(1, max_count);
+    let cursor = data.c.as_deref().map(ItemCursor::decode).transpose()?;
+    let unread_only = data
+        .xt
+        .as_deref()
+        .is_some_and(|xt| normalize_stream_id(xt) == READ);
+    let oldest = match data.ot {
+        Some(ot) => Some(kst_from_timestamp(ot)?),
+        None => None,
+    };
+
+    let (mut items, mut continuation) = match stream {
+        Stream::Channels(channel_ids) => {
+            let ItemCursor::PubDate { pub_date, rss_id } =
+                cursor.unwrap_or_else(ItemCursor::first_by_pub_date)
+            else {
+                return Err(OmniNewsError::InvalidValue(
+                    "Continuation does not match stream".to_string(),
+                ));
+            };
+            let items = subscribe_repository::select_subscription_items_before_cursor(
+                pool,
+                channel_ids,
+                pub_date,
+                rss_id,
+                limit + 1,
+                unread_only.then_some(user_id),
+                false,
+            )
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select stream items: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            split_page(items, limit, ItemCursor::by_pub_date)
+        }
+        Stream::Starred => {
+            let ItemCursor::Id { id } = cursor.unwrap_or_else(ItemCursor::first_by_id) else {
+                return Err(OmniNewsError::InvalidValue(
+                    "Continuation does not match stream".to_string(),
+                ));
+            };
+            let items = bookmark_repository::select_bookmarked_items_before_rss_id(
+                pool,
+                user_id,
+                id,
+                limit + 1,
+            )
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select bookmarked items: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            let (mut items, continuation) = split_page(items, limit, |item| ItemCursor::Id {
+                id: item.rss_id.unwrap_or_default(),
+            });
+            if unread_only {
+                let read_rss_ids = read_service::find_read_rss_ids(
+                    pool,
+                    user_id,
+                    items.iter().filter_map(|item| item.rss_id).collect(),
+                )
+                .await?;
+                items.retain(|item| {
+                    !item
+                        .rss_id
+                        .is_some_and(|rss_id| read_rss_ids.contains(&rss_id))
+                });
+            }
+            (items, continuation)
+        }
+    };
+
+    if let Some(oldest) = oldest {
+        let count = items.len();
+        items.retain(|item| item.rss_pub_date.is_some_and(|pub_date| pub_date >= oldest));
+        // 발행일 내림차순이므로 기준보다 오래된 아이템이 나오면 다음 페이지도 없음
+        if items.len() < count {
+            continuation = None;
+        }
+    }
+    mute_service::find_mute_filter(pool, user_id)
+        .await?
+        .retain_visible(&mut items);
+
+    Ok((items, continuation))
+}
+
+/// 아이템마다 reading-list, 라벨, 읽음, 별표 상태를 붙임
+async fn build_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    items: Vec<RssItem>,
+) -> Result<Vec<GReaderItemResponseDto>, OmniNewsError> {
+    if items.is_empty() {
+        return Ok(vec![]);
+    }
+    let rss_ids: Vec<i32> = items.iter().filter_map(|item| item.rss_id).collect();
+    let channel_ids: Vec<i32> = items
+        .iter()
+        .filter_map(|item| item.channel_id)
+        .collect::<BTreeSet<i32>>()
+        .into_iter()
+        .collect();
+
+    let read_rss_ids = read_service::find_read_rss_ids(pool, user_id, rss_ids.clone()).await?;
+    let starred_rss_ids: HashSet<i32> =
+        bookmark_repository::select_bookmarked_rss_ids(pool, user_id, rss_ids)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select bookmarked rss ids: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect();
+    let channels: HashMap<i32, _> =
+        rss_channel_repository::select_rss_channels_by_ids(pool, channel_ids)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select rss channels: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|channel| channel.channel_id.map(|channel_id| (channel_id, channel)))
+            .collect();
+    let channel_labels = find_channel_labels(pool, user_id).await?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let rss_id = item.rss_id.unwrap_or_default();
+            let channel_id = item.channel_id.unwrap_or_default();
+
+            let mut categories = vec![READING_LIST.to_string()];
+            categories.extend(
+                channel_labels
+                    .get(&channel_id)
+                    .into_iter()
+                    .flatten()
+                    .map(|label| label_stream_id(label)),
+            );
+            if read_rss_ids.contains(&rss_id) {
+                categories.push(READ.to_string());
+            }
+            if starred_rss_ids.contains(&rss_id) {
+                categories.push(STARRED.to_string());
+            }
+
+            GReaderItemResponseDto::new(item, categories, channels.get(&channel_id))
+        })
+        .collect())
+}
+
+/// 스트림 ID를 구독 채널 목록 또는 북마크로 변환
+async fn find_stream(
+    pool: &MySqlPool,
+    user_id: i32,
+    stream_id: &str,
+) -> Result<Stream, OmniNewsError> {
+    if stream_id == READING_LIST {
+        return Ok(Stream::Channels(
+            find_subscription_channel_ids(pool, user_id).await?,
+        ));
+    }
+    if stream_id == STARRED {
+        return Ok(Stream::Starred);
+    }
+
+    if let Some(label) = stream_id.strip_prefix(LABEL_PREFIX) {
+        let channel_ids: BTreeSet<i32> = find_channel_labels(pool, user_id)
+            .await?
+            .into_iter()
+            .filter(|(_, labels)| labels.iter().any(|name| name == label))
+            .map(|(channel_id, _)| channel_id)
+            .collect();
+        if channel_ids.is_empty() && !find_folder_names(pool, user_id).await?.contains(label) {
+            return Err(OmniNewsError::NotFound("Label not found".to_string()));
+        }
+        return Ok(Stream::Channels(channel_ids.into_iter().collect()));
+    }
+
+    if let Some(feed) = stream_id.strip_prefix(FEED_PREFIX) {
+        // 피드 ID는 채널 ID, 다른 클라이언트에서 가져온 경우 피드 주소일 수 있음
+        let channel_id = match feed.parse::<i32>() {
+            Ok(channel_id) => channel_id,
+            Err(_) => {
+                match rss_channel_repository::select_rss_channel_by_rss_link(pool, feed.to_string())
+                    .await
+                {
+                    Ok(channel) => channel.channel_id.unwrap_or_default(),
+                    Err(sqlx::Error::RowNotFound) => {
+                        return Err(OmniNewsError::NotFound("Feed not found".to_string()));
+                    }
+                    Err(e) => {
+                        subscription_error!("[Service] Failed to select rss channel: {}", e);
+                        return Err(OmniNewsError::Database(e));
+                    }
+                }
+            }
+        };
+        let subscribed =
+            subscribe_repository::is_already_subscribe_channel(pool, user_id, channel_id)
+                .await
+                .map_err(|e| {
+                    subscription_error!("[Service] Failed to check subscription: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+        if !subscribed {
+            return Err(OmniNewsError::NotFound(
+                "Subscribed feed not found".to_string(),
+            ));
+        }
+        return Ok(Stream::Channels(vec![channel_id]));
+    }
+
+    Err(OmniNewsError::InvalidValue(format!(
+        "Unsupported stream: {stream_id}"
+    )))
+}
+
+async fn find_subscription_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<i32>, OmniNewsError> {
+    match subscribe_repository::select_subscription_channel_ids(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select subscription channel ids: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_folder_names(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<BTreeSet<String>, OmniNewsError> {
+    match folder_repository::select_folders(pool, user_id).await {
+        Ok(res) => Ok(res
+            .into_iter()
+            .filter_map(|folder| folder.folder_name)
+            .collect()),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 채널 ID별 채널이 속한 폴더 이름 (폴더 계층은 라벨로 표현하지 않음)
+async fn find_channel_labels(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<HashMap<i32, Vec<String>>, OmniNewsError> {
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut channel_labels: HashMap<i32, Vec<String>> = HashMap::new();
+    for folder in folders {
+        let (Some(folder_id), Some(folder_name)) = (folder.folder_id, folder.folder_name) else {
+            continue;
+        };
+        let channels = folder_repository::select_channels_in_folder(pool, folder_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select channels in folder: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+        for channel_id in channels
+            .into_iter()
+            .filter_map(|channel| channel.channel_id)
+        {
+            let labels = channel_labels.entry(channel_id).or_default();
+            if !labels.contains(&folder_name) {
+                labels.push(folder_name.clone());
+            }
+        }
+    }
+
+    Ok(channel_labels)
+}
+
+/// `user/<사용자 ID>/...`를 `user/-/...`로 통일
+fn normalize_stream_id(stream_id: &str) -> String {
+    let stream_id = stream_id.trim();
+    match stream_id
+        .strip_prefix("user/")
+        .and_then(|rest| rest.split_once('/'))
+    {
+        Some((_, rest)) => format!("user/-/{rest}"),
+        None => stream_id.to_string(),
+    }
+}
+
+/// 긴 형식(`tag:google.com,2005:reader/item/<16진수>`), 10진수, 16자리 16진수 아이템 ID
+fn parse_item_ids(ids: &[String]) -> Result<Vec<i32>, OmniNewsError> {
+    ids.iter()
+        .map(|id| {
+            let id = id.trim();
+            let parsed = match id.strip_prefix(ITEM_ID_PREFIX) {
+                Some(hex) => i64::from_str_radix(hex, 16).ok(),
+                None if id.len() == 16 => i64::from_str_radix(id, 16).ok(),
+                None => id.parse::<i64>().ok(),
+            };
+            parsed
+                .and_then(|id| i32::try_from(id).ok())
+                .ok_or_else(|| OmniNewsError::InvalidValue(format!("Invalid item id: {id}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn normalize_stream_id_replaces_user_id() {
+        assert_eq!(
+            normalize_stream_id("user/1234/state/com.google/read"),
+            "user/-/state/com.google/read"
+        );
+        assert_eq!(normalize_stream_id(" user/-/label/IT "), "user/-/label/IT");
+        assert_eq!(normalize_stream_id("feed/12"), "feed/12");
+    }
+
+    #[test]
+    fn parse_item_ids_accepts_long_hex_and_decimal_forms() {
+        assert_eq!(
+            parse_item_ids(&ids(&[
+                "tag:google.com,2005:reader/item/0000000000003039",
+                "0000000000003039",
+                "12345",
+            ]))
+            .unwrap(),
+            vec![12345, 12345, 12345]
+        );
+    }
+
+    #[test]
+    fn parse_item_ids_rejects_invalid_or_out_of_range_ids() {
+        assert!(parse_item_ids(&ids(&["tag:google.com,2005:reader/item/xyz"])).is_err());
+        assert!(parse_item_ids(&ids(&["00000000ffffffff"])).is_err());
+        assert!(parse_item_ids(&ids(&["12345", "abc"])).is_err());
+    }
+}
@@ -1,8 +1,13 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::{
     dto::{
         rss::{
-            request::{RssItemRequestDto, UpdateRssRankRequestDto},
-            response::RssItemResponseDto,
+            request::{RelatedRssItemRequestDto, RssItemRequestDto, UpdateRssRankRequestDto},
+            response::{
+                RelatedRssItemResponseDto, RssItemListResponseDto, RssItemPageResponseDto,
+                RssItemResponseDto,
+            },
         },
         search::{request::SearchRequestDto, response::SearchResponseDto},
     },
@@ -12,12 +17,24 @@ use crate::{
         rss::{NewRssItem, RssItem},
         search::SearchType,
     },
-    repository::rss_item_repository,
+    repository::{
+        embedding_repository, rss_channel_repository, rss_item_repository, story_repository,
+    },
     rss_error, rss_warn,
-    service::embedding_service,
-    utils::{annoy_util::load_rss_annoy, embedding_util::EmbeddingService},
+    service::{
+        embedding_service, feed_rule_service,
+        mute_service::{self, MuteFilter},
+        notification_service, saved_search_service, search_service, story_service,
+    },
+    utils::{
+        annoy_util::{find_rss_annoy_by_vector, load_rss_annoy},
+        cursor_util::{page_size, split_page, ItemCursor},
+        embedding_util::{cosine_similarity, decode_embedding, EmbeddingService},
+        language_util::detect_language,
+    },
 };
 use chrono::{DateTime, NaiveDateTime};
+use rand::{rng, seq::SliceRandom};
 use rss::{Channel, Item};
 use scraper::{Html, Selector};
 use sqlx::MySqlPool;
@@ -33,12 +50,20 @@ pub async fn create_rss_items_and_embedding(
         .image()
         .map_or(String::new(), |image| image.url().to_string()); // TODO rss cateogory 미구현 상태.
 
+    // 아이템 언어 감지 실패 시 채널 언어 사용
+    let channel_language = rss_channel_repository::select_rss_channel_by_id(pool, channel_id)
+        .await
+        .ok()
+        .and_then(|channel| channel.channel_language);
+
+    let mut rss_ids = Vec::new();
     for (i, rss_item) in channel.items_mut().iter_mut().enumerate() {
-        create_rss_item_and_embedding(
+        let rss_id = create_rss_item_and_embedding(
             pool,
             embedding_service,
             channel_id,
             channel_image_url.clone(),
+            channel_language.clone(),
             if let Some(links) = &item_image_links {
                 links.get(i).cloned()
             } else {
@@ -47,6 +72,17 @@ pub async fn create_rss_items_and_embedding(
             rss_item,
         )
         .await?;
+        rss_ids.push(rss_id);
+    }
+
+    if let Err(e) = saved_search_service::record_saved_search_hits(pool, rss_ids.clone()).await {
+        rss_warn!("[Service] Failed to record saved search hits: {:?}", e);
+    }
+    if let Err(e) = feed_rule_service::apply_feed_rules(pool, rss_ids.clone()).await {
+        rss_warn!("[Service] Failed to apply feed rules: {:?}", e);
+    }
+    if let Err(e) = notification_service::notify_new_items(pool, channel_id, rss_ids).await {
+        rss_warn!("[Service] Failed to queue push notifications: {:?}", e);
     }
     Ok(())
 }
@@ -55,9 +91,10 @@ pub async fn create_rss_item_and_embedding(
     embedding_service: &EmbeddingService,
     channel_id: i32,
     channel_image_url: String,
+    channel_language: Option<String>,
     item_image_link: Option<String>,
     rss_item: &mut Item,
-) -> Result<bool, OmniNewsError> {
+) -> Result<i32, OmniNewsError> {
     let description = rss_item.description().unwrap_or("None");
     let extracted_description = extract_html_passage(description);
 
@@ -71,15 +108,22 @@ pub async fn create_rss_item_and_embedding(
 
     let item_image_link = use_channel_url_if_none(item_image_link, channel_image_url.clone());
 
-    let item = match make_rss_item(channel_id, rss_item, item_image_link) {
+    let mut item = match make_rss_item(channel_id, rss_item, item_image_link) {
         Ok(item) => item,
         Err(e) => {
             rss_error!("[Service] Failed to make rss item: {}", e);
             return Err(e);
         }
     };
+    item.rss_language = detect_language(&format!(
+        "{}\n{}",
+        item.rss_title.clone().unwrap_or_default(),
+        extracted_description.0
+    ))
+    .or(channel_language);
 
     let item_id = store_rss_item(pool, item.clone()).await.unwrap();
+    store_rss_item_tags(pool, item_id, rss_item).await;
 
     let sentence = format!(
         "{}\n{}\n{}",
@@ -97,10 +141,32 @@ pub async fn create_rss_item_and_embedding(
 
     let _ =
         embedding_service::create_embedding(pool, embedding_service, sentence, embedding).await?;
-    Ok(true)
+
+    // 스토리 묶음 실패는 아이템 저장에 영향 주지 않음
+    if let Err(e) = story_service::assign_rss_item_to_story(pool, item_id).await {
+        rss_warn!("[Service] Failed to assign rss item to story: {:?}", e);
+    }
+    Ok(item_id)
 }
 
-fn extract_html_passage(html: &str) -> (String, Option<String>) {
+// rss <category>를 아이템 태그로 저장
+async fn store_rss_item_tags(pool: &MySqlPool, item_id: i32, rss_item: &Item) {
+    for category in rss_item.categories() {
+        let tag_name = category.name().trim().chars().take(100).collect::<String>();
+        if tag_name.is_empty() {
+            continue;
+        }
+
+        if let Err(e) = rss_item_repository::insert_rss_item_tag(pool, item_id, tag_name).await {
+            rss_warn!("[Service] Failed to insert rss item tag: {}", e);
+        }
+    }
+}
+
+/// 설명 HTML에서 본문 텍스트(최대 200자)와 첫 이미지 링크를 추출
+///
+/// 언어 감지 백필도 수집 때와 같은 텍스트로 감지하도록 이 함수를 사용함
+pub fn extract_html_passage(html: &str) -> (String, Option<String>) {
     let document = Html::parse_document(html);
 
     let passage_selector = Selector::parse("h3, p").unwrap();
@@ -220,8 +286,16 @@ async fn store_rss_item(pool: &MySqlPool, mut rss_item: NewRssItem) -> Result<i3
 pub async fn get_rss_list(
     pool: &MySqlPool,
     embedding_service: &EmbeddingService,
+    user_email: String,
     value: SearchRequestDto,
 ) -> Result<SearchResponseDto, OmniNewsError> {
+    let mute_filter = mute_service::find_mute_filter_by_email(pool, user_email).await?;
+
+    // page 방식은 클라이언트 이전 기간 동안만 유지
+    if value.cursor.is_some() || value.search_page_size.is_none() {
+        return get_rss_list_by_cursor(pool, embedding_service, value, &mute_filter).await;
+    }
+
     let search_value = value.search_value.unwrap_or_default();
     let load_annoy = load_rss_annoy(embedding_service, search_value.clone()).await?;
     let page = value.search_page_size.unwrap_or_default();
@@ -232,6 +306,8 @@ pub async fn get_rss_list(
     let mut searched_rss_items =
         rss_item_repository::select_rss_item_by_embedding_id(pool, search_value.as_str(), 0)
             .await?;
+
+    let hidden_count = mute_filter.retain_visible(&mut searched_rss_items);
     let total = searched_rss_items.len() as i32;
     // Provide 20 rss item each select request
     let offset = (page - 1) * 20;
@@ -289,13 +365,112 @@ pub async fn get_rss_list(
             });
         }
     };
-    Ok(SearchResponseDto::new(
-        vec![],
-        RssItemResponseDto::from_model_list(searched_rss_items),
-        total,
-        page,
-        has_next,
-    ))
+    let items = if value.collapse_story.unwrap_or(false) {
+        story_service::collapse_stories(pool, searched_rss_items).await?
+    } else {
+        RssItemResponseDto::from_model_list(searched_rss_items)
+    };
+    let items =
+        search_service::attach_snippets(embedding_service, &search_value, items, &HashSet::new())
+            .await;
+
+    let mut response = SearchResponseDto::new(vec![], items, total, page, has_next);
+    response.hidden_count = Some(hidden_count);
+    Ok(response)
+}
+
+/// 커서 기반 아이템 검색
+///
+/// 최신순, 정확도순은 (rss_pub_date, rss_id), 인기순은 (rss_rank, rss_id) 순서로 필요한 만큼만 조회.
+async fn get_rss_list_by_cursor(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    value: SearchRequestDto,
+    mute_filter: &MuteFilter,
+) -> Result<SearchResponseDto, OmniNewsError> {
+    let search_value = value.search_value.unwrap_or_default();
+    let search_type = value.search_type.unwrap_or(SearchType::Accuracy);
+    let limit = page_size(value.size);
+    let collapse_story = value.collapse_story.unwrap_or(false);
+    let cursor = value
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?;
+
+    let (mut searched_rss_items, next_cursor) = match search_type {
+        SearchType::Popularity => {
+            let ItemCursor::Rank { rss_rank, rss_id } =
+                cursor.unwrap_or_else(ItemCursor::first_by_rank)
+            else {
+                return Err(OmniNewsError::InvalidValue(
+                    "Cursor does not match search type".to_string(),
+                ));
+            };
+            let items = rss_item_repository::select_rss_items_by_search_value_before_rank(
+                pool,
+                &search_value,
+                rss_rank,
+                rss_id,
+                limit + 1,
+                collapse_story,
+            )
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select searched items by rank: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            split_page(items, limit, ItemCursor::by_rank)
+        }
+        SearchType::Accuracy | SearchType::Latest => {
+            let ItemCursor::PubDate { pub_date, rss_id } =
+                cursor.unwrap_or_else(ItemCursor::first_by_pub_date)
+            else {
+                return Err(OmniNewsError::InvalidValue(
+                    "Cursor does not match search type".to_string(),
+                ));
+            };
+            let items = rss_item_repository::select_rss_items_by_search_value_before_pub_date(
+                pool,
+                &search_value,
+                pub_date,
+                rss_id,
+                limit + 1,
+                collapse_story,
+            )
+            .await
+            .map_err(|e| {
+                rss_error!(
+                    "[Service] Failed to select searched items by pub date: {}",
+                    e
+                );
+                OmniNewsError::Database(e)
+            })?;
+            split_page(items, limit, ItemCursor::by_pub_date)
+        }
+    };
+
+    let total = rss_item_repository::count_rss_items_by_search_value(pool, &search_value)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to count searched items: {}", e);
+            OmniNewsError::Database(e)
+        })? as i32;
+
+    let hidden_count = mute_filter.retain_visible(&mut searched_rss_items);
+
+    let items = if collapse_story {
+        story_service::collapse_stories(pool, searched_rss_items).await?
+    } else {
+        RssItemResponseDto::from_model_list(searched_rss_items)
+    };
+    let items =
+        search_service::attach_snippets(embedding_service, &search_value, items, &HashSet::new())
+            .await;
+
+    let mut response = SearchResponseDto::new_with_cursor(items, total, next_cursor);
+    response.hidden_count = Some(hidden_count);
+    Ok(response)
 }
 
 #[allow(unused)]
@@ -324,16 +499,16 @@ async fn push_rss_item(
     }
 }
 
-// TODO 상위 100개 중 50개 랜덤 반환
+/// 상위 100개 중 50개 랜덤 반환 (개인화 추천이 불가능할 때 사용)
 pub async fn get_recommend_item(
     pool: &MySqlPool,
 ) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
     match rss_item_repository::select_rss_items_order_by_rss_rank(pool).await {
-        Ok(res) => {
-            //            let mut rng = rng();
-            //            res.shuffle(&mut rng);
-            //            Ok(res.into_iter().take(50).collect())
-            Ok(RssItemResponseDto::from_model_list(res))
+        Ok(mut res) => {
+            res.shuffle(&mut rng());
+            Ok(RssItemResponseDto::from_model_list(
+                res.into_iter().take(50).collect(),
+            ))
         }
         Err(e) => {
             rss_error!(
@@ -348,6 +523,54 @@ pub async fn get_recommend_item(
 pub async fn get_rss_item_by_channel_id_pagenation(
     pool: &MySqlPool,
     data: RssItemRequestDto,
+) -> Result<RssItemListResponseDto, OmniNewsError> {
+    // page 방식은 클라이언트 이전 기간 동안만 유지
+    if data.page.is_some() && data.cursor.is_none() {
+        return get_rss_item_by_channel_id_page(pool, data)
+            .await
+            .map(RssItemListResponseDto::Legacy);
+    }
+
+    let channel_id = data.channel_id.unwrap_or_default();
+    let limit = page_size(data.size);
+    let ItemCursor::PubDate { pub_date, rss_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_pub_date)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match item order".to_string(),
+        ));
+    };
+
+    match rss_item_repository::select_rss_items_by_channel_id_before_cursor(
+        pool,
+        channel_id,
+        pub_date,
+        rss_id,
+        limit + 1,
+    )
+    .await
+    {
+        Ok(res) => {
+            let (items, next_cursor) = split_page(res, limit, ItemCursor::by_pub_date);
+            Ok(RssItemListResponseDto::Page(RssItemPageResponseDto::new(
+                RssItemResponseDto::from_model_list(items),
+                next_cursor,
+            )))
+        }
+        Err(e) => {
+            rss_error!("[Service] Failed to select items by channel id: {:?}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn get_rss_item_by_channel_id_page(
+    pool: &MySqlPool,
+    data: RssItemRequestDto,
 ) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
     let channel_id = data.channel_id.unwrap_or_default();
     let page = data.page.unwrap_or(1);
@@ -383,3 +606,164 @@ pub async fn update_rss_item_rank(
         Err(e) => Err(OmniNewsError::Database(e)),
     }
 }
+
+// 연관 아이템 조회 설정
+const RELATED_DEFAULT_LIMIT: i32 = 10;
+const RELATED_MAX_LIMIT: i32 = 50;
+const RELATED_INDEX_CANDIDATES: i32 = 100;
+// 인덱스 생성 이후 추가된 아이템 보완용
+const RELATED_RECENT_CANDIDATES: i32 = 500;
+// 이 이상 유사하면 같은 기사로 보고 하나만 노출 (원본과 이 이상 유사한 후보도 제외)
+const NEAR_DUPLICATE_SIMILARITY: f32 = 0.95;
+
+pub async fn get_related_items(
+    pool: &MySqlPool,
+    rss_id: i32,
+    data: RelatedRssItemRequestDto,
+) -> Result<Vec<RelatedRssItemResponseDto>, OmniNewsError> {
+    let limit = data
+        .limit
+        .unwrap_or(RELATED_DEFAULT_LIMIT)
+        .clamp(1, RELATED_MAX_LIMIT) as usize;
+    let exclude_same_channel = data.exclude_same_channel.unwrap_or(false);
+
+    let source = rss_item_repository::select_rss_item_by_id(pool, rss_id)
+        .await
+        .map_err(|e| {
+            rss_warn!("[Service] Failed to find rss item {}: {}", rss_id, e);
+            OmniNewsError::NotFound("Rss item not found".to_string())
+        })?;
+    let source_embedding = embedding_repository::select_embedding_by_rss_id(pool, rss_id)
+        .await
+        .map_err(|e| {
+            rss_warn!(
+                "[Service] Failed to find embedding of rss item {}: {}",
+                rss_id,
+                e
+            );
+            OmniNewsError::NotFound("Embedding not found".to_string())
+        })?;
+    let source_vector = decode_embedding(&source_embedding.embedding_value.unwrap_or_default());
+
+    // 1. 후보 임베딩 수집 (인덱스 + 최신 아이템)
+    // 인덱스 파일 로드와 탐색은 블로킹 작업이라 별도 스레드에서 실행
+    let index_vector = source_vector.clone();
+    let index_ids = tokio::task::spawn_blocking(move || {
+        find_rss_annoy_by_vector(index_vector, RELATED_INDEX_CANDIDATES)
+    })
+    .await
+    .unwrap_or_else(|e| {
+        rss_warn!("[Service] Failed to search rss index: {}", e);
+        vec![]
+    });
+    let mut candidates = embedding_repository::select_embeddings_by_ids(pool, index_ids)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select embeddings by ids: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    candidates.extend(
+        embedding_repository::select_recent_rss_embeddings(pool, RELATED_RECENT_CANDIDATES)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select recent embeddings: {}", e);
+                OmniNewsError::Database(e)
+            })?,
+    );
+
+    // 2. 원본 기준 유사도 계산 후 정렬
+    let mut seen = HashSet::new();
+    let mut scored: Vec<(i32, Vec<f32>, f32)> = candidates
+        .into_iter()
+        .filter_map(|embedding| {
+            let candidate_rss_id = embedding.rss_id?;
+            if candidate_rss_id == rss_id || !seen.insert(candidate_rss_id) {
+                return None;
+            }
+            let vector = decode_embedding(&embedding.embedding_value.unwrap_or_default());
+            let similarity = cosine_similarity(&source_vector, &vector);
+            // 원본과 사실상 같은 기사는 연관 기사로 보지 않음
+            (similarity < NEAR_DUPLICATE_SIMILARITY).then_some((
+                candidate_rss_id,
+                vector,
+                similarity,
+            ))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    let candidate_ids = scored.iter().map(|(id, _, _)| *id).collect::<Vec<i32>>();
+    let mut items: HashMap<i32, RssItem> =
+        rss_item_repository::select_rss_items_by_ids(pool, candidate_ids)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select rss items by ids: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|item| item.rss_id.map(|id| (id, item)))
+            .collect();
+
+    // 원본과 같은 스토리로 묶인 아이템은 같은 사건의 다른 출처이므로 제외
+    let mut story_rss_ids: Vec<i32> = items.keys().copied().collect();
+    story_rss_ids.push(rss_id);
+    let story_ids_by_rss_id: HashMap<i32, i32> =
+        story_repository::select_rss_story_items_by_rss_ids(pool, story_rss_ids)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select story items: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|story_item| Some((story_item.rss_id?, story_item.story_id?)))
+            .collect();
+    let source_story_id = story_ids_by_rss_id.get(&rss_id).copied();
+
+    // 3. 채널, 같은 스토리 제외, 중복 기사 제거
+    let mut result: Vec<RelatedRssItemResponseDto> = Vec::new();
+    let mut kept_vectors: Vec<Vec<f32>> = Vec::new();
+    let mut kept_titles: HashSet<String> = HashSet::new();
+
+    for (candidate_id, vector, similarity) in scored {
+        if result.len() >= limit {
+            break;
+        }
+        let Some(item) = items.remove(&candidate_id) else {
+            continue;
+        };
+        if exclude_same_channel && item.channel_id == source.channel_id {
+            continue;
+        }
+        if item.rss_link.is_some() && item.rss_link == source.rss_link {
+            continue;
+        }
+        if source_story_id.is_some()
+            && story_ids_by_rss_id.get(&candidate_id).copied() == source_story_id
+        {
+            continue;
+        }
+
+        let title = normalize_title(item.rss_title.as_deref().unwrap_or_default());
+        let is_duplicate = (!title.is_empty() && kept_titles.contains(&title))
+            || kept_vectors
+                .iter()
+                .any(|kept| cosine_similarity(kept, &vector) >= NEAR_DUPLICATE_SIMILARITY);
+        if is_duplicate {
+            continue;
+        }
+
+        kept_titles.insert(title);
+        kept_vectors.push(vector);
+        result.push(RelatedRssItemResponseDto::new(item, similarity));
+    }
+
+    Ok(result)
+}
+
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
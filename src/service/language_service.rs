@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use sqlx::MySqlPool;
+
+use crate::{
+    model::error::OmniNewsError,
+    repository::{rss_channel_repository, rss_item_repository},
+    rss_error, rss_info,
+    service::item_service::extract_html_passage,
+    utils::{
+        language_util::{detect_language, normalize_language_tag},
+        time_util::kst_now,
+    },
+};
+
+const BACKFILL_BATCH_SIZE: i32 = 500;
+
+/// 언어가 없거나 정규화되지 않은 기존 채널, 아이템의 언어를 채움
+///
+/// 서버 시작 시 한 번 실행되며, 이미 채워진 행은 건너뜀
+pub async fn backfill_languages(pool: &MySqlPool) -> Result<(), OmniNewsError> {
+    let channel_languages = backfill_channel_languages(pool).await?;
+    let item_count = backfill_item_languages(pool, &channel_languages).await?;
+
+    rss_info!(
+        "[Service] Language backfill done: {} channels, {} items",
+        channel_languages.len(),
+        item_count
+    );
+    Ok(())
+}
+
+/// 채널 언어를 정규화 ("ko-KR" -> "ko", "None" -> 감지값)하고 채널별 언어를 반환
+async fn backfill_channel_languages(
+    pool: &MySqlPool,
+) -> Result<HashMap<i32, String>, OmniNewsError> {
+    let mut channel_languages = HashMap::new();
+    let mut last_channel_id = 0;
+
+    loop {
+        let channels = rss_channel_repository::select_rss_channels_after_id(
+            pool,
+            last_channel_id,
+            BACKFILL_BATCH_SIZE,
+        )
+        .await
+        .map_err(|e| {
+            rss_error!(
+                "[Service] Failed to select channels for language backfill: {}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?;
+        let Some(last) = channels.last() else {
+            break;
+        };
+        last_channel_id = last.channel_id.unwrap_or_default();
+
+        for channel in channels {
+            let channel_id = channel.channel_id.unwrap_or_default();
+            let language = channel
+                .channel_language
+                .as_deref()
+                .and_then(normalize_language_tag)
+                .or_else(|| {
+                    detect_language(&format!(
+                        "{}\n{}",
+                        channel.channel_title.unwrap_or_default(),
+                        channel.channel_description.unwrap_or_default()
+                    ))
+                });
+
+            if language != channel.channel_language {
+                if let Err(e) = rss_channel_repository::update_rss_channel_language(
+                    pool,
+                    channel_id,
+                    language.clone(),
+                )
+                .await
+                {
+                    rss_error!("[Service] Failed to update channel language: {}", e);
+                }
+            }
+            if let Some(language) = language {
+                channel_languages.insert(channel_id, language);
+            }
+        }
+    }
+
+    Ok(channel_languages)
+}
+
+async fn backfill_item_languages(
+    pool: &MySqlPool,
+    channel_languages: &HashMap<i32, String>,
+) -> Result<usize, OmniNewsError> {
+    let mut updated = 0;
+    let mut last_rss_id = 0;
+
+    loop {
+        let items = rss_item_repository::select_rss_items_without_language(
+            pool,
+            last_rss_id,
+            BACKFILL_BATCH_SIZE,
+        )
+        .await
+        .map_err(|e| {
+            rss_error!(
+                "[Service] Failed to select items for language backfill: {}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?;
+        let Some(last) = items.last() else {
+            break;
+        };
+        last_rss_id = last.rss_id.unwrap_or_default();
+
+        for item in items {
+            // 수집 때처럼 설명 HTML에서 추출한 텍스트로 감지
+            let (description, _) =
+                extract_html_passage(item.rss_description.as_deref().unwrap_or("None"));
+            let language = detect_language(&format!(
+                "{}\n{}",
+                item.rss_title.unwrap_or_default(),
+                description
+            ))
+            .or_else(|| {
+                item.channel_id
+                    .and_then(|channel_id| channel_languages.get(&channel_id).cloned())
+            });
+            if language.is_none() {
+                continue;
+            }
+
+            let rss_id = item.rss_id.unwrap_or_default();
+            match rss_item_repository::update_rss_item_language(pool, rss_id, language).await {
+                Ok(_) => updated += 1,
+                Err(e) => {
+                    rss_error!("[Service] Failed to update item language: {}", e);
+                    continue;
+                }
+            }
+            // 동기화에서 바뀐 언어를 다시 보내도록 기록
+            if let Err(e) =
+                rss_item_repository::upsert_rss_item_update(pool, rss_id, kst_now()).await
+            {
+                rss_error!("[Service] Failed to record item update: {}", e);
+            }
+        }
+    }
+
+    Ok(updated)
+}
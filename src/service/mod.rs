@@ -1,11 +1,29 @@
+pub mod app_password_service;
+pub mod bookmark_service;
 pub mod channel_css_service;
 pub mod channel_service;
+pub mod digest_service;
 pub mod embedding_service;
+pub mod feed_rule_service;
+pub mod feed_token_service;
+pub mod feedback_service;
+pub mod fever_service;
 pub mod folder_service;
+pub mod greader_service;
 pub mod item_service;
+pub mod language_service;
+pub mod mute_service;
 pub mod news_service;
+pub mod notification_service;
 pub mod omninews_subscription_service;
+pub mod read_service;
+pub mod reading_service;
+pub mod recommendation_service;
+pub mod saved_search_service;
+pub mod search_service;
+pub mod story_service;
 pub mod subscription_service;
+pub mod sync_service;
 pub mod user_service;
 
 pub mod premium;
@@ -0,0 +1,355 @@
+use std::collections::HashSet;
+
+use regex::{Regex, RegexBuilder};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        mute::{
+            request::MuteRuleRequestDto,
+            response::{MutePreviewResponseDto, MuteRuleResponseDto},
+        },
+        rss::response::RssItemResponseDto,
+    },
+    model::{
+        error::OmniNewsError,
+        mute::{MuteRule, MuteRuleType},
+        rss::RssItem,
+    },
+    repository::{mute_repository, subscribe_repository},
+    subscription_error, subscription_warn,
+    utils::time_util::kst_now,
+};
+
+use super::user_service;
+
+const MAX_RULES: i64 = 100;
+const MAX_RULE_VALUE_LENGTH: usize = 200;
+// 정규식 컴파일 크기 제한 (과도하게 큰 패턴 방지)
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+const PREVIEW_ITEM_LIMIT: i32 = 200;
+const PREVIEW_SAMPLE_COUNT: usize = 20;
+
+/// 사용자의 숨김 규칙을 컴파일한 필터
+#[derive(Debug, Default)]
+pub struct MuteFilter {
+    keywords: Vec<String>,
+    regexes: Vec<Regex>,
+    authors: Vec<String>,
+    domains: Vec<String>,
+    channel_ids: HashSet<i32>,
+}
+
+impl MuteFilter {
+    pub fn new(rules: &[MuteRule]) -> Self {
+        let mut filter = MuteFilter::default();
+        for rule in rules {
+            let (Some(rule_type), Some(value)) = (
+                rule.mute_rule_type
+                    .as_deref()
+                    .and_then(MuteRuleType::from_value),
+                rule.mute_rule_value.as_deref(),
+            ) else {
+                continue;
+            };
+            filter.add(rule_type, value);
+        }
+        filter
+    }
+
+    fn add(&mut self, rule_type: MuteRuleType, value: &str) {
+        match rule_type {
+            MuteRuleType::Keyword => self.keywords.push(value.to_lowercase()),
+            MuteRuleType::Regex => match build_regex(value) {
+                Ok(regex) => self.regexes.push(regex),
+                Err(e) => subscription_warn!("[Service] Skip invalid mute regex {}: {}", value, e),
+            },
+            MuteRuleType::Author => self.authors.push(value.to_lowercase()),
+            MuteRuleType::Domain => self.domains.push(value.to_lowercase()),
+            MuteRuleType::Channel => {
+                if let Ok(channel_id) = value.parse() {
+                    self.channel_ids.insert(channel_id);
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty()
+            && self.regexes.is_empty()
+            && self.authors.is_empty()
+            && self.domains.is_empty()
+            && self.channel_ids.is_empty()
+    }
+
+    pub fn hides(&self, item: &RssItem) -> bool {
+        self.is_muted(
+            item.channel_id,
+            item.rss_title.as_deref(),
+            item.rss_description.as_deref(),
+            item.rss_author.as_deref(),
+            item.rss_link.as_deref(),
+        )
+    }
+
+    pub fn hides_response(&self, item: &RssItemResponseDto) -> bool {
+        self.is_muted(
+            item.channel_id,
+            item.rss_title.as_deref(),
+            item.rss_description.as_deref(),
+            item.rss_author.as_deref(),
+            item.rss_link.as_deref(),
+        )
+    }
+
+    /// 숨길 아이템을 제외하고 제외한 개수 반환
+    pub fn retain_visible(&self, items: &mut Vec<RssItem>) -> i32 {
+        let before = items.len();
+        if !self.is_empty() {
+            items.retain(|item| !self.hides(item));
+        }
+        (before - items.len()) as i32
+    }
+
+    fn is_muted(
+        &self,
+        channel_id: Option<i32>,
+        title: Option<&str>,
+        description: Option<&str>,
+        author: Option<&str>,
+        link: Option<&str>,
+    ) -> bool {
+        if channel_id.is_some_and(|id| self.channel_ids.contains(&id)) {
+            return true;
+        }
+        if let Some(author) = author {
+            let author = author.trim().to_lowercase();
+            if self.authors.iter().any(|muted| *muted == author) {
+                return true;
+            }
+        }
+        if let Some(host) = link.and_then(link_host) {
+            if self
+                .domains
+                .iter()
+                .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")))
+            {
+                return true;
+            }
+        }
+        if self.keywords.is_empty() && self.regexes.is_empty() {
+            return false;
+        }
+
+        let text = format!(
+            "{}\n{}",
+            title.unwrap_or_default(),
+            description.unwrap_or_default()
+        );
+        let lowered = text.to_lowercase();
+        self.keywords
+            .iter()
+            .any(|keyword| lowered.contains(keyword.as_str()))
+            || self.regexes.iter().any(|regex| regex.is_match(&text))
+    }
+}
+
+pub async fn create_mute_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    data: MuteRuleRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let (rule_type, value) = validate_rule(data)?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let rule_count = mute_repository::count_mute_rules_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to count mute rules: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if rule_count >= MAX_RULES {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_RULES} mute rules are allowed"
+        )));
+    }
+
+    match mute_repository::insert_mute_rule(pool, user_id, rule_type.as_str(), &value, kst_now())
+        .await
+    {
+        Ok(res) => Ok(res),
+        Err(e)
+            if e.as_database_error()
+                .is_some_and(|e| e.is_unique_violation()) =>
+        {
+            Err(OmniNewsError::AlreadyExists(value))
+        }
+        Err(e) => {
+            subscription_error!("[Service] Failed to insert mute rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_mute_rules(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<MuteRuleResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match mute_repository::select_mute_rules_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(MuteRuleResponseDto::from_model_list(res)),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select mute rules: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn delete_mute_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    mute_rule_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match mute_repository::delete_mute_rule(pool, user_id, mute_rule_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Mute rule not found".to_string())),
+        Err(e) => {
+            subscription_error!("[Service] Failed to delete mute rule: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 저장하기 전에 규칙이 최근 구독 아이템 중 무엇을 숨기는지 확인
+pub async fn preview_mute_rule(
+    pool: &MySqlPool,
+    user_email: String,
+    data: MuteRuleRequestDto,
+) -> Result<MutePreviewResponseDto, OmniNewsError> {
+    let (rule_type, value) = validate_rule(data)?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let channel_ids = subscribe_repository::select_subscription_channel_ids(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select subscription channel ids: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if channel_ids.is_empty() {
+        return Ok(MutePreviewResponseDto::new(0, 0, vec![]));
+    }
+    let items = subscribe_repository::select_subscription_items(
+        pool,
+        channel_ids,
+        0,
+        PREVIEW_ITEM_LIMIT,
+        false,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    let mut filter = MuteFilter::default();
+    filter.add(rule_type, &value);
+
+    let checked_count = items.len() as i32;
+    let hidden: Vec<RssItem> = items
+        .into_iter()
+        .filter(|item| filter.hides(item))
+        .collect();
+    let hidden_count = hidden.len() as i32;
+
+    Ok(MutePreviewResponseDto::new(
+        checked_count,
+        hidden_count,
+        RssItemResponseDto::from_model_list(
+            hidden.into_iter().take(PREVIEW_SAMPLE_COUNT).collect(),
+        ),
+    ))
+}
+
+pub async fn find_mute_filter(pool: &MySqlPool, user_id: i32) -> Result<MuteFilter, OmniNewsError> {
+    match mute_repository::select_mute_rules_by_user_id(pool, user_id).await {
+        Ok(res) => Ok(MuteFilter::new(&res)),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select mute rules: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn find_mute_filter_by_email(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<MuteFilter, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    find_mute_filter(pool, user_id).await
+}
+
+/// 종류별로 규칙 값을 검증하고 저장할 형태로 정리
+fn validate_rule(data: MuteRuleRequestDto) -> Result<(MuteRuleType, String), OmniNewsError> {
+    let Some(rule_type) = data.mute_rule_type else {
+        return Err(OmniNewsError::InvalidValue(
+            "mute_rule_type is required".to_string(),
+        ));
+    };
+    let value = data.mute_rule_value.unwrap_or_default().trim().to_string();
+    if value.is_empty() || value.chars().count() > MAX_RULE_VALUE_LENGTH {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "mute_rule_value must be 1-{MAX_RULE_VALUE_LENGTH} characters"
+        )));
+    }
+
+    let value = match rule_type {
+        MuteRuleType::Keyword | MuteRuleType::Author => value,
+        MuteRuleType::Regex => {
+            build_regex(&value)
+                .map_err(|e| OmniNewsError::InvalidValue(format!("Invalid regex: {e}")))?;
+            value
+        }
+        MuteRuleType::Domain => link_host(&value)
+            .filter(|host| host.contains('.'))
+            .ok_or_else(|| OmniNewsError::InvalidValue(format!("Invalid domain: {value}")))?,
+        MuteRuleType::Channel => value
+            .parse::<i32>()
+            .ok()
+            .filter(|channel_id| *channel_id > 0)
+            .map(|channel_id| channel_id.to_string())
+            .ok_or_else(|| OmniNewsError::InvalidValue(format!("Invalid channel id: {value}")))?,
+    };
+    Ok((rule_type, value))
+}
+
+fn build_regex(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+/// 링크나 도메인 문자열에서 호스트만 추출 (소문자, `www.` 제외)
+fn link_host(link: &str) -> Option<String> {
+    let link = link.trim();
+    let without_scheme = link.split_once("://").map_or(link, |(_, rest)| rest);
+    let host = without_scheme
+        .split(['/', '?', '#'])
+        .next()?
+        .rsplit('@')
+        .next()?
+        .split(':')
+        .next()?
+        .to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+
+    if host.is_empty() {
+        None
+    } else {
+        Some(host)
+    }
+}
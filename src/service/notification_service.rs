@@ -0,0 +1,997 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration as StdDuration,
+};
+
+use chrono::{Duration, FixedOffset, NaiveDateTime, TimeZone, Timelike};
+use chrono_tz::Tz;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::notification::{
+        request::{
+            NotificationListRequestDto, NotificationPreferenceRequestDto,
+            NotificationPreferenceTargetRequestDto, NotificationReadRequestDto,
+            PushSettingRequestDto,
+        },
+        response::{
+            NotificationPageResponseDto, NotificationPreferenceResponseDto, PushSettingResponseDto,
+            UserNotificationResponseDto,
+        },
+    },
+    model::{
+        error::OmniNewsError,
+        notification::{
+            NewUserNotification, NotificationLevel, NotificationPreference, NotificationType,
+        },
+        push::{PendingPushItem, PushTarget, UserPushSetting},
+        rss::RssItem,
+    },
+    repository::{
+        folder_repository, notification_repository, push_repository, rss_channel_repository,
+        rss_item_repository, subscribe_repository, user_repository,
+    },
+    user_error, user_info, user_warn,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        fcm_util::{self, FcmSendResult, PushMessage},
+        time_util::kst_now,
+    },
+};
+
+use super::{mute_service, user_service};
+
+const DEFAULT_TIMEZONE: &str = "Asia/Seoul";
+const MAX_KEYWORDS: usize = 20;
+const MAX_KEYWORD_LENGTH: usize = 50;
+/// 알림함 보관 기간
+const NOTIFICATION_RETENTION_DAYS: i64 = 90;
+/// 이보다 오래전에 발행된 아이템은 새 글 알림에서 제외 (처음 추가된 채널의 과거 글 등)
+const NEW_ITEM_WINDOW_HOURS: i64 = 24;
+/// 이보다 오래 대기한 아이템은 보내지 않고 삭제
+const PENDING_EXPIRE_HOURS: i64 = 24;
+/// 사용자별 최소 발송 간격, 그 사이 새 글은 모아서 다음에 한 번에 보냄
+const PUSH_MIN_INTERVAL_MINUTES: i64 = 15;
+const DISPATCH_INTERVAL: StdDuration = StdDuration::from_secs(60);
+/// 한 번에 조회할 사용자 수
+const DISPATCH_PAGE_SIZE: i32 = 200;
+/// 한 주기에 발송할 최대 사용자 수
+const DISPATCH_BATCH_SIZE: usize = 200;
+/// FCM 발송 실패 시 재시도 간격 상한 (2, 4, 8분... 순으로 늘어남)
+const PUSH_RETRY_MAX_MINUTES: i64 = 6 * 60;
+
+pub async fn get_push_setting(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<PushSettingResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let setting = find_push_setting(pool, user_id).await?;
+    Ok(PushSettingResponseDto::from_model(
+        setting.unwrap_or_else(|| default_push_setting(user_id)),
+    ))
+}
+
+pub async fn update_push_setting(
+    pool: &MySqlPool,
+    user_email: String,
+    data: PushSettingRequestDto,
+) -> Result<PushSettingResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let mut setting = find_push_setting(pool, user_id)
+        .await?
+        .unwrap_or_else(|| default_push_setting(user_id));
+
+    match (data.push_quiet_start_hour, data.push_quiet_end_hour) {
+        (Some(start), Some(end)) => {
+            if !(0..=23).contains(&start) || !(0..=23).contains(&end) || start == end {
+                return Err(OmniNewsError::InvalidValue(
+                    "Quiet hours must be 0-23 and different".to_string(),
+                ));
+            }
+            setting.push_quiet_start_hour = Some(start);
+            setting.push_quiet_end_hour = Some(end);
+        }
+        (None, None) => {
+            setting.push_quiet_start_hour = None;
+            setting.push_quiet_end_hour = None;
+        }
+        _ => {
+            return Err(OmniNewsError::InvalidValue(
+                "push_quiet_start_hour and push_quiet_end_hour must be set together".to_string(),
+            ));
+        }
+    }
+    if let Some(timezone) = data.push_timezone {
+        let timezone = timezone.trim().to_string();
+        if timezone.parse::<Tz>().is_err() {
+            return Err(OmniNewsError::InvalidValue(format!(
+                "Unknown timezone: {timezone}"
+            )));
+        }
+        setting.push_timezone = Some(timezone);
+    }
+    setting.push_updated_at = Some(kst_now());
+
+    push_repository::upsert_user_push_setting(pool, setting.clone())
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to upsert push setting: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(PushSettingResponseDto::from_model(setting))
+}
+
+pub async fn get_notification_preferences(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<NotificationPreferenceResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let preferences = notification_repository::select_notification_preferences(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select notification preferences: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    Ok(preferences
+        .into_iter()
+        .map(NotificationPreferenceResponseDto::from_model)
+        .collect())
+}
+
+/// 구독 중인 채널 또는 내 폴더의 알림 수준 저장
+pub async fn update_notification_preference(
+    pool: &MySqlPool,
+    user_email: String,
+    data: NotificationPreferenceRequestDto,
+) -> Result<NotificationPreferenceResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    validate_preference_target(pool, user_id, data.channel_id, data.folder_id).await?;
+
+    let Some(level) = data.notification_level else {
+        return Err(OmniNewsError::InvalidValue(
+            "notification_level is required".to_string(),
+        ));
+    };
+    let keywords = normalize_keywords(data.notification_keywords.unwrap_or_default());
+    if level == NotificationLevel::Keyword && (keywords.is_empty() || keywords.len() > MAX_KEYWORDS)
+    {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Keyword level needs 1-{MAX_KEYWORDS} keywords"
+        )));
+    }
+    if keywords
+        .iter()
+        .any(|keyword| keyword.chars().count() > MAX_KEYWORD_LENGTH)
+    {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Keywords must be at most {MAX_KEYWORD_LENGTH} characters"
+        )));
+    }
+
+    let preference = NotificationPreference {
+        notification_preference_id: None,
+        user_id: Some(user_id),
+        channel_id: data.channel_id,
+        folder_id: data.folder_id,
+        notification_level: Some(level.as_str().to_string()),
+        notification_keywords: Some(serde_json::to_string(&keywords).unwrap_or_default()),
+        notification_preference_updated_at: Some(kst_now()),
+    };
+    notification_repository::upsert_notification_preference(pool, preference.clone())
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to upsert notification preference: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(NotificationPreferenceResponseDto::from_model(preference))
+}
+
+/// 설정을 지워 상위 설정(폴더 또는 기본값)을 따르게 함
+pub async fn delete_notification_preference(
+    pool: &MySqlPool,
+    user_email: String,
+    data: NotificationPreferenceTargetRequestDto,
+) -> Result<bool, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    if data.channel_id.is_some() == data.folder_id.is_some() {
+        return Err(OmniNewsError::InvalidValue(
+            "Either channel_id or folder_id is required".to_string(),
+        ));
+    }
+
+    match notification_repository::delete_notification_preference(
+        pool,
+        user_id,
+        data.channel_id,
+        data.folder_id,
+    )
+    .await
+    {
+        Ok(true) => Ok(true),
+        Ok(false) => Err(OmniNewsError::NotFound(
+            "Notification preference not found".to_string(),
+        )),
+        Err(e) => {
+            user_error!("[Service] Failed to delete notification preference: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_notifications(
+    pool: &MySqlPool,
+    user_email: String,
+    data: NotificationListRequestDto,
+) -> Result<NotificationPageResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let limit = page_size(data.size);
+    let ItemCursor::Id { id: cursor_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_id)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match notification order".to_string(),
+        ));
+    };
+
+    let notifications =
+        notification_repository::select_user_notifications(pool, user_id, cursor_id, limit + 1)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select notifications: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let (notifications, next_cursor) =
+        split_page(notifications, limit, |notification| ItemCursor::Id {
+            id: notification.notification_id.unwrap_or_default(),
+        });
+
+    let unread_count = notification_repository::count_unread_notifications(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to count unread notifications: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(NotificationPageResponseDto::new(
+        notifications
+            .into_iter()
+            .map(UserNotificationResponseDto::from_model)
+            .collect(),
+        unread_count,
+        next_cursor,
+    ))
+}
+
+/// 읽음 처리한 알림 수 반환
+pub async fn mark_notifications_read(
+    pool: &MySqlPool,
+    user_email: String,
+    data: NotificationReadRequestDto,
+) -> Result<u64, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    notification_repository::update_notifications_read(
+        pool,
+        user_id,
+        data.notification_ids.unwrap_or_default(),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to update notifications read: {}", e);
+        OmniNewsError::Database(e)
+    })
+}
+
+pub async fn delete_notification(
+    pool: &MySqlPool,
+    user_email: String,
+    notification_id: i32,
+) -> Result<bool, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match notification_repository::delete_user_notification(pool, user_id, notification_id).await {
+        Ok(true) => Ok(true),
+        Ok(false) => Err(OmniNewsError::NotFound(
+            "Notification not found".to_string(),
+        )),
+        Err(e) => {
+            user_error!("[Service] Failed to delete notification: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 삭제한 알림 수 반환
+pub async fn clear_notifications(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<u64, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    notification_repository::delete_user_notifications(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to delete notifications: {}", e);
+            OmniNewsError::Database(e)
+        })
+}
+
+/// 새로 수집한 아이템을 구독자의 알림 수준에 따라 알림함에 기록하고 푸시 대기열에 추가
+/// (발송은 `run_push_dispatcher`)
+pub async fn notify_new_items(
+    pool: &MySqlPool,
+    channel_id: i32,
+    rss_ids: Vec<i32>,
+) -> Result<u64, OmniNewsError> {
+    let now = kst_now();
+    let published_after = now - Duration::hours(NEW_ITEM_WINDOW_HOURS);
+
+    let mut items = rss_item_repository::select_rss_items_by_ids(pool, rss_ids)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select new items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    items.retain(|item| {
+        item.rss_pub_date
+            .is_some_and(|date| date >= published_after)
+    });
+    if items.is_empty() {
+        return Ok(0);
+    }
+
+    let subscriber_ids =
+        subscribe_repository::select_subscriber_ids_by_channel_id(pool, channel_id)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select channel subscribers: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let preferences =
+        notification_repository::select_notification_preferences_by_channel_id(pool, channel_id)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select notification preferences: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let mut folder_ids_by_user: HashMap<i32, Vec<i32>> = HashMap::new();
+    for folder in folder_repository::select_folders_by_channel_id(pool, channel_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select channel folders: {}", e);
+            OmniNewsError::Database(e)
+        })?
+    {
+        if let (Some(user_id), Some(folder_id)) = (folder.user_id, folder.folder_id) {
+            folder_ids_by_user
+                .entry(user_id)
+                .or_default()
+                .push(folder_id);
+        }
+    }
+    let channel_title = rss_channel_repository::select_rss_channel_by_id(pool, channel_id)
+        .await
+        .ok()
+        .and_then(|channel| channel.channel_title)
+        .unwrap_or_default();
+
+    let push_user_ids: HashSet<i32> =
+        push_repository::select_push_enabled_user_ids(pool, subscriber_ids.clone())
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select push enabled users: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect();
+
+    let mut notified_count = 0;
+    for user_id in subscriber_ids {
+        let user_preferences = preferences
+            .iter()
+            .filter(|preference| preference.user_id == Some(user_id))
+            .collect::<Vec<&NotificationPreference>>();
+        let folder_ids = folder_ids_by_user
+            .get(&user_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let (level, keywords) = resolve_level(&user_preferences, channel_id, folder_ids);
+        if level == NotificationLevel::None {
+            continue;
+        }
+
+        let mut user_items = items
+            .iter()
+            .filter(|item| level == NotificationLevel::All || matches_keywords(item, &keywords))
+            .cloned()
+            .collect::<Vec<RssItem>>();
+        if user_items.is_empty() {
+            continue;
+        }
+        mute_service::find_mute_filter(pool, user_id)
+            .await?
+            .retain_visible(&mut user_items);
+        if user_items.is_empty() {
+            continue;
+        }
+
+        let notifications = user_items
+            .iter()
+            .map(|item| NewUserNotification {
+                user_id: Some(user_id),
+                notification_type: Some(NotificationType::NewItem.as_str().to_string()),
+                notification_title: Some(channel_title.chars().take(200).collect()),
+                notification_body: Some(
+                    item.rss_title
+                        .clone()
+                        .unwrap_or_default()
+                        .chars()
+                        .take(500)
+                        .collect(),
+                ),
+                rss_id: item.rss_id,
+                channel_id: Some(channel_id),
+                feedback_id: None,
+                notification_created_at: Some(now),
+            })
+            .collect();
+        notified_count += notification_repository::insert_user_notifications(pool, notifications)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to insert notifications: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+
+        if !push_user_ids.contains(&user_id) {
+            continue;
+        }
+        push_repository::insert_pending_push_items(
+            pool,
+            user_id,
+            user_items.iter().filter_map(|item| item.rss_id).collect(),
+            now,
+        )
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to insert pending push items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    }
+    Ok(notified_count)
+}
+
+/// 피드 규칙에 맞은 아이템을 알림함에 기록하고 푸시 대기열에 추가
+///
+/// 뮤트 필터에 걸린 아이템은 건너뛰고, 방해 금지 시간에는 발송을 미룸 (`run_push_dispatcher`)
+pub async fn notify_feed_rule_match(
+    pool: &MySqlPool,
+    user_id: i32,
+    rule_name: &str,
+    item: &RssItem,
+) -> Result<bool, OmniNewsError> {
+    let mut items = vec![item.clone()];
+    mute_service::find_mute_filter(pool, user_id)
+        .await?
+        .retain_visible(&mut items);
+    let Some(rss_id) = items.first().and_then(|item| item.rss_id) else {
+        return Ok(false);
+    };
+    let now = kst_now();
+
+    let notification = NewUserNotification {
+        user_id: Some(user_id),
+        notification_type: Some(NotificationType::FeedRule.as_str().to_string()),
+        notification_title: Some(rule_name.chars().take(200).collect()),
+        notification_body: Some(
+            item.rss_title
+                .clone()
+                .unwrap_or_default()
+                .chars()
+                .take(500)
+                .collect(),
+        ),
+        rss_id: Some(rss_id),
+        channel_id: item.channel_id,
+        feedback_id: None,
+        notification_created_at: Some(now),
+    };
+    notification_repository::insert_user_notifications(pool, vec![notification])
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to insert feed rule notification: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    push_repository::insert_pending_push_items(pool, user_id, vec![rss_id], now)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to insert pending push items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    Ok(true)
+}
+
+/// 대기열의 새 글을 사용자별로 모아 주기적으로 발송
+pub async fn run_push_dispatcher(pool: MySqlPool) {
+    let mut interval = tokio::time::interval(DISPATCH_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(e) = dispatch_pending_pushes(&pool).await {
+            user_error!("[Service] Push dispatcher failed: {}", e);
+        }
+    }
+}
+
+async fn dispatch_pending_pushes(pool: &MySqlPool) -> Result<(), OmniNewsError> {
+    let now = kst_now();
+    push_repository::delete_stale_pending_push_items(
+        pool,
+        now - Duration::hours(PENDING_EXPIRE_HOURS),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to delete stale pending pushes: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    notification_repository::delete_notifications_before(
+        pool,
+        now - Duration::days(NOTIFICATION_RETENTION_DAYS),
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to delete old notifications: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    // 방해 금지 시간인 사용자는 건너뛰고 다음 페이지를 조회해 배치를 채움
+    let mut sent_count = 0;
+    let mut after_user_id = 0;
+    while sent_count < DISPATCH_BATCH_SIZE {
+        let targets = push_repository::select_push_targets(
+            pool,
+            after_user_id,
+            now - Duration::minutes(PUSH_MIN_INTERVAL_MINUTES),
+            now,
+            DISPATCH_PAGE_SIZE,
+        )
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select push targets: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        let Some(last_user_id) = targets.last().and_then(|target| target.user_id) else {
+            break;
+        };
+        let is_last_page = targets.len() < DISPATCH_PAGE_SIZE as usize;
+        after_user_id = last_user_id;
+
+        for target in targets {
+            if sent_count >= DISPATCH_BATCH_SIZE {
+                break;
+            }
+            if is_quiet_time(&target, now) {
+                continue;
+            }
+            match send_pending_push(pool, target, now).await {
+                Ok(_) => sent_count += 1,
+                Err(e) => user_warn!("[Service] Failed to send push: {}", e),
+            }
+        }
+        if is_last_page {
+            break;
+        }
+    }
+    Ok(())
+}
+
+async fn send_pending_push(
+    pool: &MySqlPool,
+    target: PushTarget,
+    now: NaiveDateTime,
+) -> Result<(), OmniNewsError> {
+    let user_id = target.user_id.unwrap_or_default();
+    let token = target.user_fcm_token.unwrap_or_default();
+    let failure_count = target.push_failure_count.unwrap_or_default();
+
+    let items = push_repository::select_pending_push_items(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select pending push items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let Some(max_pending_id) = items.iter().filter_map(|item| item.push_pending_id).max() else {
+        return Ok(());
+    };
+
+    let result = match fcm_util::send_push(build_push_message(token.clone(), &items)).await {
+        Ok(res) => res,
+        Err(e) => {
+            // 대기열은 그대로 두고 재시도를 늦춤 (오래된 아이템은 PENDING_EXPIRE_HOURS 후 삭제)
+            let failure_count = failure_count + 1;
+            push_repository::update_push_failure(
+                pool,
+                user_id,
+                failure_count,
+                now + push_retry_delay(failure_count),
+            )
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to update push failure: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+            return Err(e);
+        }
+    };
+    if result == FcmSendResult::InvalidToken {
+        user_info!("[Service] Removed invalid FCM token: user_id {}", user_id);
+        user_repository::delete_user_fcm_token(pool, user_id, token)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to delete fcm token: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    } else {
+        push_repository::update_push_last_sent_at(pool, user_id, now)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to update push last sent at: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    }
+
+    push_repository::delete_pending_push_items(pool, user_id, max_pending_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to delete pending push items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    Ok(())
+}
+
+/// 한 채널의 글이면 채널 이름을 제목으로, 여러 채널이면 새 글 수를 제목으로 사용
+fn build_push_message(token: String, items: &[PendingPushItem]) -> PushMessage {
+    let first = &items[0];
+    let first_title = first.rss_title.clone().unwrap_or_default();
+    let is_single_channel = items.iter().all(|item| item.channel_id == first.channel_id);
+
+    let title = if is_single_channel {
+        first.channel_title.clone().unwrap_or_default()
+    } else {
+        format!("새 글 {}개", items.len())
+    };
+    let body = if items.len() == 1 {
+        first_title
+    } else {
+        format!("{} 외 {}개", first_title, items.len() - 1)
+    };
+
+    let mut data = HashMap::new();
+    data.insert("type".to_string(), "new_items".to_string());
+    data.insert("count".to_string(), items.len().to_string());
+    data.insert(
+        "rss_id".to_string(),
+        first.rss_id.unwrap_or_default().to_string(),
+    );
+    if is_single_channel {
+        data.insert(
+            "channel_id".to_string(),
+            first.channel_id.unwrap_or_default().to_string(),
+        );
+    }
+
+    PushMessage {
+        token,
+        title,
+        body,
+        data,
+    }
+}
+
+/// 시작 시각부터 종료 시각 전까지 (자정을 넘는 구간 포함)
+fn is_quiet_time(target: &PushTarget, now: NaiveDateTime) -> bool {
+    let (Some(start), Some(end)) = (target.push_quiet_start_hour, target.push_quiet_end_hour)
+    else {
+        return false;
+    };
+    let tz = target
+        .push_timezone
+        .as_deref()
+        .and_then(|tz| tz.parse::<Tz>().ok())
+        .unwrap_or(chrono_tz::Asia::Seoul);
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let hour = kst
+        .from_local_datetime(&now)
+        .unwrap()
+        .with_timezone(&tz)
+        .hour() as i32;
+
+    if start < end {
+        start <= hour && hour < end
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+/// 연속 실패 횟수에 따라 2분부터 두 배씩, 최대 PUSH_RETRY_MAX_MINUTES
+fn push_retry_delay(failure_count: i32) -> Duration {
+    let minutes = 1_i64 << failure_count.clamp(1, 16);
+    Duration::minutes(minutes.min(PUSH_RETRY_MAX_MINUTES))
+}
+
+/// 채널 설정 > 채널이 속한 폴더 설정 > 기본값(All) 순으로 적용
+/// 채널이 여러 폴더에 있으면 가장 넓은 수준을 적용하고 키워드는 합침
+/// (설정이 없는 폴더는 기본값 All로 봄)
+fn resolve_level(
+    preferences: &[&NotificationPreference],
+    channel_id: i32,
+    folder_ids: &[i32],
+) -> (NotificationLevel, Vec<String>) {
+    if let Some(preference) = preferences
+        .iter()
+        .find(|preference| preference.channel_id == Some(channel_id))
+    {
+        return (preference.level(), preference.keywords());
+    }
+
+    let mut folder_preferences = vec![];
+    for folder_id in folder_ids {
+        match preferences
+            .iter()
+            .find(|preference| preference.folder_id == Some(*folder_id))
+        {
+            Some(preference) if preference.level() != NotificationLevel::All => {
+                folder_preferences.push(preference)
+            }
+            _ => return (NotificationLevel::All, vec![]),
+        }
+    }
+    if folder_preferences.is_empty() {
+        return (NotificationLevel::All, vec![]);
+    }
+
+    let keywords = folder_preferences
+        .iter()
+        .filter(|preference| preference.level() == NotificationLevel::Keyword)
+        .flat_map(|preference| preference.keywords())
+        .collect::<Vec<String>>();
+    if keywords.is_empty() {
+        (NotificationLevel::None, vec![])
+    } else {
+        (NotificationLevel::Keyword, keywords)
+    }
+}
+
+fn matches_keywords(item: &RssItem, keywords: &[String]) -> bool {
+    let text = format!(
+        "{}\n{}",
+        item.rss_title.as_deref().unwrap_or_default(),
+        item.rss_description.as_deref().unwrap_or_default()
+    )
+    .to_lowercase();
+    keywords
+        .iter()
+        .any(|keyword| text.contains(&keyword.to_lowercase()))
+}
+
+fn normalize_keywords(keywords: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = vec![];
+    for keyword in keywords {
+        let keyword = keyword.trim().to_string();
+        if !keyword.is_empty() && !normalized.contains(&keyword) {
+            normalized.push(keyword);
+        }
+    }
+    normalized
+}
+
+/// `channel_id`는 구독 중인 채널, `folder_id`는 내 폴더여야 함
+async fn validate_preference_target(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_id: Option<i32>,
+    folder_id: Option<i32>,
+) -> Result<(), OmniNewsError> {
+    match (channel_id, folder_id) {
+        (Some(channel_id), None) => {
+            let is_subscribed =
+                subscribe_repository::is_already_subscribe_channel(pool, user_id, channel_id)
+                    .await
+                    .map_err(|e| {
+                        user_error!("[Service] Failed to check subscription: {}", e);
+                        OmniNewsError::Database(e)
+                    })?;
+            if !is_subscribed {
+                return Err(OmniNewsError::NotFound(
+                    "Subscribed channel not found".to_string(),
+                ));
+            }
+            Ok(())
+        }
+        (None, Some(folder_id)) => {
+            let folders = folder_repository::select_folders(pool, user_id)
+                .await
+                .map_err(|e| {
+                    user_error!("[Service] Failed to select folders: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+            if !folders
+                .iter()
+                .any(|folder| folder.folder_id == Some(folder_id))
+            {
+                return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+            }
+            Ok(())
+        }
+        _ => Err(OmniNewsError::InvalidValue(
+            "Either channel_id or folder_id is required".to_string(),
+        )),
+    }
+}
+
+async fn find_push_setting(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<UserPushSetting>, OmniNewsError> {
+    push_repository::select_user_push_setting(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select push setting: {}", e);
+            OmniNewsError::Database(e)
+        })
+}
+
+fn default_push_setting(user_id: i32) -> UserPushSetting {
+    UserPushSetting {
+        user_id: Some(user_id),
+        push_quiet_start_hour: None,
+        push_quiet_end_hour: None,
+        push_timezone: Some(DEFAULT_TIMEZONE.to_string()),
+        push_last_sent_at: None,
+        push_failure_count: Some(0),
+        push_retry_at: None,
+        push_updated_at: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn preference(
+        channel_id: Option<i32>,
+        folder_id: Option<i32>,
+        level: NotificationLevel,
+        keywords: &[&str],
+    ) -> NotificationPreference {
+        NotificationPreference {
+            notification_preference_id: None,
+            user_id: Some(1),
+            channel_id,
+            folder_id,
+            notification_level: Some(level.as_str().to_string()),
+            notification_keywords: Some(serde_json::to_string(keywords).unwrap()),
+            notification_preference_updated_at: None,
+        }
+    }
+
+    fn push_target(start: Option<i32>, end: Option<i32>, timezone: Option<&str>) -> PushTarget {
+        PushTarget {
+            user_id: Some(1),
+            user_fcm_token: None,
+            push_quiet_start_hour: start,
+            push_quiet_end_hour: end,
+            push_timezone: timezone.map(str::to_string),
+            push_failure_count: Some(0),
+        }
+    }
+
+    fn kst(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .and_then(|date| date.and_hms_opt(hour, minute, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn resolve_level_defaults_to_all() {
+        assert_eq!(
+            resolve_level(&[], 10, &[]),
+            (NotificationLevel::All, vec![])
+        );
+        assert_eq!(
+            resolve_level(&[], 10, &[1, 2]),
+            (NotificationLevel::All, vec![])
+        );
+    }
+
+    #[test]
+    fn resolve_level_prefers_channel_preference() {
+        let channel = preference(Some(10), None, NotificationLevel::Keyword, &["금리"]);
+        let folder = preference(None, Some(1), NotificationLevel::All, &[]);
+
+        assert_eq!(
+            resolve_level(&[&folder, &channel], 10, &[1]),
+            (NotificationLevel::Keyword, vec!["금리".to_string()])
+        );
+    }
+
+    #[test]
+    fn resolve_level_treats_folder_without_preference_as_all() {
+        let muted = preference(None, Some(1), NotificationLevel::None, &[]);
+
+        assert_eq!(
+            resolve_level(&[&muted], 10, &[1]),
+            (NotificationLevel::None, vec![])
+        );
+        assert_eq!(
+            resolve_level(&[&muted], 10, &[1, 2]),
+            (NotificationLevel::All, vec![])
+        );
+    }
+
+    #[test]
+    fn resolve_level_merges_folder_keywords() {
+        let economy = preference(None, Some(1), NotificationLevel::Keyword, &["금리"]);
+        let market = preference(None, Some(2), NotificationLevel::Keyword, &["환율"]);
+        let muted = preference(None, Some(3), NotificationLevel::None, &[]);
+
+        assert_eq!(
+            resolve_level(&[&economy, &market, &muted], 10, &[1, 2, 3]),
+            (
+                NotificationLevel::Keyword,
+                vec!["금리".to_string(), "환율".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn is_quiet_time_without_hours_is_false() {
+        assert!(!is_quiet_time(&push_target(None, Some(7), None), kst(3, 0)));
+        assert!(!is_quiet_time(
+            &push_target(Some(22), None, None),
+            kst(23, 0)
+        ));
+    }
+
+    #[test]
+    fn is_quiet_time_within_a_day() {
+        let target = push_target(Some(9), Some(18), None);
+
+        assert!(!is_quiet_time(&target, kst(8, 59)));
+        assert!(is_quiet_time(&target, kst(9, 0)));
+        assert!(is_quiet_time(&target, kst(17, 59)));
+        assert!(!is_quiet_time(&target, kst(18, 0)));
+    }
+
+    #[test]
+    fn is_quiet_time_across_midnight() {
+        let target = push_target(Some(22), Some(7), None);
+
+        assert!(is_quiet_time(&target, kst(23, 0)));
+        assert!(is_quiet_time(&target, kst(0, 30)));
+        assert!(is_quiet_time(&target, kst(6, 59)));
+        assert!(!is_quiet_time(&target, kst(7, 0)));
+        assert!(!is_quiet_time(&target, kst(12, 0)));
+    }
+
+    #[test]
+    fn is_quiet_time_uses_user_timezone() {
+        // KST 12:00 = 뉴욕 전날 22:00 (EST)
+        let target = push_target(Some(22), Some(7), Some("America/New_York"));
+        assert!(is_quiet_time(&target, kst(12, 0)));
+        assert!(!is_quiet_time(&target, kst(23, 0)));
+
+        // 알 수 없는 시간대는 서울 기준
+        let target = push_target(Some(22), Some(7), Some("Mars/Base"));
+        assert!(!is_quiet_time(&target, kst(12, 0)));
+    }
+}
@@ -1,16 +1,36 @@
 use scraper::{Html, Selector};
+use sqlx::MySqlPool;
 
 use crate::{
     dto::premium::rss::{request::RssSummaryRequestDto, response::RssSummaryResponseDto},
     model::error::OmniNewsError,
-    utils::gemini::gemini_summarize,
+    repository::rss_item_repository,
+    utils::{
+        gemini::{gemini_summarize, SUMMARY_FAILED_TEXT},
+        time_util::kst_now,
+    },
 };
 
+/// 수집된 아이템의 요약은 `rss_item_summary`에 저장해 글당 한 번만 요약
 pub async fn summary(
+    pool: &MySqlPool,
     item_link_data: RssSummaryRequestDto,
 ) -> Result<RssSummaryResponseDto, OmniNewsError> {
     let item_link = item_link_data.rss_link;
 
+    let rss_id = rss_item_repository::select_item_by_link(pool, item_link.clone())
+        .await
+        .ok()
+        .and_then(|item| item.rss_id);
+
+    if let Some(rss_id) = rss_id {
+        match rss_item_repository::select_rss_item_summary(pool, rss_id).await {
+            Ok(Some(text)) => return Ok(RssSummaryResponseDto { text }),
+            Ok(None) => {}
+            Err(e) => warn!("[Service] Failed to select cached summary: {}", e),
+        }
+    }
+
     let body = {
         let client = reqwest::Client::new();
         let res = client.get(item_link).send().await?.text().await?;
@@ -27,9 +47,23 @@ pub async fn summary(
     };
 
     let summarized_body = gemini_summarize(60, &body).await;
+
+    if let Some(rss_id) = rss_id {
+        if summarized_body != SUMMARY_FAILED_TEXT {
+            if let Err(e) = rss_item_repository::insert_rss_item_summary(
+                pool,
+                rss_id,
+                summarized_body.clone(),
+                kst_now(),
+            )
+            .await
+            {
+                warn!("[Service] Failed to cache summary: {}", e);
+            }
+        }
+    }
+
     Ok(RssSummaryResponseDto {
         text: summarized_body,
     })
-    // TODO: 한번 요약한거 db저장해서 글당 한번만 ㄱㄴ하도록 하기.
-    // 요약은 잘함 ㅇㅇ.
 }
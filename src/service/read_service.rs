@@ -0,0 +1,323 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::read::{
+        request::MarkReadBeforeRequestDto,
+        response::{
+            ChannelUnreadCountResponseDto, FolderUnreadCountResponseDto, UnreadCountResponseDto,
+        },
+    },
+    model::{error::OmniNewsError, sync::SyncEntity},
+    repository::{folder_repository, read_repository, rss_item_repository, subscribe_repository},
+    subscription_error, subscription_warn,
+    utils::time_util::kst_now,
+};
+
+use super::{sync_service, user_service};
+
+// 읽음 상태는 채널별 기준선 + 기준선과 다른 아이템만 개별 저장
+// (모두 읽음 처리 시 기준선만 이동하므로 많이 읽는 사용자도 행 수가 늘지 않음)
+
+pub async fn mark_item_read(
+    pool: &MySqlPool,
+    user_email: String,
+    rss_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    mark_item_read_by_user_id(pool, user_id, rss_id).await
+}
+
+/// `user_articles_read`는 읽기 기록에서 관리하므로 여기서는 읽음 상태만 바꿈
+pub async fn mark_item_read_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<(), OmniNewsError> {
+    set_item_read(pool, user_id, rss_id, true, kst_now()).await
+}
+
+pub async fn mark_item_unread(
+    pool: &MySqlPool,
+    user_email: String,
+    rss_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    mark_item_unread_by_user_id(pool, user_id, rss_id).await
+}
+
+pub async fn mark_item_unread_by_user_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<(), OmniNewsError> {
+    set_item_read(pool, user_id, rss_id, false, kst_now()).await
+}
+
+pub async fn mark_channel_read(
+    pool: &MySqlPool,
+    user_email: String,
+    channel_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    mark_channels_read(pool, user_id, vec![channel_id], None).await
+}
+
+pub async fn mark_folder_read(
+    pool: &MySqlPool,
+    user_email: String,
+    folder_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let channel_ids = find_user_folder_channel_ids(pool, user_id, folder_id).await?;
+
+    mark_channels_read(pool, user_id, channel_ids, None).await
+}
+
+/// 기준 시각 이전에 발행된 아이템을 모두 읽음 처리 (채널 미지정 시 구독 채널 전체)
+pub async fn mark_read_before(
+    pool: &MySqlPool,
+    user_email: String,
+    data: MarkReadBeforeRequestDto,
+) -> Result<(), OmniNewsError> {
+    let Some(before) = data.before else {
+        return Err(OmniNewsError::InvalidValue(
+            "before is required".to_string(),
+        ));
+    };
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let channel_ids = match data.channel_ids {
+        Some(channel_ids) => channel_ids,
+        None => subscribe_repository::select_subscription_channel_ids(pool, user_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select subscription channel ids: {}", e);
+                OmniNewsError::Database(e)
+            })?,
+    };
+
+    mark_channels_read(pool, user_id, channel_ids, Some(before)).await
+}
+
+/// 구독 채널, 폴더별 안 읽은 아이템 수
+pub async fn get_unread_counts(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<UnreadCountResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let mut channel_ids: BTreeSet<i32> =
+        subscribe_repository::select_subscription_channel_ids(pool, user_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select subscription channel ids: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect();
+
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let mut folder_channel_ids = Vec::with_capacity(folders.len());
+    for folder in folders {
+        let folder_id = folder.folder_id.unwrap_or_default();
+        let ids: Vec<i32> = folder_repository::select_channels_in_folder(pool, folder_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select channels in folder: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|channel| channel.channel_id)
+            .collect();
+        channel_ids.extend(ids.iter().copied());
+        folder_channel_ids.push((folder_id, ids));
+    }
+
+    let counts: HashMap<i32, i64> = read_repository::select_unread_counts_by_channel_ids(
+        pool,
+        user_id,
+        channel_ids.iter().copied().collect(),
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select unread counts: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter_map(|count| Some((count.channel_id?, count.unread_count.unwrap_or_default())))
+    .collect();
+    let count_of = |channel_id: &i32| counts.get(channel_id).copied().unwrap_or_default();
+
+    let channels = channel_ids
+        .iter()
+        .map(|channel_id| ChannelUnreadCountResponseDto::new(*channel_id, count_of(channel_id)))
+        .collect();
+    let folders = folder_channel_ids
+        .into_iter()
+        .map(|(folder_id, ids)| {
+            FolderUnreadCountResponseDto::new(folder_id, ids.iter().map(count_of).sum())
+        })
+        .collect();
+    let total = channel_ids.iter().map(count_of).sum();
+
+    Ok(UnreadCountResponseDto::new(total, channels, folders))
+}
+
+/// 주어진 아이템 중 읽은 아이템 ID
+pub async fn find_read_rss_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_ids: Vec<i32>,
+) -> Result<HashSet<i32>, OmniNewsError> {
+    match read_repository::select_read_rss_ids(pool, user_id, rss_ids).await {
+        Ok(res) => Ok(res.into_iter().collect()),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select read rss ids: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn find_user_folder_channel_ids(
+    pool: &MySqlPool,
+    user_id: i32,
+    folder_id: i32,
+) -> Result<Vec<i32>, OmniNewsError> {
+    let folders = folder_repository::select_folders(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if !folders
+        .iter()
+        .any(|folder| folder.folder_id == Some(folder_id))
+    {
+        return Err(OmniNewsError::NotFound("Folder not found".to_string()));
+    }
+
+    match folder_repository::select_channels_in_folder(pool, folder_id).await {
+        Ok(res) => Ok(res
+            .into_iter()
+            .filter_map(|channel| channel.channel_id)
+            .collect()),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select channels in folder: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 아이템 읽음 상태 변경
+///
+/// 기준선과 같은 상태가 되면 개별 상태는 삭제
+///
+/// 동기화로 올라온 변경은 클라이언트에서 바꾼 시각을 `changed_at`으로 씀
+pub async fn set_item_read(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+    read: bool,
+    changed_at: NaiveDateTime,
+) -> Result<(), OmniNewsError> {
+    let item = rss_item_repository::select_rss_item_by_id(pool, rss_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Item not found".into()),
+            e => {
+                subscription_error!("[Service] Failed to select item: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+    let channel_id = item.channel_id.unwrap_or_default();
+
+    let mark = read_repository::select_channel_read_mark(pool, user_id, channel_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select channel read mark: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let covered = mark.is_some_and(|mark| mark.covers(rss_id, item.rss_pub_date));
+
+    let result = if read == covered {
+        read_repository::delete_item_read_state(pool, user_id, rss_id).await
+    } else {
+        read_repository::upsert_item_read_state(pool, user_id, rss_id, channel_id, read, changed_at)
+            .await
+    };
+
+    match result {
+        Ok(_) => {
+            sync_service::record_change(pool, user_id, SyncEntity::ItemRead, rss_id, changed_at)
+                .await;
+            Ok(())
+        }
+        Err(e) => {
+            subscription_error!("[Service] Failed to update item read state: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 채널 기준선을 이동하고 기준선에 포함된 개별 상태를 정리
+///
+/// `before`가 없으면 현재 채널의 모든 아이템을 읽음 처리
+pub async fn mark_channels_read(
+    pool: &MySqlPool,
+    user_id: i32,
+    channel_ids: Vec<i32>,
+    before: Option<NaiveDateTime>,
+) -> Result<(), OmniNewsError> {
+    let now = kst_now();
+
+    for channel_id in channel_ids {
+        let read_mark_rss_id = match before {
+            Some(_) => 0,
+            None => match read_repository::select_max_rss_id_by_channel_id(pool, channel_id)
+                .await
+                .map_err(|e| {
+                    subscription_error!("[Service] Failed to select max rss id: {}", e);
+                    OmniNewsError::Database(e)
+                })? {
+                Some(max_rss_id) => max_rss_id,
+                // 아이템이 없는 채널
+                None => continue,
+            },
+        };
+
+        read_repository::upsert_channel_read_mark(
+            pool,
+            user_id,
+            channel_id,
+            read_mark_rss_id,
+            before,
+            now,
+        )
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to update channel read mark: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+        if let Err(e) =
+            read_repository::delete_item_read_states_covered_by_mark(pool, user_id, channel_id)
+                .await
+        {
+            subscription_warn!("[Service] Failed to clean up item read states: {}", e);
+        }
+        sync_service::record_change(pool, user_id, SyncEntity::ChannelRead, channel_id, now).await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,365 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::reading::{
+        request::{
+            ReadingEventRequestDto, ReadingEventsRequestDto, ReadingHistoryRequestDto,
+            ReadingStatsRequestDto,
+        },
+        response::{
+            ChannelReadingStatResponseDto, ReadingHistoryPageResponseDto,
+            ReadingHistoryResponseDto, ReadingPeriodStatResponseDto, ReadingStatsResponseDto,
+            TagReadingStatResponseDto,
+        },
+    },
+    model::{error::OmniNewsError, reading::ReadingEventType},
+    repository::{reading_repository, rss_item_repository, user_repository},
+    user_error, user_warn,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        time_util::kst_now,
+    },
+};
+
+use super::{read_service, user_service};
+
+const MAX_EVENTS: usize = 100;
+/// 이벤트 하나로 기록할 수 있는 최대 체류 시간 (앱을 켜 둔 채 방치한 경우 제외)
+const MAX_DWELL_SECONDS: i32 = 30 * 60;
+const DEFAULT_STATS_DAYS: i64 = 30;
+const MAX_STATS_DAYS: i64 = 366;
+const TOP_CHANNELS: i32 = 5;
+const TOP_TAGS: i32 = 10;
+
+/// 읽기 이벤트를 순서대로 기록하고 처리한 이벤트 수 반환
+///
+/// 삭제된 아이템 등 처리할 수 없는 이벤트는 건너뜀
+pub async fn record_events(
+    pool: &MySqlPool,
+    user_email: String,
+    data: ReadingEventsRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let events = data.events.unwrap_or_default();
+    if events.len() > MAX_EVENTS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_EVENTS} events can be sent at once"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let mut recorded = 0;
+    for event in events {
+        let result = match event.event_type {
+            Some(ReadingEventType::Open) => record_open(pool, user_id, &event).await,
+            Some(ReadingEventType::Dwell) => record_dwell(pool, user_id, &event).await,
+            None => Ok(false),
+        };
+        match result {
+            Ok(true) => recorded += 1,
+            Ok(false) => {}
+            Err(OmniNewsError::NotFound(_)) => {
+                user_warn!(
+                    "[Service] Skip reading event for missing item: {:?}",
+                    event.rss_id
+                );
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(recorded)
+}
+
+/// 최근 읽은 순 읽기 기록
+pub async fn get_histories(
+    pool: &MySqlPool,
+    user_email: String,
+    data: ReadingHistoryRequestDto,
+) -> Result<ReadingHistoryPageResponseDto, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let limit = page_size(data.size);
+    let ItemCursor::Id { id: cursor_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_id)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match reading history order".to_string(),
+        ));
+    };
+
+    let histories =
+        reading_repository::select_reading_histories_before_id(pool, user_id, cursor_id, limit + 1)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select reading histories: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let (histories, next_cursor) = split_page(histories, limit, |history| ItemCursor::Id {
+        id: history.reading_history_id.unwrap_or_default(),
+    });
+
+    Ok(ReadingHistoryPageResponseDto::new(
+        ReadingHistoryResponseDto::from_model_list(histories),
+        next_cursor,
+    ))
+}
+
+/// 기간별 읽기 통계 (기본 최근 30일)
+pub async fn get_stats(
+    pool: &MySqlPool,
+    user_email: String,
+    data: ReadingStatsRequestDto,
+) -> Result<ReadingStatsResponseDto, OmniNewsError> {
+    let today = kst_now().date();
+    let to = parse_date(data.to.as_deref())?.unwrap_or(today);
+    let from =
+        parse_date(data.from.as_deref())?.unwrap_or(to - Duration::days(DEFAULT_STATS_DAYS - 1));
+    if from > to || (to - from).num_days() >= MAX_STATS_DAYS {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Range must be within {MAX_STATS_DAYS} days"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let daily_stats = reading_repository::select_daily_reading_stats(pool, user_id, from, to)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select daily reading stats: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let top_channels =
+        reading_repository::select_top_channel_reading_stats(pool, user_id, from, to, TOP_CHANNELS)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select channel reading stats: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let top_tags =
+        reading_repository::select_top_tag_reading_stats(pool, user_id, from, to, TOP_TAGS)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to select tag reading stats: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+    let reading_dates = reading_repository::select_reading_dates(pool, user_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select reading dates: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let by_date: BTreeMap<NaiveDate, (i64, i64)> = daily_stats
+        .into_iter()
+        .filter_map(|stat| {
+            Some((
+                stat.stat_date?,
+                (
+                    stat.stat_articles_read.unwrap_or_default(),
+                    stat.stat_seconds.unwrap_or_default(),
+                ),
+            ))
+        })
+        .collect();
+
+    let mut daily = Vec::new();
+    let mut weekly: BTreeMap<NaiveDate, (i64, i64)> = BTreeMap::new();
+    let mut date = from;
+    while date <= to {
+        let (articles_read, seconds) = by_date.get(&date).copied().unwrap_or_default();
+        daily.push(ReadingPeriodStatResponseDto::new(
+            date,
+            articles_read,
+            seconds,
+        ));
+
+        let week_start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+        let week = weekly.entry(week_start).or_default();
+        week.0 += articles_read;
+        week.1 += seconds;
+        date += Duration::days(1);
+    }
+    let weekly = weekly
+        .into_iter()
+        .map(|(week_start, (articles_read, seconds))| {
+            ReadingPeriodStatResponseDto::new(week_start, articles_read, seconds)
+        })
+        .collect();
+
+    let range_dates: Vec<NaiveDate> = reading_dates
+        .iter()
+        .copied()
+        .filter(|date| (from..=to).contains(date))
+        .collect();
+
+    Ok(ReadingStatsResponseDto {
+        from: Some(from),
+        to: Some(to),
+        total_articles_read: Some(by_date.values().map(|(articles, _)| articles).sum()),
+        total_seconds: Some(by_date.values().map(|(_, seconds)| seconds).sum()),
+        current_streak: Some(current_streak(&reading_dates, today)),
+        longest_streak: Some(longest_streak(&range_dates)),
+        daily: Some(daily),
+        weekly: Some(weekly),
+        top_channels: Some(ChannelReadingStatResponseDto::from_model_list(top_channels)),
+        top_tags: Some(TagReadingStatResponseDto::from_model_list(top_tags)),
+    })
+}
+
+/// 기록을 남기고, 처음 연 아이템이면 읽음 처리와 읽은 아이템 수 집계
+async fn record_open(
+    pool: &MySqlPool,
+    user_id: i32,
+    event: &ReadingEventRequestDto,
+) -> Result<bool, OmniNewsError> {
+    let Some(rss_id) = event.rss_id else {
+        return Ok(false);
+    };
+    let item = rss_item_repository::select_rss_item_by_id(pool, rss_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Item not found".into()),
+            e => {
+                user_error!("[Service] Failed to select item: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+    let channel_id = item.channel_id.unwrap_or_default();
+    let now = kst_now();
+    let opened_at = event.occurred_at.filter(|at| *at <= now).unwrap_or(now);
+
+    let first_open = !reading_repository::exists_reading_history(pool, user_id, rss_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select reading history: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    reading_repository::insert_reading_history(pool, user_id, rss_id, channel_id, opened_at)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to insert reading history: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    if first_open {
+        let stat_date = opened_at.date();
+        reading_repository::upsert_reading_daily_stat(pool, user_id, stat_date, channel_id, 1, 0)
+            .await
+            .map_err(|e| {
+                user_error!("[Service] Failed to update reading daily stat: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+        if let Err(e) =
+            reading_repository::increment_reading_tag_stats(pool, user_id, stat_date, rss_id).await
+        {
+            user_warn!("[Service] Failed to update reading tag stats: {}", e);
+        }
+        if let Err(e) = user_repository::increment_user_articles_read(pool, user_id).await {
+            user_warn!("[Service] Failed to increment articles read: {}", e);
+        }
+    }
+
+    read_service::mark_item_read_by_user_id(pool, user_id, rss_id).await?;
+    Ok(true)
+}
+
+/// 마지막으로 연 기록과 그날 통계에 체류 시간 추가
+async fn record_dwell(
+    pool: &MySqlPool,
+    user_id: i32,
+    event: &ReadingEventRequestDto,
+) -> Result<bool, OmniNewsError> {
+    let (Some(rss_id), Some(seconds)) = (event.rss_id, event.seconds) else {
+        return Ok(false);
+    };
+    if seconds <= 0 {
+        return Ok(false);
+    }
+    let seconds = seconds.min(MAX_DWELL_SECONDS);
+
+    let Some(history) = reading_repository::select_latest_reading_history(pool, user_id, rss_id)
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to select reading history: {}", e);
+            OmniNewsError::Database(e)
+        })?
+    else {
+        return Ok(false);
+    };
+
+    reading_repository::add_reading_history_seconds(
+        pool,
+        history.reading_history_id.unwrap_or_default(),
+        seconds,
+    )
+    .await
+    .map_err(|e| {
+        user_error!("[Service] Failed to update reading history: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    if let (Some(channel_id), Some(opened_at)) = (history.channel_id, history.reading_opened_at) {
+        reading_repository::upsert_reading_daily_stat(
+            pool,
+            user_id,
+            opened_at.date(),
+            channel_id,
+            0,
+            seconds,
+        )
+        .await
+        .map_err(|e| {
+            user_error!("[Service] Failed to update reading daily stat: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    }
+    Ok(true)
+}
+
+fn parse_date(value: Option<&str>) -> Result<Option<NaiveDate>, OmniNewsError> {
+    value
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|_| OmniNewsError::InvalidValue(format!("Invalid date: {value}")))
+        })
+        .transpose()
+}
+
+/// 오늘 아직 읽지 않았더라도 어제까지 이어졌으면 유지되는 연속 일수
+fn current_streak(dates: &[NaiveDate], today: NaiveDate) -> i32 {
+    let mut expected = match dates.last() {
+        Some(last) if *last == today => today,
+        Some(last) if *last == today - Duration::days(1) => *last,
+        _ => return 0,
+    };
+
+    let mut streak = 0;
+    for date in dates.iter().rev() {
+        if *date != expected {
+            break;
+        }
+        streak += 1;
+        expected -= Duration::days(1);
+    }
+    streak
+}
+
+fn longest_streak(dates: &[NaiveDate]) -> i32 {
+    let mut longest = 0;
+    let mut streak = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for date in dates {
+        streak = match previous {
+            Some(previous) if *date - previous == Duration::days(1) => streak + 1,
+            _ => 1,
+        };
+        longest = longest.max(streak);
+        previous = Some(*date);
+    }
+    longest
+}
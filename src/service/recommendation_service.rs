@@ -0,0 +1,356 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDateTime};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::rss::response::{RssChannelResponseDto, RssItemResponseDto},
+    model::{
+        error::OmniNewsError,
+        rss::{RssChannel, RssItem},
+    },
+    repository::{
+        embedding_repository, reading_repository, rss_channel_repository, rss_item_repository,
+        subscribe_repository,
+    },
+    rss_error, rss_info,
+    utils::{
+        embedding_util::{cosine_similarity, decode_embedding},
+        time_util::kst_now,
+    },
+};
+
+use super::{channel_service, item_service, mute_service, user_service};
+
+// 추천 후보 조회 범위
+const ITEM_CANDIDATE_DAYS: i64 = 3;
+const ITEM_CANDIDATE_LIMIT: i32 = 1000;
+const CHANNEL_CANDIDATE_LIMIT: i32 = 500;
+
+const RECOMMEND_ITEM_COUNT: usize = 50;
+const RECOMMEND_CHANNEL_COUNT: usize = 20;
+
+// 점수 = 관심사 유사도 * SIMILARITY_WEIGHT + 인기도 * POPULARITY_WEIGHT + 최신성 * RECENCY_WEIGHT
+const SIMILARITY_WEIGHT: f32 = 0.6;
+const POPULARITY_WEIGHT: f32 = 0.25;
+const RECENCY_WEIGHT: f32 = 0.15;
+// 최신성 점수가 절반이 되는 시간
+const RECENCY_HALF_LIFE_HOURS: f32 = 24.0;
+
+// 관심사 벡터에 반영할 최근 읽은 글 범위
+const READ_HISTORY_DAYS: i64 = 30;
+const READ_HISTORY_LIMIT: i32 = 100;
+// 관심사 벡터 = 구독 채널 평균 * (1 - READ_HISTORY_WEIGHT) + 읽은 글 평균 * READ_HISTORY_WEIGHT
+const READ_HISTORY_WEIGHT: f32 = 0.5;
+
+// MMR: 1.0에 가까울수록 점수 우선, 0.0에 가까울수록 다양성 우선
+const MMR_LAMBDA: f32 = 0.7;
+
+struct Candidate<T> {
+    value: T,
+    vector: Vec<f32>,
+    score: f32,
+}
+
+pub async fn get_recommend_item(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let mute_filter = mute_service::find_mute_filter(pool, user_id).await?;
+
+    let Some(interest) = build_interest_vector(pool, user_id).await? else {
+        rss_info!(
+            "[Service] No interest vector for user {}, use popular items",
+            user_id
+        );
+        return item_service::get_recommend_item(pool).await.map(|items| {
+            items
+                .into_iter()
+                .filter(|item| !mute_filter.hides_response(item))
+                .collect()
+        });
+    };
+
+    let now = kst_now();
+    let mut items = rss_item_repository::select_rss_items_pub_date_after(
+        pool,
+        now - Duration::days(ITEM_CANDIDATE_DAYS),
+        ITEM_CANDIDATE_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!(
+            "[Service] Failed to select recommend item candidates: {}",
+            e
+        );
+        OmniNewsError::Database(e)
+    })?;
+    mute_filter.retain_visible(&mut items);
+
+    let rss_ids = items.iter().filter_map(|item| item.rss_id).collect();
+    let vectors: HashMap<i32, Vec<f32>> =
+        embedding_repository::select_embeddings_by_rss_ids(pool, rss_ids)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select item embeddings: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|embedding| {
+                Some((
+                    embedding.rss_id?,
+                    decode_embedding(&embedding.embedding_value?),
+                ))
+            })
+            .collect();
+
+    let max_rank = items
+        .iter()
+        .map(|item| item.rss_rank.unwrap_or_default())
+        .max()
+        .unwrap_or_default();
+
+    let candidates = items
+        .into_iter()
+        .filter_map(|item| {
+            let vector = vectors.get(&item.rss_id?)?.clone();
+            let score = SIMILARITY_WEIGHT * cosine_similarity(&interest, &vector)
+                + POPULARITY_WEIGHT * popularity_score(item.rss_rank.unwrap_or_default(), max_rank)
+                + RECENCY_WEIGHT * recency_score(item.rss_pub_date, now);
+            Some(Candidate {
+                value: item,
+                vector,
+                score,
+            })
+        })
+        .collect();
+
+    let items: Vec<RssItem> = select_by_mmr(candidates, RECOMMEND_ITEM_COUNT);
+    Ok(RssItemResponseDto::from_model_list(items))
+}
+
+pub async fn get_recommend_channel(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<RssChannelResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let Some(interest) = build_interest_vector(pool, user_id).await? else {
+        rss_info!(
+            "[Service] No interest vector for user {}, use popular channels",
+            user_id
+        );
+        return channel_service::get_recommend_channel(pool).await;
+    };
+
+    let subscribed: HashSet<i32> =
+        subscribe_repository::select_subscription_channel_ids(pool, user_id)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select subscription channel ids: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .collect();
+
+    let vectors: HashMap<i32, Vec<f32>> =
+        embedding_repository::select_channel_embeddings_order_by_channel_rank(
+            pool,
+            CHANNEL_CANDIDATE_LIMIT,
+        )
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select channel embeddings: {}", e);
+            OmniNewsError::Database(e)
+        })?
+        .into_iter()
+        .filter_map(|embedding| {
+            let channel_id = embedding.channel_id?;
+            if subscribed.contains(&channel_id) {
+                return None;
+            }
+            Some((channel_id, decode_embedding(&embedding.embedding_value?)))
+        })
+        .collect();
+
+    let channels =
+        rss_channel_repository::select_rss_channels_by_ids(pool, vectors.keys().copied().collect())
+            .await
+            .map_err(|e| {
+                rss_error!(
+                    "[Service] Failed to select recommend channel candidates: {}",
+                    e
+                );
+                OmniNewsError::Database(e)
+            })?;
+
+    let max_rank = channels
+        .iter()
+        .map(|channel| channel.channel_rank.unwrap_or_default())
+        .max()
+        .unwrap_or_default();
+
+    let candidates = channels
+        .into_iter()
+        .filter_map(|channel| {
+            let vector = vectors.get(&channel.channel_id?)?.clone();
+            let score = SIMILARITY_WEIGHT * cosine_similarity(&interest, &vector)
+                + POPULARITY_WEIGHT
+                    * popularity_score(channel.channel_rank.unwrap_or_default(), max_rank);
+            Some(Candidate {
+                value: channel,
+                vector,
+                score,
+            })
+        })
+        .collect();
+
+    let channels: Vec<RssChannel> = select_by_mmr(candidates, RECOMMEND_CHANNEL_COUNT);
+    Ok(RssChannelResponseDto::from_model_list(channels))
+}
+
+/// 사용자의 관심사 벡터 (구독 채널 임베딩 평균과 최근 읽은 글 임베딩 평균의 가중합)
+async fn build_interest_vector(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Option<Vec<f32>>, OmniNewsError> {
+    let channel_ids = subscribe_repository::select_subscription_channel_ids(pool, user_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select subscription channel ids: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let channel_vectors = embedding_repository::select_embeddings_by_channel_ids(pool, channel_ids)
+        .await
+        .map_err(|e| {
+            rss_error!(
+                "[Service] Failed to select subscription channel embeddings: {}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?
+        .into_iter()
+        .filter_map(|embedding| embedding.embedding_value)
+        .map(|value| decode_embedding(&value))
+        .collect::<Vec<Vec<f32>>>();
+
+    let read_rss_ids = reading_repository::select_recent_read_rss_ids(
+        pool,
+        user_id,
+        kst_now() - Duration::days(READ_HISTORY_DAYS),
+        READ_HISTORY_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select recent read rss ids: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    let read_vectors = embedding_repository::select_embeddings_by_rss_ids(pool, read_rss_ids)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select read item embeddings: {}", e);
+            OmniNewsError::Database(e)
+        })?
+        .into_iter()
+        .filter_map(|embedding| embedding.embedding_value)
+        .map(|value| decode_embedding(&value))
+        .collect::<Vec<Vec<f32>>>();
+
+    Ok(blend_vectors(
+        mean_vector(&channel_vectors),
+        mean_vector(&read_vectors),
+        READ_HISTORY_WEIGHT,
+    ))
+}
+
+/// 한쪽만 있으면 그대로 사용, 차원이 다르면 구독 채널 벡터 우선
+fn blend_vectors(
+    base: Option<Vec<f32>>,
+    other: Option<Vec<f32>>,
+    other_weight: f32,
+) -> Option<Vec<f32>> {
+    match (base, other) {
+        (Some(base), Some(other)) if base.len() == other.len() => Some(
+            base.iter()
+                .zip(other.iter())
+                .map(|(b, o)| b * (1.0 - other_weight) + o * other_weight)
+                .collect(),
+        ),
+        (Some(base), _) => Some(base),
+        (None, other) => other,
+    }
+}
+
+fn mean_vector(vectors: &[Vec<f32>]) -> Option<Vec<f32>> {
+    let dimension = vectors.first()?.len();
+    let mut mean = vec![0.0; dimension];
+    let mut count = 0;
+
+    for vector in vectors.iter().filter(|vector| vector.len() == dimension) {
+        for (sum, value) in mean.iter_mut().zip(vector.iter()) {
+            *sum += value;
+        }
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+    for value in &mut mean {
+        *value /= count as f32;
+    }
+    Some(mean)
+}
+
+fn popularity_score(rank: i32, max_rank: i32) -> f32 {
+    if rank <= 0 || max_rank <= 0 {
+        return 0.0;
+    }
+    (1.0 + rank as f32).ln() / (1.0 + max_rank as f32).ln()
+}
+
+fn recency_score(pub_date: Option<NaiveDateTime>, now: NaiveDateTime) -> f32 {
+    let Some(pub_date) = pub_date else {
+        return 0.0;
+    };
+    let age_hours = (now - pub_date).num_minutes().max(0) as f32 / 60.0;
+    0.5_f32.powf(age_hours / RECENCY_HALF_LIFE_HOURS)
+}
+
+/// Maximal Marginal Relevance로 점수가 높으면서 서로 겹치지 않는 후보를 고름
+fn select_by_mmr<T>(mut candidates: Vec<Candidate<T>>, count: usize) -> Vec<T> {
+    let mut selected = Vec::with_capacity(count);
+    // 후보별로 이미 선택된 후보와의 최대 유사도
+    let mut max_similarity = vec![0.0_f32; candidates.len()];
+
+    while selected.len() < count && !candidates.is_empty() {
+        let best = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, candidate)| {
+                (
+                    i,
+                    MMR_LAMBDA * candidate.score - (1.0 - MMR_LAMBDA) * max_similarity[i],
+                )
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+            .unwrap();
+
+        let chosen = candidates.swap_remove(best);
+        max_similarity.swap_remove(best);
+
+        for (i, candidate) in candidates.iter().enumerate() {
+            let similarity = cosine_similarity(&chosen.vector, &candidate.vector);
+            if similarity > max_similarity[i] {
+                max_similarity[i] = similarity;
+            }
+        }
+        selected.push(chosen.value);
+    }
+
+    selected
+}
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+
+use sqlx::MySqlPool;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        rss::response::RssItemResponseDto,
+        search::{
+            request::CreateSavedSearchRequestDto,
+            response::{SavedSearchHitsResponseDto, SavedSearchResponseDto},
+        },
+    },
+    model::{
+        error::OmniNewsError,
+        rss::RssItem,
+        search::{NewSavedSearch, SavedSearch, SavedSearchHit, SavedSearchMode},
+    },
+    repository::{embedding_repository, rss_item_repository, saved_search_repository},
+    rss_error, rss_info,
+    utils::{
+        embedding_util::{
+            cosine_similarity, decode_embedding, embedding_sentence, encode_embedding,
+            EmbeddingService,
+        },
+        feed_util::{build_rss_feed, public_feed_url},
+        language_util::normalize_language_tag,
+        time_util::kst_now,
+    },
+};
+
+use super::user_service;
+
+const MAX_QUERY_LENGTH: usize = 200;
+const MAX_NAME_LENGTH: usize = 100;
+// 이 이상 유사하면 의미 검색 알림으로 기록
+const SEMANTIC_HIT_SIMILARITY: f32 = 0.5;
+// 알림 목록에서 저장된 검색별 최대 아이템 수
+const MAX_HITS_PER_SEARCH: i32 = 100;
+const FEED_ITEM_LIMIT: i32 = 50;
+
+pub async fn create_saved_search(
+    pool: &MySqlPool,
+    embedding_service: &EmbeddingService,
+    user_email: String,
+    data: CreateSavedSearchRequestDto,
+) -> Result<i32, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let query = data
+        .saved_search_query
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .take(MAX_QUERY_LENGTH)
+        .collect::<String>();
+    if query.is_empty() {
+        return Err(OmniNewsError::InvalidValue("saved_search_query".into()));
+    }
+    let name = data
+        .saved_search_name
+        .map(|name| {
+            name.trim()
+                .chars()
+                .take(MAX_NAME_LENGTH)
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| query.clone());
+    let mode = data.saved_search_mode.unwrap_or(SavedSearchMode::Keyword);
+
+    let embedding = match mode {
+        SavedSearchMode::Semantic => Some(encode_embedding(
+            &embedding_sentence(embedding_service, query.clone()).await?,
+        )),
+        SavedSearchMode::Keyword => None,
+    };
+
+    let saved_search = NewSavedSearch {
+        user_id: Some(user_id),
+        saved_search_name: Some(name),
+        saved_search_query: Some(query),
+        saved_search_mode: Some(mode.as_str().to_string()),
+        saved_search_channel_ids: data.channel_ids.filter(|ids| !ids.is_empty()).map(|ids| {
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        }),
+        saved_search_language: data.language.as_deref().and_then(normalize_language_tag),
+        saved_search_embedding: embedding,
+        saved_search_token: Some(Uuid::new_v4().simple().to_string()),
+        saved_search_created_at: Some(kst_now()),
+    };
+
+    match saved_search_repository::insert_saved_search(pool, saved_search).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            rss_error!("[Service] Failed to insert saved search: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn get_saved_searches(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<SavedSearchResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let saved_searches = saved_search_repository::select_saved_searches_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select saved searches: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut unseen_counts: HashMap<i32, i32> = HashMap::new();
+    for hit in find_unseen_hits(pool, user_id).await? {
+        *unseen_counts
+            .entry(hit.saved_search_id.unwrap_or_default())
+            .or_default() += 1;
+    }
+
+    Ok(saved_searches
+        .into_iter()
+        .map(|saved_search| {
+            let unseen_count = saved_search
+                .saved_search_id
+                .and_then(|id| unseen_counts.get(&id).copied())
+                .unwrap_or_default();
+            let feed_url = saved_search_feed_url(&saved_search);
+            SavedSearchResponseDto::new(saved_search, feed_url, unseen_count)
+        })
+        .collect())
+}
+
+pub async fn delete_saved_search(
+    pool: &MySqlPool,
+    user_email: String,
+    saved_search_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    match saved_search_repository::delete_saved_search(pool, user_id, saved_search_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(OmniNewsError::NotFound("Saved search not found".into())),
+        Err(e) => {
+            rss_error!("[Service] Failed to delete saved search: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 저장된 검색별 아직 확인하지 않은 알림 아이템
+pub async fn get_unseen_hits(
+    pool: &MySqlPool,
+    user_email: String,
+) -> Result<Vec<SavedSearchHitsResponseDto>, OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    let saved_searches = saved_search_repository::select_saved_searches_by_user_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select saved searches: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    let mut rss_ids_by_search: HashMap<i32, Vec<i32>> = HashMap::new();
+    for hit in find_unseen_hits(pool, user_id).await? {
+        let (Some(saved_search_id), Some(rss_id)) = (hit.saved_search_id, hit.rss_id) else {
+            continue;
+        };
+        rss_ids_by_search
+            .entry(saved_search_id)
+            .or_default()
+            .push(rss_id);
+    }
+
+    let items: HashMap<i32, RssItem> = rss_item_repository::select_rss_items_by_ids(
+        pool,
+        rss_ids_by_search.values().flatten().copied().collect(),
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select saved search hit items: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter_map(|item| Some((item.rss_id?, item)))
+    .collect();
+
+    Ok(saved_searches
+        .iter()
+        .map(|saved_search| {
+            let hit_items = rss_ids_by_search
+                .get(&saved_search.saved_search_id.unwrap_or_default())
+                .map(|rss_ids| {
+                    rss_ids
+                        .iter()
+                        .filter_map(|rss_id| items.get(rss_id).cloned())
+                        .map(RssItemResponseDto::from_model)
+                        .collect()
+                })
+                .unwrap_or_default();
+            SavedSearchHitsResponseDto::new(saved_search, hit_items)
+        })
+        .collect())
+}
+
+pub async fn mark_hits_seen(
+    pool: &MySqlPool,
+    user_email: String,
+    saved_search_id: i32,
+) -> Result<(), OmniNewsError> {
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+
+    // 확인할 알림이 없어도 성공이므로, 다른 사용자의 저장된 검색인지는 따로 확인
+    saved_search_repository::select_saved_search_by_id(pool, user_id, saved_search_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Saved search not found".into()),
+            e => {
+                rss_error!("[Service] Failed to select saved search: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+
+    match saved_search_repository::update_saved_search_hits_seen(pool, user_id, saved_search_id)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            rss_error!("[Service] Failed to update saved search hits seen: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 저장된 검색의 비공개 RSS 피드 (토큰으로만 접근)
+pub async fn get_saved_search_feed(pool: &MySqlPool, token: &str) -> Result<String, OmniNewsError> {
+    let saved_search = saved_search_repository::select_saved_search_by_token(pool, token)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => OmniNewsError::NotFound("Saved search not found".into()),
+            e => {
+                rss_error!("[Service] Failed to select saved search by token: {}", e);
+                OmniNewsError::Database(e)
+            }
+        })?;
+
+    let rss_ids = saved_search_repository::select_recent_saved_search_hits(
+        pool,
+        saved_search.saved_search_id.unwrap_or_default(),
+        FEED_ITEM_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select saved search hits: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter_map(|hit| hit.rss_id)
+    .collect::<Vec<i32>>();
+
+    let mut items = rss_item_repository::select_rss_items_by_ids(pool, rss_ids)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select saved search feed items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    items.sort_by(|a, b| b.rss_pub_date.cmp(&a.rss_pub_date));
+
+    let name = saved_search.saved_search_name.clone().unwrap_or_default();
+    Ok(build_rss_feed(
+        &format!("Omninews - {name}"),
+        &saved_search_feed_url(&saved_search),
+        &format!(
+            "Saved search: {}",
+            saved_search.saved_search_query.unwrap_or_default()
+        ),
+        items,
+    ))
+}
+
+/// 새로 수집된 아이템 중 저장된 검색에 해당하는 아이템을 알림으로 기록
+pub async fn record_saved_search_hits(
+    pool: &MySqlPool,
+    rss_ids: Vec<i32>,
+) -> Result<(), OmniNewsError> {
+    if rss_ids.is_empty() {
+        return Ok(());
+    }
+
+    let saved_searches = saved_search_repository::select_all_saved_searches(pool)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select saved searches: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    if saved_searches.is_empty() {
+        return Ok(());
+    }
+
+    let items = rss_item_repository::select_rss_items_by_ids(pool, rss_ids.clone())
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select new items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let vectors: HashMap<i32, Vec<f32>> =
+        embedding_repository::select_embeddings_by_rss_ids(pool, rss_ids)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select new item embeddings: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|embedding| {
+                Some((
+                    embedding.rss_id?,
+                    decode_embedding(&embedding.embedding_value?),
+                ))
+            })
+            .collect();
+
+    let now = kst_now();
+    let mut hit_count = 0;
+    for saved_search in &saved_searches {
+        let saved_search_id = saved_search.saved_search_id.unwrap_or_default();
+        let query_vector = saved_search
+            .saved_search_embedding
+            .as_deref()
+            .map(decode_embedding);
+
+        for item in &items {
+            let rss_id = item.rss_id.unwrap_or_default();
+            let item_vector = vectors.get(&rss_id).map(Vec::as_slice);
+            if !is_saved_search_hit(saved_search, query_vector.as_deref(), item, item_vector) {
+                continue;
+            }
+
+            match saved_search_repository::insert_saved_search_hit(
+                pool,
+                saved_search_id,
+                rss_id,
+                now,
+            )
+            .await
+            {
+                Ok(true) => hit_count += 1,
+                Ok(false) => {}
+                Err(e) => rss_error!("[Service] Failed to insert saved search hit: {}", e),
+            }
+        }
+    }
+
+    rss_info!("[Service] Recorded {} saved search hits", hit_count);
+    Ok(())
+}
+
+fn is_saved_search_hit(
+    saved_search: &SavedSearch,
+    query_vector: Option<&[f32]>,
+    item: &RssItem,
+    item_vector: Option<&[f32]>,
+) -> bool {
+    if let Some(channel_ids) = &saved_search.saved_search_channel_ids {
+        let channel_id = item.channel_id.unwrap_or_default().to_string();
+        if !channel_ids.split(',').any(|id| id.trim() == channel_id) {
+            return false;
+        }
+    }
+    if let Some(language) = &saved_search.saved_search_language {
+        if item.rss_language.as_ref() != Some(language) {
+            return false;
+        }
+    }
+
+    let mode = SavedSearchMode::from_value(
+        saved_search
+            .saved_search_mode
+            .as_deref()
+            .unwrap_or_default(),
+    );
+    match mode {
+        SavedSearchMode::Keyword => {
+            let text = format!(
+                "{} {}",
+                item.rss_title.as_deref().unwrap_or_default(),
+                item.rss_description.as_deref().unwrap_or_default()
+            )
+            .to_lowercase();
+            // 한글은 조사가 붙어도 매칭되도록 부분 문자열로 비교
+            saved_search
+                .saved_search_query
+                .as_deref()
+                .unwrap_or_default()
+                .to_lowercase()
+                .split_whitespace()
+                .all(|term| text.contains(term))
+        }
+        SavedSearchMode::Semantic => match (query_vector, item_vector) {
+            (Some(query_vector), Some(item_vector)) => {
+                cosine_similarity(query_vector, item_vector) >= SEMANTIC_HIT_SIMILARITY
+            }
+            _ => false,
+        },
+    }
+}
+
+async fn find_unseen_hits(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<SavedSearchHit>, OmniNewsError> {
+    saved_search_repository::select_unseen_saved_search_hits_by_user_id(
+        pool,
+        user_id,
+        MAX_HITS_PER_SEARCH,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select unseen saved search hits: {}", e);
+        OmniNewsError::Database(e)
+    })
+}
+
+fn saved_search_feed_url(saved_search: &SavedSearch) -> String {
+    public_feed_url(&format!(
+        "/search/saved/feed/{}",
+        saved_search
+            .saved_search_token
+            .as_deref()
+            .unwrap_or_default()
+    ))
+}
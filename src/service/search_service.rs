@@ -0,0 +1,279 @@
+use std::{collections::HashSet, env, sync::LazyLock};
+
+use chrono::Duration;
+use sha2::{Digest, Sha256};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        rss::response::{RssChannelResponseDto, RssItemResponseDto},
+        search::{
+            request::SearchSuggestRequestDto,
+            response::{SearchSnippetResponseDto, SearchSuggestResponseDto},
+        },
+    },
+    model::{
+        error::OmniNewsError,
+        search::{NewSearchQueryLog, SearchMatchType},
+    },
+    repository::{rss_channel_repository, rss_item_repository, search_repository},
+    rss_error, rss_warn,
+    utils::{
+        db_util::escape_like,
+        embedding_util::{cosine_similarity, embedding_sentence, EmbeddingService},
+        hangul_util::{is_jamo_prefix, is_jamo_word_prefix, jamo_prefix_query},
+        snippet_util::{
+            escape_html, highlight, lexical_snippet, search_terms, split_sentences, truncate_chars,
+        },
+        time_util::kst_now,
+    },
+};
+
+use super::user_service;
+
+const DEFAULT_SUGGEST_LIMIT: i32 = 5;
+const MAX_SUGGEST_LIMIT: i32 = 20;
+// 자모 prefix 필터링 전 DB에서 가져올 후보 개수
+const SUGGEST_CANDIDATE_LIMIT: i32 = 300;
+// 인기 검색어 집계 기간
+const POPULAR_QUERY_DAYS: i64 = 7;
+const MAX_QUERY_LENGTH: usize = 200;
+const SNIPPET_MAX_CHARS: usize = 120;
+// 문장 임베딩은 비용이 크므로 앞쪽 결과, 앞쪽 문장만 비교하고 나머지는 첫 문장 사용
+const SEMANTIC_SNIPPET_ITEMS: usize = 10;
+const SEMANTIC_SNIPPET_SENTENCES: usize = 5;
+
+// salt 없이 해시하면 사용자 ID로 되돌릴 수 있으므로, 설정되지 않았으면 검색어를 기록하지 않음
+static SEARCH_LOG_SALT: LazyLock<Option<String>> = LazyLock::new(|| {
+    let salt = env::var("SEARCH_LOG_SALT")
+        .ok()
+        .filter(|salt| !salt.trim().is_empty());
+    if salt.is_none() {
+        rss_warn!("[Service] SEARCH_LOG_SALT is not set, search queries are not logged");
+    }
+    salt
+});
+
+pub async fn get_search_suggest(
+    pool: &MySqlPool,
+    data: SearchSuggestRequestDto,
+) -> Result<SearchSuggestResponseDto, OmniNewsError> {
+    let query = normalize_query(&data.q.unwrap_or_default());
+    let limit = data
+        .limit
+        .unwrap_or(DEFAULT_SUGGEST_LIMIT)
+        .clamp(1, MAX_SUGGEST_LIMIT) as usize;
+
+    if query.is_empty() {
+        return Ok(SearchSuggestResponseDto::new(vec![], vec![], vec![]));
+    }
+
+    // 마지막 글자는 조합 중일 수 있으므로 음절 범위로 후보를 가져온 뒤 자모 단위로 거름
+    // (모음 자모만 입력했으면 후보를 좁힐 수 없어 빈 결과)
+    let Some(prefix) = jamo_prefix_query(&query) else {
+        return Ok(SearchSuggestResponseDto::new(vec![], vec![], vec![]));
+    };
+    let stem = escape_like(&prefix.stem);
+    let range = prefix
+        .range
+        .as_ref()
+        .map(|(start, end)| (start.as_str(), end.as_str()));
+
+    let channels = rss_channel_repository::select_rss_channels_by_title_prefix(
+        pool,
+        &stem,
+        range,
+        SUGGEST_CANDIDATE_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select channels by title prefix: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter(|channel| {
+        is_jamo_word_prefix(&query, channel.channel_title.as_deref().unwrap_or_default())
+    })
+    .take(limit)
+    .collect();
+
+    let since = kst_now() - Duration::days(POPULAR_QUERY_DAYS);
+    let queries = search_repository::select_popular_search_queries(
+        pool,
+        &stem,
+        range,
+        since,
+        SUGGEST_CANDIDATE_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select popular search queries: {}", e);
+        OmniNewsError::Database(e)
+    })?
+    .into_iter()
+    .filter_map(|popular| popular.search_query)
+    .filter(|search_query| search_query != &query && is_jamo_prefix(&query, search_query))
+    .take(limit)
+    .collect();
+
+    let tags =
+        rss_item_repository::select_rss_tags_by_prefix(pool, &stem, range, SUGGEST_CANDIDATE_LIMIT)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select rss tags by prefix: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|tag| tag.tag_name)
+            .filter(|tag_name| is_jamo_prefix(&query, tag_name))
+            .take(limit)
+            .collect();
+
+    Ok(SearchSuggestResponseDto::new(
+        RssChannelResponseDto::from_model_list(channels),
+        queries,
+        tags,
+    ))
+}
+
+/// 검색어 기록. 사용자는 해시로만 저장되어 인기 검색어 집계(사용자 수 기준)에만 사용됨.
+///
+/// 검색 응답을 기다리게 하지 않도록 백그라운드에서 저장하고, 실패해도 검색에는 영향 없음
+pub fn record_search_query(pool: &MySqlPool, user_email: String, search_value: &str) {
+    let query = normalize_query(search_value);
+    if query.is_empty() {
+        return;
+    }
+    let Some(salt) = SEARCH_LOG_SALT.as_deref() else {
+        return;
+    };
+
+    let pool = pool.clone();
+    tokio::spawn(async move {
+        let user_id = match user_service::find_user_id_by_email(&pool, user_email).await {
+            Ok(user_id) => user_id,
+            Err(_) => return,
+        };
+        let log = NewSearchQueryLog {
+            search_user_hash: Some(anonymize_user(salt, user_id)),
+            search_query: Some(query),
+            search_created_at: Some(kst_now()),
+        };
+
+        if let Err(e) = search_repository::insert_search_query_log(&pool, log).await {
+            rss_error!("[Service] Failed to insert search query log: {}", e);
+        }
+    });
+}
+
+/// 검색 결과에 매칭 이유(스니펫)를 붙임
+///
+/// 키워드 매칭은 검색어를 하이라이트하고, 의미 검색 매칭(`semantic_rss_ids`)은 검색어와 가장 가까운 문장을 사용
+pub async fn attach_snippets(
+    embedding_service: &EmbeddingService,
+    query: &str,
+    mut items: Vec<RssItemResponseDto>,
+    semantic_rss_ids: &HashSet<i32>,
+) -> Vec<RssItemResponseDto> {
+    let terms = search_terms(query);
+
+    let has_semantic = items
+        .iter()
+        .any(|item| item.rss_id.is_some_and(|id| semantic_rss_ids.contains(&id)));
+    let query_vector = if has_semantic {
+        embedding_sentence(embedding_service, query.to_string())
+            .await
+            .map_err(|e| {
+                rss_warn!(
+                    "[Service] Failed to embed search query for snippet: {:?}",
+                    e
+                )
+            })
+            .ok()
+    } else {
+        None
+    };
+
+    let mut semantic_count = 0;
+    for item in items.iter_mut() {
+        let is_semantic = item.rss_id.is_some_and(|id| semantic_rss_ids.contains(&id));
+        let title = item.rss_title.as_deref().unwrap_or_default();
+        let description = item.rss_description.as_deref().unwrap_or_default();
+
+        let snippet = if is_semantic {
+            semantic_count += 1;
+            let vector = query_vector
+                .as_deref()
+                .filter(|_| semantic_count <= SEMANTIC_SNIPPET_ITEMS);
+            let sentence = best_sentence(embedding_service, vector, description).await;
+
+            SearchSnippetResponseDto::new(
+                SearchMatchType::Semantic,
+                highlight(title, &terms).or_else(|| item.rss_title.as_deref().map(escape_html)),
+                sentence.map(|sentence| {
+                    highlight(&sentence, &terms).unwrap_or_else(|| {
+                        escape_html(&truncate_chars(&sentence, SNIPPET_MAX_CHARS))
+                    })
+                }),
+            )
+        } else {
+            SearchSnippetResponseDto::new(
+                SearchMatchType::Lexical,
+                highlight(title, &terms).or_else(|| item.rss_title.as_deref().map(escape_html)),
+                lexical_snippet(description, &terms, SNIPPET_MAX_CHARS)
+                    .or_else(|| Some(escape_html(&truncate_chars(description, SNIPPET_MAX_CHARS))))
+                    .filter(|text| !text.is_empty()),
+            )
+        };
+        item.snippet = Some(snippet);
+    }
+
+    items
+}
+
+// 검색어 벡터와 가장 가까운 문장, 벡터가 없으면 첫 문장
+async fn best_sentence(
+    embedding_service: &EmbeddingService,
+    query_vector: Option<&[f32]>,
+    text: &str,
+) -> Option<String> {
+    let sentences: Vec<String> = split_sentences(text)
+        .into_iter()
+        .take(SEMANTIC_SNIPPET_SENTENCES)
+        .collect();
+    let Some(query_vector) = query_vector.filter(|_| sentences.len() > 1) else {
+        return sentences.into_iter().next();
+    };
+
+    let mut best: Option<(f32, String)> = None;
+    for sentence in sentences {
+        let Ok(vector) = embedding_sentence(embedding_service, sentence.clone()).await else {
+            continue;
+        };
+        let similarity = cosine_similarity(query_vector, &vector);
+        match &best {
+            Some((score, _)) if *score >= similarity => {}
+            _ => best = Some((similarity, sentence)),
+        }
+    }
+
+    best.map(|(_, sentence)| sentence)
+}
+
+fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+        .chars()
+        .take(MAX_QUERY_LENGTH)
+        .collect()
+}
+
+fn anonymize_user(salt: &str, user_id: i32) -> String {
+    format!(
+        "{:x}",
+        Sha256::digest(format!("{salt}:{user_id}").as_bytes())
+    )
+}
@@ -0,0 +1,358 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDateTime};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::rss::response::{RssItemResponseDto, RssStoryResponseDto},
+    model::{
+        error::OmniNewsError,
+        rss::RssItem,
+        story::{NewRssStory, RssStory, RssStorySource},
+    },
+    repository::{embedding_repository, rss_item_repository, story_repository},
+    rss_error, rss_info, rss_warn,
+    utils::{
+        embedding_util::{cosine_similarity, decode_embedding, encode_embedding},
+        time_util::kst_now,
+    },
+};
+
+// 아이템 발행 시각 전후로 진행 중이던 스토리만 새 아이템과 비교
+const STORY_WINDOW_HOURS: i64 = 24;
+const STORY_CANDIDATE_LIMIT: i32 = 200;
+// 동시에 같은 스토리에 합류할 때 중심 갱신 재시도 횟수
+const STORY_UPDATE_ATTEMPTS: usize = 3;
+
+// 임베딩만으로 같은 스토리로 보는 유사도
+const STORY_EMBEDDING_THRESHOLD: f32 = 0.85;
+// 제목도 비슷하면 완화해서 적용하는 유사도
+const STORY_EMBEDDING_WITH_TITLE_THRESHOLD: f32 = 0.75;
+const STORY_TITLE_THRESHOLD: f32 = 0.4;
+
+// 묶인 항목 하나에 노출할 최대 출처 수
+const STORY_MAX_SOURCES: usize = 20;
+
+/// 새 아이템을 가장 가까운 스토리에 넣고, 없으면 새 스토리를 만듦
+///
+/// 스토리 중심(centroid)은 소속 아이템 임베딩의 평균이며,
+/// 대표 헤드라인은 중심에 가장 가까운 아이템의 제목으로 갱신됨
+pub async fn assign_rss_item_to_story(pool: &MySqlPool, rss_id: i32) -> Result<i32, OmniNewsError> {
+    let item = rss_item_repository::select_rss_item_by_id(pool, rss_id)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select rss item for story: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+    let vector = embedding_repository::select_embedding_by_rss_id(pool, rss_id)
+        .await
+        .map_err(|e| {
+            rss_error!(
+                "[Service] Failed to select rss item embedding for story: {}",
+                e
+            );
+            OmniNewsError::Database(e)
+        })?
+        .embedding_value
+        .map(|value| decode_embedding(&value))
+        .unwrap_or_default();
+    if vector.is_empty() {
+        return Err(OmniNewsError::Embedding);
+    }
+
+    let now = kst_now();
+    let title = item.rss_title.clone().unwrap_or_default();
+
+    let published_at = item.rss_pub_date.unwrap_or(now).min(now);
+    let stories = story_repository::select_rss_stories_active_between(
+        pool,
+        published_at - Duration::hours(STORY_WINDOW_HOURS),
+        published_at + Duration::hours(STORY_WINDOW_HOURS),
+        STORY_CANDIDATE_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        rss_error!("[Service] Failed to select recent stories: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+
+    let matched = stories
+        .into_iter()
+        .filter_map(|story| {
+            let centroid = decode_embedding(story.story_centroid.as_deref()?);
+            let similarity = cosine_similarity(&vector, &centroid);
+            let title_similarity =
+                title_similarity(&title, story.story_title.as_deref().unwrap_or_default());
+
+            let is_same_story = similarity >= STORY_EMBEDDING_THRESHOLD
+                || (similarity >= STORY_EMBEDDING_WITH_TITLE_THRESHOLD
+                    && title_similarity >= STORY_TITLE_THRESHOLD);
+            is_same_story.then_some((story, centroid, similarity))
+        })
+        .max_by(|a, b| a.2.total_cmp(&b.2));
+
+    let Some((story, centroid, similarity)) = matched else {
+        return create_story(pool, item, vector, now).await;
+    };
+
+    join_story(pool, story, centroid, item, vector, similarity, now).await
+}
+
+async fn create_story(
+    pool: &MySqlPool,
+    item: RssItem,
+    vector: Vec<f32>,
+    now: NaiveDateTime,
+) -> Result<i32, OmniNewsError> {
+    let rss_id = item.rss_id.unwrap_or_default();
+    let story = NewRssStory {
+        story_title: item.rss_title,
+        story_rss_id: Some(rss_id),
+        story_centroid: Some(encode_embedding(&vector)),
+        story_item_count: Some(1),
+        story_created_at: Some(now),
+        story_updated_at: Some(now),
+    };
+
+    let story_id = story_repository::insert_rss_story(pool, story)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to insert story: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    story_repository::insert_rss_story_item(pool, story_id, rss_id, 1.0)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to insert story item: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    Ok(story_id)
+}
+
+/// 스토리에 아이템을 넣고 중심과 대표 헤드라인을 갱신
+///
+/// 중심은 읽은 시점의 `story_item_count`가 그대로일 때만 저장하고,
+/// 다른 아이템이 먼저 합류했으면 스토리를 다시 읽어 계산함
+async fn join_story(
+    pool: &MySqlPool,
+    mut story: RssStory,
+    mut centroid: Vec<f32>,
+    item: RssItem,
+    vector: Vec<f32>,
+    similarity: f32,
+    now: NaiveDateTime,
+) -> Result<i32, OmniNewsError> {
+    let story_id = story.story_id.unwrap_or_default();
+    let rss_id = item.rss_id.unwrap_or_default();
+
+    story_repository::insert_rss_story_item(pool, story_id, rss_id, similarity)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to insert story item: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+
+    for attempt in 1..=STORY_UPDATE_ATTEMPTS {
+        if attempt > 1 {
+            story = story_repository::select_rss_story_by_id(pool, story_id)
+                .await
+                .map_err(|e| {
+                    rss_error!("[Service] Failed to reload story: {}", e);
+                    OmniNewsError::Database(e)
+                })?;
+            centroid = story
+                .story_centroid
+                .as_deref()
+                .map(decode_embedding)
+                .unwrap_or_else(|| vector.clone());
+        }
+
+        let count = story.story_item_count.unwrap_or(1).max(1);
+        let new_centroid: Vec<f32> = centroid
+            .iter()
+            .zip(vector.iter())
+            .map(|(c, v)| (c * count as f32 + v) / (count + 1) as f32)
+            .collect();
+
+        let mut updated = story.clone();
+        // 대표 아이템보다 새 아이템이 중심에 더 가까우면 대표 헤드라인 교체
+        let representative_similarity = match story.story_rss_id {
+            Some(representative_id) => {
+                embedding_repository::select_embedding_by_rss_id(pool, representative_id)
+                    .await
+                    .ok()
+                    .and_then(|embedding| embedding.embedding_value)
+                    .map(|value| cosine_similarity(&decode_embedding(&value), &new_centroid))
+                    .unwrap_or_default()
+            }
+            None => f32::MIN,
+        };
+        if cosine_similarity(&vector, &new_centroid) > representative_similarity {
+            updated.story_rss_id = Some(rss_id);
+            updated.story_title = item.rss_title.clone();
+        }
+
+        updated.story_centroid = Some(encode_embedding(&new_centroid));
+        updated.story_item_count = Some(count + 1);
+        updated.story_updated_at = Some(now);
+
+        let is_updated = story_repository::update_rss_story_if_unchanged(
+            pool,
+            updated,
+            story.story_item_count.unwrap_or_default(),
+        )
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to update story: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        if is_updated {
+            rss_info!("[Service] Rss item {} joined story {}", rss_id, story_id);
+            return Ok(story_id);
+        }
+    }
+
+    rss_warn!(
+        "[Service] Rss item {} joined story {} but its centroid was not updated after {} attempts",
+        rss_id,
+        story_id,
+        STORY_UPDATE_ATTEMPTS
+    );
+    Ok(story_id)
+}
+
+/// 같은 스토리에 속한 아이템을 하나로 묶어 반환
+///
+/// 목록 순서는 유지하며, 스토리에서 처음 등장한 아이템만 남기고
+/// 해당 아이템의 `story`에 대표 헤드라인과 출처 목록을 채움
+///
+/// 페이지 간 중복은 조회 쿼리의 `collapse_story` 조건으로 걸러지며,
+/// 여기서는 한 목록 안에 남은 중복만 정리함
+pub async fn collapse_stories(
+    pool: &MySqlPool,
+    items: Vec<RssItem>,
+) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    let rss_ids = items.iter().filter_map(|item| item.rss_id).collect();
+    let story_ids_by_rss_id: HashMap<i32, i32> =
+        story_repository::select_rss_story_items_by_rss_ids(pool, rss_ids)
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select story items: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter_map(|story_item| Some((story_item.rss_id?, story_item.story_id?)))
+            .collect();
+
+    let mut seen_story_ids = HashSet::new();
+    let items: Vec<(RssItem, Option<i32>)> = items
+        .into_iter()
+        .filter_map(|item| {
+            let story_id = item
+                .rss_id
+                .and_then(|rss_id| story_ids_by_rss_id.get(&rss_id).copied());
+            match story_id {
+                Some(story_id) if !seen_story_ids.insert(story_id) => None,
+                _ => Some((item, story_id)),
+            }
+        })
+        .collect();
+
+    let story_ids: Vec<i32> = seen_story_ids.into_iter().collect();
+    let stories: HashMap<i32, RssStory> =
+        story_repository::select_rss_stories_by_ids(pool, story_ids.clone())
+            .await
+            .map_err(|e| {
+                rss_error!("[Service] Failed to select stories: {}", e);
+                OmniNewsError::Database(e)
+            })?
+            .into_iter()
+            .filter(|story| story.story_item_count.unwrap_or_default() > 1)
+            .filter_map(|story| Some((story.story_id?, story)))
+            .collect();
+
+    let mut sources: HashMap<i32, Vec<RssStorySource>> = HashMap::new();
+    for source in story_repository::select_rss_story_sources_by_story_ids(pool, story_ids)
+        .await
+        .map_err(|e| {
+            rss_error!("[Service] Failed to select story sources: {}", e);
+            OmniNewsError::Database(e)
+        })?
+    {
+        let Some(story_id) = source.story_id else {
+            continue;
+        };
+        let story_sources = sources.entry(story_id).or_default();
+        if story_sources.len() < STORY_MAX_SOURCES {
+            story_sources.push(source);
+        }
+    }
+
+    Ok(items
+        .into_iter()
+        .map(|(item, story_id)| {
+            let mut dto = RssItemResponseDto::from_model(item);
+            if let Some(story) = story_id.and_then(|story_id| stories.get(&story_id)) {
+                let story_sources = sources
+                    .remove(&story.story_id.unwrap_or_default())
+                    .unwrap_or_default();
+                dto.story = Some(RssStoryResponseDto::new(story.clone(), story_sources));
+            }
+            dto
+        })
+        .collect())
+}
+
+/// 공백, 문장부호를 제외한 제목의 문자 bigram Jaccard 유사도
+fn title_similarity(a: &str, b: &str) -> f32 {
+    let a = title_bigrams(a);
+    let b = title_bigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(&b).count();
+    let union = a.union(&b).count();
+    intersection as f32 / union as f32
+}
+
+fn title_bigrams(title: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_similarity_ignores_punctuation_spacing_and_case() {
+        assert_eq!(
+            title_similarity("삼성전자 실적 발표", "삼성전자, 실적발표"),
+            1.0
+        );
+        assert_eq!(title_similarity("Apple WWDC 2026", "apple wwdc-2026"), 1.0);
+    }
+
+    #[test]
+    fn title_similarity_scores_shared_bigrams() {
+        let similarity = title_similarity("삼성전자 3분기 실적 발표", "삼성전자 3분기 실적 공개");
+        assert!((similarity - 8.0 / 12.0).abs() < f32::EPSILON);
+        assert!(similarity >= STORY_TITLE_THRESHOLD);
+    }
+
+    #[test]
+    fn title_similarity_is_zero_for_unrelated_or_short_titles() {
+        assert_eq!(
+            title_similarity("삼성전자 실적 발표", "내일 전국에 비"),
+            0.0
+        );
+        assert_eq!(title_similarity("", "삼성전자"), 0.0);
+        assert_eq!(title_similarity("속보", "!"), 0.0);
+    }
+}
@@ -1,16 +1,29 @@
+use std::collections::HashSet;
+
 use sqlx::MySqlPool;
 
 use crate::{
     dto::{
-        rss::response::{RssChannelResponseDto, RssItemResponseDto},
+        rss::response::{
+            RssChannelResponseDto, RssItemListResponseDto, RssItemPageResponseDto,
+            RssItemResponseDto,
+        },
         subscribe::request::{SubscribeRequestDto, SubscribeRssItemRequestDto},
     },
-    model::error::OmniNewsError,
+    model::{error::OmniNewsError, sync::SyncEntity},
     repository::subscribe_repository,
     subscription_error, subscription_info,
+    utils::{
+        cursor_util::{page_size, split_page, ItemCursor},
+        time_util::kst_now,
+    },
 };
 
-use super::{channel_service, user_service};
+use super::{
+    channel_service,
+    mute_service::{self, MuteFilter},
+    read_service, story_service, sync_service, user_service,
+};
 
 pub async fn get_subscription_channels(
     pool: &MySqlPool,
@@ -46,6 +59,14 @@ pub async fn subscribe_channel(
             let _ = channel_service::update_rss_channel_rank(pool, channel_id, 1)
                 .await
                 .unwrap();
+            sync_service::record_change(
+                pool,
+                user_id,
+                SyncEntity::Subscription,
+                channel_id,
+                kst_now(),
+            )
+            .await;
             Ok(())
         }
         Err(e) => {
@@ -75,6 +96,14 @@ pub async fn unsubscribe_channel(
             let _ = channel_service::update_rss_channel_rank(pool, channel_id, -1)
                 .await
                 .unwrap();
+            sync_service::record_change(
+                pool,
+                user_id,
+                SyncEntity::Subscription,
+                channel_id,
+                kst_now(),
+            )
+            .await;
             Ok(())
         }
         Err(e) => {
@@ -86,25 +115,115 @@ pub async fn unsubscribe_channel(
 
 pub async fn get_subscription_items(
     pool: &MySqlPool,
+    user_email: String,
     data: SubscribeRssItemRequestDto,
-) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+) -> Result<RssItemListResponseDto, OmniNewsError> {
     let channel_ids: Vec<i32> = data
         .channel_ids
+        .clone()
         .unwrap()
         .split(',')
         .filter_map(|s| s.trim().parse().ok())
         .collect();
 
+    // page 방식은 클라이언트 이전 기간 동안만 유지
+    if data.page.is_some() && data.cursor.is_none() {
+        let mute_filter = mute_service::find_mute_filter_by_email(pool, user_email).await?;
+        return get_subscription_items_page(pool, channel_ids, data, &mute_filter)
+            .await
+            .map(RssItemListResponseDto::Legacy);
+    }
+
+    let limit = page_size(data.size);
+    let ItemCursor::PubDate { pub_date, rss_id } = data
+        .cursor
+        .as_deref()
+        .map(ItemCursor::decode)
+        .transpose()?
+        .unwrap_or_else(ItemCursor::first_by_pub_date)
+    else {
+        return Err(OmniNewsError::InvalidValue(
+            "Cursor does not match item order".to_string(),
+        ));
+    };
+
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let unread_only = data.unread_only.unwrap_or(false);
+    let mute_filter = mute_service::find_mute_filter(pool, user_id).await?;
+
+    let items = subscribe_repository::select_subscription_items_before_cursor(
+        pool,
+        channel_ids,
+        pub_date,
+        rss_id,
+        limit + 1,
+        unread_only.then_some(user_id),
+        data.collapse_story.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let (mut items, next_cursor) = split_page(items, limit, ItemCursor::by_pub_date);
+    // 숨긴 만큼 페이지가 짧아질 수 있지만 커서는 숨기기 전 마지막 아이템 기준
+    let hidden_count = mute_filter.retain_visible(&mut items);
+
+    let read_rss_ids = if unread_only {
+        HashSet::new()
+    } else {
+        read_service::find_read_rss_ids(
+            pool,
+            user_id,
+            items.iter().filter_map(|item| item.rss_id).collect(),
+        )
+        .await?
+    };
+    let mut items = if data.collapse_story.unwrap_or(false) {
+        story_service::collapse_stories(pool, items).await?
+    } else {
+        RssItemResponseDto::from_model_list(items)
+    };
+    for item in items.iter_mut() {
+        item.is_read = item.rss_id.map(|id| read_rss_ids.contains(&id));
+    }
+
+    let mut page = RssItemPageResponseDto::new(items, next_cursor);
+    page.hidden_count = Some(hidden_count);
+    Ok(RssItemListResponseDto::Page(page))
+}
+
+async fn get_subscription_items_page(
+    pool: &MySqlPool,
+    channel_ids: Vec<i32>,
+    data: SubscribeRssItemRequestDto,
+    mute_filter: &MuteFilter,
+) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
     let size = 20;
     let page = data.page.unwrap_or(1);
     let offset = (page - 1) * size;
 
-    match subscribe_repository::select_subscription_items(pool, channel_ids, offset, size).await {
-        Ok(res) => Ok(RssItemResponseDto::from_model_list(res)),
+    let mut items = match subscribe_repository::select_subscription_items(
+        pool,
+        channel_ids,
+        offset,
+        size,
+        data.collapse_story.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(res) => res,
         Err(e) => {
             subscription_error!("Failed to select subscription items: {}", e);
-            Err(OmniNewsError::Database(e))
+            return Err(OmniNewsError::Database(e));
         }
+    };
+    mute_filter.retain_visible(&mut items);
+
+    if data.collapse_story.unwrap_or(false) {
+        story_service::collapse_stories(pool, items).await
+    } else {
+        Ok(RssItemResponseDto::from_model_list(items))
     }
 }
 
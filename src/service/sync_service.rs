@@ -0,0 +1,775 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration as StdDuration,
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, NaiveDateTime};
+use sqlx::MySqlPool;
+
+use crate::{
+    dto::{
+        bookmark::response::BookmarkResponseDto,
+        folder::response::RssFolderResponseDto,
+        rss::response::{RssChannelResponseDto, RssItemResponseDto},
+        sync::{
+            request::{SyncRequestDto, SyncUploadRequestDto},
+            response::{
+                SyncChannelReadResponseDto, SyncConflictResponseDto, SyncDeletedResponseDto,
+                SyncItemReadResponseDto, SyncResponseDto, SyncUploadResponseDto,
+            },
+        },
+    },
+    model::{
+        bookmark::Bookmark,
+        error::OmniNewsError,
+        folder::RssFolder,
+        rss::{RssChannel, RssItem},
+        sync::{SyncChange, SyncEntity},
+    },
+    repository::{
+        bookmark_repository, folder_repository, read_repository, rss_item_repository,
+        subscribe_repository, sync_repository,
+    },
+    subscription_error, subscription_info, subscription_warn,
+    utils::time_util::kst_now,
+};
+
+use super::{bookmark_service, mute_service, read_service, user_service};
+
+// 변경 기록에는 바뀐 대상만 남기고, 동기화할 때 대상의 현재 상태를 조회 (없으면 삭제로 응답)
+// 올라온 변경은 대상(아이템 읽음, 북마크) 단위로 마지막 변경 시각과 비교해 나중에 바뀐 쪽을 반영 (last-writer-wins)
+// 필드 단위로 합치지 않으므로, 북마크는 저장 여부만 올릴 수 있고 메모, 태그는 북마크 API로 바꿈
+// 변경 기록은 SYNC_CHANGE_RETENTION_DAYS 동안 보관하고, 그보다 오래된 토큰은 전체 동기화로 응답
+
+const MAX_SYNC_CHANGES: i32 = 1000;
+const MAX_SYNC_ITEMS: i32 = 500;
+// 새로 구독한 채널은 이전 동기화 전에 올라온 최신 아이템도 함께 보냄
+const NEW_SUBSCRIPTION_ITEM_LIMIT: i32 = 50;
+const FULL_SYNC_BOOKMARK_LIMIT: i32 = 1000;
+const MAX_UPLOAD_CHANGES: usize = 500;
+const SYNC_CHANGE_RETENTION_DAYS: i64 = 30;
+// 정리 중인 기록을 가리키지 않도록 보관 기간보다 하루 짧게 토큰을 인정
+const SYNC_TOKEN_MAX_AGE_DAYS: i64 = SYNC_CHANGE_RETENTION_DAYS - 1;
+const PRUNE_INTERVAL: StdDuration = StdDuration::from_secs(60 * 60);
+const PRUNE_BATCH_SIZE: i32 = 10_000;
+
+/// 동기화 위치 (마지막으로 보낸 변경 ID, 아이템 ID, 이후 변경을 기준으로 삼는 발급 시각)
+struct SyncToken {
+    change_id: i64,
+    rss_id: i32,
+    issued_at: NaiveDateTime,
+}
+
+impl SyncToken {
+    /// 예: (123, 4567, 2025-01-01T09:00:00) -> "czoxMjM6NDU2NzoxNzM1NzIyMDAw"
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "s:{}:{}:{}",
+            self.change_id,
+            self.rss_id,
+            self.issued_at.and_utc().timestamp()
+        ))
+    }
+
+    fn decode(token: &str) -> Result<Self, OmniNewsError> {
+        let invalid = || OmniNewsError::InvalidValue(format!("Invalid sync token: {token}"));
+
+        let raw = URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+        let mut parts = raw.splitn(4, ':');
+        let (Some("s"), Some(change_id), Some(rss_id)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        // 발급 시각이 없는 이전 형식 토큰은 오래된 토큰으로 보고 전체 동기화
+        let issued_at = match parts.next() {
+            Some(issued_at) => issued_at
+                .parse()
+                .ok()
+                .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                .ok_or_else(invalid)?
+                .naive_utc(),
+            None => DateTime::UNIX_EPOCH.naive_utc(),
+        };
+
+        Ok(SyncToken {
+            change_id: change_id.parse().map_err(|_| invalid())?,
+            rss_id: rss_id.parse().map_err(|_| invalid())?,
+            issued_at,
+        })
+    }
+}
+
+/// 변경 기록 실패는 원래 요청을 실패시키지 않음 (다음 전체 동기화에서 맞춰짐)
+pub async fn record_change(
+    pool: &MySqlPool,
+    user_id: i32,
+    entity: SyncEntity,
+    entity_id: i32,
+    changed_at: NaiveDateTime,
+) {
+    if let Err(e) =
+        sync_repository::insert_sync_change(pool, user_id, entity, entity_id, changed_at, kst_now())
+            .await
+    {
+        subscription_warn!("[Service] Failed to record sync change: {}", e);
+    }
+}
+
+/// 사용자 ID 없이 폴더 ID만 받는 API용 (폴더 소유자 기준으로 기록)
+pub async fn record_folder_change(pool: &MySqlPool, folder_id: i32, changed_at: NaiveDateTime) {
+    if let Err(e) =
+        sync_repository::insert_folder_sync_changes(pool, folder_id, changed_at, kst_now()).await
+    {
+        subscription_warn!("[Service] Failed to record folder sync change: {}", e);
+    }
+}
+
+/// `since` 이후 변경 조회. 토큰이 없거나 보관 기간보다 오래되었으면 현재 상태 전체를 보냄
+pub async fn get_changes(
+    pool: &MySqlPool,
+    user_email: String,
+    data: SyncRequestDto,
+) -> Result<SyncResponseDto, OmniNewsError> {
+    let token = data.since.as_deref().map(SyncToken::decode).transpose()?;
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let now = kst_now();
+
+    // 상태보다 먼저 읽어 두어, 조회 중에 생긴 변경은 다음 동기화에 다시 포함되게 함
+    let last_change_id = sync_repository::select_last_sync_change_id(pool, user_id)
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select last sync change id: {}", e);
+            OmniNewsError::Database(e)
+        })?
+        .unwrap_or_default();
+    let channels = find_subscription_channels(pool, user_id).await?;
+
+    match token {
+        Some(token) if token.issued_at >= now - Duration::days(SYNC_TOKEN_MAX_AGE_DAYS) => {
+            get_delta(pool, user_id, token, last_change_id, channels, now).await
+        }
+        _ => get_snapshot(pool, user_id, last_change_id, channels, now).await,
+    }
+}
+
+/// 보관 기간이 지난 변경 기록을 주기적으로 정리
+pub async fn run_change_pruner(pool: MySqlPool) {
+    let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(e) = prune_changes(&pool).await {
+            subscription_error!("[Service] Sync change pruner failed: {}", e);
+        }
+    }
+}
+
+async fn prune_changes(pool: &MySqlPool) -> Result<(), OmniNewsError> {
+    let recorded_before = kst_now() - Duration::days(SYNC_CHANGE_RETENTION_DAYS);
+    let mut deleted = 0;
+    loop {
+        let count = sync_repository::delete_sync_changes_recorded_before(
+            pool,
+            recorded_before,
+            PRUNE_BATCH_SIZE,
+        )
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to delete old sync changes: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        deleted += count;
+        if count < PRUNE_BATCH_SIZE as u64 {
+            break;
+        }
+    }
+
+    if deleted > 0 {
+        subscription_info!("[Service] Pruned {} old sync changes", deleted);
+    }
+    Ok(())
+}
+
+/// 오프라인에서 바꾼 읽음 상태와 북마크를 반영
+///
+/// 서버에서 더 나중에 바뀐 대상은 반영하지 않고 서버 값을 충돌로 돌려줌
+pub async fn upload_changes(
+    pool: &MySqlPool,
+    user_email: String,
+    data: SyncUploadRequestDto,
+) -> Result<SyncUploadResponseDto, OmniNewsError> {
+    let item_read_states = data.item_read_states.unwrap_or_default();
+    let bookmarks = data.bookmarks.unwrap_or_default();
+    if item_read_states.len() + bookmarks.len() > MAX_UPLOAD_CHANGES {
+        return Err(OmniNewsError::InvalidValue(format!(
+            "Up to {MAX_UPLOAD_CHANGES} changes can be uploaded at once"
+        )));
+    }
+    let user_id = user_service::find_user_id_by_email(pool, user_email).await?;
+    let now = kst_now();
+
+    let mut applied_count = 0;
+    let mut conflicts = Vec::new();
+    for state in item_read_states {
+        let (Some(rss_id), Some(is_read), Some(changed_at)) =
+            (state.rss_id, state.is_read, state.changed_at)
+        else {
+            return Err(OmniNewsError::InvalidValue(
+                "rss_id, is_read and changed_at are required".to_string(),
+            ));
+        };
+        let item = match rss_item_repository::select_rss_item_by_id(pool, rss_id).await {
+            Ok(item) => item,
+            // 삭제된 아이템은 건너뜀
+            Err(sqlx::Error::RowNotFound) => continue,
+            Err(e) => {
+                subscription_error!("[Service] Failed to select item: {}", e);
+                return Err(OmniNewsError::Database(e));
+            }
+        };
+        let server_changed_at = find_last_changed_at(pool, user_id, SyncEntity::ItemRead, rss_id)
+            .await?
+            .max(
+                find_last_changed_at(
+                    pool,
+                    user_id,
+                    SyncEntity::ChannelRead,
+                    item.channel_id.unwrap_or_default(),
+                )
+                .await?,
+            );
+        let Some(changed_at) = accept_client_change(server_changed_at, changed_at, now) else {
+            let server_value = read_service::find_read_rss_ids(pool, user_id, vec![rss_id])
+                .await?
+                .contains(&rss_id);
+            conflicts.push(SyncConflictResponseDto::new(
+                SyncEntity::ItemRead.as_str(),
+                rss_id,
+                server_value,
+                server_changed_at,
+            ));
+            continue;
+        };
+
+        read_service::set_item_read(pool, user_id, rss_id, is_read, changed_at).await?;
+        applied_count += 1;
+    }
+
+    for bookmark in bookmarks {
+        let (Some(rss_id), Some(is_saved), Some(changed_at)) =
+            (bookmark.rss_id, bookmark.is_saved, bookmark.changed_at)
+        else {
+            return Err(OmniNewsError::InvalidValue(
+                "rss_id, is_saved and changed_at are required".to_string(),
+            ));
+        };
+        let server_changed_at =
+            find_last_changed_at(pool, user_id, SyncEntity::Bookmark, rss_id).await?;
+        let Some(changed_at) = accept_client_change(server_changed_at, changed_at, now) else {
+            let server_value = find_bookmark_by_rss_id(pool, user_id, rss_id)
+                .await?
+                .is_some();
+            conflicts.push(SyncConflictResponseDto::new(
+                SyncEntity::Bookmark.as_str(),
+                rss_id,
+                server_value,
+                server_changed_at,
+            ));
+            continue;
+        };
+
+        match bookmark_service::sync_bookmark(pool, user_id, rss_id, is_saved, changed_at).await {
+            Ok(_) => applied_count += 1,
+            // 삭제된 아이템은 건너뜀
+            Err(OmniNewsError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(SyncUploadResponseDto::new(applied_count, conflicts))
+}
+
+/// 구독 채널, 폴더, 북마크 전체와 최신 아이템
+/// 나중에 바뀐 쪽이 이기는 병합 (같은 시각이면 클라이언트 변경 반영)
+///
+/// 반영할 변경 시각을 반환하며, 서버에서 더 나중에 바뀌었으면 `None`.
+/// 클라이언트 시계가 빨라도 서버 시각보다 늦은 변경으로 보지 않음
+fn accept_client_change(
+    server_changed_at: Option<NaiveDateTime>,
+    changed_at: NaiveDateTime,
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let changed_at = changed_at.min(now);
+    match server_changed_at {
+        Some(server_changed_at) if server_changed_at > changed_at => None,
+        _ => Some(changed_at),
+    }
+}
+
+async fn get_snapshot(
+    pool: &MySqlPool,
+    user_id: i32,
+    last_change_id: i64,
+    channels: Vec<RssChannel>,
+    now: NaiveDateTime,
+) -> Result<SyncResponseDto, OmniNewsError> {
+    let channel_ids: Vec<i32> = channels.iter().filter_map(|c| c.channel_id).collect();
+    let items = subscribe_repository::select_subscription_items_before_rss_id(
+        pool,
+        channel_ids,
+        i32::MAX,
+        MAX_SYNC_ITEMS,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let token = SyncToken {
+        change_id: last_change_id,
+        rss_id: items
+            .first()
+            .and_then(|item| item.rss_id)
+            .unwrap_or_default(),
+        issued_at: now,
+    };
+
+    let bookmarks = bookmark_repository::select_bookmarks_before_id(
+        pool,
+        user_id,
+        i32::MAX,
+        FULL_SYNC_BOOKMARK_LIMIT,
+        None,
+        None,
+        None,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select bookmarks: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let folders = find_folders(pool, user_id).await?;
+
+    let mut response = SyncResponseDto::new(token.encode(), false, true);
+    response.items = Some(build_items(pool, user_id, items).await?);
+    response.subscriptions = Some(RssChannelResponseDto::from_model_list(channels));
+    response.folders = Some(build_folders(pool, folders.into_values().collect()).await?);
+    response.bookmarks = Some(build_bookmarks(pool, bookmarks).await?);
+    Ok(response)
+}
+
+/// 토큰 이후 변경된 대상의 현재 상태, 새 아이템과 내용이 바뀐 아이템
+async fn get_delta(
+    pool: &MySqlPool,
+    user_id: i32,
+    token: SyncToken,
+    last_change_id: i64,
+    channels: Vec<RssChannel>,
+    now: NaiveDateTime,
+) -> Result<SyncResponseDto, OmniNewsError> {
+    // 바뀐 아이템이 한 번에 보낼 수 있는 수보다 많으면 전체 동기화
+    let channel_ids: Vec<i32> = channels.iter().filter_map(|c| c.channel_id).collect();
+    let updated_items = subscribe_repository::select_subscription_items_updated_since(
+        pool,
+        channel_ids.clone(),
+        token.rss_id,
+        token.issued_at,
+        MAX_SYNC_ITEMS + 1,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!(
+            "[Service] Failed to select updated subscription items: {}",
+            e
+        );
+        OmniNewsError::Database(e)
+    })?;
+    if updated_items.len() > MAX_SYNC_ITEMS as usize {
+        return get_snapshot(pool, user_id, last_change_id, channels, now).await;
+    }
+
+    let mut changes = sync_repository::select_sync_changes(
+        pool,
+        user_id,
+        token.change_id,
+        last_change_id,
+        MAX_SYNC_CHANGES + 1,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select sync changes: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    let mut has_more = changes.len() > MAX_SYNC_CHANGES as usize;
+    changes.truncate(MAX_SYNC_CHANGES as usize);
+    let change_id = match has_more {
+        true => changes
+            .last()
+            .and_then(|change| change.sync_change_id)
+            .unwrap_or(token.change_id),
+        false => last_change_id.max(token.change_id),
+    };
+
+    let mut items = subscribe_repository::select_subscription_items_after_rss_id(
+        pool,
+        channel_ids,
+        token.rss_id,
+        MAX_SYNC_ITEMS + 1,
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select subscription items: {}", e);
+        OmniNewsError::Database(e)
+    })?;
+    if items.len() > MAX_SYNC_ITEMS as usize {
+        has_more = true;
+        items.truncate(MAX_SYNC_ITEMS as usize);
+    }
+    let rss_id = items
+        .last()
+        .and_then(|item| item.rss_id)
+        .unwrap_or(token.rss_id);
+    items.extend(updated_items);
+
+    let mut changed: HashMap<&'static str, Vec<SyncChange>> = HashMap::new();
+    for change in changes {
+        let Some(entity) = change
+            .sync_entity
+            .as_deref()
+            .and_then(SyncEntity::from_value)
+        else {
+            continue;
+        };
+        changed.entry(entity.as_str()).or_default().push(change);
+    }
+    let mut changed_of = |entity: SyncEntity| changed.remove(entity.as_str()).unwrap_or_default();
+    let subscription_changes = changed_of(SyncEntity::Subscription);
+    let folder_changes = changed_of(SyncEntity::Folder);
+    let item_read_changes = changed_of(SyncEntity::ItemRead);
+    let channel_read_changes = changed_of(SyncEntity::ChannelRead);
+    let bookmark_changes = changed_of(SyncEntity::Bookmark);
+
+    let mut deleted_channel_ids = Vec::new();
+    let mut subscribed_ids = HashSet::new();
+    for channel_id in subscription_changes.iter().filter_map(|c| c.sync_entity_id) {
+        if channels.iter().any(|c| c.channel_id == Some(channel_id)) {
+            subscribed_ids.insert(channel_id);
+        } else {
+            deleted_channel_ids.push(channel_id);
+        }
+    }
+    for channel_id in &subscribed_ids {
+        let older_items = subscribe_repository::select_subscription_items_before_rss_id(
+            pool,
+            vec![*channel_id],
+            token.rss_id.saturating_add(1),
+            NEW_SUBSCRIPTION_ITEM_LIMIT,
+        )
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select subscription items: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        items.extend(older_items);
+    }
+    items.sort_by_key(|item| item.rss_id);
+    items.dedup_by_key(|item| item.rss_id);
+    let subscriptions = channels
+        .into_iter()
+        .filter(|c| c.channel_id.is_some_and(|id| subscribed_ids.contains(&id)))
+        .collect();
+
+    let mut user_folders = find_folders(pool, user_id).await?;
+    let mut folders = Vec::new();
+    let mut deleted_folder_ids = Vec::new();
+    for folder_id in folder_changes.iter().filter_map(|c| c.sync_entity_id) {
+        match user_folders.remove(&folder_id) {
+            Some(folder) => folders.push(folder),
+            None => deleted_folder_ids.push(folder_id),
+        }
+    }
+
+    let read_rss_ids = read_service::find_read_rss_ids(
+        pool,
+        user_id,
+        item_read_changes
+            .iter()
+            .filter_map(|c| c.sync_entity_id)
+            .collect(),
+    )
+    .await?;
+    let item_read_states = item_read_changes
+        .iter()
+        .filter_map(|change| {
+            let rss_id = change.sync_entity_id?;
+            Some(SyncItemReadResponseDto::new(
+                rss_id,
+                read_rss_ids.contains(&rss_id),
+                change.sync_changed_at,
+            ))
+        })
+        .collect();
+
+    let mut channel_read_marks = Vec::new();
+    for channel_id in channel_read_changes.iter().filter_map(|c| c.sync_entity_id) {
+        let mark = read_repository::select_channel_read_mark(pool, user_id, channel_id)
+            .await
+            .map_err(|e| {
+                subscription_error!("[Service] Failed to select channel read mark: {}", e);
+                OmniNewsError::Database(e)
+            })?;
+        if let Some(mark) = mark {
+            channel_read_marks.push(SyncChannelReadResponseDto::from_model(mark));
+        }
+    }
+
+    let mut bookmarks = Vec::new();
+    let mut deleted_bookmark_rss_ids = Vec::new();
+    for rss_id in bookmark_changes.iter().filter_map(|c| c.sync_entity_id) {
+        match find_bookmark_by_rss_id(pool, user_id, rss_id).await? {
+            Some(bookmark) => bookmarks.push(bookmark),
+            None => deleted_bookmark_rss_ids.push(rss_id),
+        }
+    }
+
+    // 남은 변경이 있으면 이전 발급 시각을 유지해 그 사이 바뀐 아이템을 다음 요청에서도 보냄
+    let token = SyncToken {
+        change_id,
+        rss_id,
+        issued_at: if has_more { token.issued_at } else { now },
+    };
+    let mut response = SyncResponseDto::new(token.encode(), has_more, false);
+    response.items = Some(build_items(pool, user_id, items).await?);
+    response.subscriptions = Some(RssChannelResponseDto::from_model_list(subscriptions));
+    response.folders = Some(build_folders(pool, folders).await?);
+    response.item_read_states = Some(item_read_states);
+    response.channel_read_marks = Some(channel_read_marks);
+    response.bookmarks = Some(build_bookmarks(pool, bookmarks).await?);
+    response.deleted = Some(SyncDeletedResponseDto::new(
+        deleted_channel_ids,
+        deleted_folder_ids,
+        deleted_bookmark_rss_ids,
+    ));
+    Ok(response)
+}
+
+/// 숨김 규칙을 적용하고 읽음 여부를 채움
+async fn build_items(
+    pool: &MySqlPool,
+    user_id: i32,
+    mut items: Vec<RssItem>,
+) -> Result<Vec<RssItemResponseDto>, OmniNewsError> {
+    mute_service::find_mute_filter(pool, user_id)
+        .await?
+        .retain_visible(&mut items);
+    let read_rss_ids = read_service::find_read_rss_ids(
+        pool,
+        user_id,
+        items.iter().filter_map(|item| item.rss_id).collect(),
+    )
+    .await?;
+
+    let mut items = RssItemResponseDto::from_model_list(items);
+    for item in items.iter_mut() {
+        item.is_read = item.rss_id.map(|id| read_rss_ids.contains(&id));
+    }
+    Ok(items)
+}
+
+async fn build_folders(
+    pool: &MySqlPool,
+    folders: Vec<RssFolder>,
+) -> Result<Vec<RssFolderResponseDto>, OmniNewsError> {
+    let mut result = Vec::with_capacity(folders.len());
+    for folder in folders {
+        let channels = folder_repository::select_channels_in_folder(
+            pool,
+            folder.folder_id.unwrap_or_default(),
+        )
+        .await
+        .map_err(|e| {
+            subscription_error!("[Service] Failed to select channels in folder: {}", e);
+            OmniNewsError::Database(e)
+        })?;
+        result.push(RssFolderResponseDto::new(folder, channels));
+    }
+    Ok(result)
+}
+
+async fn build_bookmarks(
+    pool: &MySqlPool,
+    bookmarks: Vec<Bookmark>,
+) -> Result<Vec<BookmarkResponseDto>, OmniNewsError> {
+    let mut tags_by_bookmark: HashMap<i32, Vec<String>> = HashMap::new();
+    for tag in bookmark_repository::select_bookmark_tags_by_bookmark_ids(
+        pool,
+        bookmarks.iter().filter_map(|b| b.bookmark_id).collect(),
+    )
+    .await
+    .map_err(|e| {
+        subscription_error!("[Service] Failed to select bookmark tags: {}", e);
+        OmniNewsError::Database(e)
+    })? {
+        if let (Some(bookmark_id), Some(tag_name)) = (tag.bookmark_id, tag.tag_name) {
+            tags_by_bookmark
+                .entry(bookmark_id)
+                .or_default()
+                .push(tag_name);
+        }
+    }
+
+    Ok(bookmarks
+        .into_iter()
+        .map(|bookmark| {
+            let tags = bookmark
+                .bookmark_id
+                .and_then(|id| tags_by_bookmark.remove(&id))
+                .unwrap_or_default();
+            BookmarkResponseDto::new(bookmark, tags)
+        })
+        .collect())
+}
+
+async fn find_subscription_channels(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<Vec<RssChannel>, OmniNewsError> {
+    match subscribe_repository::select_subscription_channels(pool, user_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select subscription channels: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 폴더 ID별 사용자 폴더
+async fn find_folders(
+    pool: &MySqlPool,
+    user_id: i32,
+) -> Result<HashMap<i32, RssFolder>, OmniNewsError> {
+    match folder_repository::select_folders(pool, user_id).await {
+        Ok(res) => Ok(res
+            .into_iter()
+            .filter_map(|folder| Some((folder.folder_id?, folder)))
+            .collect()),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select folders: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_bookmark_by_rss_id(
+    pool: &MySqlPool,
+    user_id: i32,
+    rss_id: i32,
+) -> Result<Option<Bookmark>, OmniNewsError> {
+    match bookmark_repository::select_bookmark_by_rss_id(pool, user_id, rss_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select bookmark: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+async fn find_last_changed_at(
+    pool: &MySqlPool,
+    user_id: i32,
+    entity: SyncEntity,
+    entity_id: i32,
+) -> Result<Option<NaiveDateTime>, OmniNewsError> {
+    match sync_repository::select_last_changed_at(pool, user_id, entity, entity_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            subscription_error!("[Service] Failed to select last sync change: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn kst(hour: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn sync_token_round_trips() {
+        let token = SyncToken {
+            change_id: 123,
+            rss_id: 4567,
+            issued_at: kst(9, 0),
+        };
+        let encoded = token.encode();
+        assert_eq!(encoded, "czoxMjM6NDU2NzoxNzM1NzIyMDAw");
+
+        let decoded = SyncToken::decode(&encoded).unwrap();
+        assert_eq!(decoded.change_id, 123);
+        assert_eq!(decoded.rss_id, 4567);
+        assert_eq!(decoded.issued_at, kst(9, 0));
+    }
+
+    #[test]
+    fn sync_token_without_issued_at_is_treated_as_expired() {
+        let decoded = SyncToken::decode(&URL_SAFE_NO_PAD.encode("s:123:4567")).unwrap();
+        assert_eq!(decoded.change_id, 123);
+        assert_eq!(decoded.issued_at, DateTime::UNIX_EPOCH.naive_utc());
+    }
+
+    #[test]
+    fn sync_token_rejects_malformed_values() {
+        for raw in ["i:123:4567:0", "s:abc:4567:0", "s:123", "s:123:4567:soon"] {
+            assert!(SyncToken::decode(&URL_SAFE_NO_PAD.encode(raw)).is_err());
+        }
+        assert!(SyncToken::decode("not base64!").is_err());
+    }
+
+    #[test]
+    fn later_client_change_wins() {
+        let now = kst(12, 0);
+        assert_eq!(
+            accept_client_change(None, kst(10, 0), now),
+            Some(kst(10, 0))
+        );
+        assert_eq!(
+            accept_client_change(Some(kst(9, 0)), kst(10, 0), now),
+            Some(kst(10, 0))
+        );
+        assert_eq!(
+            accept_client_change(Some(kst(10, 0)), kst(10, 0), now),
+            Some(kst(10, 0))
+        );
+    }
+
+    #[test]
+    fn later_server_change_wins() {
+        let now = kst(12, 0);
+        assert_eq!(
+            accept_client_change(Some(kst(11, 0)), kst(10, 0), now),
+            None
+        );
+    }
+
+    #[test]
+    fn client_change_is_clamped_to_server_time() {
+        let now = kst(12, 0);
+        assert_eq!(accept_client_change(None, kst(18, 0), now), Some(now));
+        assert_eq!(
+            accept_client_change(Some(kst(12, 30)), kst(18, 0), now),
+            None
+        );
+    }
+}
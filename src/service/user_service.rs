@@ -1,15 +1,17 @@
 use std::env;
 
-use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use jsonwebtoken::{encode, EncodingKey, Header};
+use sha2::{Digest, Sha256};
 use sqlx::MySqlPool;
+use uuid::Uuid;
 
 use crate::{
     auth_middleware::Claims,
     dto::{
         auth::{
             request::VerifyRefreshTokenRequestDto,
-            response::{AccessTokenResponseDto, JwtTokenResponseDto},
+            response::{AccessTokenResponseDto, JwtTokenResponseDto, SessionResponseDto},
         },
         user::{
             request::{
@@ -22,13 +24,22 @@ use crate::{
     model::{
         auth::{AccessToken, JwtToken, TokenType},
         error::OmniNewsError,
+        session::NewUserSession,
         user::NewUser,
     },
-    repository::user_repository,
+    repository::{session_repository, user_repository},
     service::omninews_subscription_service::{self},
     user_error, user_info, user_warn,
+    utils::time_util::kst_now,
 };
 
+/// 요청마다 세션을 갱신하지 않도록 마지막 사용 시각은 이 간격으로만 기록
+const SESSION_LAST_SEEN_INTERVAL_MINUTES: i64 = 5;
+const MAX_DEVICE_NAME_LEN: usize = 100;
+const MAX_PLATFORM_LEN: usize = 20;
+// 사용자당 유지하는 최대 세션 수 (넘으면 오래 사용하지 않은 세션부터 삭제)
+const MAX_SESSIONS_PER_USER: i32 = 20;
+
 pub async fn demo_login(
     pool: &MySqlPool,
     user: DemoLoginRequestDto,
@@ -60,42 +71,28 @@ pub async fn demo_login(
     login_or_create_user(pool, login_dto).await
 }
 
-/// 1. access token O refresh token O -> processed in auto login.
-/// 2. access token X refresh token O -> processed in auto login.
-/// 3. access token X refresh token X -> Reissue refresh token and access token or Create user +
-///    Update User Info
+/// 기존 사용자는 새 세션을 발급하고 정보를 갱신, 없으면 사용자 생성
+///
+/// 다른 기기의 세션은 유지됩니다.
 pub async fn login_or_create_user(
     pool: &MySqlPool,
     user: LoginUserRequestDto,
 ) -> Result<JwtTokenResponseDto, OmniNewsError> {
-    let (is_access_available, is_refresh_available) = user_repository::validate_users_all_tokens(
-        pool,
-        user.user_email.clone().unwrap_or_default(),
-    )
-    .await
-    .map_err(|e| {
-        user_error!(
-            "[Service] Failed to validate access and refresh token: {}",
-            e
-        );
-        OmniNewsError::TokenValidationError
-    })?;
-
-    // 1 or 2 -> new login
-    if is_access_available || is_refresh_available {
-        user_warn!("[Service] Access or refresh token is already alived ailed to login");
-    }
-
-    // 3. access token X refresh token X -> reissue both token or create user
+    // 1. 기존 사용자 -> 새 세션 발급
     if (user_repository::select_user_id_by_email(pool, &user.user_email.clone().unwrap()).await)
         .is_ok()
     {
-        // 3-1. reissue both
         user_info!(
-            "[Service] 3-1. Success login: {}",
+            "[Service] 1. Success login: {}",
             user.user_email.clone().unwrap()
         );
-        let jwt_token = issue_tokens(pool, user.user_email.clone().unwrap()).await?;
+        let jwt_token = issue_tokens(
+            pool,
+            user.user_email.clone().unwrap(),
+            user.user_device_name.clone(),
+            user.user_platform.clone(),
+        )
+        .await?;
         // update user info
         match user_repository::update_user_info(
             pool,
@@ -119,16 +116,12 @@ pub async fn login_or_create_user(
                 return Err(OmniNewsError::Database(e));
             }
         }
-        user_info!(
-            "[Service] User info updated for email: {}",
-            user.user_email.clone().unwrap()
-        );
 
         return Ok(JwtTokenResponseDto::from_model(jwt_token));
     }
-    // 3-2.create user
+    // 2. create user
     user_info!(
-        "[Service] 3-2. Success login: {}",
+        "[Service] 2. Success login: {}",
         user.user_email.clone().unwrap()
     );
 
@@ -140,57 +133,53 @@ async fn create_user(
     pool: &MySqlPool,
     user: LoginUserRequestDto,
 ) -> Result<JwtToken, OmniNewsError> {
-    let (access_token, access_token_expires_at) = make_token(
-        TokenType::Access,
-        user.user_email.clone().unwrap_or_default(),
-    )?;
-
-    let (refresh_token, refresh_token_expires_at) = make_token(
-        TokenType::Refresh,
-        user.user_email.clone().unwrap_or_default(),
-    )?;
-
-    let ktc_now = Utc::now()
-        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
-        .naive_utc();
-
-    let new_user = NewUser::new(
-        user,
-        JwtToken::new(
-            access_token.clone(),
-            refresh_token.clone(),
-            access_token_expires_at,
-            refresh_token_expires_at,
-        ),
-        ktc_now,
-    );
-    match user_repository::insert_user(pool, new_user).await {
-        Ok(_) => Ok(JwtToken::new(
-            access_token,
-            refresh_token,
-            access_token_expires_at,
-            refresh_token_expires_at,
-        )),
+    let user_email = user.user_email.clone().unwrap_or_default();
+    let device_name = user.user_device_name.clone();
+    let platform = user.user_platform.clone();
 
-        Err(e) => {
-            user_error!("[Service] Failed to create user: {}", e);
-            Err(OmniNewsError::Database(e))
-        }
+    let new_user = NewUser::new(user, kst_now());
+    if let Err(e) = user_repository::insert_user(pool, new_user).await {
+        user_error!("[Service] Failed to create user: {}", e);
+        return Err(OmniNewsError::Database(e));
     }
+
+    issue_tokens(pool, user_email, device_name, platform).await
 }
 
-pub async fn vliadate_access_token(
+/// 유효한 액세스 토큰의 세션 ID, 마지막 사용 시각도 갱신
+pub async fn find_session_id_by_access_token(
     pool: &MySqlPool,
     token: String,
     email: String,
-) -> Result<bool, OmniNewsError> {
-    match user_repository::validate_access_token_by_user_email(pool, token, email).await {
-        Ok(_) => Ok(true),
+) -> Result<i32, OmniNewsError> {
+    let now = kst_now();
+    let session_id = match session_repository::select_session_id_by_access_token_hash(
+        pool,
+        &hash_token(&token),
+        &email,
+        now,
+    )
+    .await
+    {
+        Ok(res) => res,
         Err(_) => {
             user_error!("[Service] Token validation failed");
-            Err(OmniNewsError::TokenValidationError)
+            return Err(OmniNewsError::TokenValidationError);
         }
+    };
+
+    if let Err(e) = session_repository::update_session_last_seen(
+        pool,
+        session_id,
+        now,
+        now - Duration::minutes(SESSION_LAST_SEEN_INTERVAL_MINUTES),
+    )
+    .await
+    {
+        user_warn!("[Service] Failed to update session last seen: {}", e);
     }
+
+    Ok(session_id)
 }
 
 pub async fn validate_refresh_token(
@@ -200,16 +189,17 @@ pub async fn validate_refresh_token(
     let user_email = refresh_token.email.clone().unwrap_or_default();
     let refresh_token = refresh_token.token.unwrap_or_default();
 
-    match user_repository::validate_refresh_token_by_user_email(
+    match session_repository::select_session_id_by_refresh_token_hash(
         pool,
-        refresh_token,
-        user_email.clone(),
+        &hash_token(&refresh_token),
+        &user_email,
+        kst_now(),
     )
     .await
     {
-        Ok(_) => {
+        Ok(session_id) => {
             user_info!("[Service] Reissue access token.");
-            let acces_token = reissue_access_token(pool, user_email).await?;
+            let acces_token = reissue_access_token(pool, session_id, user_email).await?;
 
             Ok(AccessTokenResponseDto::from_model(acces_token))
         }
@@ -222,39 +212,78 @@ pub async fn validate_refresh_token(
 
 async fn reissue_access_token(
     pool: &MySqlPool,
+    session_id: i32,
     user_email: String,
 ) -> Result<AccessToken, OmniNewsError> {
-    let (access_token, access_token_expires_at) =
-        make_token(TokenType::Access, user_email.clone())?;
+    let (access_token, access_token_expires_at) = make_token(TokenType::Access, user_email)?;
 
-    let _ = user_repository::update_user_access_token(
+    let _ = session_repository::update_session_access_token(
         pool,
-        user_email,
-        access_token.clone(),
+        session_id,
+        &hash_token(&access_token),
         access_token_expires_at,
+        kst_now(),
     )
     .await?;
 
     Ok(AccessToken::new(access_token, access_token_expires_at))
 }
 
-async fn issue_tokens(pool: &MySqlPool, user_email: String) -> Result<JwtToken, OmniNewsError> {
+/// 새 세션을 만들고 토큰 발급 (토큰은 해시만 저장)
+async fn issue_tokens(
+    pool: &MySqlPool,
+    user_email: String,
+    device_name: Option<String>,
+    platform: Option<String>,
+) -> Result<JwtToken, OmniNewsError> {
+    let now = kst_now();
+    let user_id = find_user_id_by_email(pool, user_email.clone()).await?;
+
+    if let Err(e) = session_repository::delete_expired_user_sessions(pool, user_id, now).await {
+        user_warn!("[Service] Failed to delete expired sessions: {}", e);
+    }
+
     let (access_token, access_token_expires_at) =
         make_token(TokenType::Access, user_email.clone())?;
 
     let (refresh_token, refresh_token_expires_at) =
         make_token(TokenType::Refresh, user_email.clone())?;
 
-    let tokens = JwtToken::new(
-        access_token,
-        refresh_token,
-        access_token_expires_at,
-        refresh_token_expires_at,
-    );
-    match user_repository::update_uesr_tokens(pool, user_email, tokens.clone()).await {
-        Ok(_) => Ok(tokens),
+    let session = NewUserSession {
+        user_id: Some(user_id),
+        session_access_token_hash: Some(hash_token(&access_token)),
+        session_refresh_token_hash: Some(hash_token(&refresh_token)),
+        session_access_token_expires_at: Some(access_token_expires_at),
+        session_refresh_token_expires_at: Some(refresh_token_expires_at),
+        session_device_name: device_name
+            .map(|name| {
+                name.trim()
+                    .chars()
+                    .take(MAX_DEVICE_NAME_LEN)
+                    .collect::<String>()
+            })
+            .filter(|name| !name.is_empty()),
+        session_platform: platform.map(|p| p.chars().take(MAX_PLATFORM_LEN).collect()),
+        session_created_at: Some(now),
+    };
+
+    match session_repository::insert_user_session(pool, session).await {
+        Ok(_) => {
+            if let Err(e) =
+                session_repository::delete_old_user_sessions(pool, user_id, MAX_SESSIONS_PER_USER)
+                    .await
+            {
+                user_warn!("[Service] Failed to delete old sessions: {}", e);
+            }
+            Ok(JwtToken::new(
+                access_token,
+                refresh_token,
+                access_token_expires_at,
+                refresh_token_expires_at,
+            ))
+        }
         Err(e) => {
-            user_error!("[Service] Failed to update user tokens: {}", e);
+            user_error!("[Service] Failed to create user session: {}", e);
             Err(OmniNewsError::Database(e))
         }
     }
@@ -275,6 +304,7 @@ fn make_token(
                 sub: sub.clone(),
                 company: "kdh".to_owned(),
                 exp: access_token_exp,
+                jti: Uuid::new_v4().simple().to_string(),
             };
 
             let access_token = match encode(
@@ -306,6 +336,7 @@ fn make_token(
                 sub,
                 company: "kdh".to_owned(),
                 exp: refresh_token_exp,
+                jti: Uuid::new_v4().simple().to_string(),
             };
 
             let refresh_token = match encode(
@@ -353,6 +384,7 @@ pub async fn apple_login(
             user_social_login_provider: Some("apple".to_string()),
             user_social_provider_id: apple_login.user_social_provider_id,
             user_platform: Some("ios".to_string()),
+            user_device_name: apple_login.user_device_name,
         },
     )
     .await
@@ -390,8 +422,38 @@ pub async fn validate_premium_user(
     omninews_subscription_service::verify_is_subscribed_user(pool, user_email).await
 }
 
-pub async fn delete_user_token(pool: &MySqlPool, user_email: String) -> Result<(), OmniNewsError> {
-    match user_repository::delete_user_token_by_email(pool, user_email).await {
+/// `user_role`이 admin인 사용자
+pub async fn validate_admin_user(
+    pool: &MySqlPool,
+    user_email: &str,
+) -> Result<bool, OmniNewsError> {
+    match user_repository::select_user_role_by_email(pool, user_email).await {
+        Ok(role) => Ok(role.as_deref() == Some("admin")),
+        Err(e) => {
+            user_error!("[Service] Failed to find user role: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 로그아웃, `session_id`가 없으면 모든 세션 삭제
+pub async fn delete_user_token(
+    pool: &MySqlPool,
+    user_email: String,
+    session_id: Option<i32>,
+) -> Result<(), OmniNewsError> {
+    let user_id = find_user_id_by_email(pool, user_email).await?;
+
+    let result = match session_id {
+        Some(session_id) => session_repository::delete_user_session(pool, user_id, session_id)
+            .await
+            .map(|_| ()),
+        None => session_repository::delete_user_sessions(pool, user_id, None)
+            .await
+            .map(|_| ()),
+    };
+
+    match result {
         Ok(_) => Ok(()),
         Err(e) => {
             user_error!("[Service] Failed to remove user token: {}", e);
@@ -399,6 +461,61 @@ pub async fn delete_user_token(pool: &MySqlPool, user_email: String) -> Result<(
         }
     }
 }
+
+/// 로그인된 기기 목록 (최근 사용순)
+pub async fn get_sessions(
+    pool: &MySqlPool,
+    user_email: String,
+    current_session_id: Option<i32>,
+) -> Result<Vec<SessionResponseDto>, OmniNewsError> {
+    let user_id = find_user_id_by_email(pool, user_email).await?;
+
+    match session_repository::select_user_sessions(pool, user_id, kst_now()).await {
+        Ok(res) => Ok(res
+            .into_iter()
+            .map(|session| SessionResponseDto::from_model(session, current_session_id))
+            .collect()),
+        Err(e) => {
+            user_error!("[Service] Failed to find user sessions: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+pub async fn revoke_session(
+    pool: &MySqlPool,
+    user_email: String,
+    session_id: i32,
+) -> Result<bool, OmniNewsError> {
+    let user_id = find_user_id_by_email(pool, user_email).await?;
+
+    match session_repository::delete_user_session(pool, user_id, session_id).await {
+        Ok(true) => Ok(true),
+        Ok(false) => Err(OmniNewsError::NotFound("session not found".into())),
+        Err(e) => {
+            user_error!("[Service] Failed to revoke user session: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
+/// 현재 세션을 제외한 모든 세션 로그아웃, 삭제한 세션 수 반환
+pub async fn revoke_other_sessions(
+    pool: &MySqlPool,
+    user_email: String,
+    current_session_id: Option<i32>,
+) -> Result<u64, OmniNewsError> {
+    let user_id = find_user_id_by_email(pool, user_email).await?;
+
+    match session_repository::delete_user_sessions(pool, user_id, current_session_id).await {
+        Ok(res) => Ok(res),
+        Err(e) => {
+            user_error!("[Service] Failed to revoke user sessions: {}", e);
+            Err(OmniNewsError::Database(e))
+        }
+    }
+}
+
 pub async fn update_user_notification_setting(
     pool: &MySqlPool,
     user_email: String,
@@ -461,3 +578,7 @@ pub async fn remove_user(pool: &MySqlPool, user_email: String) -> Result<bool, O
         }
     }
 }
+
+fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
@@ -69,6 +69,15 @@ pub async fn load_rss_annoy(
     Ok((filtered_ids, filtered_distances))
 }
 
+/// 임베딩 벡터로 rss 인덱스를 조회합니다. 반환되는 id는 embedding_id입니다.
+pub fn find_rss_annoy_by_vector(vector: Vec<f32>, n: i32) -> Vec<i32> {
+    let annoy = rannoy::Rannoy::new(384);
+    annoy.load(PathBuf::from("../resources/rss_embeddings.ann"));
+
+    let (result_ids, _) = annoy.get_nns_by_vector(vector, n, 10000);
+    result_ids
+}
+
 #[allow(dead_code)]
 pub async fn load_news_annoy(
     service: &State<EmbeddingService>,
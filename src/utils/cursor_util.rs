@@ -0,0 +1,221 @@
+// 아이템 목록 커서 페이지네이션 유틸
+// 커서는 마지막으로 받은 아이템의 정렬 키를 base64로 감싼 문자열이며, 클라이언트는 내용을 해석하지 않고 그대로 돌려보냄.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+use crate::model::{error::OmniNewsError, rss::RssItem};
+
+pub const DEFAULT_PAGE_SIZE: i32 = 20;
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+/// 요청한 페이지 크기를 허용 범위로 맞춥니다.
+pub fn page_size(size: Option<i32>) -> i32 {
+    size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+/// 아이템 정렬 위치
+///
+/// `PubDate`는 (rss_pub_date, rss_id) 내림차순, `Rank`는 (rss_rank, rss_id) 내림차순 목록에서 사용합니다.
+/// 발행일이 없는 아이템은 가장 뒤에 위치하므로 `pub_date`가 None일 수 있습니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemCursor {
+    PubDate {
+        pub_date: Option<NaiveDateTime>,
+        rss_id: i32,
+    },
+    Rank {
+        rss_rank: i32,
+        rss_id: i32,
+    },
+    /// 생성 순서(id 내림차순) 목록용 (예: 북마크)
+    Id {
+        id: i32,
+    },
+}
+
+impl ItemCursor {
+    /// 첫 페이지 조회용 커서 (모든 아이템보다 앞)
+    pub fn first_by_pub_date() -> Self {
+        ItemCursor::PubDate {
+            pub_date: NaiveDate::from_ymd_opt(9999, 12, 31)
+                .and_then(|date| date.and_hms_opt(23, 59, 59)),
+            rss_id: i32::MAX,
+        }
+    }
+
+    pub fn first_by_rank() -> Self {
+        ItemCursor::Rank {
+            rss_rank: i32::MAX,
+            rss_id: i32::MAX,
+        }
+    }
+
+    pub fn first_by_id() -> Self {
+        ItemCursor::Id { id: i32::MAX }
+    }
+
+    pub fn by_pub_date(item: &RssItem) -> Self {
+        ItemCursor::PubDate {
+            pub_date: item.rss_pub_date,
+            rss_id: item.rss_id.unwrap_or_default(),
+        }
+    }
+
+    pub fn by_rank(item: &RssItem) -> Self {
+        ItemCursor::Rank {
+            rss_rank: item.rss_rank.unwrap_or_default(),
+            rss_id: item.rss_id.unwrap_or_default(),
+        }
+    }
+
+    /// 예: PubDate(2025-01-01 09:00:00, 42) -> "ZDoxNzM1NzIyMDAwMDAwOjQy"
+    pub fn encode(&self) -> String {
+        let raw = match self {
+            ItemCursor::PubDate { pub_date, rss_id } => format!(
+                "d:{}:{rss_id}",
+                pub_date.map_or("-".to_string(), |date| date
+                    .and_utc()
+                    .timestamp_millis()
+                    .to_string())
+            ),
+            ItemCursor::Rank { rss_rank, rss_id } => format!("r:{rss_rank}:{rss_id}"),
+            ItemCursor::Id { id } => format!("i:{id}"),
+        };
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, OmniNewsError> {
+        let invalid = || OmniNewsError::InvalidValue(format!("Invalid cursor: {cursor}"));
+
+        let raw = URL_SAFE_NO_PAD
+            .decode(cursor.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+        if let Some(id) = raw.strip_prefix("i:") {
+            return Ok(ItemCursor::Id {
+                id: id.parse().map_err(|_| invalid())?,
+            });
+        }
+
+        let mut parts = raw.splitn(3, ':');
+        let (Some(kind), Some(key), Some(rss_id)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let rss_id: i32 = rss_id.parse().map_err(|_| invalid())?;
+
+        match kind {
+            "d" if key == "-" => Ok(ItemCursor::PubDate {
+                pub_date: None,
+                rss_id,
+            }),
+            "d" => {
+                let millis: i64 = key.parse().map_err(|_| invalid())?;
+                let pub_date = DateTime::from_timestamp_millis(millis)
+                    .ok_or_else(invalid)?
+                    .naive_utc();
+                Ok(ItemCursor::PubDate {
+                    pub_date: Some(pub_date),
+                    rss_id,
+                })
+            }
+            "r" => Ok(ItemCursor::Rank {
+                rss_rank: key.parse().map_err(|_| invalid())?,
+                rss_id,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// `limit + 1`개를 조회한 결과에서 페이지와 다음 커서를 나눕니다.
+pub fn split_page<T>(
+    mut items: Vec<T>,
+    limit: i32,
+    to_cursor: fn(&T) -> ItemCursor,
+) -> (Vec<T>, Option<String>) {
+    let limit = limit as usize;
+    if items.len() <= limit {
+        return (items, None);
+    }
+    items.truncate(limit);
+    let next_cursor = items.last().map(|item| to_cursor(item).encode());
+    (items, next_cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(cursor: ItemCursor) {
+        assert_eq!(ItemCursor::decode(&cursor.encode()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn encode_decode_round_trips() {
+        round_trip(ItemCursor::PubDate {
+            pub_date: NaiveDate::from_ymd_opt(2025, 1, 1)
+                .and_then(|date| date.and_hms_opt(9, 0, 0)),
+            rss_id: 42,
+        });
+        round_trip(ItemCursor::PubDate {
+            pub_date: None,
+            rss_id: 7,
+        });
+        round_trip(ItemCursor::Rank {
+            rss_rank: -3,
+            rss_id: 5,
+        });
+        round_trip(ItemCursor::Id { id: 11 });
+        round_trip(ItemCursor::first_by_pub_date());
+        round_trip(ItemCursor::first_by_rank());
+        round_trip(ItemCursor::first_by_id());
+    }
+
+    #[test]
+    fn encode_matches_documented_example() {
+        let cursor = ItemCursor::PubDate {
+            pub_date: NaiveDate::from_ymd_opt(2025, 1, 1)
+                .and_then(|date| date.and_hms_opt(9, 0, 0)),
+            rss_id: 42,
+        };
+        assert_eq!(cursor.encode(), "ZDoxNzM1NzIyMDAwMDAwOjQy");
+    }
+
+    #[test]
+    fn decode_rejects_invalid_cursors() {
+        for raw in ["", "not base64!", "x:1:2", "d:abc:1", "r:1", "r:1:x", "i:x"] {
+            let cursor = URL_SAFE_NO_PAD.encode(raw);
+            assert!(
+                matches!(
+                    ItemCursor::decode(&cursor),
+                    Err(OmniNewsError::InvalidValue(_))
+                ),
+                "{raw}"
+            );
+        }
+        assert!(ItemCursor::decode("!!!").is_err());
+    }
+
+    #[test]
+    fn page_size_is_clamped() {
+        assert_eq!(page_size(None), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(Some(0)), 1);
+        assert_eq!(page_size(Some(1000)), MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn split_page_returns_next_cursor_only_when_more() {
+        let to_cursor: fn(&i32) -> ItemCursor = |id| ItemCursor::Id { id: *id };
+
+        let (items, next) = split_page(vec![5, 4, 3], 3, to_cursor);
+        assert_eq!(items, vec![5, 4, 3]);
+        assert_eq!(next, None);
+
+        let (items, next) = split_page(vec![5, 4, 3], 2, to_cursor);
+        assert_eq!(items, vec![5, 4]);
+        assert_eq!(next, Some(ItemCursor::Id { id: 4 }.encode()));
+    }
+}
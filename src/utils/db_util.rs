@@ -20,3 +20,27 @@ pub async fn get_db(pool: &MySqlPool) -> Result<PoolConnection<MySql>, sqlx::Err
         e
     })
 }
+
+/// `LIKE` 패턴에 넣을 값의 와일드카드(`%`, `_`)와 이스케이프 문자를 이스케이프
+pub fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_like_escapes_wildcards_and_backslash() {
+        assert_eq!(escape_like("100%_done"), "100\\%\\_done");
+        assert_eq!(escape_like("a\\b"), "a\\\\b");
+        assert_eq!(escape_like("한국"), "한국");
+    }
+}
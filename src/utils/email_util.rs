@@ -0,0 +1,95 @@
+// SMTP로 메일을 보내는 유틸
+// 개발 환경은 로컬 SMTP(mailpit)로 보내 확인합니다. SMTP_TLS=none
+
+use std::env;
+
+use lettre::{
+    message::{
+        header::{HeaderName, HeaderValue},
+        Mailbox, MultiPart,
+    },
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::model::error::OmniNewsError;
+
+/// 보낼 메일. `unsubscribe_url`이 있으면 원클릭 수신 거부 헤더(RFC 8058)를 붙입니다.
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+    pub unsubscribe_url: Option<String>,
+}
+
+/// HTML과 텍스트 본문을 함께 보냅니다.
+pub async fn send_email(message: EmailMessage) -> Result<(), OmniNewsError> {
+    let from = env::var("SMTP_FROM")
+        .map_err(|_| OmniNewsError::Config("SMTP_FROM not set".to_string()))?
+        .parse::<Mailbox>()
+        .map_err(|e| OmniNewsError::Config(format!("Invalid SMTP_FROM: {e}")))?;
+    let to = message
+        .to
+        .parse::<Mailbox>()
+        .map_err(|e| OmniNewsError::InvalidValue(format!("Invalid recipient: {e}")))?;
+
+    let mut builder = Message::builder()
+        .from(from)
+        .to(to)
+        .subject(message.subject);
+    if let Some(url) = message.unsubscribe_url {
+        builder = builder
+            .raw_header(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                format!("<{url}>"),
+            ))
+            .raw_header(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                "List-Unsubscribe=One-Click".to_string(),
+            ));
+    }
+
+    let email = builder
+        .multipart(MultiPart::alternative_plain_html(
+            message.text_body,
+            message.html_body,
+        ))
+        .map_err(|e| OmniNewsError::Email(e.to_string()))?;
+
+    smtp_transport()?
+        .send(email)
+        .await
+        .map_err(|e| OmniNewsError::Email(e.to_string()))?;
+
+    Ok(())
+}
+
+fn smtp_transport() -> Result<AsyncSmtpTransport<Tokio1Executor>, OmniNewsError> {
+    let host = env::var("SMTP_HOST")
+        .map_err(|_| OmniNewsError::Config("SMTP_HOST not set".to_string()))?;
+    let tls = env::var("SMTP_TLS").unwrap_or_else(|_| "starttls".to_string());
+
+    let mut builder = match tls.as_str() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&host)
+            .map_err(|e| OmniNewsError::Email(e.to_string()))?,
+        "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+            .map_err(|e| OmniNewsError::Email(e.to_string()))?,
+        "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&host),
+        _ => return Err(OmniNewsError::Config(format!("Invalid SMTP_TLS: {tls}"))),
+    };
+
+    if let Some(port) = env::var("SMTP_PORT")
+        .ok()
+        .and_then(|p| p.parse::<u16>().ok())
+    {
+        builder = builder.port(port);
+    }
+    if let (Ok(username), Ok(password)) = (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD")) {
+        if !username.is_empty() {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+    }
+
+    Ok(builder.build())
+}
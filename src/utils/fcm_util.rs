@@ -0,0 +1,182 @@
+// FCM HTTP v1 API로 푸시를 보내는 유틸
+// 서비스 계정 파일(FCM_CREDENTIALS_PATH)로 OAuth 토큰을 받아 캐시합니다.
+// FCM_BASE_URL과 서비스 계정의 token_uri를 바꾸면 로컬 모의 서버로 보낼 수 있습니다.
+
+use std::{
+    collections::HashMap,
+    env, fs,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::model::error::OmniNewsError;
+
+const DEFAULT_FCM_BASE_URL: &str = "https://fcm.googleapis.com";
+const DEFAULT_CREDENTIALS_PATH: &str = "omninews_firebase_sdk.json";
+const FCM_SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+const TOKEN_LIFETIME_SECS: u64 = 60 * 60;
+// 만료 직전 토큰은 다시 발급
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+static ACCESS_TOKEN: Mutex<Option<(String, Instant)>> = Mutex::new(None);
+
+#[derive(Deserialize)]
+struct ServiceAccount {
+    project_id: String,
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Serialize)]
+struct ServiceAccountClaims {
+    iss: String,
+    scope: String,
+    aud: String,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+pub struct PushMessage {
+    pub token: String,
+    pub title: String,
+    pub body: String,
+    pub data: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FcmSendResult {
+    Sent,
+    /// 앱 삭제 등으로 더 이상 쓸 수 없는 토큰
+    InvalidToken,
+}
+
+pub async fn send_push(message: PushMessage) -> Result<FcmSendResult, OmniNewsError> {
+    let account = load_service_account()?;
+    let access_token = access_token(&account).await?;
+    let base_url = env::var("FCM_BASE_URL").unwrap_or_else(|_| DEFAULT_FCM_BASE_URL.to_string());
+
+    let body = json!({
+        "message": {
+            "token": message.token,
+            "notification": {
+                "title": message.title,
+                "body": message.body,
+            },
+            "data": message.data,
+        }
+    });
+
+    let res = Client::new()
+        .post(format!(
+            "{}/v1/projects/{}/messages:send",
+            base_url.trim_end_matches('/'),
+            account.project_id
+        ))
+        .bearer_auth(access_token)
+        .json(&body)
+        .send()
+        .await?;
+
+    let status = res.status();
+    if status.is_success() {
+        return Ok(FcmSendResult::Sent);
+    }
+
+    let error = res.json::<Value>().await.unwrap_or_default();
+    if is_invalid_token(status, &error) {
+        return Ok(FcmSendResult::InvalidToken);
+    }
+    if status == StatusCode::UNAUTHORIZED {
+        *ACCESS_TOKEN.lock().unwrap() = None;
+    }
+    Err(OmniNewsError::Config(format!(
+        "FCM send failed: {status} {error}"
+    )))
+}
+
+// UNREGISTERED(404)이거나 토큰 형식이 잘못된 INVALID_ARGUMENT(400)
+fn is_invalid_token(status: StatusCode, error: &Value) -> bool {
+    let error_code = error["error"]["details"]
+        .as_array()
+        .and_then(|details| {
+            details
+                .iter()
+                .find_map(|detail| detail["errorCode"].as_str())
+        })
+        .unwrap_or_default();
+
+    match error_code {
+        "UNREGISTERED" => true,
+        "INVALID_ARGUMENT" => {
+            status == StatusCode::BAD_REQUEST
+                && error["error"]["message"]
+                    .as_str()
+                    .is_some_and(|message| message.contains("registration token"))
+        }
+        _ => false,
+    }
+}
+
+fn load_service_account() -> Result<ServiceAccount, OmniNewsError> {
+    let path =
+        env::var("FCM_CREDENTIALS_PATH").unwrap_or_else(|_| DEFAULT_CREDENTIALS_PATH.to_string());
+    let content = fs::read_to_string(&path)
+        .map_err(|e| OmniNewsError::Config(format!("Failed to read {path}: {e}")))?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| OmniNewsError::Config(format!("Invalid FCM credentials: {e}")))
+}
+
+async fn access_token(account: &ServiceAccount) -> Result<String, OmniNewsError> {
+    if let Some((token, expires_at)) = ACCESS_TOKEN.lock().unwrap().clone() {
+        if Instant::now() + TOKEN_REFRESH_MARGIN < expires_at {
+            return Ok(token);
+        }
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let claims = ServiceAccountClaims {
+        iss: account.client_email.clone(),
+        scope: FCM_SCOPE.to_string(),
+        aud: account.token_uri.clone(),
+        iat: now,
+        exp: now + TOKEN_LIFETIME_SECS,
+    };
+    let encoding_key = EncodingKey::from_rsa_pem(account.private_key.as_bytes())
+        .map_err(|_| OmniNewsError::Config("Invalid FCM private key".into()))?;
+    let assertion = encode(&Header::new(Algorithm::RS256), &claims, &encoding_key)
+        .map_err(|_| OmniNewsError::TokenCreateError)?;
+
+    let res = Client::new()
+        .post(&account.token_uri)
+        .form(&[
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", assertion.as_str()),
+        ])
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<AccessTokenResponse>()
+        .await?;
+
+    let expires_at =
+        Instant::now() + Duration::from_secs(res.expires_in.unwrap_or(TOKEN_LIFETIME_SECS));
+    *ACCESS_TOKEN.lock().unwrap() = Some((res.access_token.clone(), expires_at));
+
+    Ok(res.access_token)
+}
@@ -0,0 +1,256 @@
+// 내부 데이터를 RSS/Atom/JSON 피드로 내보내는 유틸
+
+use std::env;
+
+use chrono::{FixedOffset, NaiveDateTime, Utc};
+use quick_xml::se::to_string_with_root;
+use reqwest::Url;
+use rss::{ChannelBuilder, GuidBuilder, Item, ItemBuilder};
+use serde::Serialize;
+
+use crate::{
+    model::{error::OmniNewsError, rss::RssItem},
+    CURRENT_VERSION,
+};
+
+/// 외부에서 접근 가능한 피드 URL을 만듭니다.
+/// 예: "/search/saved/feed/abc" -> "https://api.example.com/v1/api/search/saved/feed/abc"
+pub fn public_feed_url(path: &str) -> String {
+    let base_url = env::var("PUBLIC_BASE_URL").unwrap_or_default();
+    format!(
+        "{}/{CURRENT_VERSION}/api{}",
+        base_url.trim_end_matches('/'),
+        path
+    )
+}
+
+/// http, https 링크만 남깁니다. 피드가 준 `javascript:` 등의 링크는 None
+pub fn safe_link(link: Option<String>) -> Option<String> {
+    link.filter(|link| {
+        Url::parse(link.trim()).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+    })
+}
+
+/// 내보낼 피드 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+/// 아이템 목록을 RSS 2.0 XML로 변환합니다.
+pub fn build_rss_feed(title: &str, link: &str, description: &str, items: Vec<RssItem>) -> String {
+    let items = items.into_iter().map(to_rss_item).collect::<Vec<Item>>();
+
+    ChannelBuilder::default()
+        .title(title)
+        .link(link)
+        .description(description)
+        .generator("Omninews".to_string())
+        .items(items)
+        .build()
+        .to_string()
+}
+
+/// 아이템 목록을 Atom 1.0 XML로 변환합니다.
+pub fn build_atom_feed(
+    title: &str,
+    link: &str,
+    self_link: &str,
+    items: Vec<RssItem>,
+) -> Result<String, OmniNewsError> {
+    let updated = items
+        .iter()
+        .filter_map(|item| item.rss_pub_date)
+        .max()
+        .and_then(to_rfc3339)
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+
+    let feed = AtomFeed {
+        xmlns: "http://www.w3.org/2005/Atom",
+        title: title.to_string(),
+        id: self_link.to_string(),
+        updated: updated.clone(),
+        link: vec![
+            AtomLink {
+                href: self_link.to_string(),
+                rel: Some("self"),
+            },
+            AtomLink {
+                href: link.to_string(),
+                rel: Some("alternate"),
+            },
+        ],
+        author: AtomPerson {
+            name: "Omninews".to_string(),
+        },
+        generator: "Omninews",
+        entry: items
+            .into_iter()
+            .map(|item| to_atom_entry(item, &updated))
+            .collect(),
+    };
+
+    match to_string_with_root("feed", &feed) {
+        Ok(xml) => Ok(format!(r#"<?xml version="1.0" encoding="UTF-8"?>{xml}"#)),
+        Err(e) => Err(OmniNewsError::Config(format!(
+            "Failed to build Atom feed: {e}"
+        ))),
+    }
+}
+
+/// 아이템 목록을 JSON Feed 1.1로 변환합니다.
+pub fn build_json_feed(
+    title: &str,
+    link: &str,
+    feed_url: &str,
+    items: Vec<RssItem>,
+) -> Result<String, OmniNewsError> {
+    let feed = JsonFeed {
+        version: "https://jsonfeed.org/version/1.1",
+        title: title.to_string(),
+        home_page_url: link.to_string(),
+        feed_url: feed_url.to_string(),
+        items: items.into_iter().map(to_json_feed_item).collect(),
+    };
+
+    serde_json::to_string(&feed)
+        .map_err(|e| OmniNewsError::Config(format!("Failed to build JSON feed: {e}")))
+}
+
+#[derive(Serialize)]
+struct JsonFeed {
+    version: &'static str,
+    title: String,
+    home_page_url: String,
+    feed_url: String,
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(Serialize)]
+struct JsonFeedItem {
+    id: String,
+    url: Option<String>,
+    title: Option<String>,
+    content_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_published: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<AtomPerson>,
+}
+
+// JSON Feed는 id, content_text가 필수
+fn to_json_feed_item(item: RssItem) -> JsonFeedItem {
+    JsonFeedItem {
+        id: item
+            .rss_link
+            .clone()
+            .unwrap_or_else(|| item.rss_id.unwrap_or_default().to_string()),
+        url: item.rss_link,
+        title: item.rss_title,
+        content_text: item.rss_description.unwrap_or_default(),
+        image: item.rss_image_link.filter(|image| !image.is_empty()),
+        date_published: item.rss_pub_date.and_then(to_rfc3339),
+        authors: item
+            .rss_author
+            .filter(|author| !author.is_empty())
+            .map(|name| AtomPerson { name })
+            .into_iter()
+            .collect(),
+    }
+}
+
+#[derive(Serialize)]
+struct AtomFeed {
+    #[serde(rename = "@xmlns")]
+    xmlns: &'static str,
+    title: String,
+    id: String,
+    updated: String,
+    link: Vec<AtomLink>,
+    author: AtomPerson,
+    generator: &'static str,
+    entry: Vec<AtomEntry>,
+}
+
+#[derive(Serialize)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+    #[serde(rename = "@rel", skip_serializing_if = "Option::is_none")]
+    rel: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct AtomPerson {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct AtomEntry {
+    title: String,
+    id: String,
+    link: AtomLink,
+    updated: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<AtomPerson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+}
+
+// 발행일이 없는 아이템은 피드 갱신 시각을 사용
+fn to_atom_entry(item: RssItem, feed_updated: &str) -> AtomEntry {
+    let link = item.rss_link.unwrap_or_default();
+
+    AtomEntry {
+        title: item.rss_title.unwrap_or_default(),
+        id: link.clone(),
+        link: AtomLink {
+            href: link,
+            rel: None,
+        },
+        updated: item
+            .rss_pub_date
+            .and_then(to_rfc3339)
+            .unwrap_or_else(|| feed_updated.to_string()),
+        author: item
+            .rss_author
+            .filter(|author| !author.is_empty())
+            .map(|name| AtomPerson { name }),
+        summary: item.rss_description.filter(|desc| !desc.is_empty()),
+    }
+}
+
+fn to_rss_item(item: RssItem) -> Item {
+    let guid = GuidBuilder::default()
+        .value(item.rss_link.clone().unwrap_or_default())
+        .permalink(true)
+        .build();
+
+    ItemBuilder::default()
+        .title(item.rss_title)
+        .description(item.rss_description)
+        .link(item.rss_link)
+        .author(item.rss_author)
+        .pub_date(item.rss_pub_date.and_then(to_rfc2822))
+        .guid(guid)
+        .build()
+}
+
+// DB에는 KST 기준으로 저장됨
+fn to_rfc2822(pub_date: NaiveDateTime) -> Option<String> {
+    pub_date
+        .and_local_timezone(FixedOffset::east_opt(9 * 3600).unwrap())
+        .single()
+        .map(|date| date.to_rfc2822())
+}
+
+fn to_rfc3339(pub_date: NaiveDateTime) -> Option<String> {
+    pub_date
+        .and_local_timezone(FixedOffset::east_opt(9 * 3600).unwrap())
+        .single()
+        .map(|date| date.to_rfc3339())
+}
@@ -62,6 +62,9 @@ struct Parts {
     text: String,
 }
 
+/// 요약에 실패했을 때 반환하는 문구
+pub const SUMMARY_FAILED_TEXT: &str = "본문 내용을 요약할 수 없습니다.";
+
 pub async fn gemini_summarize(summarize_num: i32, phrase: &str) -> String {
     let prompt = format!(
         "아래 내용을 요약에 어울리는 객관적 서술체(‘~한다’, ‘~로 보인다’, ‘~라고 밝혔다’)로 요약해 주세요. \
@@ -96,19 +99,19 @@ pub async fn gemini_summarize(summarize_num: i32, phrase: &str) -> String {
                     }
                     Err(e) => {
                         eprintln!("❌ JSON 파싱 실패: {e}");
-                        "본문 내용을 요약할 수 없습니다.".to_string()
+                        SUMMARY_FAILED_TEXT.to_string()
                     }
                 }
             } else {
                 let status = resp.status();
                 let body = resp.text().await.unwrap_or_default();
                 eprintln!("❌ llama-server 응답 오류: {status} - {body}");
-                "본문 내용을 요약할 수 없습니다.".to_string()
+                SUMMARY_FAILED_TEXT.to_string()
             }
         }
         Err(e) => {
             eprintln!("❌ 요청 실패: {e}");
-            "본문 내용을 요약할 수 없습니다.".to_string()
+            SUMMARY_FAILED_TEXT.to_string()
         }
     }
 }
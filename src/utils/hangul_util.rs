@@ -0,0 +1,289 @@
+// 한글 음절 -> 호환 자모 분해 유틸
+// 타이핑 중인 음절("하" -> "한", "ㄱ" -> "가")도 prefix 매칭되도록 겹모음, 겹받침까지 분해함.
+
+const HANGUL_SYLLABLE_START: u32 = 0xAC00;
+const HANGUL_SYLLABLE_END: u32 = 0xD7A3;
+
+const CHOSEONG: [&str; 19] = [
+    "ㄱ", "ㄲ", "ㄴ", "ㄷ", "ㄸ", "ㄹ", "ㅁ", "ㅂ", "ㅃ", "ㅅ", "ㅆ", "ㅇ", "ㅈ", "ㅉ", "ㅊ", "ㅋ",
+    "ㅌ", "ㅍ", "ㅎ",
+];
+
+const JUNGSEONG: [&str; 21] = [
+    "ㅏ", "ㅐ", "ㅑ", "ㅒ", "ㅓ", "ㅔ", "ㅕ", "ㅖ", "ㅗ", "ㅗㅏ", "ㅗㅐ", "ㅗㅣ", "ㅛ", "ㅜ",
+    "ㅜㅓ", "ㅜㅔ", "ㅜㅣ", "ㅠ", "ㅡ", "ㅡㅣ", "ㅣ",
+];
+
+const JONGSEONG: [&str; 28] = [
+    "", "ㄱ", "ㄲ", "ㄱㅅ", "ㄴ", "ㄴㅈ", "ㄴㅎ", "ㄷ", "ㄹ", "ㄹㄱ", "ㄹㅁ", "ㄹㅂ", "ㄹㅅ",
+    "ㄹㅌ", "ㄹㅍ", "ㄹㅎ", "ㅁ", "ㅂ", "ㅂㅅ", "ㅅ", "ㅆ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ",
+    "ㅎ",
+];
+
+pub fn is_hangul_syllable(c: char) -> bool {
+    (HANGUL_SYLLABLE_START..=HANGUL_SYLLABLE_END).contains(&(c as u32))
+}
+
+pub fn is_hangul_jamo(c: char) -> bool {
+    (0x3131..=0x318E).contains(&(c as u32))
+}
+
+// 단독으로 입력된 겹자모 분해
+fn decompose_compat_jamo(c: char) -> Option<&'static str> {
+    match c {
+        'ㄳ' => Some("ㄱㅅ"),
+        'ㄵ' => Some("ㄴㅈ"),
+        'ㄶ' => Some("ㄴㅎ"),
+        'ㄺ' => Some("ㄹㄱ"),
+        'ㄻ' => Some("ㄹㅁ"),
+        'ㄼ' => Some("ㄹㅂ"),
+        'ㄽ' => Some("ㄹㅅ"),
+        'ㄾ' => Some("ㄹㅌ"),
+        'ㄿ' => Some("ㄹㅍ"),
+        'ㅀ' => Some("ㄹㅎ"),
+        'ㅄ' => Some("ㅂㅅ"),
+        'ㅘ' => Some("ㅗㅏ"),
+        'ㅙ' => Some("ㅗㅐ"),
+        'ㅚ' => Some("ㅗㅣ"),
+        'ㅝ' => Some("ㅜㅓ"),
+        'ㅞ' => Some("ㅜㅔ"),
+        'ㅟ' => Some("ㅜㅣ"),
+        'ㅢ' => Some("ㅡㅣ"),
+        _ => None,
+    }
+}
+
+/// 문자열을 자모 단위로 분해합니다. 한글 외 문자는 소문자로 변환합니다.
+/// 예: "한국" -> "ㅎㅏㄴㄱㅜㄱ", "과" -> "ㄱㅗㅏ"
+pub fn decompose_jamo(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() * 3);
+
+    for c in text.chars() {
+        if is_hangul_syllable(c) {
+            let index = c as u32 - HANGUL_SYLLABLE_START;
+            let cho = (index / 588) as usize;
+            let jung = ((index % 588) / 28) as usize;
+            let jong = (index % 28) as usize;
+
+            result.push_str(CHOSEONG[cho]);
+            result.push_str(JUNGSEONG[jung]);
+            result.push_str(JONGSEONG[jong]);
+        } else if let Some(jamo) = decompose_compat_jamo(c) {
+            result.push_str(jamo);
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+
+    result
+}
+
+/// 입력 중인 검색어가 후보 문자열의 자모 prefix인지 확인합니다.
+/// 예: "한ㄱ", "하", "한구" 모두 "한국"에 매칭됩니다.
+pub fn is_jamo_prefix(query: &str, candidate: &str) -> bool {
+    let query = decompose_jamo(query.trim());
+    if query.is_empty() {
+        return false;
+    }
+    decompose_jamo(candidate.trim()).starts_with(&query)
+}
+
+/// 후보 문자열의 단어 중 하나라도 자모 prefix로 매칭되는지 확인합니다.
+pub fn is_jamo_word_prefix(query: &str, candidate: &str) -> bool {
+    is_jamo_prefix(query, candidate)
+        || candidate
+            .split_whitespace()
+            .any(|word| is_jamo_prefix(query, word))
+}
+
+/// 입력 중인 검색어로 후보를 조회할 조건
+///
+/// `stem`은 완성된 앞부분이며 `LIKE 'stem%'`로 조회하고,
+/// 마지막 글자가 조합 중이면 `range`(`stem` + 시작 음절 이상, `stem` + 끝 음절 미만)로 더 좁힘
+#[derive(Debug, Clone, PartialEq)]
+pub struct JamoPrefixQuery {
+    pub stem: String,
+    pub range: Option<(String, String)>,
+}
+
+/// 입력 중인 검색어의 후보 조회 조건을 반환합니다.
+/// 마지막 글자가 한글이면 아직 조합 중일 수 있으므로 그 글자로 시작할 수 있는 음절 범위로 찾고
+/// ("하" -> 하~핳, 호 -> 호~횧(과, 화 포함), "ㄱ" -> 가~깋), 받침이나 자모가 붙어 있으면
+/// 다음 글자의 초성일 수 있으므로 받침 없는 음절부터 찾습니다 ("한" -> 하~핳, "한ㄱ" -> 하~핳).
+/// 초성이 될 수 없는 자모(모음 등)만 있으면 후보를 좁힐 수 없어 None을 반환합니다.
+/// 예: "AI" -> stem "AI", "한국" -> stem "한", 범위 "한구" 이상 "한궈" 미만
+pub fn jamo_prefix_query(query: &str) -> Option<JamoPrefixQuery> {
+    let chars: Vec<char> = query.trim().chars().collect();
+    let last = *chars.last()?;
+
+    if !is_hangul_syllable(last) && !is_hangul_jamo(last) {
+        return Some(JamoPrefixQuery {
+            stem: chars.iter().collect(),
+            range: None,
+        });
+    }
+
+    let previous = chars.len().checked_sub(2).map(|i| chars[i]);
+    let (stem_len, first, end) = if is_hangul_syllable(last) {
+        let (cho, jung, jong) = syllable_indices(last);
+        let jung_end = if jong == 0 {
+            compound_jungseong_end(jung)
+        } else {
+            jung
+        };
+        (
+            chars.len() - 1,
+            compose(cho, jung, 0),
+            compose(cho, jung_end, 27),
+        )
+    } else if let Some(previous) = previous.filter(|&c| is_hangul_syllable(c)) {
+        // 앞 음절의 받침이거나 다음 음절의 초성
+        let (cho, jung, _) = syllable_indices(previous);
+        (
+            chars.len() - 2,
+            compose(cho, jung, 0),
+            compose(cho, jung, 27),
+        )
+    } else {
+        let cho = CHOSEONG
+            .iter()
+            .position(|choseong| choseong.starts_with(last))? as u32;
+        (chars.len() - 1, compose(cho, 0, 0), compose(cho, 20, 27))
+    };
+
+    let stem: String = chars[..stem_len].iter().collect();
+    let after_end = char::from_u32(end as u32 + 1)?;
+    Some(JamoPrefixQuery {
+        range: Some((format!("{stem}{first}"), format!("{stem}{after_end}"))),
+        stem,
+    })
+}
+
+fn syllable_indices(c: char) -> (u32, u32, u32) {
+    let index = c as u32 - HANGUL_SYLLABLE_START;
+    (index / 588, (index % 588) / 28, index % 28)
+}
+
+fn compose(cho: u32, jung: u32, jong: u32) -> char {
+    char::from_u32(HANGUL_SYLLABLE_START + cho * 588 + jung * 28 + jong).unwrap_or_default()
+}
+
+// 뒤에 모음이 더 붙을 수 있는 중성 ("ㅗ" -> "ㅘ", "ㅙ", "ㅚ")의 마지막 인덱스
+fn compound_jungseong_end(jung: u32) -> u32 {
+    match jung {
+        8 => 11,
+        13 => 16,
+        18 => 19,
+        _ => jung,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompose_jamo_splits_syllables_and_compound_jamo() {
+        assert_eq!(decompose_jamo("한국"), "ㅎㅏㄴㄱㅜㄱ");
+        assert_eq!(decompose_jamo("과"), "ㄱㅗㅏ");
+        assert_eq!(decompose_jamo("닭"), "ㄷㅏㄹㄱ");
+        assert_eq!(decompose_jamo("ㄺ"), "ㄹㄱ");
+        assert_eq!(decompose_jamo("AI 뉴스"), "ai ㄴㅠㅅㅡ");
+    }
+
+    #[test]
+    fn is_jamo_prefix_matches_syllables_being_typed() {
+        assert!(is_jamo_prefix("한ㄱ", "한국"));
+        assert!(is_jamo_prefix("하", "한국"));
+        assert!(is_jamo_prefix("한구", "한국"));
+        assert!(is_jamo_prefix("ㄱ", "가나"));
+        assert!(!is_jamo_prefix("국", "한국"));
+        assert!(!is_jamo_prefix(" ", "한국"));
+    }
+
+    #[test]
+    fn is_jamo_word_prefix_matches_any_word() {
+        assert!(is_jamo_word_prefix("경", "한국 경제"));
+        assert!(!is_jamo_word_prefix("정", "한국 경제"));
+    }
+
+    // SQL 조건(`LIKE 'stem%'`, 범위)과 자모 prefix 필터를 함께 적용한 결과
+    fn is_candidate(query: &str, candidate: &str) -> bool {
+        let Some(prefix) = jamo_prefix_query(query) else {
+            return false;
+        };
+        let in_range = prefix
+            .range
+            .as_ref()
+            .is_none_or(|(start, end)| candidate >= start.as_str() && candidate < end.as_str());
+        candidate.starts_with(&prefix.stem) && in_range && is_jamo_prefix(query, candidate)
+    }
+
+    #[test]
+    fn jamo_prefix_query_keeps_non_hangul_query_as_stem() {
+        assert_eq!(
+            jamo_prefix_query(" AI "),
+            Some(JamoPrefixQuery {
+                stem: "AI".to_string(),
+                range: None,
+            })
+        );
+    }
+
+    #[test]
+    fn jamo_prefix_query_uses_syllable_range_for_trailing_syllable() {
+        assert_eq!(
+            jamo_prefix_query("하"),
+            Some(JamoPrefixQuery {
+                stem: String::new(),
+                range: Some(("하".to_string(), "해".to_string())),
+            })
+        );
+        assert_eq!(
+            jamo_prefix_query("한국"),
+            Some(JamoPrefixQuery {
+                stem: "한".to_string(),
+                range: Some(("한구".to_string(), "한궈".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn jamo_prefix_query_uses_choseong_block_for_lone_jamo() {
+        assert_eq!(
+            jamo_prefix_query("ㄱ"),
+            Some(JamoPrefixQuery {
+                stem: String::new(),
+                range: Some(("가".to_string(), "까".to_string())),
+            })
+        );
+        assert_eq!(
+            jamo_prefix_query("AI ㄱ"),
+            Some(JamoPrefixQuery {
+                stem: "AI ".to_string(),
+                range: Some(("AI 가".to_string(), "AI 까".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn jamo_prefix_query_rejects_vowel_and_empty() {
+        assert_eq!(jamo_prefix_query("ㅏ"), None);
+        assert_eq!(jamo_prefix_query("   "), None);
+    }
+
+    #[test]
+    fn partially_typed_syllables_find_candidates() {
+        assert!(is_candidate("하", "한국"));
+        assert!(is_candidate("ㄱ", "국"));
+        assert!(is_candidate("한", "한국"));
+        assert!(is_candidate("한", "하나은행"));
+        assert!(is_candidate("한ㄱ", "한국"));
+        assert!(is_candidate("한구", "한국은행"));
+        assert!(is_candidate("호", "화학"));
+        assert!(is_candidate("AI", "AI 뉴스"));
+        assert!(!is_candidate("하", "허니"));
+        assert!(!is_candidate("ㄱ", "나라"));
+        assert!(!is_candidate("한국", "한강"));
+    }
+}
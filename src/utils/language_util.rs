@@ -0,0 +1,58 @@
+// 오프라인 언어 감지 유틸 (whatlang trigram 기반)
+// 저장 형식은 ISO 639-1 코드("ko", "en", "ja", "zh" ...)이며, 639-1 코드가 없는 언어는 639-3 코드를 사용함.
+
+use whatlang::{detect, Lang};
+
+// 이보다 짧은 텍스트는 감지 결과를 신뢰하지 않음
+const MIN_DETECT_CHARS: usize = 2;
+
+/// 텍스트의 언어 코드를 반환합니다. 신뢰할 수 없는 결과는 None을 반환합니다.
+/// 예: "한국은행 기준금리 동결" -> Some("ko")
+pub fn detect_language(text: &str) -> Option<String> {
+    let text = text.trim();
+    if text.chars().filter(|c| c.is_alphabetic()).count() < MIN_DETECT_CHARS {
+        return None;
+    }
+
+    let info = detect(text)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    Some(lang_to_code(info.lang()).to_string())
+}
+
+/// 피드의 `<language>` 값을 언어 코드로 정규화합니다.
+/// 예: "ko-KR" -> Some("ko"), "en_us" -> Some("en"), "None" -> None
+pub fn normalize_language_tag(tag: &str) -> Option<String> {
+    let primary = tag
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let is_code =
+        (2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic());
+    is_code.then_some(primary)
+}
+
+fn lang_to_code(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Kor => "ko",
+        Lang::Eng => "en",
+        Lang::Jpn => "ja",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Rus => "ru",
+        Lang::Por => "pt",
+        Lang::Ita => "it",
+        Lang::Vie => "vi",
+        Lang::Tha => "th",
+        Lang::Ind => "id",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        lang => lang.code(),
+    }
+}
@@ -1,4 +1,14 @@
 pub mod annoy_util;
+pub mod cursor_util;
 pub mod db_util;
+pub mod email_util;
 pub mod embedding_util;
+pub mod fcm_util;
+pub mod feed_util;
 pub mod gemini;
+pub mod hangul_util;
+pub mod language_util;
+pub mod opml_util;
+pub mod rate_limit_util;
+pub mod snippet_util;
+pub mod time_util;
@@ -0,0 +1,183 @@
+// OPML 가져오기/내보내기 유틸
+// 폴더는 하위 outline을 가진 outline, 채널은 `xmlUrl`을 가진 outline으로 표현하며 순서와 중첩을 그대로 유지함.
+
+use quick_xml::{de::from_str, se::to_string_with_root};
+use serde::{Deserialize, Serialize};
+
+use crate::model::error::OmniNewsError;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Opml {
+    #[serde(rename = "@version", default)]
+    version: String,
+    #[serde(default)]
+    head: OpmlHead,
+    body: OpmlBody,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct OpmlHead {
+    #[serde(default)]
+    title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OpmlBody {
+    #[serde(default)]
+    outline: Vec<OpmlOutline>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpmlOutline {
+    #[serde(rename = "@text", default)]
+    pub text: String,
+    #[serde(rename = "@title", skip_serializing_if = "Option::is_none", default)]
+    pub title: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none", default)]
+    pub outline_type: Option<String>,
+    #[serde(rename = "@xmlUrl", skip_serializing_if = "Option::is_none", default)]
+    pub xml_url: Option<String>,
+    #[serde(rename = "@htmlUrl", skip_serializing_if = "Option::is_none", default)]
+    pub html_url: Option<String>,
+    #[serde(rename = "outline", default)]
+    pub children: Vec<OpmlOutline>,
+}
+
+impl OpmlOutline {
+    pub fn folder(name: String, children: Vec<OpmlOutline>) -> Self {
+        Self {
+            text: name.clone(),
+            title: Some(name),
+            children,
+            ..Default::default()
+        }
+    }
+
+    pub fn feed(title: String, xml_url: String, html_url: Option<String>) -> Self {
+        Self {
+            text: title.clone(),
+            title: Some(title),
+            outline_type: Some("rss".to_string()),
+            xml_url: Some(xml_url),
+            html_url,
+            children: vec![],
+        }
+    }
+
+    /// `xmlUrl`이 없으면 폴더로 봄
+    pub fn is_feed(&self) -> bool {
+        self.xml_url
+            .as_deref()
+            .is_some_and(|url| !url.trim().is_empty())
+    }
+
+    /// 표시 이름 (`title`이 없으면 `text`)
+    pub fn name(&self) -> String {
+        self.title
+            .as_deref()
+            .filter(|title| !title.trim().is_empty())
+            .unwrap_or(&self.text)
+            .trim()
+            .to_string()
+    }
+}
+
+/// OPML 문서에서 최상위 outline 목록을 반환합니다.
+pub fn parse_opml(xml: &str) -> Result<Vec<OpmlOutline>, OmniNewsError> {
+    match from_str::<Opml>(xml) {
+        Ok(opml) => Ok(opml.body.outline),
+        Err(e) => Err(OmniNewsError::InvalidValue(format!("Invalid OPML: {e}"))),
+    }
+}
+
+/// outline 목록을 OPML 2.0 문서로 변환합니다.
+pub fn build_opml(title: &str, outlines: Vec<OpmlOutline>) -> Result<String, OmniNewsError> {
+    let opml = Opml {
+        version: "2.0".to_string(),
+        head: OpmlHead {
+            title: title.to_string(),
+        },
+        body: OpmlBody { outline: outlines },
+    };
+
+    match to_string_with_root("opml", &opml) {
+        Ok(xml) => Ok(format!(r#"<?xml version="1.0" encoding="UTF-8"?>{xml}"#)),
+        Err(e) => Err(OmniNewsError::Config(format!("Failed to build OPML: {e}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head><title>구독</title></head>
+  <body>
+    <outline text="경제" title="경제">
+      <outline text="한국경제" type="rss" xmlUrl="https://example.com/rss" htmlUrl="https://example.com"/>
+      <outline text="빈 주소" xmlUrl=" "/>
+    </outline>
+    <outline text="Tech News" type="rss" xmlUrl="https://tech.example.com/feed"/>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn parse_opml_keeps_folders_and_order() {
+        let outlines = parse_opml(OPML).unwrap();
+
+        assert_eq!(outlines.len(), 2);
+        assert!(!outlines[0].is_feed());
+        assert_eq!(outlines[0].name(), "경제");
+        assert_eq!(outlines[0].children.len(), 2);
+        assert!(outlines[0].children[0].is_feed());
+        assert_eq!(
+            outlines[0].children[0].xml_url.as_deref(),
+            Some("https://example.com/rss")
+        );
+        assert!(!outlines[0].children[1].is_feed());
+        assert!(outlines[1].is_feed());
+        assert_eq!(outlines[1].name(), "Tech News");
+    }
+
+    #[test]
+    fn parse_opml_rejects_invalid_xml() {
+        assert!(matches!(
+            parse_opml("<opml><body>"),
+            Err(OmniNewsError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn build_opml_round_trips() {
+        let outlines = vec![
+            OpmlOutline::folder(
+                "경제 & 금융".to_string(),
+                vec![OpmlOutline::feed(
+                    "한국경제".to_string(),
+                    "https://example.com/rss?a=1&b=2".to_string(),
+                    None,
+                )],
+            ),
+            OpmlOutline::feed(
+                "Tech".to_string(),
+                "https://tech.example.com/feed".to_string(),
+                Some("https://tech.example.com".to_string()),
+            ),
+        ];
+
+        let xml = build_opml("구독", outlines).unwrap();
+        let parsed = parse_opml(&xml).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].name(), "경제 & 금융");
+        assert_eq!(
+            parsed[0].children[0].xml_url.as_deref(),
+            Some("https://example.com/rss?a=1&b=2")
+        );
+        assert_eq!(
+            parsed[1].html_url.as_deref(),
+            Some("https://tech.example.com")
+        );
+    }
+}
@@ -0,0 +1,99 @@
+// 키별 요청 수를 고정 구간(window) 단위로 세는 인메모리 요청 제한 유틸
+// 서버 인스턴스마다 따로 세므로 대략적인 제한으로만 사용.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+// 기록된 키가 이보다 많아지면 만료된 구간을 정리 (구간마다 최대 한 번)
+const PRUNE_THRESHOLD: usize = 10_000;
+
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    state: Mutex<RateLimitState>,
+}
+
+struct RateLimitState {
+    hits: HashMap<String, (Instant, u32)>,
+    pruned_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            max_requests,
+            window,
+            state: Mutex::new(RateLimitState {
+                hits: HashMap::new(),
+                pruned_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// 요청을 기록하고 허용 여부를 반환합니다.
+    pub fn check(&self, key: &str) -> bool {
+        let now = Instant::now();
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        // 정리해도 키가 줄지 않는 경우 매 요청마다 전체를 훑지 않도록 구간마다 한 번만 정리
+        if state.hits.len() > PRUNE_THRESHOLD && now.duration_since(state.pruned_at) >= self.window
+        {
+            state
+                .hits
+                .retain(|_, (started_at, _)| now.duration_since(*started_at) < self.window);
+            state.pruned_at = now;
+        }
+
+        let (started_at, count) = state.hits.entry(key.to_string()).or_insert((now, 0));
+        if now.duration_since(*started_at) >= self.window {
+            *started_at = now;
+            *count = 0;
+        }
+        *count += 1;
+        *count <= self.max_requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_allows_up_to_max_requests_per_key() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+
+        assert!(limiter.check("a"));
+        assert!(limiter.check("a"));
+        assert!(!limiter.check("a"));
+        assert!(limiter.check("b"));
+    }
+
+    #[test]
+    fn check_resets_after_window() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(20));
+
+        assert!(limiter.check("a"));
+        assert!(!limiter.check("a"));
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(limiter.check("a"));
+    }
+
+    #[test]
+    fn check_prunes_expired_keys_once_per_window() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(20));
+        for i in 0..=PRUNE_THRESHOLD {
+            limiter.check(&i.to_string());
+        }
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert!(limiter.check("new"));
+        let state = limiter.state.lock().unwrap();
+        assert_eq!(state.hits.len(), 1);
+    }
+}
@@ -0,0 +1,283 @@
+// 검색 결과 하이라이트, 스니펫 유틸
+// 한글은 조사가 붙어 띄어쓰기 단위로 자를 수 없으므로 ("경제" -> "경제가") 부분 문자열로 매칭하고,
+// 바이트가 아닌 문자 단위로 잘라 한글이 깨지지 않게 함.
+// 피드 원문에 태그가 섞여 있을 수 있으므로 반환하는 문자열은 모두 HTML 이스케이프하고 하이라이트 마커만 태그로 남김.
+
+pub const HIGHLIGHT_START: &str = "<em>";
+pub const HIGHLIGHT_END: &str = "</em>";
+const ELLIPSIS: &str = "…";
+
+/// 검색어를 하이라이트할 단어 목록으로 나눕니다. (소문자, 중복 제거)
+pub fn search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace().map(|term| term.to_lowercase()) {
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    // 긴 단어를 먼저 매칭해야 "한국은행"이 "한국"보다 우선함
+    terms.sort_by_key(|term| std::cmp::Reverse(term.chars().count()));
+    terms
+}
+
+/// 매칭된 단어를 하이라이트 마커로 감쌉니다. 매칭이 없으면 None을 반환합니다.
+/// 예: ("한국은행 기준금리", ["금리"]) -> "한국은행 기준<em>금리</em>", ("<속보> 금리", ["금리"]) -> "&lt;속보&gt; <em>금리</em>"
+pub fn highlight(text: &str, terms: &[String]) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let ranges = match_ranges(&chars, terms);
+    if ranges.is_empty() {
+        return None;
+    }
+    Some(wrap_ranges(&chars, &ranges))
+}
+
+/// 처음 매칭된 위치 주변을 최대 `max_chars` 글자로 잘라 하이라이트합니다.
+pub fn lexical_snippet(text: &str, terms: &[String], max_chars: usize) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let ranges = match_ranges(&chars, terms);
+    let first = ranges.first()?;
+
+    // 매칭 위치가 앞쪽 1/3 정도에 오도록 자름
+    let start = first.0.saturating_sub(max_chars / 3);
+    let end = (start + max_chars).min(chars.len());
+    let start = end.saturating_sub(max_chars);
+
+    let window = &chars[start..end];
+    let window_ranges: Vec<(usize, usize)> = ranges
+        .iter()
+        .filter(|(s, e)| *s >= start && *e <= end)
+        .map(|(s, e)| (s - start, e - start))
+        .collect();
+
+    Some(format!(
+        "{}{}{}",
+        if start > 0 { ELLIPSIS } else { "" },
+        wrap_ranges(window, &window_ranges).trim(),
+        if end < chars.len() { ELLIPSIS } else { "" }
+    ))
+}
+
+/// 문장 단위로 나눕니다. 한글 문장 끝("다.", "요?"), 마침표, 줄바꿈을 기준으로 하며
+/// 소수점("3.5%")에서는 나누지 않습니다.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        current.push(c);
+
+        let next = chars.get(i + 1).copied();
+        let is_decimal_point = c == '.'
+            && i > 0
+            && chars[i - 1].is_ascii_digit()
+            && next.is_some_and(|n| n.is_ascii_digit());
+        let is_end = matches!(c, '.' | '?' | '!' | '。' | '…' | '\n') && !is_decimal_point;
+        // 닫는 따옴표, 괄호는 앞 문장에 붙임
+        let next_is_closing =
+            next.is_some_and(|n| matches!(n, '"' | '\'' | '”' | '’' | ')' | '」'));
+
+        if is_end && !next_is_closing {
+            push_sentence(&mut sentences, &mut current);
+        }
+    }
+    push_sentence(&mut sentences, &mut current);
+
+    sentences
+}
+
+/// 문자 최대 개수로 자릅니다.
+pub fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    format!(
+        "{}{ELLIPSIS}",
+        text.chars().take(max_chars).collect::<String>().trim_end()
+    )
+}
+
+/// 하이라이트 없이 반환하는 텍스트도 같은 형식이 되도록 이스케이프합니다.
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    push_escaped(&mut result, text.chars());
+    result
+}
+
+fn push_sentence(sentences: &mut Vec<String>, current: &mut String) {
+    let sentence = current.trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence.to_string());
+    }
+    current.clear();
+}
+
+// 대소문자 구분 없이 매칭되는 문자 구간 (겹치지 않음, 시작 위치 순)
+fn match_ranges(chars: &[char], terms: &[String]) -> Vec<(usize, usize)> {
+    let lower: Vec<char> = chars.iter().map(|&c| lowercase_char(c)).collect();
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for term in terms {
+        let term: Vec<char> = term.chars().map(lowercase_char).collect();
+        if term.is_empty() || term.len() > lower.len() {
+            continue;
+        }
+
+        let mut i = 0;
+        while i + term.len() <= lower.len() {
+            let end = i + term.len();
+            let overlaps = ranges.iter().any(|&(s, e)| i < e && s < end);
+            if !overlaps && lower[i..end] == term[..] {
+                ranges.push((i, end));
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    ranges.sort();
+    ranges
+}
+
+fn wrap_ranges(chars: &[char], ranges: &[(usize, usize)]) -> String {
+    let mut result = String::with_capacity(chars.len() * 3);
+    let mut last = 0;
+
+    for &(start, end) in ranges {
+        push_escaped(&mut result, chars[last..start].iter().copied());
+        result.push_str(HIGHLIGHT_START);
+        push_escaped(&mut result, chars[start..end].iter().copied());
+        result.push_str(HIGHLIGHT_END);
+        last = end;
+    }
+    push_escaped(&mut result, chars[last..].iter().copied());
+
+    result
+}
+
+fn push_escaped(result: &mut String, chars: impl Iterator<Item = char>) {
+    for c in chars {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+}
+
+// 길이가 바뀌지 않도록 소문자 변환 결과의 첫 글자만 사용
+fn lowercase_char(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(query: &str) -> Vec<String> {
+        search_terms(query)
+    }
+
+    #[test]
+    fn search_terms_dedups_lowercases_and_sorts_longest_first() {
+        assert_eq!(terms("한국 한국은행 AI ai"), vec!["한국은행", "한국", "ai"]);
+        assert!(terms("   ").is_empty());
+    }
+
+    #[test]
+    fn highlight_wraps_matches_case_insensitively() {
+        assert_eq!(
+            highlight("한국은행 기준금리", &terms("금리")),
+            Some("한국은행 기준<em>금리</em>".to_string())
+        );
+        assert_eq!(
+            highlight("OpenAI releases", &terms("openai")),
+            Some("<em>OpenAI</em> releases".to_string())
+        );
+        assert_eq!(highlight("한국은행", &terms("금리")), None);
+    }
+
+    #[test]
+    fn highlight_prefers_longer_terms() {
+        assert_eq!(
+            highlight("한국은행 한국", &terms("한국 한국은행")),
+            Some("<em>한국은행</em> <em>한국</em>".to_string())
+        );
+    }
+
+    #[test]
+    fn highlight_escapes_html_outside_markers() {
+        assert_eq!(
+            highlight("<속보> 금리 & 환율", &terms("금리")),
+            Some("&lt;속보&gt; <em>금리</em> &amp; 환율".to_string())
+        );
+        assert_eq!(
+            highlight("<b>", &terms("<b>")),
+            Some("<em>&lt;b&gt;</em>".to_string())
+        );
+    }
+
+    #[test]
+    fn lexical_snippet_cuts_around_first_match() {
+        let text = "가".repeat(30) + "금리" + &"나".repeat(30);
+        let snippet = lexical_snippet(&text, &terms("금리"), 12).unwrap();
+
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("<em>금리</em>"));
+        assert_eq!(
+            snippet
+                .replace("<em>", "")
+                .replace("</em>", "")
+                .trim_matches('…')
+                .chars()
+                .count(),
+            12
+        );
+    }
+
+    #[test]
+    fn lexical_snippet_without_match_is_none() {
+        assert_eq!(lexical_snippet("한국은행", &terms("금리"), 10), None);
+    }
+
+    #[test]
+    fn lexical_snippet_keeps_short_text_whole() {
+        assert_eq!(
+            lexical_snippet("기준금리 동결", &terms("금리"), 50),
+            Some("기준<em>금리</em> 동결".to_string())
+        );
+    }
+
+    #[test]
+    fn split_sentences_keeps_decimals_and_closing_quotes() {
+        assert_eq!(
+            split_sentences("금리가 3.5%로 올랐다. 총재는 \"동결한다.\"라고 했다. 다음은?\n끝"),
+            vec![
+                "금리가 3.5%로 올랐다.",
+                "총재는 \"동결한다.\"라고 했다.",
+                "다음은?",
+                "끝"
+            ]
+        );
+        assert!(split_sentences("  ").is_empty());
+    }
+
+    #[test]
+    fn truncate_chars_counts_characters_not_bytes() {
+        assert_eq!(truncate_chars("한국은행", 4), "한국은행");
+        assert_eq!(truncate_chars("한국은행 기준금리", 5), "한국은행…");
+    }
+
+    #[test]
+    fn escape_html_escapes_special_characters() {
+        assert_eq!(
+            escape_html(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+}
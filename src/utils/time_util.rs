@@ -0,0 +1,22 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+
+use crate::model::error::OmniNewsError;
+
+/// DB에 저장하는 시각은 모두 KST 기준
+pub fn kst_now() -> NaiveDateTime {
+    Utc::now()
+        .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+        .naive_local()
+}
+
+/// 초 단위 시각을 DB 기준(KST) 시각으로 변환
+pub fn kst_from_timestamp(secs: i64) -> Result<NaiveDateTime, OmniNewsError> {
+    match DateTime::from_timestamp(secs, 0) {
+        Some(date) => Ok(date
+            .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())
+            .naive_local()),
+        None => Err(OmniNewsError::InvalidValue(format!(
+            "Invalid timestamp: {secs}"
+        ))),
+    }
+}